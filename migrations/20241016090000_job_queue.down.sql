@@ -0,0 +1,16 @@
+-- Postgres cannot drop a value from an enum, so the `running` status is left in place. Any job
+-- that is running is put back in the queue.
+update
+  jobs
+set
+  status = 'pending'
+where
+  status = 'running';
+
+drop index if exists jobs_pending_idx;
+
+alter table jobs
+  drop column if exists heartbeat_at,
+  drop column if exists locked_until,
+  drop column if exists locked_by,
+  drop column if exists attempts;
@@ -0,0 +1,14 @@
+-- Jobs are now run by workers which claim them from the `jobs` table. A claimed job is `running`
+-- and is leased to a single worker until `locked_until`. Workers extend their lease with
+-- heartbeats while they work. Jobs whose lease has expired are re-queued.
+alter type job_status add value if not exists 'running' after 'pending';
+
+alter table jobs
+  add column if not exists attempts int not null default 0,
+  add column if not exists locked_by text,
+  add column if not exists locked_until timestamptz,
+  add column if not exists heartbeat_at timestamptz;
+
+create index if not exists jobs_pending_idx on jobs(created_at)
+where
+  status = 'pending';
@@ -0,0 +1,7 @@
+drop trigger if exists set_updated_at on job_items;
+
+drop table if exists job_items;
+
+drop type if exists job_item_status;
+
+drop type if exists job_item_kind;
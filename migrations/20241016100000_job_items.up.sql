@@ -0,0 +1,34 @@
+-- The kinds of records a job can touch
+create type job_item_kind as enum(
+  'volunteer',
+  'mentor',
+  'nonprofit'
+);
+
+-- The outcome of a job for a single record
+create type job_item_status as enum(
+  'pending',
+  'succeeded',
+  'skipped',
+  'failed'
+);
+
+-- Tracks the outcome of a job for each record it touches. `item_key` identifies the record within
+-- the job (for example, a volunteer's id for an export or their email for an import), and
+-- `entity_id` is the id of the record in Pantheon, once it exists.
+create table if not exists job_items(
+  id uuid not null default uuid_generate_v4() primary key,
+  created_at timestamptz not null default now(),
+  updated_at timestamptz,
+  job_id uuid not null references jobs(id) on delete cascade,
+  kind job_item_kind not null,
+  item_key text not null,
+  entity_id uuid,
+  label text,
+  status job_item_status not null default 'pending' ::job_item_status,
+  error text,
+  unique (job_id, kind, item_key)
+);
+
+select
+  trigger_updated_at('job_items');
@@ -0,0 +1 @@
+drop index if exists jobs_created_at_idx;
@@ -0,0 +1,2 @@
+-- Jobs are listed newest first, a page at a time.
+create index if not exists jobs_created_at_idx on jobs(created_at desc, id desc);
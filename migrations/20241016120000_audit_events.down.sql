@@ -0,0 +1,7 @@
+drop index if exists audit_events_target_ids_idx;
+
+drop index if exists audit_events_created_at_idx;
+
+drop table if exists audit_events;
+
+drop type if exists audit_outcome;
@@ -0,0 +1,26 @@
+-- The outcome of an audited action
+create type audit_outcome as enum(
+  'succeeded',
+  'failed',
+  'cancelled'
+);
+
+-- Records who performed each privileged action, what it touched, and how it turned out. Rows are
+-- never updated or deleted, so the log outlives the records it refers to. `target_ids` holds the
+-- ids of the records the action touched, and `summary` describes the request without copying it
+-- wholesale.
+create table if not exists audit_events(
+  id uuid not null default uuid_generate_v4() primary key,
+  created_at timestamptz not null default now(),
+  principal text not null,
+  action text not null,
+  target_ids uuid[] not null default '{}',
+  summary jsonb not null default '{}' ::jsonb,
+  outcome audit_outcome not null,
+  error text
+);
+
+-- Audit events are listed newest first, a page at a time, and are often searched by target.
+create index if not exists audit_events_created_at_idx on audit_events(created_at desc, id desc);
+
+create index if not exists audit_events_target_ids_idx on audit_events using gin(target_ids);
@@ -0,0 +1,17 @@
+drop index if exists nonprofit_clients_external_id_idx;
+
+drop index if exists mentors_external_id_idx;
+
+drop index if exists volunteers_external_id_idx;
+
+alter table nonprofit_clients
+  drop column if exists active,
+  drop column if exists external_id;
+
+alter table mentors
+  drop column if exists active,
+  drop column if exists external_id;
+
+alter table volunteers
+  drop column if exists active,
+  drop column if exists external_id;
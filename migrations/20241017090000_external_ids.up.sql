@@ -0,0 +1,21 @@
+-- Records imported from an external source (e.g. Airtable) keep the source's ID for them, so that
+-- syncing the source again updates them instead of creating duplicates. Records which have been
+-- removed from the source are marked inactive rather than deleted.
+alter table volunteers
+  add column if not exists external_id text,
+  add column if not exists active boolean not null default true;
+
+alter table mentors
+  add column if not exists external_id text,
+  add column if not exists active boolean not null default true;
+
+alter table nonprofit_clients
+  add column if not exists external_id text,
+  add column if not exists active boolean not null default true;
+
+-- An external ID identifies at most one record of each kind in a project cycle.
+create unique index if not exists volunteers_external_id_idx on volunteers(project_cycle_id, external_id);
+
+create unique index if not exists mentors_external_id_idx on mentors(project_cycle_id, external_id);
+
+create unique index if not exists nonprofit_clients_external_id_idx on nonprofit_clients(project_cycle_id, external_id);
@@ -0,0 +1,3 @@
+drop trigger if exists set_updated_at on airtable_mapping_profiles;
+
+drop table if exists airtable_mapping_profiles;
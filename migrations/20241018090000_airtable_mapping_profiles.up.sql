@@ -0,0 +1,16 @@
+-- Describes how an Airtable base is laid out (its table, view, and field names, and how its values
+-- map onto ours), so that bases with different layouts can be imported. An import chooses which
+-- profile to read a base with. `mapping` is read by the Airtable service, not the database.
+create table if not exists airtable_mapping_profiles(
+  id uuid not null default uuid_generate_v4() primary key,
+  created_at timestamptz not null default now(),
+  updated_at timestamptz,
+  name text not null,
+  description text,
+  mapping jsonb not null,
+  -- constraints
+  unique (name)
+);
+
+select
+  trigger_updated_at('airtable_mapping_profiles');
@@ -0,0 +1,3 @@
+drop trigger if exists set_updated_at on airtable_webhooks;
+
+drop table if exists airtable_webhooks;
@@ -0,0 +1,20 @@
+-- Webhooks registered on Airtable bases. When Airtable notifies one, the payloads after `cursor`
+-- are read and a sync of the webhook's project cycle is queued. Airtable only returns the MAC
+-- secret when a webhook is registered, and notifications are checked against it.
+create table if not exists airtable_webhooks(
+  id uuid not null default uuid_generate_v4() primary key,
+  created_at timestamptz not null default now(),
+  updated_at timestamptz,
+  base_id text not null,
+  webhook_id text not null,
+  mac_secret_base64 text not null,
+  project_cycle_id uuid not null references project_cycles(id) on delete cascade,
+  mapping_profile_id uuid references airtable_mapping_profiles(id) on delete set null,
+  cursor bigint not null default 1,
+  expiration_time timestamptz,
+  -- constraints
+  unique (webhook_id)
+);
+
+select
+  trigger_updated_at('airtable_webhooks');
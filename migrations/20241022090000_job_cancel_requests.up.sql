@@ -0,0 +1,5 @@
+-- A running job can only be stopped by the worker running it, which may be in another process.
+-- Cancelling a running job records the request here, and the worker sees it when it next sends a
+-- heartbeat.
+alter table jobs
+  add column if not exists cancel_requested_at timestamptz;
@@ -0,0 +1,9 @@
+-- CSV files uploaded for an import. They hold personal information, so they are kept out of the
+-- job's details, which are returned by the jobs API, and deleted once the import has run.
+create table if not exists csv_uploads(
+  id uuid not null default uuid_generate_v4() primary key,
+  created_at timestamptz not null default now(),
+  volunteers text not null default '',
+  mentors text not null default '',
+  nonprofits text not null default ''
+);
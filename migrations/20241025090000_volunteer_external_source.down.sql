@@ -0,0 +1,4 @@
+alter table volunteers
+  drop column if exists external_source;
+
+drop type if exists external_source;
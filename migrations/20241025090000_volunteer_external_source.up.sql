@@ -0,0 +1,17 @@
+-- Where a volunteer was imported from. Exports only write a volunteer's Workspace email back to
+-- the source if it was an Airtable base, since the external IDs of other sources aren't Airtable
+-- record IDs. Volunteers synced before this was recorded were all imported from Airtable.
+create type external_source as enum(
+  'airtable',
+  'csv'
+);
+
+alter table volunteers
+  add column if not exists external_source external_source;
+
+update
+  volunteers
+set
+  external_source = 'airtable'
+where
+  external_id is not null;
@@ -0,0 +1,33 @@
+drop view if exists exported_volunteer_details;
+
+drop view if exists mentor_details;
+
+drop view if exists nonprofit_client_details;
+
+drop view if exists volunteer_details;
+
+drop table if exists audit_events;
+
+drop table if exists job_items;
+
+drop table if exists volunteers_exported_to_workspace;
+
+drop table if exists jobs;
+
+drop table if exists volunteer_mentors;
+
+drop table if exists client_mentors;
+
+drop table if exists client_volunteers;
+
+drop table if exists nonprofit_clients;
+
+drop table if exists mentors;
+
+drop table if exists volunteer_team_roles;
+
+drop table if exists team_roles;
+
+drop table if exists volunteers;
+
+drop table if exists project_cycles;
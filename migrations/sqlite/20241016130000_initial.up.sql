@@ -0,0 +1,510 @@
+-- The SQLite schema mirrors the Postgres schema in the parent directory, which is the source of
+-- truth. SQLite has no uuid, enum, array, or timestamp types, so they are emulated:
+--
+-- * IDs are 16-byte blobs. New IDs are random (version 4) UUIDs.
+-- * Timestamps are UTC text in RFC 3339 format with millisecond precision.
+-- * Enums are text, restricted to the same values as the Postgres enum.
+-- * Arrays are JSON arrays. Arrays of enums hold the values' serialized (Airtable) names.
+--
+-- Postgres' `set_updated_at` triggers become one `after update` trigger per table.
+--
+-- project_cycles table
+create table if not exists project_cycles(
+  id blob not null primary key default (unhex(printf('%s4%s%s%s', hex(randomblob(6)), substr(hex(randomblob(2)), 2), substr('89ab', 1 + abs(random()) % 4, 1), substr(hex(randomblob(8)), 2)))),
+  created_at text not null default (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+  updated_at text,
+  name text not null,
+  description text,
+  archived boolean not null default false,
+  -- constraints
+  unique (name)
+);
+
+create trigger if not exists project_cycles_set_updated_at
+  after update on project_cycles
+  for each row
+  when new.updated_at is old.updated_at
+begin
+  update
+    project_cycles
+  set
+    updated_at = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+  where
+    rowid = new.rowid;
+end;
+
+--
+-- volunteers table
+create table if not exists volunteers(
+  id blob not null primary key default (unhex(printf('%s4%s%s%s', hex(randomblob(6)), substr(hex(randomblob(2)), 2), substr('89ab', 1 + abs(random()) % 4, 1), substr(hex(randomblob(8)), 2)))),
+  created_at text not null default (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+  updated_at text,
+  project_cycle_id blob not null references project_cycles(id) on delete cascade,
+  first_name text not null,
+  last_name text not null,
+  email text not null,
+  phone text,
+  volunteer_gender text not null default 'prefer_not_to_say' check (volunteer_gender in ('woman', 'man', 'non_binary', 'other', 'prefer_not_to_say')),
+  volunteer_ethnicity text not null default '[]',
+  volunteer_age_range text not null default 'prefer_not_to_say' check (volunteer_age_range in ('17_and_under', '18-24', '25-29', '30-34', '35-39', '40-44', '45-59', '60-64', '65+', 'prefer_not_to_say')),
+  university text not null default '[]',
+  lgbt text not null default 'prefer_not_to_say' check (lgbt in ('yes', 'no', 'ally', 'prefer_not_to_say')),
+  country text not null,
+  us_state text,
+  fli text not null default '["Prefer not to say"]',
+  student_stage text not null default 'recent_graduate' check (student_stage in ('freshman', 'sophomore', 'junior', 'senior', 'masters_student', 'phd_student', 'recent_graduate')),
+  majors text not null default '[]',
+  minors text not null default '[]',
+  hear_about text not null,
+  -- constraints
+  unique (email)
+);
+
+create trigger if not exists volunteers_set_updated_at
+  after update on volunteers
+  for each row
+  when new.updated_at is old.updated_at
+begin
+  update
+    volunteers
+  set
+    updated_at = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+  where
+    rowid = new.rowid;
+end;
+
+--
+-- team_roles table
+create table if not exists team_roles(
+  id blob not null primary key default (unhex(printf('%s4%s%s%s', hex(randomblob(6)), substr(hex(randomblob(2)), 2), substr('89ab', 1 + abs(random()) % 4, 1), substr(hex(randomblob(8)), 2)))),
+  created_at text not null default (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+  updated_at text,
+  name text not null,
+  description text not null,
+  -- constraints
+  unique (name)
+);
+
+create trigger if not exists team_roles_set_updated_at
+  after update on team_roles
+  for each row
+  when new.updated_at is old.updated_at
+begin
+  update
+    team_roles
+  set
+    updated_at = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+  where
+    rowid = new.rowid;
+end;
+
+insert into team_roles(name, description)
+  values ('product_lead', 'A product lead is a senior volunteer with either experience at Develop for Good or is an exceptional candidate. They supervise 2-5 teams.'),
+('product_manager', 'A product manager is a student-manager level volunteer with exceptional leadership skills.'),
+('engineering_manager', 'An engineering manager is a student-manager level volunteer with exceptional engineering skills, assigned to engineering projects.'),
+('design_manager', 'A design manager is a student-manager level volunteer with exceptional design skills, assigned to design projects'),
+('engineer', 'An engineer volunteer is usually assigned to engineering projects'),
+('designer', 'A designer volunteer is usually assigned to design projects');
+
+--
+-- volunteer_team_roles table
+create table if not exists volunteer_team_roles(
+  project_cycle_id blob not null references project_cycles(id) on delete cascade,
+  volunteer_id blob not null references volunteers(id) on delete cascade,
+  role_id blob not null references team_roles(id) on delete cascade,
+  primary key (project_cycle_id, volunteer_id, role_id)
+);
+
+--
+-- mentors table
+create table if not exists mentors(
+  id blob not null primary key default (unhex(printf('%s4%s%s%s', hex(randomblob(6)), substr(hex(randomblob(2)), 2), substr('89ab', 1 + abs(random()) % 4, 1), substr(hex(randomblob(8)), 2)))),
+  created_at text not null default (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+  updated_at text,
+  project_cycle_id blob not null references project_cycles(id) on delete cascade,
+  first_name text not null,
+  last_name text not null,
+  email text not null,
+  phone text,
+  company text not null,
+  job_title text not null,
+  country text not null,
+  us_state text,
+  years_experience text not null check (years_experience in ('2-5', '6-10', '11-15', '16-20', '21+')),
+  experience_level text not null check (experience_level in ('intermediate', 'first_level_management', 'middle_management', 'senior_or_executive')),
+  prior_mentor boolean not null default false,
+  prior_mentee boolean not null default false,
+  prior_student boolean not null default false,
+  university text not null default '[]',
+  hear_about text not null,
+  -- constraints
+  unique (email)
+);
+
+create trigger if not exists mentors_set_updated_at
+  after update on mentors
+  for each row
+  when new.updated_at is old.updated_at
+begin
+  update
+    mentors
+  set
+    updated_at = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+  where
+    rowid = new.rowid;
+end;
+
+--
+-- nonprofit_clients table
+create table if not exists nonprofit_clients(
+  id blob not null primary key default (unhex(printf('%s4%s%s%s', hex(randomblob(6)), substr(hex(randomblob(2)), 2), substr('89ab', 1 + abs(random()) % 4, 1), substr(hex(randomblob(8)), 2)))),
+  created_at text not null default (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+  updated_at text,
+  project_cycle_id blob not null references project_cycles(id) on delete cascade,
+  representative_first_name text not null,
+  representative_last_name text not null,
+  representative_job_title text,
+  email text not null,
+  email_cc text,
+  phone text not null,
+  org_name text not null,
+  project_name text not null,
+  org_website text,
+  country_hq text,
+  us_state_hq text,
+  address text not null,
+  size text not null check (size in ('0', '1-5', '6-20', '21-50', '51-100', '101-500', '500+')),
+  impact_causes text not null default '[]',
+  -- constraints
+  unique (email, project_cycle_id, org_name, project_name)
+);
+
+create trigger if not exists nonprofit_clients_set_updated_at
+  after update on nonprofit_clients
+  for each row
+  when new.updated_at is old.updated_at
+begin
+  update
+    nonprofit_clients
+  set
+    updated_at = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+  where
+    rowid = new.rowid;
+end;
+
+--
+-- client_volunteers join table
+create table if not exists client_volunteers(
+  created_at text not null default (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+  updated_at text,
+  volunteer_id blob not null references volunteers(id) on delete cascade,
+  client_id blob not null references nonprofit_clients(id) on delete cascade,
+  project_cycle_id blob not null references project_cycles(id) on delete cascade,
+  currently_active boolean not null,
+  primary key (volunteer_id, client_id, project_cycle_id)
+);
+
+create trigger if not exists client_volunteers_set_updated_at
+  after update on client_volunteers
+  for each row
+  when new.updated_at is old.updated_at
+begin
+  update
+    client_volunteers
+  set
+    updated_at = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+  where
+    rowid = new.rowid;
+end;
+
+--
+-- client_mentors join table
+create table if not exists client_mentors(
+  created_at text not null default (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+  updated_at text,
+  mentor_id blob not null references mentors(id) on delete cascade,
+  client_id blob not null references nonprofit_clients(id) on delete cascade,
+  project_cycle_id blob not null references project_cycles(id) on delete cascade,
+  primary key (mentor_id, client_id, project_cycle_id)
+);
+
+--
+-- volunteer_mentors join table
+create table if not exists volunteer_mentors(
+  mentor_id blob not null references mentors(id) on delete cascade,
+  volunteer_id blob not null references volunteers(id) on delete cascade,
+  project_cycle_id blob not null references project_cycles(id) on delete cascade,
+  primary key (mentor_id, volunteer_id, project_cycle_id)
+);
+
+--
+-- jobs table
+-- A claimed job is `running` and is leased to a single worker until `locked_until`.
+create table if not exists jobs(
+  id blob not null primary key default (unhex(printf('%s4%s%s%s', hex(randomblob(6)), substr(hex(randomblob(2)), 2), substr('89ab', 1 + abs(random()) % 4, 1), substr(hex(randomblob(8)), 2)))),
+  created_at text not null default (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+  updated_at text,
+  project_cycle_id blob references project_cycles(id) on delete cascade,
+  status text not null default 'pending' check (status in ('pending', 'running', 'complete', 'cancelled', 'error')),
+  label text not null,
+  description text,
+  details text not null default '{}',
+  attempts integer not null default 0,
+  locked_by text,
+  locked_until text,
+  heartbeat_at text
+);
+
+create trigger if not exists jobs_set_updated_at
+  after update on jobs
+  for each row
+  when new.updated_at is old.updated_at
+begin
+  update
+    jobs
+  set
+    updated_at = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+  where
+    rowid = new.rowid;
+end;
+
+create index if not exists jobs_pending_idx on jobs(created_at)
+where
+  status = 'pending';
+
+create index if not exists jobs_created_at_idx on jobs(created_at desc, id desc);
+
+--
+-- volunteers_exported_to_workspace table
+create table if not exists volunteers_exported_to_workspace(
+  id blob not null primary key default (unhex(printf('%s4%s%s%s', hex(randomblob(6)), substr(hex(randomblob(2)), 2), substr('89ab', 1 + abs(random()) % 4, 1), substr(hex(randomblob(8)), 2)))),
+  created_at text not null default (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+  updated_at text,
+  volunteer_id blob not null references volunteers(id) on delete cascade,
+  job_id blob not null references jobs(id) on delete cascade,
+  workspace_email text not null,
+  org_unit text not null,
+  unique (volunteer_id, job_id)
+);
+
+create trigger if not exists volunteers_exported_to_workspace_set_updated_at
+  after update on volunteers_exported_to_workspace
+  for each row
+  when new.updated_at is old.updated_at
+begin
+  update
+    volunteers_exported_to_workspace
+  set
+    updated_at = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+  where
+    rowid = new.rowid;
+end;
+
+--
+-- job_items table
+create table if not exists job_items(
+  id blob not null primary key default (unhex(printf('%s4%s%s%s', hex(randomblob(6)), substr(hex(randomblob(2)), 2), substr('89ab', 1 + abs(random()) % 4, 1), substr(hex(randomblob(8)), 2)))),
+  created_at text not null default (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+  updated_at text,
+  job_id blob not null references jobs(id) on delete cascade,
+  kind text not null check (kind in ('volunteer', 'mentor', 'nonprofit')),
+  item_key text not null,
+  entity_id blob,
+  label text,
+  status text not null default 'pending' check (status in ('pending', 'succeeded', 'skipped', 'failed')),
+  error text,
+  unique (job_id, kind, item_key)
+);
+
+create trigger if not exists job_items_set_updated_at
+  after update on job_items
+  for each row
+  when new.updated_at is old.updated_at
+begin
+  update
+    job_items
+  set
+    updated_at = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+  where
+    rowid = new.rowid;
+end;
+
+--
+-- audit_events table
+-- `target_ids` is a JSON array of hyphenated UUIDs.
+create table if not exists audit_events(
+  id blob not null primary key default (unhex(printf('%s4%s%s%s', hex(randomblob(6)), substr(hex(randomblob(2)), 2), substr('89ab', 1 + abs(random()) % 4, 1), substr(hex(randomblob(8)), 2)))),
+  created_at text not null default (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+  principal text not null,
+  action text not null,
+  target_ids text not null default '[]',
+  summary text not null default '{}',
+  outcome text not null check (outcome in ('succeeded', 'failed', 'cancelled')),
+  error text
+);
+
+create index if not exists audit_events_created_at_idx on audit_events(created_at desc, id desc);
+
+--
+-- Views
+--
+-- The JSON arrays Postgres builds with `json_agg` are built here with correlated subqueries, so
+-- the IDs inside them are formatted as hyphenated UUIDs by hand.
+create view volunteer_details as
+select
+  v.id as volunteer_id,
+  v.created_at,
+  v.updated_at,
+  v.project_cycle_id,
+  pc.name as project_cycle_name,
+  v.first_name,
+  v.last_name,
+  v.email,
+  v.phone,
+  v.volunteer_gender,
+  v.volunteer_ethnicity,
+  v.volunteer_age_range,
+  v.university,
+  v.lgbt,
+  v.country,
+  v.us_state,
+  v.fli,
+  v.student_stage,
+  v.majors,
+  v.minors,
+  v.hear_about,
+(
+    select
+      vew.workspace_email
+    from
+      volunteers_exported_to_workspace vew
+    where
+      vew.volunteer_id = v.id
+    order by
+      vew.created_at desc
+    limit 1) as workspace_email,
+(
+    select
+      json_group_array(json_object('clientId', lower(substr(hex(nc.id), 1, 8) || '-' || substr(hex(nc.id), 9, 4) || '-' || substr(hex(nc.id), 13, 4) || '-' || substr(hex(nc.id), 17, 4) || '-' || substr(hex(nc.id), 21)), 'orgName', nc.org_name, 'projectName', nc.project_name, 'currentlyActive', json(iif(cv.currently_active, 'true', 'false'))))
+    from
+      client_volunteers cv
+      join nonprofit_clients nc on cv.client_id = nc.id
+    where
+      cv.volunteer_id = v.id) as clients,
+(
+    select
+      json_group_array(json_object('mentorId', lower(substr(hex(m.id), 1, 8) || '-' || substr(hex(m.id), 9, 4) || '-' || substr(hex(m.id), 13, 4) || '-' || substr(hex(m.id), 17, 4) || '-' || substr(hex(m.id), 21)), 'firstName', m.first_name, 'lastName', m.last_name, 'email', m.email, 'phone', m.phone, 'company', m.company, 'jobTitle', m.job_title))
+    from
+      volunteer_mentors vm
+      join mentors m on vm.mentor_id = m.id
+    where
+      vm.volunteer_id = v.id) as mentors,
+(
+    select
+      json_group_array(json_object('roleId', lower(substr(hex(tr.id), 1, 8) || '-' || substr(hex(tr.id), 9, 4) || '-' || substr(hex(tr.id), 13, 4) || '-' || substr(hex(tr.id), 17, 4) || '-' || substr(hex(tr.id), 21)), 'name', tr.name, 'description', tr.description))
+    from
+      volunteer_team_roles vtr
+      join team_roles tr on vtr.role_id = tr.id
+    where
+      vtr.volunteer_id = v.id) as roles
+from
+  volunteers v
+  left join project_cycles pc on pc.id = v.project_cycle_id;
+
+create view nonprofit_client_details as
+select
+  nc.id as client_id,
+  nc.created_at,
+  nc.updated_at,
+  nc.project_cycle_id,
+  pc.name as project_cycle_name,
+  nc.representative_first_name,
+  nc.representative_last_name,
+  nc.representative_job_title,
+  nc.email,
+  nc.email_cc,
+  nc.phone,
+  nc.org_name,
+  nc.project_name,
+  nc.impact_causes,
+  nc.org_website,
+  nc.country_hq,
+  nc.us_state_hq,
+  nc.address,
+  nc.size,
+(
+    select
+      json_group_array(json_object('id', lower(substr(hex(v.id), 1, 8) || '-' || substr(hex(v.id), 9, 4) || '-' || substr(hex(v.id), 13, 4) || '-' || substr(hex(v.id), 17, 4) || '-' || substr(hex(v.id), 21)), 'first_name', v.first_name, 'last_name', v.last_name, 'email', v.email, 'phone', v.phone, 'volunteer_gender', v.volunteer_gender, 'volunteer_ethnicity', json(v.volunteer_ethnicity), 'volunteer_age_range', v.volunteer_age_range))
+    from
+      client_volunteers cv
+      join volunteers v on cv.volunteer_id = v.id
+    where
+      cv.client_id = nc.id) as volunteers,
+(
+    select
+      json_group_array(json_object('id', lower(substr(hex(m.id), 1, 8) || '-' || substr(hex(m.id), 9, 4) || '-' || substr(hex(m.id), 13, 4) || '-' || substr(hex(m.id), 17, 4) || '-' || substr(hex(m.id), 21)), 'first_name', m.first_name, 'last_name', m.last_name, 'email', m.email, 'phone', m.phone, 'company', m.company, 'job_title', m.job_title))
+    from
+      client_mentors cm
+      join mentors m on cm.mentor_id = m.id
+    where
+      cm.client_id = nc.id) as mentors
+from
+  nonprofit_clients nc
+  left join project_cycles pc on pc.id = nc.project_cycle_id;
+
+create view mentor_details as
+select
+  m.id as mentor_id,
+  m.created_at,
+  m.updated_at,
+  m.project_cycle_id,
+  pc.name as project_cycle_name,
+  m.first_name,
+  m.last_name,
+  m.email,
+  m.phone,
+  m.company,
+  m.job_title,
+  m.country,
+  m.us_state,
+  m.years_experience,
+  m.experience_level,
+  m.prior_mentor,
+  m.prior_mentee,
+  m.prior_student,
+  m.university,
+  m.hear_about,
+(
+    select
+      json_group_array(json_object('volunteer_id', lower(substr(hex(v.id), 1, 8) || '-' || substr(hex(v.id), 9, 4) || '-' || substr(hex(v.id), 13, 4) || '-' || substr(hex(v.id), 17, 4) || '-' || substr(hex(v.id), 21)), 'email', v.email, 'name', v.first_name || ' ' || v.last_name))
+    from
+      volunteer_mentors vm
+      join volunteers v on vm.volunteer_id = v.id
+    where
+      vm.mentor_id = m.id) as volunteers,
+(
+    select
+      json_group_array(json_object('client_id', lower(substr(hex(nc.id), 1, 8) || '-' || substr(hex(nc.id), 9, 4) || '-' || substr(hex(nc.id), 13, 4) || '-' || substr(hex(nc.id), 17, 4) || '-' || substr(hex(nc.id), 21)), 'org_name', nc.org_name, 'project_name', nc.project_name))
+    from
+      client_mentors cm
+      join nonprofit_clients nc on cm.client_id = nc.id
+    where
+      cm.mentor_id = m.id) as clients
+from
+  mentors m
+  left join project_cycles pc on m.project_cycle_id = pc.id;
+
+create view exported_volunteer_details as
+select
+  ev.id,
+  ev.created_at,
+  ev.updated_at,
+  ev.volunteer_id,
+  ev.workspace_email,
+  ev.org_unit,
+  j.id as job_id,
+  j.project_cycle_id,
+  j.status
+from
+  volunteers_exported_to_workspace ev
+  left join jobs j on ev.job_id = j.id;
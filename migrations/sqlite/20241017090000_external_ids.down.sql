@@ -0,0 +1,17 @@
+drop index if exists nonprofit_clients_external_id_idx;
+
+drop index if exists mentors_external_id_idx;
+
+drop index if exists volunteers_external_id_idx;
+
+alter table nonprofit_clients drop column active;
+
+alter table nonprofit_clients drop column external_id;
+
+alter table mentors drop column active;
+
+alter table mentors drop column external_id;
+
+alter table volunteers drop column active;
+
+alter table volunteers drop column external_id;
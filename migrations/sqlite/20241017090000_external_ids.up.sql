@@ -0,0 +1,19 @@
+-- Mirrors ../20241017090000_external_ids.up.sql. SQLite adds one column per statement.
+alter table volunteers add column external_id text;
+
+alter table volunteers add column active boolean not null default true;
+
+alter table mentors add column external_id text;
+
+alter table mentors add column active boolean not null default true;
+
+alter table nonprofit_clients add column external_id text;
+
+alter table nonprofit_clients add column active boolean not null default true;
+
+-- An external ID identifies at most one record of each kind in a project cycle.
+create unique index if not exists volunteers_external_id_idx on volunteers(project_cycle_id, external_id);
+
+create unique index if not exists mentors_external_id_idx on mentors(project_cycle_id, external_id);
+
+create unique index if not exists nonprofit_clients_external_id_idx on nonprofit_clients(project_cycle_id, external_id);
@@ -0,0 +1,3 @@
+drop trigger if exists airtable_mapping_profiles_set_updated_at;
+
+drop table if exists airtable_mapping_profiles;
@@ -0,0 +1,24 @@
+-- Mirrors ../20241018090000_airtable_mapping_profiles.up.sql
+create table if not exists airtable_mapping_profiles(
+  id blob not null primary key default (unhex(printf('%s4%s%s%s', hex(randomblob(6)), substr(hex(randomblob(2)), 2), substr('89ab', 1 + abs(random()) % 4, 1), substr(hex(randomblob(8)), 2)))),
+  created_at text not null default (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+  updated_at text,
+  name text not null,
+  description text,
+  mapping text not null,
+  -- constraints
+  unique (name)
+);
+
+create trigger if not exists airtable_mapping_profiles_set_updated_at
+  after update on airtable_mapping_profiles
+  for each row
+  when new.updated_at is old.updated_at
+begin
+  update
+    airtable_mapping_profiles
+  set
+    updated_at = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+  where
+    rowid = new.rowid;
+end;
@@ -0,0 +1,3 @@
+drop trigger if exists airtable_webhooks_set_updated_at;
+
+drop table if exists airtable_webhooks;
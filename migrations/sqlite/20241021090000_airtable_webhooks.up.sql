@@ -0,0 +1,28 @@
+-- Mirrors ../20241021090000_airtable_webhooks.up.sql
+create table if not exists airtable_webhooks(
+  id blob not null primary key default (unhex(printf('%s4%s%s%s', hex(randomblob(6)), substr(hex(randomblob(2)), 2), substr('89ab', 1 + abs(random()) % 4, 1), substr(hex(randomblob(8)), 2)))),
+  created_at text not null default (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+  updated_at text,
+  base_id text not null,
+  webhook_id text not null,
+  mac_secret_base64 text not null,
+  project_cycle_id blob not null references project_cycles(id) on delete cascade,
+  mapping_profile_id blob references airtable_mapping_profiles(id) on delete set null,
+  cursor integer not null default 1,
+  expiration_time text,
+  -- constraints
+  unique (webhook_id)
+);
+
+create trigger if not exists airtable_webhooks_set_updated_at
+  after update on airtable_webhooks
+  for each row
+  when new.updated_at is old.updated_at
+begin
+  update
+    airtable_webhooks
+  set
+    updated_at = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+  where
+    rowid = new.rowid;
+end;
@@ -0,0 +1,2 @@
+-- Mirrors ../20241022090000_job_cancel_requests.up.sql
+alter table jobs add column cancel_requested_at text;
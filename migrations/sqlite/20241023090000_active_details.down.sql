@@ -0,0 +1,150 @@
+-- Mirrors ../20241023090000_active_details.down.sql
+drop view if exists volunteer_details;
+
+create view volunteer_details as
+select
+  v.id as volunteer_id,
+  v.created_at,
+  v.updated_at,
+  v.project_cycle_id,
+  pc.name as project_cycle_name,
+  v.first_name,
+  v.last_name,
+  v.email,
+  v.phone,
+  v.volunteer_gender,
+  v.volunteer_ethnicity,
+  v.volunteer_age_range,
+  v.university,
+  v.lgbt,
+  v.country,
+  v.us_state,
+  v.fli,
+  v.student_stage,
+  v.majors,
+  v.minors,
+  v.hear_about,
+(
+    select
+      vew.workspace_email
+    from
+      volunteers_exported_to_workspace vew
+    where
+      vew.volunteer_id = v.id
+    order by
+      vew.created_at desc
+    limit 1) as workspace_email,
+(
+    select
+      json_group_array(json_object('clientId', lower(substr(hex(nc.id), 1, 8) || '-' || substr(hex(nc.id), 9, 4) || '-' || substr(hex(nc.id), 13, 4) || '-' || substr(hex(nc.id), 17, 4) || '-' || substr(hex(nc.id), 21)), 'orgName', nc.org_name, 'projectName', nc.project_name, 'currentlyActive', json(iif(cv.currently_active, 'true', 'false'))))
+    from
+      client_volunteers cv
+      join nonprofit_clients nc on cv.client_id = nc.id
+    where
+      cv.volunteer_id = v.id) as clients,
+(
+    select
+      json_group_array(json_object('mentorId', lower(substr(hex(m.id), 1, 8) || '-' || substr(hex(m.id), 9, 4) || '-' || substr(hex(m.id), 13, 4) || '-' || substr(hex(m.id), 17, 4) || '-' || substr(hex(m.id), 21)), 'firstName', m.first_name, 'lastName', m.last_name, 'email', m.email, 'phone', m.phone, 'company', m.company, 'jobTitle', m.job_title))
+    from
+      volunteer_mentors vm
+      join mentors m on vm.mentor_id = m.id
+    where
+      vm.volunteer_id = v.id) as mentors,
+(
+    select
+      json_group_array(json_object('roleId', lower(substr(hex(tr.id), 1, 8) || '-' || substr(hex(tr.id), 9, 4) || '-' || substr(hex(tr.id), 13, 4) || '-' || substr(hex(tr.id), 17, 4) || '-' || substr(hex(tr.id), 21)), 'name', tr.name, 'description', tr.description))
+    from
+      volunteer_team_roles vtr
+      join team_roles tr on vtr.role_id = tr.id
+    where
+      vtr.volunteer_id = v.id) as roles
+from
+  volunteers v
+  left join project_cycles pc on pc.id = v.project_cycle_id;
+
+drop view if exists mentor_details;
+
+create view mentor_details as
+select
+  m.id as mentor_id,
+  m.created_at,
+  m.updated_at,
+  m.project_cycle_id,
+  pc.name as project_cycle_name,
+  m.first_name,
+  m.last_name,
+  m.email,
+  m.phone,
+  m.company,
+  m.job_title,
+  m.country,
+  m.us_state,
+  m.years_experience,
+  m.experience_level,
+  m.prior_mentor,
+  m.prior_mentee,
+  m.prior_student,
+  m.university,
+  m.hear_about,
+(
+    select
+      json_group_array(json_object('volunteer_id', lower(substr(hex(v.id), 1, 8) || '-' || substr(hex(v.id), 9, 4) || '-' || substr(hex(v.id), 13, 4) || '-' || substr(hex(v.id), 17, 4) || '-' || substr(hex(v.id), 21)), 'email', v.email, 'name', v.first_name || ' ' || v.last_name))
+    from
+      volunteer_mentors vm
+      join volunteers v on vm.volunteer_id = v.id
+    where
+      vm.mentor_id = m.id) as volunteers,
+(
+    select
+      json_group_array(json_object('client_id', lower(substr(hex(nc.id), 1, 8) || '-' || substr(hex(nc.id), 9, 4) || '-' || substr(hex(nc.id), 13, 4) || '-' || substr(hex(nc.id), 17, 4) || '-' || substr(hex(nc.id), 21)), 'org_name', nc.org_name, 'project_name', nc.project_name))
+    from
+      client_mentors cm
+      join nonprofit_clients nc on cm.client_id = nc.id
+    where
+      cm.mentor_id = m.id) as clients
+from
+  mentors m
+  left join project_cycles pc on m.project_cycle_id = pc.id;
+
+drop view if exists nonprofit_client_details;
+
+create view nonprofit_client_details as
+select
+  nc.id as client_id,
+  nc.created_at,
+  nc.updated_at,
+  nc.project_cycle_id,
+  pc.name as project_cycle_name,
+  nc.representative_first_name,
+  nc.representative_last_name,
+  nc.representative_job_title,
+  nc.email,
+  nc.email_cc,
+  nc.phone,
+  nc.org_name,
+  nc.project_name,
+  nc.impact_causes,
+  nc.org_website,
+  nc.country_hq,
+  nc.us_state_hq,
+  nc.address,
+  nc.size,
+(
+    select
+      json_group_array(json_object('id', lower(substr(hex(v.id), 1, 8) || '-' || substr(hex(v.id), 9, 4) || '-' || substr(hex(v.id), 13, 4) || '-' || substr(hex(v.id), 17, 4) || '-' || substr(hex(v.id), 21)), 'first_name', v.first_name, 'last_name', v.last_name, 'email', v.email, 'phone', v.phone, 'volunteer_gender', v.volunteer_gender, 'volunteer_ethnicity', json(v.volunteer_ethnicity), 'volunteer_age_range', v.volunteer_age_range))
+    from
+      client_volunteers cv
+      join volunteers v on cv.volunteer_id = v.id
+    where
+      cv.client_id = nc.id) as volunteers,
+(
+    select
+      json_group_array(json_object('id', lower(substr(hex(m.id), 1, 8) || '-' || substr(hex(m.id), 9, 4) || '-' || substr(hex(m.id), 13, 4) || '-' || substr(hex(m.id), 17, 4) || '-' || substr(hex(m.id), 21)), 'first_name', m.first_name, 'last_name', m.last_name, 'email', m.email, 'phone', m.phone, 'company', m.company, 'job_title', m.job_title))
+    from
+      client_mentors cm
+      join mentors m on cm.mentor_id = m.id
+    where
+      cm.client_id = nc.id) as mentors
+from
+  nonprofit_clients nc
+  left join project_cycles pc on pc.id = nc.project_cycle_id;
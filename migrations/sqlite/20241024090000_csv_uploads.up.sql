@@ -0,0 +1,8 @@
+-- Mirrors ../20241024090000_csv_uploads.up.sql
+create table if not exists csv_uploads(
+  id blob not null primary key default (unhex(printf('%s4%s%s%s', hex(randomblob(6)), substr(hex(randomblob(2)), 2), substr('89ab', 1 + abs(random()) % 4, 1), substr(hex(randomblob(8)), 2)))),
+  created_at text not null default (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+  volunteers text not null default '',
+  mentors text not null default '',
+  nonprofits text not null default ''
+);
@@ -0,0 +1 @@
+alter table volunteers drop column external_source;
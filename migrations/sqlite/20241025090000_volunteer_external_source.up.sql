@@ -0,0 +1,9 @@
+-- Mirrors ../20241025090000_volunteer_external_source.up.sql
+alter table volunteers add column external_source text check (external_source in ('airtable', 'csv'));
+
+update
+  volunteers
+set
+  external_source = 'airtable'
+where
+  external_id is not null;
@@ -1,15 +1,14 @@
-use anyhow::Result;
-
 use super::responses::{ListBasesResponse, SchemaResponse};
+use crate::error::Result;
 use crate::Airtable;
 
 impl Airtable {
     pub async fn list_bases(&self, offset: Option<String>) -> Result<ListBasesResponse> {
-        let mut url = "https://api.airtable.com/v0/meta/bases".to_owned();
+        let mut url = format!("{}/v0/meta/bases", self.base_url);
         if let Some(offset) = offset {
             url.push_str(&format!("?offset={}", offset));
         }
-        let data = self.http.get(&url).send().await?.json::<ListBasesResponse>().await?;
+        let data = self.send::<ListBasesResponse>(None, self.http.get(&url)).await?;
         Ok(data)
     }
 
@@ -21,9 +20,9 @@ impl Airtable {
         let query =
             include.iter().map(|v| format!("include={}", v)).collect::<Vec<String>>().join("&");
 
-        let url = format!("https://api.airtable.com/v0/meta/bases/{base_id}/tables?{query}");
+        let url = format!("{}/v0/meta/bases/{base_id}/tables?{query}", self.base_url);
 
-        let data = self.http.get(url).send().await?.json::<SchemaResponse>().await?;
+        let data = self.send::<SchemaResponse>(Some(base_id), self.http.get(url)).await?;
 
         Ok(data)
     }
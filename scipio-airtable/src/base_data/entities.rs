@@ -119,3 +119,31 @@ pub struct Record<T> {
     pub fields: T,
     pub created_time: String,
 }
+
+/// The fields of a record to create, or to write when upserting.
+///
+/// * `fields`: The fields of the record
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct NewRecord<T> {
+    pub fields: T,
+}
+
+/// The fields to write to an existing record.
+///
+/// * `id`: The ID of the record
+/// * `fields`: The fields to write
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RecordUpdate<T> {
+    pub id: String,
+    pub fields: T,
+}
+
+/// A record which was deleted.
+///
+/// * `id`: The ID of the record
+/// * `deleted`: Whether the record was deleted
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DeletedRecord {
+    pub id: String,
+    pub deleted: bool,
+}
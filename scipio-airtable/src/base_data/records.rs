@@ -1,14 +1,24 @@
 use std::fmt::Display;
 
-use anyhow::Result;
 use derive_builder::Builder;
 use derive_more::derive::Display;
+use futures::stream::{self, Stream, TryStreamExt};
+use reqwest_middleware::RequestBuilder;
 use scipio_macros::ToQueryString;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
-use super::responses::{GetRecordResponse, ListRecordsResponse};
+use super::entities::{NewRecord, Record, RecordUpdate};
+use super::responses::{
+    CreateRecordsResponse, DeleteRecordResponse, DeleteRecordsResponse, GetRecordResponse,
+    ListRecordsResponse, UpdateRecordResponse, UpdateRecordsResponse,
+};
+use crate::error::{AirtableError, Result};
 use crate::Airtable;
 
+/// The most records Airtable accepts in a single create, update, or delete request.
+pub const MAX_RECORDS_PER_REQUEST: usize = 10;
+
 /// A struct representing a sort query parameter.
 ///
 /// * `field`: The field to sort
@@ -81,6 +91,58 @@ pub struct GetRecordQuery {
     pub return_fields_by_field_id: Option<bool>,
 }
 
+/// How to write the fields of existing records.
+///
+/// More information about the difference can be found
+/// [here](https://airtable.com/developers/web/api/update-multiple-records)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum UpdateMethod {
+    /// Only the fields in the request are written (`PATCH`).
+    #[default]
+    Merge,
+    /// Fields which aren't in the request are cleared (`PUT`).
+    Replace,
+}
+
+/// Options for creating, updating, or upserting records.
+///
+/// * `typecast`: Whether Airtable should convert string values to the type of the field they are
+///   written to, creating select options if it needs to
+/// * `return_fields_by_field_id`: Whether the fields of the returned records are keyed by field ID
+///   instead of name
+#[serde_with::skip_serializing_none]
+#[derive(Default, Debug, Serialize, Builder, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WriteRecordsOptions {
+    #[builder(default, setter(into))]
+    pub typecast: Option<bool>,
+    #[builder(default, setter(into))]
+    pub return_fields_by_field_id: Option<bool>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PerformUpsert<'a> {
+    fields_to_merge_on: &'a [String],
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WriteRecordsBody<'a, R> {
+    records: &'a [R],
+    perform_upsert: Option<PerformUpsert<'a>>,
+    #[serde(flatten)]
+    options: &'a WriteRecordsOptions,
+}
+
+#[derive(Debug, Serialize)]
+struct WriteRecordBody<'a, T> {
+    fields: &'a T,
+    #[serde(flatten)]
+    options: &'a WriteRecordsOptions,
+}
+
 impl Airtable {
     pub async fn list_records<T>(
         &self,
@@ -92,15 +154,67 @@ impl Airtable {
         T: for<'de> Deserialize<'de>,
     {
         let url = format!(
-            "https://api.airtable.com/v0/{base_id}/{table_id}/{query}",
+            "{}/v0/{base_id}/{table_id}/{query}",
+            self.base_url,
             query = query.map(|q| q.to_query_string()).unwrap_or_default()
         );
 
-        let data = self.http.get(&url).send().await?.json::<ListRecordsResponse<T>>().await?;
+        let data = self.send::<ListRecordsResponse<T>>(Some(base_id), self.http.get(&url)).await?;
 
         Ok(data)
     }
 
+    /// List every record in a table, following `offset` from page to page.
+    ///
+    /// Pages are fetched as the stream is read, so records can be processed without holding the
+    /// whole table in memory. Note that `max_records` limits the total number of records listed,
+    /// not the number of records in each page.
+    ///
+    /// * `base_id`: The ID of the base
+    /// * `table_id`: The ID or name of the table
+    /// * `query`: Query parameters for listing the records. `offset` is where to start from.
+    pub fn list_all_records<'a, T>(
+        &'a self,
+        base_id: &'a str,
+        table_id: &'a str,
+        query: Option<&ListRecordsQuery>,
+    ) -> impl Stream<Item = Result<Record<T>>> + 'a
+    where
+        T: DeserializeOwned + 'a,
+    {
+        let query = query.cloned().unwrap_or_default();
+
+        stream::try_unfold(Some(query), move |query| async move {
+            let Some(query) = query else {
+                return Ok::<_, AirtableError>(None);
+            };
+
+            let res = self.list_records::<T>(base_id, table_id, Some(&query)).await?;
+            let next_query =
+                res.offset.map(|offset| ListRecordsQuery { offset: Some(offset), ..query });
+
+            Ok(Some((stream::iter(res.records.into_iter().map(Ok)), next_query)))
+        })
+        .try_flatten()
+    }
+
+    /// List every record in a table into memory. See `list_all_records`.
+    ///
+    /// * `base_id`: The ID of the base
+    /// * `table_id`: The ID or name of the table
+    /// * `query`: Query parameters for listing the records
+    pub async fn collect_all_records<T>(
+        &self,
+        base_id: &str,
+        table_id: &str,
+        query: Option<&ListRecordsQuery>,
+    ) -> Result<Vec<Record<T>>>
+    where
+        T: DeserializeOwned,
+    {
+        self.list_all_records(base_id, table_id, query).try_collect().await
+    }
+
     pub async fn get_record<T>(
         &self,
         base_id: &str,
@@ -112,25 +226,209 @@ impl Airtable {
         T: for<'de> Deserialize<'de>,
     {
         let url = format!(
-            "https://api.airtable.com/v0/{base_id}/{table_id}/{record_id}/{query}",
+            "{}/v0/{base_id}/{table_id}/{record_id}/{query}",
+            self.base_url,
             query = query.map(|q| q.to_query_string()).unwrap_or_default()
         );
 
-        let data = self.http.get(&url).send().await?.json::<GetRecordResponse<T>>().await?;
+        let data = self.send::<GetRecordResponse<T>>(Some(base_id), self.http.get(&url)).await?;
 
         Ok(data)
     }
 
+    /// Create records in a table. Records are created `MAX_RECORDS_PER_REQUEST` at a time, and the
+    /// responses are combined.
+    ///
+    /// * `base_id`: The ID of the base
+    /// * `table_id`: The ID or name of the table
+    /// * `records`: The records to create
+    /// * `options`: Options for writing the records
+    pub async fn create_records<T>(
+        &self,
+        base_id: &str,
+        table_id: &str,
+        records: &[NewRecord<T>],
+        options: &WriteRecordsOptions,
+    ) -> Result<CreateRecordsResponse<T>>
+    where
+        T: Serialize + for<'de> Deserialize<'de>,
+    {
+        let url = format!("{}/v0/{base_id}/{table_id}", self.base_url);
+
+        let mut created = CreateRecordsResponse { records: Vec::with_capacity(records.len()) };
+        for chunk in records.chunks(MAX_RECORDS_PER_REQUEST) {
+            let body = WriteRecordsBody { records: chunk, perform_upsert: None, options };
+            let request = self.http.post(&url).json(&body);
+            let data = self.send::<CreateRecordsResponse<T>>(Some(base_id), request).await?;
+
+            created.records.extend(data.records);
+        }
+
+        Ok(created)
+    }
+
+    /// Update a record in a table.
+    ///
+    /// * `base_id`: The ID of the base
+    /// * `table_id`: The ID or name of the table
+    /// * `record_id`: The ID of the record
+    /// * `fields`: The fields to write
+    /// * `method`: Whether fields which aren't written are kept or cleared
+    /// * `options`: Options for writing the record
     pub async fn update_record<T>(
         &self,
         base_id: &str,
         table_id: &str,
         record_id: &str,
-        data: T,
-    ) -> Result<()>
+        fields: &T,
+        method: UpdateMethod,
+        options: &WriteRecordsOptions,
+    ) -> Result<UpdateRecordResponse<T>>
     where
+        T: Serialize + for<'de> Deserialize<'de>,
+    {
+        let url = format!("{}/v0/{base_id}/{table_id}/{record_id}", self.base_url);
+
+        let body = WriteRecordBody { fields, options };
+        let request = self.write_request(&url, method).json(&body);
+        let data = self.send::<UpdateRecordResponse<T>>(Some(base_id), request).await?;
+
+        Ok(data)
+    }
+
+    /// Update records in a table. Records are updated `MAX_RECORDS_PER_REQUEST` at a time, and the
+    /// responses are combined.
+    ///
+    /// * `base_id`: The ID of the base
+    /// * `table_id`: The ID or name of the table
+    /// * `records`: The records to update
+    /// * `method`: Whether fields which aren't written are kept or cleared
+    /// * `options`: Options for writing the records
+    pub async fn update_records<T>(
+        &self,
+        base_id: &str,
+        table_id: &str,
+        records: &[RecordUpdate<T>],
+        method: UpdateMethod,
+        options: &WriteRecordsOptions,
+    ) -> Result<UpdateRecordsResponse<T>>
+    where
+        T: Serialize + for<'de> Deserialize<'de>,
+    {
+        self.write_records(base_id, table_id, records, None, method, options).await
+    }
+
+    /// Upsert records in a table. A record which matches an existing record on every field in
+    /// `fields_to_merge_on` updates it, and any other record is created. Records are upserted
+    /// `MAX_RECORDS_PER_REQUEST` at a time, and the responses are combined.
+    ///
+    /// More information about upserts can be found
+    /// [here](https://airtable.com/developers/web/api/update-multiple-records)
+    ///
+    /// * `base_id`: The ID of the base
+    /// * `table_id`: The ID or name of the table
+    /// * `records`: The records to upsert
+    /// * `fields_to_merge_on`: The fields records are matched on. There must be between 1 and 3.
+    /// * `method`: Whether fields which aren't written are kept or cleared
+    /// * `options`: Options for writing the records
+    pub async fn upsert_records<T>(
+        &self,
+        base_id: &str,
+        table_id: &str,
+        records: &[NewRecord<T>],
+        fields_to_merge_on: &[String],
+        method: UpdateMethod,
+        options: &WriteRecordsOptions,
+    ) -> Result<UpdateRecordsResponse<T>>
+    where
+        T: Serialize + for<'de> Deserialize<'de>,
+    {
+        let perform_upsert = PerformUpsert { fields_to_merge_on };
+        self.write_records(base_id, table_id, records, Some(perform_upsert), method, options).await
+    }
+
+    /// Delete a record from a table.
+    ///
+    /// * `base_id`: The ID of the base
+    /// * `table_id`: The ID or name of the table
+    /// * `record_id`: The ID of the record
+    pub async fn delete_record(
+        &self,
+        base_id: &str,
+        table_id: &str,
+        record_id: &str,
+    ) -> Result<DeleteRecordResponse> {
+        let url = format!("{}/v0/{base_id}/{table_id}/{record_id}", self.base_url);
+
+        let request = self.http.delete(&url);
+        let data = self.send::<DeleteRecordResponse>(Some(base_id), request).await?;
+
+        Ok(data)
+    }
+
+    /// Delete records from a table. Records are deleted `MAX_RECORDS_PER_REQUEST` at a time, and
+    /// the responses are combined.
+    ///
+    /// * `base_id`: The ID of the base
+    /// * `table_id`: The ID or name of the table
+    /// * `record_ids`: The IDs of the records
+    pub async fn delete_records(
+        &self,
+        base_id: &str,
+        table_id: &str,
+        record_ids: &[String],
+    ) -> Result<DeleteRecordsResponse> {
+        let url = format!("{}/v0/{base_id}/{table_id}", self.base_url);
+
+        let mut deleted = DeleteRecordsResponse { records: Vec::with_capacity(record_ids.len()) };
+        for chunk in record_ids.chunks(MAX_RECORDS_PER_REQUEST) {
+            let query = chunk.iter().map(|id| ("records[]", id)).collect::<Vec<_>>();
+            let request = self.http.delete(&url).query(&query);
+            let data = self.send::<DeleteRecordsResponse>(Some(base_id), request).await?;
+
+            deleted.records.extend(data.records);
+        }
+
+        Ok(deleted)
+    }
+
+    async fn write_records<R, T>(
+        &self,
+        base_id: &str,
+        table_id: &str,
+        records: &[R],
+        perform_upsert: Option<PerformUpsert<'_>>,
+        method: UpdateMethod,
+        options: &WriteRecordsOptions,
+    ) -> Result<UpdateRecordsResponse<T>>
+    where
+        R: Serialize,
         T: for<'de> Deserialize<'de>,
     {
-        Ok(())
+        let url = format!("{}/v0/{base_id}/{table_id}", self.base_url);
+
+        let mut written = UpdateRecordsResponse {
+            records: Vec::with_capacity(records.len()),
+            created_records: vec![],
+            updated_records: vec![],
+        };
+        for chunk in records.chunks(MAX_RECORDS_PER_REQUEST) {
+            let body = WriteRecordsBody { records: chunk, perform_upsert, options };
+            let request = self.write_request(&url, method).json(&body);
+            let data = self.send::<UpdateRecordsResponse<T>>(Some(base_id), request).await?;
+
+            written.records.extend(data.records);
+            written.created_records.extend(data.created_records);
+            written.updated_records.extend(data.updated_records);
+        }
+
+        Ok(written)
+    }
+
+    fn write_request(&self, url: &str, method: UpdateMethod) -> RequestBuilder {
+        match method {
+            UpdateMethod::Merge => self.http.patch(url),
+            UpdateMethod::Replace => self.http.put(url),
+        }
     }
 }
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use super::entities::{Base, Record, Table};
+use super::entities::{Base, DeletedRecord, Record, Table};
 
 /// Base response from the Airtable API.
 ///
@@ -29,3 +29,38 @@ pub struct ListRecordsResponse<T> {
 }
 
 pub type GetRecordResponse<T> = Record<T>;
+
+/// Response from the Airtable API for creating records.
+///
+/// * `records`: The records which were created.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CreateRecordsResponse<T> {
+    pub records: Vec<Record<T>>,
+}
+
+/// Response from the Airtable API for updating or upserting records.
+///
+/// * `records`: The records which were written.
+/// * `created_records`: The IDs of the records which were created. Only returned for upserts.
+/// * `updated_records`: The IDs of the records which were updated. Only returned for upserts.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateRecordsResponse<T> {
+    pub records: Vec<Record<T>>,
+    #[serde(default)]
+    pub created_records: Vec<String>,
+    #[serde(default)]
+    pub updated_records: Vec<String>,
+}
+
+pub type UpdateRecordResponse<T> = Record<T>;
+
+/// Response from the Airtable API for deleting records.
+///
+/// * `records`: The records which were deleted.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DeleteRecordsResponse {
+    pub records: Vec<DeletedRecord>,
+}
+
+pub type DeleteRecordResponse = DeletedRecord;
@@ -0,0 +1,118 @@
+use std::fmt::Display;
+
+use reqwest::StatusCode;
+use serde::Deserialize;
+use thiserror::Error;
+
+pub type Result<T, E = AirtableError> = std::result::Result<T, E>;
+
+/// An error response from the Airtable API.
+///
+/// * `status`: The HTTP status of the response
+/// * `error_type`: The type of the error, e.g. `INVALID_PERMISSIONS_OR_MODEL_NOT_FOUND`
+/// * `message`: A description of the error, if Airtable gave one
+///
+/// More information about these errors can be found
+/// [here](https://airtable.com/developers/web/api/errors)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiError {
+    pub status: StatusCode,
+    pub error_type: String,
+    pub message: Option<String>,
+}
+
+impl Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.status.as_u16(), self.error_type)?;
+        if let Some(message) = &self.message {
+            write!(f, ": {message}")?;
+        }
+        Ok(())
+    }
+}
+
+/// An error from the Airtable client.
+#[derive(Debug, Error)]
+pub enum AirtableError {
+    /// The API token is invalid, or doesn't have access to the resource
+    #[error("Airtable rejected the credentials for the request ({0})")]
+    Auth(ApiError),
+    /// The base, table, or record doesn't exist
+    #[error("Airtable could not find the requested resource ({0})")]
+    NotFound(ApiError),
+    /// The request was malformed, or its data was rejected
+    #[error("Airtable rejected the request as invalid ({0})")]
+    InvalidRequest(ApiError),
+    /// Too many requests were made, even after retrying
+    #[error("Airtable rate limited the request ({0})")]
+    RateLimited(ApiError),
+    /// Airtable failed to handle the request
+    #[error("Airtable failed to handle the request ({0})")]
+    Server(ApiError),
+    /// The request could not be sent, or no response was received
+    #[error("error sending request to Airtable: {0}")]
+    Http(#[from] reqwest_middleware::Error),
+    /// The response could not be read
+    #[error("error reading response from Airtable: {0}")]
+    Decode(#[source] reqwest::Error),
+}
+
+/// The body of an error response. Most errors have a type and a message, but some (e.g. not
+/// found errors) only have a type.
+#[derive(Deserialize)]
+struct ErrorBody {
+    error: ErrorBodyError,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ErrorBodyError {
+    Detailed {
+        #[serde(rename = "type")]
+        error_type: String,
+        message: Option<String>,
+    },
+    Type(String),
+}
+
+impl AirtableError {
+    /// Build an error from an error response.
+    ///
+    /// * `status`: The HTTP status of the response
+    /// * `body`: The body of the response
+    pub fn from_response_parts(status: StatusCode, body: &str) -> Self {
+        let (error_type, message) = match serde_json::from_str::<ErrorBody>(body) {
+            Ok(ErrorBody { error: ErrorBodyError::Detailed { error_type, message } }) => {
+                (error_type, message)
+            }
+            Ok(ErrorBody { error: ErrorBodyError::Type(error_type) }) => (error_type, None),
+            Err(_) => {
+                let error_type = status.canonical_reason().unwrap_or("UNKNOWN").to_owned();
+                let body = body.trim();
+                (error_type, (!body.is_empty()).then(|| body.to_owned()))
+            }
+        };
+
+        let error = ApiError { status, error_type, message };
+
+        match status {
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => AirtableError::Auth(error),
+            StatusCode::NOT_FOUND => AirtableError::NotFound(error),
+            StatusCode::TOO_MANY_REQUESTS => AirtableError::RateLimited(error),
+            status if status.is_server_error() => AirtableError::Server(error),
+            _ => AirtableError::InvalidRequest(error),
+        }
+    }
+
+    /// The error response from Airtable, if there was one.
+    pub fn api_error(&self) -> Option<&ApiError> {
+        match self {
+            AirtableError::Auth(e)
+            | AirtableError::NotFound(e)
+            | AirtableError::InvalidRequest(e)
+            | AirtableError::RateLimited(e)
+            | AirtableError::Server(e) => Some(e),
+            AirtableError::Http(_) | AirtableError::Decode(_) => None,
+        }
+    }
+}
@@ -1,23 +1,33 @@
 pub mod base_data;
+pub mod error;
+pub mod rate_limit;
 mod retry;
 #[cfg(test)]
 mod tests;
+pub mod webhooks;
 
-use anyhow::Result;
+use error::{AirtableError, Result};
+use rate_limit::RateLimiter;
 use reqwest::header::{self, HeaderMap, HeaderValue};
-use reqwest::Client;
-use reqwest_middleware::{ClientBuilder, ClientWithMiddleware};
+use reqwest::{Client, Response};
+use reqwest_middleware::{ClientBuilder, ClientWithMiddleware, RequestBuilder};
 use reqwest_retry::policies::ExponentialBackoff;
 use reqwest_retry::RetryTransientMiddleware;
 use retry::DefaultRetryStrategy;
+use serde::de::DeserializeOwned;
+
+/// The URL of the Airtable API, which requests are made to unless another is given.
+pub const DEFAULT_BASE_URL: &str = "https://api.airtable.com";
 
 #[derive(Clone)]
 pub struct Airtable {
     http: ClientWithMiddleware,
+    rate_limiter: RateLimiter,
+    base_url: String,
 }
 
 impl Airtable {
-    pub fn new(api_token: &str, max_retries: u32) -> Result<Self> {
+    pub fn new(api_token: &str, max_retries: u32) -> anyhow::Result<Self> {
         let mut default_headers = HeaderMap::new();
         let mut auth = HeaderValue::from_str(&format!("Bearer {api_token}"))?;
 
@@ -34,6 +44,54 @@ impl Airtable {
             .with(retry_strategy)
             .build();
 
-        Ok(Self { http })
+        Ok(Self {
+            http,
+            rate_limiter: RateLimiter::default(),
+            base_url: DEFAULT_BASE_URL.to_owned(),
+        })
+    }
+
+    /// Make requests to another URL instead of `DEFAULT_BASE_URL`, e.g. a local fake of the API.
+    ///
+    /// * `base_url`: The URL to make requests to, without the API version
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_owned();
+        self
+    }
+
+    /// Send a request and read its response.
+    ///
+    /// Requests to a base wait their turn under the base's rate limit. Error responses are read
+    /// into an `AirtableError` instead of `T`.
+    ///
+    /// * `base_id`: The ID of the base the request is for, if it is for a base
+    /// * `request`: The request to send
+    async fn send<T>(&self, base_id: Option<&str>, request: RequestBuilder) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        let res = self.execute(base_id, request).await?;
+        res.json::<T>().await.map_err(AirtableError::Decode)
+    }
+
+    /// Send a request without reading the body of a successful response. This is for endpoints
+    /// which don't return anything, e.g. deleting a webhook.
+    ///
+    /// * `base_id`: The ID of the base the request is for, if it is for a base
+    /// * `request`: The request to send
+    async fn execute(&self, base_id: Option<&str>, request: RequestBuilder) -> Result<Response> {
+        if let Some(base_id) = base_id {
+            self.rate_limiter.acquire(base_id).await;
+        }
+
+        let res = request.send().await?;
+
+        let status = res.status();
+        if !status.is_success() {
+            let body = res.text().await.map_err(AirtableError::Decode)?;
+            return Err(AirtableError::from_response_parts(status, &body));
+        }
+
+        Ok(res)
     }
 }
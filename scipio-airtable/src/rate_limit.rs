@@ -0,0 +1,62 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::Mutex;
+use tokio::time::{self, Instant};
+
+/// The most requests Airtable accepts for a base in `RATE_LIMIT_WINDOW`.
+///
+/// More information about rate limits can be found
+/// [here](https://airtable.com/developers/web/api/rate-limits)
+pub const REQUESTS_PER_BASE: usize = 5;
+pub const RATE_LIMIT_WINDOW: Duration = Duration::from_secs(1);
+
+/// The times of the most recent requests to a base, oldest first.
+type RequestTimes = Arc<Mutex<VecDeque<Instant>>>;
+
+/// Limits how many requests are made to each base.
+///
+/// Each base gets a sliding window of the times of its most recent requests. A request which would
+/// go over the limit waits until the oldest request in the window is old enough to leave it.
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    limit: usize,
+    window: Duration,
+    bases: Arc<Mutex<HashMap<String, RequestTimes>>>,
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self::new(REQUESTS_PER_BASE, RATE_LIMIT_WINDOW)
+    }
+}
+
+impl RateLimiter {
+    /// * `limit`: The most requests to make to a base in `window`
+    /// * `window`: The window requests are counted in
+    pub fn new(limit: usize, window: Duration) -> Self {
+        Self { limit, window, bases: Arc::new(Mutex::new(HashMap::new())) }
+    }
+
+    /// Wait until a request can be made to a base, and count it.
+    ///
+    /// * `base_id`: The ID of the base
+    pub async fn acquire(&self, base_id: &str) {
+        let requests = {
+            let mut bases = self.bases.lock().await;
+            bases.entry(base_id.to_owned()).or_default().clone()
+        };
+
+        // Holding the lock while waiting makes requests to the same base wait their turn
+        let mut requests = requests.lock().await;
+
+        if requests.len() >= self.limit {
+            if let Some(oldest) = requests.pop_front() {
+                time::sleep_until(oldest + self.window).await;
+            }
+        }
+
+        requests.push_back(Instant::now());
+    }
+}
@@ -2,7 +2,6 @@ use rstest::rstest;
 use tracing_test::traced_test;
 use super::fixtures::{context, AsyncTestContext};
 
-#[cfg(feature = "integration")]
 #[rstest]
 #[traced_test]
 #[tokio::test]
@@ -0,0 +1,47 @@
+use reqwest::StatusCode;
+
+use crate::error::{AirtableError, ApiError};
+
+#[test]
+pub fn test_parse_error_response() {
+    let body = r#"{"error":{"type":"INVALID_VALUE_FOR_COLUMN","message":"Field \"Email\" cannot accept the provided value"}}"#;
+    let error = AirtableError::from_response_parts(StatusCode::UNPROCESSABLE_ENTITY, body);
+    let AirtableError::InvalidRequest(api_error) = &error else {
+        panic!("expected an invalid request error, got {error:?}");
+    };
+    assert_eq!(
+        api_error,
+        &ApiError {
+            status: StatusCode::UNPROCESSABLE_ENTITY,
+            error_type: "INVALID_VALUE_FOR_COLUMN".to_owned(),
+            message: Some("Field \"Email\" cannot accept the provided value".to_owned()),
+        }
+    );
+
+    // Not found errors only have a type
+    let error =
+        AirtableError::from_response_parts(StatusCode::NOT_FOUND, r#"{"error":"NOT_FOUND"}"#);
+    assert!(matches!(&error, AirtableError::NotFound(e) if e.error_type == "NOT_FOUND"));
+    assert_eq!(error.api_error().and_then(|e| e.message.as_deref()), None);
+
+    let error = AirtableError::from_response_parts(
+        StatusCode::UNAUTHORIZED,
+        r#"{"error":{"type":"AUTHENTICATION_REQUIRED","message":"Authentication required"}}"#,
+    );
+    assert!(matches!(error, AirtableError::Auth(_)));
+
+    let error = AirtableError::from_response_parts(StatusCode::TOO_MANY_REQUESTS, "");
+    assert!(matches!(error, AirtableError::RateLimited(_)));
+
+    // Bodies which aren't JSON are kept as the message
+    let error = AirtableError::from_response_parts(StatusCode::BAD_GATEWAY, "upstream error\n");
+    let AirtableError::Server(api_error) = &error else {
+        panic!("expected a server error, got {error:?}");
+    };
+    assert_eq!(api_error.error_type, "Bad Gateway");
+    assert_eq!(api_error.message.as_deref(), Some("upstream error"));
+    assert_eq!(
+        error.to_string(),
+        "Airtable failed to handle the request (502 Bad Gateway: upstream error)"
+    );
+}
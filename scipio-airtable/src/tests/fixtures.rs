@@ -1,11 +1,12 @@
 use std::future::Future;
 use std::pin::Pin;
 use std::sync::Arc;
-use std::{env, thread};
+use std::thread;
 use tracing_test::traced_test;
 
 use anyhow::Result;
 use rstest::fixture;
+use scipio_fake_server::FakeServer;
 use tokio::runtime::Runtime;
 use tokio::sync::Mutex;
 
@@ -13,9 +14,19 @@ use crate::Airtable;
 
 type BoxedAsyncFn = Box<dyn Fn() -> Pin<Box<dyn Future<Output = Result<()>> + Send>> + Send + Sync>;
 
+/// * `airtable`: The client to test
+/// * `base`, `table`, `view`: A base, a table of customers in it, and a view of the table
+/// * `record_id`: The ID of a customer
+/// * `cleanup`: Functions to run when the test ends
+/// * `_fake`: The fake Airtable API the client makes requests to, unless the real API is used
 pub struct AsyncTestContext {
     pub airtable: Airtable,
+    pub base: String,
+    pub table: String,
+    pub view: String,
+    pub record_id: String,
     pub cleanup: Arc<Mutex<Vec<BoxedAsyncFn>>>,
+    pub _fake: Option<FakeServer>,
 }
 
 impl Drop for AsyncTestContext {
@@ -27,7 +38,7 @@ impl Drop for AsyncTestContext {
                 for cleanup_fn in cleanup.lock().await.iter() {
                     if let Err(err) = cleanup_fn().await {
                         error_count += 1;
-                tracing::error!("Error during cleanup: {:?}", err);
+                        tracing::error!("Error during cleanup: {:?}", err);
                     }
                 }
                 if error_count > 0 {
@@ -40,8 +51,13 @@ impl Drop for AsyncTestContext {
     }
 }
 
+/// A context which uses the Airtable API. The base, table, view, and record to test with are read
+/// from the environment.
+#[cfg(feature = "integration")]
 #[fixture]
 pub fn context() -> AsyncTestContext {
+    use std::env;
+
     dotenvy::dotenv().expect("error loading environment variables");
     let api_token =
         env::var("TEST_AIRTABLE_API_TOKEN").expect("missing TEST_AIRTABLE_API_TOKEN variable");
@@ -49,5 +65,56 @@ pub fn context() -> AsyncTestContext {
 
     tracing::info!("Creating async test context");
 
-    AsyncTestContext { airtable: client, cleanup: Arc::new(Mutex::new(vec![])) }
+    AsyncTestContext {
+        airtable: client,
+        base: env::var("TEST_AIRTABLE_API_BASE").expect("missing TEST_AIRTABLE_BASE variable"),
+        table: env::var("TEST_AIRTABLE_API_TABLE").expect("missing TEST_AIRTABLE_TABLE variable"),
+        view: env::var("TEST_AIRTABLE_API_VIEW").expect("missing TEST_AIRTABLE_VIEW variable"),
+        record_id: env::var("TEST_AIRTABLE_API_RECORD_ID")
+            .expect("missing TEST_AIRTABLE_RECORD_ID variable"),
+        cleanup: Arc::new(Mutex::new(vec![])),
+        _fake: None,
+    }
+}
+
+/// A context which uses a fake Airtable API, with a table of customers.
+#[cfg(not(feature = "integration"))]
+#[fixture]
+pub fn context() -> AsyncTestContext {
+    use serde_json::{json, Map, Value};
+
+    use super::Customer;
+
+    // More customers than fit in one page of a listing with a small page size
+    const CUSTOMERS: usize = 12;
+
+    let fake = FakeServer::start();
+    let client = Airtable::new("test-api-token", 0)
+        .expect("error creating Airtable client")
+        .with_base_url(fake.url());
+
+    let base = fake.add_airtable_base("Scipio Test");
+    let table = fake.add_airtable_table(&base, "Customers", &Customer::field_names(), &["Grid"]);
+
+    let customers = (0..CUSTOMERS)
+        .map(|i| {
+            Customer::field_names()
+                .into_iter()
+                .map(|name| (name.to_owned(), json!(format!("{name} {i}"))))
+                .collect::<Map<String, Value>>()
+        })
+        .collect::<Vec<_>>();
+    let record_ids = fake.add_airtable_records(&base, &table, customers);
+
+    tracing::info!("Creating async test context");
+
+    AsyncTestContext {
+        airtable: client,
+        base,
+        table,
+        view: "Grid".to_owned(),
+        record_id: record_ids[0].clone(),
+        cleanup: Arc::new(Mutex::new(vec![])),
+        _fake: Some(fake),
+    }
 }
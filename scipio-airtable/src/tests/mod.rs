@@ -1,6 +1,9 @@
 mod bases;
+mod errors;
 mod fixtures;
+mod rate_limit;
 mod records;
+mod webhooks;
 
 use serde::{Deserialize, Serialize};
 
@@ -0,0 +1,23 @@
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+use crate::rate_limit::RateLimiter;
+
+#[tokio::test]
+pub async fn test_rate_limiter() {
+    let limiter = RateLimiter::new(2, Duration::from_millis(200));
+
+    let start = Instant::now();
+    for _ in 0..4 {
+        limiter.acquire("appA").await;
+    }
+    // The third and fourth requests wait for the first two to leave the window
+    assert!(start.elapsed() >= Duration::from_millis(200));
+
+    // Requests to another base aren't held up
+    let start = Instant::now();
+    limiter.acquire("appB").await;
+    limiter.acquire("appB").await;
+    assert!(start.elapsed() < Duration::from_millis(200));
+}
@@ -1,13 +1,16 @@
-use std::env;
 use tracing_test::traced_test;
 use anyhow::Result;
 use rstest::rstest;
 
+use serde_json::{json, Value};
+
 use super::fixtures::{context, AsyncTestContext};
 use super::Customer;
-use crate::base_data::records::{GetRecordQueryBuilder, ListRecordsQueryBuilder};
+use crate::base_data::entities::{NewRecord, RecordUpdate};
+use crate::base_data::records::{
+    GetRecordQueryBuilder, ListRecordsQueryBuilder, UpdateMethod, WriteRecordsOptionsBuilder,
+};
 
-#[cfg(feature = "integration")]
 #[rstest]
 #[traced_test]
 #[tokio::test]
@@ -20,13 +23,11 @@ pub async fn test_list_records(context: AsyncTestContext) -> Result<()> {
         })
     }));
 
-    let base = env::var("TEST_AIRTABLE_API_BASE").expect("missing TEST_AIRTABLE_BASE variable");
-    let table = env::var("TEST_AIRTABLE_API_TABLE").expect("missing TEST_AIRTABLE_TABLE variable");
-    let view = env::var("TEST_AIRTABLE_API_VIEW").expect("missing TEST_AIRTABLE_VIEW variable");
+    let (base, table) = (context.base.clone(), context.table.clone());
 
     let query = ListRecordsQueryBuilder::default()
         .fields(Customer::field_names().iter().map(ToString::to_string).collect::<Vec<_>>())
-        .view(view)
+        .view(context.view.clone())
         .build()?;
 
     let res = context.airtable.list_records::<Customer>(&base, &table, Some(&query)).await?;
@@ -36,7 +37,38 @@ pub async fn test_list_records(context: AsyncTestContext) -> Result<()> {
     Ok(())
 }
 
-#[cfg(feature = "integration")]
+#[rstest]
+#[traced_test]
+#[tokio::test]
+pub async fn test_list_all_records(context: AsyncTestContext) -> Result<()> {
+    use futures::TryStreamExt;
+
+    let (base, table) = (context.base.clone(), context.table.clone());
+
+    // Small pages, so that the stream has to follow `offset`
+    let query = ListRecordsQueryBuilder::default()
+        .fields(Customer::field_names().iter().map(ToString::to_string).collect::<Vec<_>>())
+        .view(context.view.clone())
+        .page_size(3)
+        .max_records(None)
+        .build()?;
+
+    let streamed = context
+        .airtable
+        .list_all_records::<Customer>(&base, &table, Some(&query))
+        .map_ok(|record| record.id)
+        .try_collect::<Vec<_>>()
+        .await?;
+
+    let collected =
+        context.airtable.collect_all_records::<Customer>(&base, &table, Some(&query)).await?;
+
+    assert!(streamed.len() > 3);
+    assert_eq!(streamed, collected.into_iter().map(|record| record.id).collect::<Vec<_>>());
+
+    Ok(())
+}
+
 #[rstest]
 #[traced_test]
 #[tokio::test]
@@ -52,35 +84,106 @@ pub async fn test_get_record(context: AsyncTestContext) -> Result<()> {
         })
     }));
 
-    let base = env::var("TEST_AIRTABLE_API_BASE").expect("missing TEST_AIRTABLE_BASE variable");
-    let table = env::var("TEST_AIRTABLE_API_TABLE").expect("missing TEST_AIRTABLE_TABLE variable");
-    let record_id =
-        env::var("TEST_AIRTABLE_API_RECORD_ID").expect("missing TEST_AIRTABLE_RECORD_ID variable");
+    let (base, table) = (context.base.clone(), context.table.clone());
 
     let query = GetRecordQueryBuilder::default().build()?;
 
-    let res =
-        context.airtable.get_record::<Customer>(&base, &table, &record_id, Some(&query)).await?;
+    let res = context
+        .airtable
+        .get_record::<Customer>(&base, &table, &context.record_id, Some(&query))
+        .await?;
 
     dbg!(&res);
 
     Ok(())
 }
 
-#[cfg(feature = "integration")]
+// The client's connections are driven by the test's runtime, so it must keep running while cleanup
+// blocks the test's thread
 #[rstest]
 #[traced_test]
-#[tokio::test]
+#[tokio::test(flavor = "multi_thread")]
 pub async fn test_update_record(context: AsyncTestContext) -> Result<()> {
+    let (base, table) = (context.base.clone(), context.table.clone());
+
+    let options = WriteRecordsOptionsBuilder::default().build()?;
+
+    // More records than fit in one request, so that every call is chunked
+    let records = (0..12)
+        .map(|i| NewRecord {
+            fields: json!({
+                "Customer Id": format!("scipio-test-{i}"),
+                "First Name": "Pete",
+                "Last Name": "Test",
+            }),
+        })
+        .collect::<Vec<_>>();
+
+    let created = context.airtable.create_records(&base, &table, &records, &options).await?;
+    assert_eq!(created.records.len(), records.len());
+
+    let record_ids = created.records.iter().map(|r| r.id.clone()).collect::<Vec<_>>();
+
     let mut cleanup = context.cleanup.lock().await;
     let airtable = context.airtable.clone();
+    let (cleanup_base, cleanup_table) = (base.clone(), table.clone());
     cleanup.push(Box::new(move || {
         let airtable = airtable.clone();
+        let (base, table, record_ids) =
+            (cleanup_base.clone(), cleanup_table.clone(), record_ids.clone());
         Box::pin(async move {
-            tracing::info!("Cleaning up test_get_record");
+            tracing::info!("Cleaning up test_update_record");
+            let deleted = airtable.delete_records(&base, &table, &record_ids).await?;
+            assert!(deleted.records.iter().all(|r| r.deleted));
             Ok(())
         })
     }));
 
+    let first = &created.records[0];
+    let updated = context
+        .airtable
+        .update_record(
+            &base,
+            &table,
+            &first.id,
+            &json!({ "Last Name": "Updated" }),
+            UpdateMethod::Merge,
+            &options,
+        )
+        .await?;
+    assert_eq!(updated.fields["First Name"], "Pete");
+    assert_eq!(updated.fields["Last Name"], "Updated");
+
+    let updates = created
+        .records
+        .iter()
+        .map(|r| RecordUpdate {
+            id: r.id.clone(),
+            // Keep the field the upsert below merges on
+            fields: json!({ "Customer Id": r.fields["Customer Id"], "First Name": "Replaced" }),
+        })
+        .collect::<Vec<_>>();
+    let replaced = context
+        .airtable
+        .update_records(&base, &table, &updates, UpdateMethod::Replace, &options)
+        .await?;
+    assert_eq!(replaced.records.len(), updates.len());
+    assert!(replaced.records.iter().all(|r| r.fields.get("Last Name").is_none()));
+
+    let upserts = [NewRecord { fields: json!({ "Customer Id": "scipio-test-0" }) }];
+    let upserted = context
+        .airtable
+        .upsert_records::<Value>(
+            &base,
+            &table,
+            &upserts,
+            &["Customer Id".to_owned()],
+            UpdateMethod::Merge,
+            &options,
+        )
+        .await?;
+    assert_eq!(upserted.updated_records, vec![first.id.clone()]);
+    assert!(upserted.created_records.is_empty());
+
     Ok(())
 }
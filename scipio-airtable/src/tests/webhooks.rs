@@ -0,0 +1,83 @@
+use anyhow::Result;
+use rstest::rstest;
+use serde_json::json;
+use tracing_test::traced_test;
+
+use super::fixtures::{context, AsyncTestContext};
+use crate::base_data::entities::NewRecord;
+use crate::base_data::records::WriteRecordsOptionsBuilder;
+use crate::webhooks::notifications::{sign_notification, verify_notification};
+use crate::webhooks::WebhookFiltersBuilder;
+
+// The client's connections are driven by the test's runtime, so it must keep running while cleanup
+// blocks the test's thread
+#[rstest]
+#[traced_test]
+#[tokio::test(flavor = "multi_thread")]
+pub async fn test_webhook_payloads(context: AsyncTestContext) -> Result<()> {
+    let (base, table) = (context.base.clone(), context.table.clone());
+
+    let filters = WebhookFiltersBuilder::default().build()?;
+    let webhook = context.airtable.create_webhook(&base, None, &filters).await?;
+    assert!(!webhook.mac_secret_base64.is_empty());
+
+    let mut cleanup = context.cleanup.lock().await;
+    let airtable = context.airtable.clone();
+    let (cleanup_base, webhook_id) = (base.clone(), webhook.id.clone());
+    cleanup.push(Box::new(move || {
+        let airtable = airtable.clone();
+        let (base, webhook_id) = (cleanup_base.clone(), webhook_id.clone());
+        Box::pin(async move {
+            tracing::info!("Cleaning up test_webhook_payloads");
+            airtable.delete_webhook(&base, &webhook_id).await?;
+            Ok(())
+        })
+    }));
+
+    let webhooks = context.airtable.list_webhooks(&base).await?;
+    assert!(webhooks.webhooks.iter().any(|w| w.id == webhook.id));
+
+    let options = WriteRecordsOptionsBuilder::default().build()?;
+    let records = [NewRecord { fields: json!({ "Customer Id": "scipio-test-webhook" }) }];
+    let created = context.airtable.create_records(&base, &table, &records, &options).await?;
+    let record_id = created.records[0].id.clone();
+    context.airtable.delete_records(&base, &table, std::slice::from_ref(&record_id)).await?;
+
+    let res = context.airtable.list_webhook_payloads(&base, &webhook.id, 1, Some(1)).await?;
+    assert_eq!(res.payloads.len(), 1);
+    assert!(res.might_have_more);
+
+    let created_records = res.payloads[0]
+        .changed_tables_by_id
+        .values()
+        .filter_map(|changes| changes["createdRecordsById"].as_object())
+        .collect::<Vec<_>>();
+    assert!(created_records.iter().any(|records| records.contains_key(&record_id)));
+
+    let res = context.airtable.list_webhook_payloads(&base, &webhook.id, res.cursor, None).await?;
+    assert_eq!(res.payloads.len(), 1);
+    assert!(!res.might_have_more);
+    assert_eq!(res.cursor, 3);
+
+    let refreshed = context.airtable.refresh_webhook(&base, &webhook.id).await?;
+    assert!(refreshed.expiration_time.is_some());
+
+    Ok(())
+}
+
+#[test]
+pub fn test_verify_notification() {
+    let secret = "c2NpcGlvLXRlc3Qtc2VjcmV0";
+    let body = br#"{"base":{"id":"appXXXXXXXXXXXXXX"},"webhook":{"id":"achXXXXXXXXXXXXXX"},"timestamp":"2024-10-21T00:00:00.000Z"}"#;
+    let signature = "hmac-sha256=a14a4a4649fcfb64c17cd86775cc33079b3880f5590d5e9cbe1b198ba713d302";
+
+    assert_eq!(sign_notification(secret, body).as_deref(), Some(signature));
+    assert!(verify_notification(secret, body, signature));
+
+    let mut tampered = body.to_vec();
+    tampered[10] = b'Y';
+    assert!(!verify_notification(secret, &tampered, signature));
+    assert!(!verify_notification("c2NpcGlvLW90aGVyLXNlY3JldA==", body, signature));
+    assert!(!verify_notification(secret, body, signature.trim_start_matches("hmac-sha256=")));
+    assert!(!verify_notification("not base64!", body, signature));
+}
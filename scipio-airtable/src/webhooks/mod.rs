@@ -0,0 +1,155 @@
+//! Webhooks notify a URL when a base changes. Airtable only says that something changed; the
+//! changes themselves are read from the webhook's payloads, starting at a cursor which the receiver
+//! keeps track of.
+//!
+//! More information about webhooks can be found
+//! [here](https://airtable.com/developers/web/api/webhooks-overview)
+
+pub mod notifications;
+pub mod responses;
+
+use derive_builder::Builder;
+use serde::Serialize;
+
+use self::responses::{
+    CreateWebhookResponse, ListWebhookPayloadsResponse, ListWebhooksResponse,
+    RefreshWebhookResponse,
+};
+use crate::error::Result;
+use crate::Airtable;
+
+/// The most payloads Airtable returns in a single request.
+pub const MAX_PAYLOADS_PER_REQUEST: u8 = 50;
+
+/// The kinds of changes a webhook can be notified about.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum WebhookDataType {
+    /// Changes to records
+    TableData,
+    /// Changes to fields
+    TableFields,
+    /// Changes to tables
+    TableMetadata,
+}
+
+/// Which changes to notify a webhook about.
+///
+/// * `data_types`: The kinds of changes to notify the webhook about
+/// * `record_change_scope`: The ID of a table or view to limit the webhook to, if it is limited to
+///   one
+///
+/// More information about these filters can be found
+/// [here](https://airtable.com/developers/web/api/model/webhooks-specification)
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Builder)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookFilters {
+    #[builder(default = "vec![WebhookDataType::TableData]")]
+    pub data_types: Vec<WebhookDataType>,
+    #[builder(default, setter(into))]
+    pub record_change_scope: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct WebhookOptions<'a> {
+    filters: &'a WebhookFilters,
+}
+
+#[derive(Debug, Serialize)]
+struct WebhookSpecification<'a> {
+    options: WebhookOptions<'a>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CreateWebhookBody<'a> {
+    notification_url: Option<&'a str>,
+    specification: WebhookSpecification<'a>,
+}
+
+impl Airtable {
+    /// Register a webhook on a base.
+    ///
+    /// The response holds the secret Airtable signs notifications with, and it can't be fetched
+    /// again, so it should be stored. Webhooks expire after 7 days unless they are refreshed.
+    ///
+    /// * `base_id`: The ID of the base
+    /// * `notification_url`: The URL Airtable notifies when the base changes. Without one, the
+    ///   webhook only records payloads, which can be polled for.
+    /// * `filters`: Which changes to record payloads for
+    pub async fn create_webhook(
+        &self,
+        base_id: &str,
+        notification_url: Option<&str>,
+        filters: &WebhookFilters,
+    ) -> Result<CreateWebhookResponse> {
+        let url = format!("{}/v0/bases/{base_id}/webhooks", self.base_url);
+        let body = CreateWebhookBody {
+            notification_url,
+            specification: WebhookSpecification { options: WebhookOptions { filters } },
+        };
+
+        self.send(Some(base_id), self.http.post(url).json(&body)).await
+    }
+
+    /// List the webhooks on a base.
+    ///
+    /// * `base_id`: The ID of the base
+    pub async fn list_webhooks(&self, base_id: &str) -> Result<ListWebhooksResponse> {
+        let url = format!("{}/v0/bases/{base_id}/webhooks", self.base_url);
+        self.send(Some(base_id), self.http.get(url)).await
+    }
+
+    /// Extend the life of a webhook by 7 days from now.
+    ///
+    /// * `base_id`: The ID of the base
+    /// * `webhook_id`: The ID of the webhook
+    pub async fn refresh_webhook(
+        &self,
+        base_id: &str,
+        webhook_id: &str,
+    ) -> Result<RefreshWebhookResponse> {
+        let url = format!("{}/v0/bases/{base_id}/webhooks/{webhook_id}/refresh", self.base_url);
+        self.send(Some(base_id), self.http.post(url)).await
+    }
+
+    /// Delete a webhook, so that Airtable stops notifying its URL.
+    ///
+    /// * `base_id`: The ID of the base
+    /// * `webhook_id`: The ID of the webhook
+    pub async fn delete_webhook(&self, base_id: &str, webhook_id: &str) -> Result<()> {
+        let url = format!("{}/v0/bases/{base_id}/webhooks/{webhook_id}", self.base_url);
+        self.execute(Some(base_id), self.http.delete(url)).await?;
+        Ok(())
+    }
+
+    /// List the payloads of a webhook, oldest first, starting at a cursor.
+    ///
+    /// Every payload has a number, and the cursor is the number of the first payload to return.
+    /// The response holds the cursor to continue from, and whether there might be more payloads
+    /// after it. Airtable keeps payloads for 7 days, and listing them extends the life of the
+    /// webhook.
+    ///
+    /// * `base_id`: The ID of the base
+    /// * `webhook_id`: The ID of the webhook
+    /// * `cursor`: The number of the first payload to return, starting at 1
+    /// * `limit`: The most payloads to return, up to `MAX_PAYLOADS_PER_REQUEST`
+    pub async fn list_webhook_payloads(
+        &self,
+        base_id: &str,
+        webhook_id: &str,
+        cursor: i64,
+        limit: Option<u8>,
+    ) -> Result<ListWebhookPayloadsResponse> {
+        let mut url = format!(
+            "{}/v0/bases/{base_id}/webhooks/{webhook_id}/payloads?cursor={cursor}",
+            self.base_url
+        );
+        if let Some(limit) = limit {
+            url.push_str(&format!("&limit={}", limit.min(MAX_PAYLOADS_PER_REQUEST)));
+        }
+
+        self.send(Some(base_id), self.http.get(url)).await
+    }
+}
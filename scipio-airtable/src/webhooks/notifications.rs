@@ -0,0 +1,69 @@
+//! Airtable notifies a webhook's URL with a small JSON body, signed with the webhook's MAC secret.
+//! The signature should be checked before the notification is trusted.
+//!
+//! More information about notifications can be found
+//! [here](https://airtable.com/developers/web/api/webhooks-overview#webhook-notification-delivery)
+
+use base64::prelude::{Engine, BASE64_STANDARD};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+/// The header Airtable puts the signature of a notification in.
+pub const MAC_HEADER: &str = "X-Airtable-Content-MAC";
+
+const MAC_PREFIX: &str = "hmac-sha256=";
+
+/// A reference to a base or webhook in a notification.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct NotificationRef {
+    pub id: String,
+}
+
+/// The body of a notification.
+///
+/// * `base`: The base which changed
+/// * `webhook`: The webhook being notified
+/// * `timestamp`: When the notification was sent
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WebhookNotification {
+    pub base: NotificationRef,
+    pub webhook: NotificationRef,
+    pub timestamp: String,
+}
+
+/// Sign the body of a notification the way Airtable does, returning the value of `MAC_HEADER`.
+/// Returns `None` if the secret isn't valid base64.
+///
+/// * `mac_secret_base64`: The webhook's MAC secret, as Airtable returned it
+/// * `body`: The raw body of the notification
+pub fn sign_notification(mac_secret_base64: &str, body: &[u8]) -> Option<String> {
+    let mac = notification_mac(mac_secret_base64, body)?;
+    Some(format!("{MAC_PREFIX}{}", hex::encode(mac.finalize().into_bytes())))
+}
+
+/// Check that a notification was signed with a webhook's MAC secret.
+///
+/// The signature is compared in constant time. The body must be exactly the bytes which were
+/// received, since re-serializing it can change the signature.
+///
+/// * `mac_secret_base64`: The webhook's MAC secret, as Airtable returned it
+/// * `body`: The raw body of the notification
+/// * `signature`: The value of `MAC_HEADER`
+pub fn verify_notification(mac_secret_base64: &str, body: &[u8], signature: &str) -> bool {
+    let Some(signature) =
+        signature.strip_prefix(MAC_PREFIX).and_then(|signature| hex::decode(signature).ok())
+    else {
+        return false;
+    };
+
+    notification_mac(mac_secret_base64, body)
+        .is_some_and(|mac| mac.verify_slice(&signature).is_ok())
+}
+
+fn notification_mac(mac_secret_base64: &str, body: &[u8]) -> Option<Hmac<Sha256>> {
+    let secret = BASE64_STANDARD.decode(mac_secret_base64).ok()?;
+    let mut mac = Hmac::<Sha256>::new_from_slice(&secret).ok()?;
+    mac.update(body);
+    Some(mac)
+}
@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Response from the Airtable API for creating a webhook.
+///
+/// * `id`: The ID of the webhook
+/// * `mac_secret_base64`: The secret notifications are signed with, encoded in base64. Airtable
+///   only returns it when the webhook is created.
+/// * `expiration_time`: When the webhook expires, unless it is refreshed
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateWebhookResponse {
+    pub id: String,
+    pub mac_secret_base64: String,
+    pub expiration_time: Option<DateTime<Utc>>,
+}
+
+/// A webhook on a base.
+///
+/// * `id`: The ID of the webhook
+/// * `notification_url`: The URL Airtable notifies, if it notifies one
+/// * `cursor_for_next_payload`: The number the next payload will have
+/// * `are_notifications_enabled`: Whether Airtable is notifying the URL. Airtable stops after
+///   notifications keep failing.
+/// * `is_hook_enabled`: Whether the webhook is recording payloads
+/// * `expiration_time`: When the webhook expires, unless it is refreshed
+///
+/// More information about this definition can be found
+/// [here](https://airtable.com/developers/web/api/list-webhooks)
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Webhook {
+    pub id: String,
+    pub notification_url: Option<String>,
+    pub cursor_for_next_payload: i64,
+    pub are_notifications_enabled: bool,
+    pub is_hook_enabled: bool,
+    pub expiration_time: Option<DateTime<Utc>>,
+}
+
+/// Response from the Airtable API for listing webhooks.
+///
+/// * `webhooks`: The webhooks on the base
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ListWebhooksResponse {
+    pub webhooks: Vec<Webhook>,
+}
+
+/// Response from the Airtable API for refreshing a webhook.
+///
+/// * `expiration_time`: When the webhook now expires
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RefreshWebhookResponse {
+    pub expiration_time: Option<DateTime<Utc>>,
+}
+
+/// A set of changes to a base, made in a single transaction.
+///
+/// * `timestamp`: When the changes were made
+/// * `base_transaction_number`: The number of the transaction the changes were made in
+/// * `changed_tables_by_id`: The changes to each table, keyed by table ID
+/// * `created_tables_by_id`: The tables which were created, keyed by table ID
+/// * `destroyed_table_ids`: The IDs of the tables which were deleted
+///
+/// The changes are left as JSON, since their shape depends on what changed. More information
+/// about this definition can be found
+/// [here](https://airtable.com/developers/web/api/model/webhooks-payload)
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookPayload {
+    pub timestamp: DateTime<Utc>,
+    pub base_transaction_number: i64,
+    #[serde(default)]
+    pub changed_tables_by_id: HashMap<String, Value>,
+    #[serde(default)]
+    pub created_tables_by_id: HashMap<String, Value>,
+    #[serde(default)]
+    pub destroyed_table_ids: Vec<String>,
+}
+
+/// Response from the Airtable API for listing the payloads of a webhook.
+///
+/// * `cursor`: The cursor to list the next payloads from
+/// * `might_have_more`: Whether there might be more payloads after `cursor`
+/// * `payloads`: The payloads, oldest first
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListWebhookPayloadsResponse {
+    pub cursor: i64,
+    pub might_have_more: bool,
+    pub payloads: Vec<WebhookPayload>,
+}
@@ -0,0 +1,917 @@
+//! A fake of the Airtable Web API: listing bases, reading base schemas, reading and writing
+//! records, and managing webhooks.
+//!
+//! More information about the real API can be found
+//! [here](https://airtable.com/developers/web/api/introduction)
+
+use axum::extract::{Path, Query, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{delete, get, post};
+use axum::{Json, Router};
+use base64::prelude::{Engine, BASE64_STANDARD};
+use chrono::{Duration, SecondsFormat, Utc};
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+
+use crate::{bearer_token, AppState};
+
+/// The most bases listed at a time.
+const BASES_PAGE_SIZE: usize = 100;
+/// The most records listed at a time, and the number listed if a page size isn't given.
+const MAX_PAGE_SIZE: usize = 100;
+/// The most records which can be written or deleted in a single request.
+const MAX_RECORDS_PER_REQUEST: usize = 10;
+/// The most webhook payloads listed at a time, and the number listed if a limit isn't given.
+const MAX_PAYLOADS_PER_REQUEST: usize = 50;
+/// How long a webhook lives after it is created or refreshed.
+const WEBHOOK_LIFETIME_DAYS: i64 = 7;
+
+pub(crate) fn router() -> Router<AppState> {
+    Router::new()
+        .route("/v0/meta/bases", get(list_bases))
+        .route("/v0/meta/bases/:base_id/tables", get(get_base_schema))
+        .route("/v0/bases/:base_id/webhooks", get(list_webhooks).post(create_webhook))
+        .route("/v0/bases/:base_id/webhooks/:webhook_id", delete(delete_webhook))
+        .route("/v0/bases/:base_id/webhooks/:webhook_id/refresh", post(refresh_webhook))
+        .route("/v0/bases/:base_id/webhooks/:webhook_id/payloads", get(list_webhook_payloads))
+        .route(
+            "/v0/:base_id/:table",
+            get(list_records)
+                .post(create_records)
+                .patch(merge_records)
+                .put(replace_records)
+                .delete(delete_records),
+        )
+        .route("/v0/:base_id/:table/", get(list_records))
+        .route(
+            "/v0/:base_id/:table/:record_id",
+            get(get_record).patch(merge_record).put(replace_record).delete(delete_record),
+        )
+        .route("/v0/:base_id/:table/:record_id/", get(get_record))
+}
+
+#[derive(Default)]
+pub(crate) struct AirtableData {
+    last_id: u64,
+    bases: Vec<Base>,
+}
+
+struct Base {
+    id: String,
+    name: String,
+    tables: Vec<Table>,
+    webhooks: Vec<Webhook>,
+}
+
+/// A webhook. It records a payload for every change to a record in its base, and the cursor of a
+/// payload is its position in `payloads`, starting at 1. Notifications are never sent.
+struct Webhook {
+    id: String,
+    notification_url: Option<String>,
+    expiration_time: String,
+    payloads: Vec<Value>,
+}
+
+impl Webhook {
+    fn to_json(&self) -> Value {
+        json!({
+            "id": self.id,
+            "notificationUrl": self.notification_url,
+            "cursorForNextPayload": self.payloads.len() + 1,
+            "areNotificationsEnabled": self.notification_url.is_some(),
+            "isHookEnabled": true,
+            "expirationTime": self.expiration_time,
+        })
+    }
+}
+
+/// How a change touched the records of a table, as the key a webhook payload lists them under.
+#[derive(Clone, Copy)]
+enum RecordChange {
+    Created,
+    Changed,
+    Destroyed,
+}
+
+/// A table. Fields and views are `(id, name)` pairs.
+struct Table {
+    id: String,
+    name: String,
+    fields: Vec<(String, String)>,
+    views: Vec<(String, String)>,
+    records: Vec<Record>,
+}
+
+struct Record {
+    id: String,
+    created_time: String,
+    fields: Map<String, Value>,
+}
+
+impl Record {
+    fn to_json(&self, field_names: Option<&[String]>) -> Value {
+        let fields = match field_names {
+            Some(names) => self
+                .fields
+                .iter()
+                .filter(|(name, _)| names.contains(name))
+                .map(|(name, value)| (name.clone(), value.clone()))
+                .collect(),
+            None => self.fields.clone(),
+        };
+
+        json!({ "id": self.id, "createdTime": self.created_time, "fields": fields })
+    }
+}
+
+impl Table {
+    fn find(&self, table: &str) -> bool {
+        self.id == table || self.name == table
+    }
+
+    fn record_mut(&mut self, record_id: &str) -> ApiResult<&mut Record> {
+        self.records.iter_mut().find(|r| r.id == record_id).ok_or_else(ApiError::not_found)
+    }
+
+    /// Write fields to a record. Writing `null`, an empty string, or an empty list clears a
+    /// field, and a field which isn't written is cleared if `replace` is set.
+    fn write_fields(
+        &self,
+        fields: &mut Map<String, Value>,
+        written: Map<String, Value>,
+        replace: bool,
+    ) -> ApiResult<()> {
+        for name in written.keys() {
+            if !self.fields.iter().any(|(_, field)| field == name) {
+                return Err(ApiError::new(
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    "UNKNOWN_FIELD_NAME",
+                    format!("Unknown field name: \"{name}\""),
+                ));
+            }
+        }
+
+        if replace {
+            fields.clear();
+        }
+        for (name, value) in written {
+            match value {
+                Value::Null => fields.remove(&name),
+                Value::String(ref s) if s.is_empty() => fields.remove(&name),
+                Value::Array(ref a) if a.is_empty() => fields.remove(&name),
+                value => fields.insert(name, value),
+            };
+        }
+
+        Ok(())
+    }
+}
+
+impl AirtableData {
+    fn next_id(&mut self, prefix: &str) -> String {
+        self.last_id += 1;
+        format!("{prefix}{:014}", self.last_id)
+    }
+
+    pub(crate) fn add_base(&mut self, name: &str) -> String {
+        let id = self.next_id("app");
+        self.bases.push(Base {
+            id: id.clone(),
+            name: name.to_owned(),
+            tables: vec![],
+            webhooks: vec![],
+        });
+        id
+    }
+
+    pub(crate) fn add_table(
+        &mut self,
+        base_id: &str,
+        name: &str,
+        fields: &[&str],
+        views: &[&str],
+    ) -> String {
+        let id = self.next_id("tbl");
+        let fields = fields.iter().map(|name| (self.next_id("fld"), name.to_string())).collect();
+        let views = views.iter().map(|name| (self.next_id("viw"), name.to_string())).collect();
+
+        let base = self.base_mut(base_id).expect("no base with this ID");
+        base.tables.push(Table {
+            id: id.clone(),
+            name: name.to_owned(),
+            fields,
+            views,
+            records: vec![],
+        });
+
+        id
+    }
+
+    pub(crate) fn add_records(
+        &mut self,
+        base_id: &str,
+        table: &str,
+        records: Vec<Map<String, Value>>,
+    ) -> Vec<String> {
+        records
+            .into_iter()
+            .map(|fields| {
+                let record =
+                    self.create_record(base_id, table, fields).expect("error adding record");
+                record["id"].as_str().unwrap_or_default().to_owned()
+            })
+            .collect()
+    }
+
+    fn base_mut(&mut self, base_id: &str) -> ApiResult<&mut Base> {
+        self.bases.iter_mut().find(|b| b.id == base_id).ok_or_else(ApiError::model_not_found)
+    }
+
+    fn table_mut(&mut self, base_id: &str, table: &str) -> ApiResult<&mut Table> {
+        self.base_mut(base_id)?
+            .tables
+            .iter_mut()
+            .find(|t| t.find(table))
+            .ok_or_else(ApiError::model_not_found)
+    }
+
+    fn create_record(
+        &mut self,
+        base_id: &str,
+        table: &str,
+        written: Map<String, Value>,
+    ) -> ApiResult<Value> {
+        let id = self.next_id("rec");
+        let record_id = id.clone();
+        let table = self.table_mut(base_id, table)?;
+
+        let mut fields = Map::new();
+        table.write_fields(&mut fields, written, false)?;
+
+        let record = Record {
+            id,
+            created_time: Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true),
+            fields,
+        };
+        let json = record.to_json(None);
+        table.records.push(record);
+
+        let table_id = table.id.clone();
+        self.record_changes(base_id, &table_id, RecordChange::Created, &[record_id]);
+
+        Ok(json)
+    }
+
+    fn update_record(
+        &mut self,
+        base_id: &str,
+        table: &str,
+        record_id: &str,
+        written: Map<String, Value>,
+        replace: bool,
+    ) -> ApiResult<Value> {
+        let table = self.table_mut(base_id, table)?;
+
+        let mut fields = std::mem::take(&mut table.record_mut(record_id)?.fields);
+        let res = table.write_fields(&mut fields, written, replace);
+
+        let record = table.record_mut(record_id)?;
+        record.fields = fields;
+        let json = res.map(|_| record.to_json(None))?;
+
+        let table_id = table.id.clone();
+        self.record_changes(base_id, &table_id, RecordChange::Changed, &[record_id.to_owned()]);
+
+        Ok(json)
+    }
+
+    fn delete_records(
+        &mut self,
+        base_id: &str,
+        table: &str,
+        record_ids: &[String],
+    ) -> ApiResult<()> {
+        let table = self.table_mut(base_id, table)?;
+
+        if record_ids.iter().any(|id| !table.records.iter().any(|r| &r.id == id)) {
+            return Err(ApiError::not_found());
+        }
+        table.records.retain(|r| !record_ids.contains(&r.id));
+
+        let table_id = table.id.clone();
+        self.record_changes(base_id, &table_id, RecordChange::Destroyed, record_ids);
+
+        Ok(())
+    }
+
+    /// Record a payload for a change to the records of a table on every webhook of its base. The
+    /// payload only says which records changed, not how.
+    fn record_changes(
+        &mut self,
+        base_id: &str,
+        table_id: &str,
+        change: RecordChange,
+        record_ids: &[String],
+    ) {
+        let Ok(base) = self.base_mut(base_id) else {
+            return;
+        };
+
+        let by_id =
+            |ids: &[String]| ids.iter().map(|id| (id.clone(), json!({}))).collect::<Map<_, _>>();
+        let changes = match change {
+            RecordChange::Created => json!({ "createdRecordsById": by_id(record_ids) }),
+            RecordChange::Changed => json!({ "changedRecordsById": by_id(record_ids) }),
+            RecordChange::Destroyed => json!({ "destroyedRecordIds": record_ids }),
+        };
+
+        for webhook in &mut base.webhooks {
+            let payload = json!({
+                "timestamp": Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true),
+                "baseTransactionNumber": webhook.payloads.len() + 1,
+                "actionMetadata": { "source": "publicApi", "sourceMetadata": {} },
+                "payloadFormat": "v0",
+                "changedTablesById": { table_id: changes.clone() },
+            });
+            webhook.payloads.push(payload);
+        }
+    }
+
+    fn webhook_mut(&mut self, base_id: &str, webhook_id: &str) -> ApiResult<&mut Webhook> {
+        self.base_mut(base_id)?
+            .webhooks
+            .iter_mut()
+            .find(|w| w.id == webhook_id)
+            .ok_or_else(ApiError::not_found)
+    }
+}
+
+type ApiResult<T> = Result<T, ApiError>;
+
+/// An error response, in the format of the Airtable API.
+#[derive(Debug)]
+struct ApiError {
+    status: StatusCode,
+    error_type: &'static str,
+    message: Option<String>,
+}
+
+impl ApiError {
+    fn new(status: StatusCode, error_type: &'static str, message: impl Into<String>) -> Self {
+        Self { status, error_type, message: Some(message.into()) }
+    }
+
+    fn invalid_request(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::UNPROCESSABLE_ENTITY, "INVALID_REQUEST_UNKNOWN", message)
+    }
+
+    /// Airtable doesn't say whether a base or table is missing, or the token can't access it.
+    fn model_not_found() -> Self {
+        Self::new(
+            StatusCode::FORBIDDEN,
+            "INVALID_PERMISSIONS_OR_MODEL_NOT_FOUND",
+            "Invalid permissions, or the requested model was not found.",
+        )
+    }
+
+    /// A missing record only has an error type.
+    fn not_found() -> Self {
+        Self { status: StatusCode::NOT_FOUND, error_type: "NOT_FOUND", message: None }
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let body = match self.message {
+            Some(message) => json!({ "error": { "type": self.error_type, "message": message } }),
+            None => json!({ "error": self.error_type }),
+        };
+        (self.status, Json(body)).into_response()
+    }
+}
+
+fn authenticate(headers: &HeaderMap) -> ApiResult<()> {
+    match bearer_token(headers) {
+        Some(_) => Ok(()),
+        None => Err(ApiError::new(
+            StatusCode::UNAUTHORIZED,
+            "AUTHENTICATION_REQUIRED",
+            "Authentication required",
+        )),
+    }
+}
+
+/// The query parameters of a request, in order.
+///
+/// Parameters are matched loosely: `fields[]` and `fields` are the same parameter, and so are
+/// `pageSize` and `page_size`.
+struct Params(Vec<(String, String)>);
+
+impl Params {
+    fn new(pairs: Vec<(String, String)>) -> Self {
+        let normalize = |key: &str| {
+            let mut name = String::with_capacity(key.len());
+            let mut upper = false;
+            for c in key.trim_end_matches("[]").chars() {
+                match c {
+                    '_' => upper = true,
+                    c if upper => {
+                        name.extend(c.to_uppercase());
+                        upper = false;
+                    }
+                    c => name.push(c),
+                }
+            }
+            name
+        };
+
+        Self(pairs.into_iter().map(|(key, value)| (normalize(&key), value)).collect())
+    }
+
+    fn get(&self, name: &str) -> Option<&str> {
+        self.0.iter().find(|(key, _)| key == name).map(|(_, value)| value.as_str())
+    }
+
+    fn get_all(&self, name: &str) -> Vec<String> {
+        self.0.iter().filter(|(key, _)| key == name).map(|(_, value)| value.clone()).collect()
+    }
+
+    fn get_number(&self, name: &str) -> ApiResult<Option<usize>> {
+        self.get(name)
+            .map(|value| {
+                value
+                    .parse::<usize>()
+                    .map_err(|_| ApiError::invalid_request(format!("Invalid {name}: {value}")))
+            })
+            .transpose()
+    }
+}
+
+/// Where to continue listing from. Offsets look like `itr<index>`.
+fn parse_offset(offset: Option<&str>) -> ApiResult<usize> {
+    let Some(offset) = offset else {
+        return Ok(0);
+    };
+
+    offset.strip_prefix("itr").and_then(|index| index.parse().ok()).ok_or_else(|| {
+        ApiError::new(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "LIST_RECORDS_ITERATOR_NOT_AVAILABLE",
+            "The offset is invalid or has expired",
+        )
+    })
+}
+
+async fn list_bases(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(params): Query<Vec<(String, String)>>,
+) -> ApiResult<Json<Value>> {
+    authenticate(&headers)?;
+
+    let params = Params::new(params);
+    let start = parse_offset(params.get("offset"))?;
+
+    let data = state.airtable.lock().unwrap();
+    let bases = data
+        .bases
+        .iter()
+        .skip(start)
+        .take(BASES_PAGE_SIZE)
+        .map(|b| json!({ "id": b.id, "name": b.name, "permissionLevel": "create" }))
+        .collect::<Vec<_>>();
+
+    let mut res = json!({ "bases": bases });
+    if start + BASES_PAGE_SIZE < data.bases.len() {
+        res["offset"] = json!(format!("itr{}", start + BASES_PAGE_SIZE));
+    }
+
+    Ok(Json(res))
+}
+
+async fn get_base_schema(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(base_id): Path<String>,
+) -> ApiResult<Json<Value>> {
+    authenticate(&headers)?;
+
+    let mut data = state.airtable.lock().unwrap();
+    let tables = data
+        .base_mut(&base_id)?
+        .tables
+        .iter()
+        .map(|table| {
+            let fields = table
+                .fields
+                .iter()
+                .map(|(id, name)| json!({ "id": id, "name": name, "type": "singleLineText" }))
+                .collect::<Vec<_>>();
+            let views = table
+                .views
+                .iter()
+                .map(|(id, name)| json!({ "id": id, "name": name, "type": "grid" }))
+                .collect::<Vec<_>>();
+
+            json!({
+                "id": table.id,
+                "name": table.name,
+                "primaryFieldId": table.fields.first().map(|(id, _)| id),
+                "fields": fields,
+                "views": views,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    Ok(Json(json!({ "tables": tables })))
+}
+
+async fn list_records(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path((base_id, table)): Path<(String, String)>,
+    Query(params): Query<Vec<(String, String)>>,
+) -> ApiResult<Json<Value>> {
+    authenticate(&headers)?;
+
+    let params = Params::new(params);
+    let page_size = params.get_number("pageSize")?.unwrap_or(MAX_PAGE_SIZE);
+    if page_size == 0 || page_size > MAX_PAGE_SIZE {
+        return Err(ApiError::invalid_request(format!("Invalid pageSize: {page_size}")));
+    }
+    let max_records = params.get_number("maxRecords")?;
+    let start = parse_offset(params.get("offset"))?;
+
+    let mut data = state.airtable.lock().unwrap();
+    let table = data.table_mut(&base_id, &table)?;
+
+    if let Some(view) = params.get("view") {
+        if !table.views.iter().any(|(id, name)| id == view || name == view) {
+            return Err(ApiError::new(
+                StatusCode::UNPROCESSABLE_ENTITY,
+                "VIEW_NAME_NOT_FOUND",
+                format!("Could not find view {view}"),
+            ));
+        }
+    }
+
+    let field_names = params.get_all("fields");
+    if let Some(name) =
+        field_names.iter().find(|name| !table.fields.iter().any(|(_, field)| field == *name))
+    {
+        return Err(ApiError::new(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "UNKNOWN_FIELD_NAME",
+            format!("Unknown field name: \"{name}\""),
+        ));
+    }
+    let field_names = (!field_names.is_empty()).then_some(field_names.as_slice());
+
+    let total = table.records.len().min(max_records.unwrap_or(usize::MAX));
+    let end = total.min(start + page_size);
+    let records = table
+        .records
+        .get(start..end)
+        .unwrap_or_default()
+        .iter()
+        .map(|record| record.to_json(field_names))
+        .collect::<Vec<_>>();
+
+    let mut res = json!({ "records": records });
+    if end < total {
+        res["offset"] = json!(format!("itr{end}"));
+    }
+
+    Ok(Json(res))
+}
+
+async fn get_record(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path((base_id, table, record_id)): Path<(String, String, String)>,
+) -> ApiResult<Json<Value>> {
+    authenticate(&headers)?;
+
+    let mut data = state.airtable.lock().unwrap();
+    let record = data.table_mut(&base_id, &table)?.record_mut(&record_id)?.to_json(None);
+
+    Ok(Json(record))
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WriteRecordsBody {
+    records: Vec<WriteRecord>,
+    perform_upsert: Option<PerformUpsert>,
+}
+
+#[derive(Deserialize)]
+struct WriteRecord {
+    id: Option<String>,
+    #[serde(default)]
+    fields: Map<String, Value>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PerformUpsert {
+    fields_to_merge_on: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct WriteRecordBody {
+    #[serde(default)]
+    fields: Map<String, Value>,
+}
+
+fn check_record_count(count: usize) -> ApiResult<()> {
+    if count == 0 || count > MAX_RECORDS_PER_REQUEST {
+        return Err(ApiError::new(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "INVALID_RECORDS",
+            format!("Between 1 and {MAX_RECORDS_PER_REQUEST} records may be written at a time"),
+        ));
+    }
+    Ok(())
+}
+
+async fn create_records(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path((base_id, table)): Path<(String, String)>,
+    Json(body): Json<WriteRecordsBody>,
+) -> ApiResult<Json<Value>> {
+    authenticate(&headers)?;
+    check_record_count(body.records.len())?;
+
+    let mut data = state.airtable.lock().unwrap();
+    let records = body
+        .records
+        .into_iter()
+        .map(|record| data.create_record(&base_id, &table, record.fields))
+        .collect::<ApiResult<Vec<_>>>()?;
+
+    Ok(Json(json!({ "records": records })))
+}
+
+async fn merge_records(
+    state: State<AppState>,
+    headers: HeaderMap,
+    path: Path<(String, String)>,
+    body: Json<WriteRecordsBody>,
+) -> ApiResult<Json<Value>> {
+    write_records(state, headers, path, body, false).await
+}
+
+async fn replace_records(
+    state: State<AppState>,
+    headers: HeaderMap,
+    path: Path<(String, String)>,
+    body: Json<WriteRecordsBody>,
+) -> ApiResult<Json<Value>> {
+    write_records(state, headers, path, body, true).await
+}
+
+/// Update records, or upsert them if `performUpsert` is set.
+async fn write_records(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path((base_id, table)): Path<(String, String)>,
+    Json(body): Json<WriteRecordsBody>,
+    replace: bool,
+) -> ApiResult<Json<Value>> {
+    authenticate(&headers)?;
+    check_record_count(body.records.len())?;
+
+    let mut data = state.airtable.lock().unwrap();
+
+    let Some(perform_upsert) = body.perform_upsert else {
+        let records = body
+            .records
+            .into_iter()
+            .map(|record| {
+                let id = record.id.ok_or_else(|| {
+                    ApiError::invalid_request("Records must have an ID unless upserting")
+                })?;
+                data.update_record(&base_id, &table, &id, record.fields, replace)
+            })
+            .collect::<ApiResult<Vec<_>>>()?;
+
+        return Ok(Json(json!({ "records": records })));
+    };
+
+    let merge_on = perform_upsert.fields_to_merge_on;
+    if merge_on.is_empty() || merge_on.len() > 3 {
+        return Err(ApiError::invalid_request("fieldsToMergeOn must have between 1 and 3 fields"));
+    }
+
+    let (mut records, mut created, mut updated) = (vec![], vec![], vec![]);
+    for record in body.records {
+        let existing = data.table_mut(&base_id, &table)?.records.iter().find_map(|existing| {
+            merge_on
+                .iter()
+                .all(|name| existing.fields.get(name) == record.fields.get(name))
+                .then(|| existing.id.clone())
+        });
+
+        let written = match existing {
+            Some(id) => {
+                updated.push(id.clone());
+                data.update_record(&base_id, &table, &id, record.fields, replace)?
+            }
+            None => {
+                let written = data.create_record(&base_id, &table, record.fields)?;
+                created.push(written["id"].clone());
+                written
+            }
+        };
+        records.push(written);
+    }
+
+    Ok(Json(json!({ "records": records, "createdRecords": created, "updatedRecords": updated })))
+}
+
+async fn merge_record(
+    state: State<AppState>,
+    headers: HeaderMap,
+    path: Path<(String, String, String)>,
+    body: Json<WriteRecordBody>,
+) -> ApiResult<Json<Value>> {
+    write_record(state, headers, path, body, false).await
+}
+
+async fn replace_record(
+    state: State<AppState>,
+    headers: HeaderMap,
+    path: Path<(String, String, String)>,
+    body: Json<WriteRecordBody>,
+) -> ApiResult<Json<Value>> {
+    write_record(state, headers, path, body, true).await
+}
+
+async fn write_record(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path((base_id, table, record_id)): Path<(String, String, String)>,
+    Json(body): Json<WriteRecordBody>,
+    replace: bool,
+) -> ApiResult<Json<Value>> {
+    authenticate(&headers)?;
+
+    let mut data = state.airtable.lock().unwrap();
+    let record = data.update_record(&base_id, &table, &record_id, body.fields, replace)?;
+
+    Ok(Json(record))
+}
+
+async fn delete_records(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path((base_id, table)): Path<(String, String)>,
+    Query(params): Query<Vec<(String, String)>>,
+) -> ApiResult<Json<Value>> {
+    authenticate(&headers)?;
+
+    let record_ids = Params::new(params).get_all("records");
+    check_record_count(record_ids.len())?;
+
+    let mut data = state.airtable.lock().unwrap();
+    data.delete_records(&base_id, &table, &record_ids)?;
+
+    let records =
+        record_ids.iter().map(|id| json!({ "id": id, "deleted": true })).collect::<Vec<_>>();
+
+    Ok(Json(json!({ "records": records })))
+}
+
+async fn delete_record(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path((base_id, table, record_id)): Path<(String, String, String)>,
+) -> ApiResult<Json<Value>> {
+    authenticate(&headers)?;
+
+    let mut data = state.airtable.lock().unwrap();
+    data.delete_records(&base_id, &table, std::slice::from_ref(&record_id))?;
+
+    Ok(Json(json!({ "id": record_id, "deleted": true })))
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CreateWebhookBody {
+    notification_url: Option<String>,
+    specification: Value,
+}
+
+fn webhook_expiration_time() -> String {
+    (Utc::now() + Duration::days(WEBHOOK_LIFETIME_DAYS))
+        .to_rfc3339_opts(SecondsFormat::Millis, true)
+}
+
+async fn create_webhook(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(base_id): Path<String>,
+    Json(body): Json<CreateWebhookBody>,
+) -> ApiResult<Json<Value>> {
+    authenticate(&headers)?;
+
+    if !body.specification["options"]["filters"]["dataTypes"].is_array() {
+        return Err(ApiError::invalid_request(
+            "specification.options.filters.dataTypes is required",
+        ));
+    }
+
+    let mut data = state.airtable.lock().unwrap();
+    let id = data.next_id("ach");
+    let mac_secret_base64 = BASE64_STANDARD.encode(format!("scipio-fake-secret-{id}"));
+    let webhook = Webhook {
+        id: id.clone(),
+        notification_url: body.notification_url,
+        expiration_time: webhook_expiration_time(),
+        payloads: vec![],
+    };
+    let expiration_time = webhook.expiration_time.clone();
+    data.base_mut(&base_id)?.webhooks.push(webhook);
+
+    Ok(Json(json!({
+        "id": id,
+        "macSecretBase64": mac_secret_base64,
+        "expirationTime": expiration_time,
+    })))
+}
+
+async fn list_webhooks(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(base_id): Path<String>,
+) -> ApiResult<Json<Value>> {
+    authenticate(&headers)?;
+
+    let mut data = state.airtable.lock().unwrap();
+    let webhooks =
+        data.base_mut(&base_id)?.webhooks.iter().map(Webhook::to_json).collect::<Vec<_>>();
+
+    Ok(Json(json!({ "webhooks": webhooks })))
+}
+
+async fn refresh_webhook(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path((base_id, webhook_id)): Path<(String, String)>,
+) -> ApiResult<Json<Value>> {
+    authenticate(&headers)?;
+
+    let mut data = state.airtable.lock().unwrap();
+    let webhook = data.webhook_mut(&base_id, &webhook_id)?;
+    webhook.expiration_time = webhook_expiration_time();
+
+    Ok(Json(json!({ "expirationTime": webhook.expiration_time })))
+}
+
+async fn delete_webhook(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path((base_id, webhook_id)): Path<(String, String)>,
+) -> ApiResult<Response> {
+    authenticate(&headers)?;
+
+    let mut data = state.airtable.lock().unwrap();
+    data.webhook_mut(&base_id, &webhook_id)?;
+    data.base_mut(&base_id)?.webhooks.retain(|w| w.id != webhook_id);
+
+    Ok(StatusCode::OK.into_response())
+}
+
+async fn list_webhook_payloads(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path((base_id, webhook_id)): Path<(String, String)>,
+    Query(params): Query<Vec<(String, String)>>,
+) -> ApiResult<Json<Value>> {
+    authenticate(&headers)?;
+
+    let params = Params::new(params);
+    let cursor = params.get_number("cursor")?.unwrap_or(1).max(1);
+    let limit = params.get_number("limit")?.unwrap_or(MAX_PAYLOADS_PER_REQUEST);
+    if limit == 0 || limit > MAX_PAYLOADS_PER_REQUEST {
+        return Err(ApiError::invalid_request(format!(
+            "limit must be between 1 and {MAX_PAYLOADS_PER_REQUEST}"
+        )));
+    }
+
+    let mut data = state.airtable.lock().unwrap();
+    let webhook = data.webhook_mut(&base_id, &webhook_id)?;
+    let payloads =
+        webhook.payloads.iter().skip(cursor - 1).take(limit).cloned().collect::<Vec<_>>();
+    let next_cursor = cursor + payloads.len();
+
+    Ok(Json(json!({
+        "cursor": next_cursor,
+        "mightHaveMore": next_cursor <= webhook.payloads.len(),
+        "payloads": payloads,
+        "payloadFormat": "v0",
+    })))
+}
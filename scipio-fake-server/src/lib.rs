@@ -0,0 +1,173 @@
+//! This crate provides a local stand-in for the HTTP APIs that the client crates talk to: Airtable,
+//! SendGrid, and the Google Workspace Admin SDK (along with Google's token endpoint).
+//!
+//! Every `FakeServer` listens on its own port and holds its own data, so tests can run side by
+//! side without credentials or the network. Point a client at `FakeServer::url` to use one.
+
+mod airtable;
+mod sendgrid;
+mod workspace;
+
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+
+use axum::http::{header, HeaderMap};
+use axum::Router;
+use serde_json::{json, Map, Value};
+use tokio::runtime::Builder;
+use tokio::sync::oneshot;
+
+/// The data held by a fake server.
+///
+/// * `url`: The URL the server is listening on
+/// * `airtable`: Bases, tables, and records
+/// * `sendgrid`: The mail that has been sent
+/// * `workspace`: Users, and the access tokens which have been issued
+#[derive(Clone)]
+struct AppState {
+    url: String,
+    airtable: Arc<Mutex<airtable::AirtableData>>,
+    sendgrid: Arc<Mutex<sendgrid::SendgridData>>,
+    workspace: Arc<Mutex<workspace::WorkspaceData>>,
+}
+
+/// A fake server, running on a thread of its own.
+///
+/// The server is stopped when this is dropped. It doesn't depend on the caller's runtime, so it
+/// can be started from synchronous code and keeps serving while the caller blocks.
+pub struct FakeServer {
+    state: AppState,
+    shutdown: Option<oneshot::Sender<()>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl FakeServer {
+    /// Start a fake server on a free port of `127.0.0.1`.
+    pub fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("error binding fake server");
+        listener.set_nonblocking(true).expect("error configuring fake server listener");
+        let addr = listener.local_addr().expect("error reading fake server address");
+
+        let state = AppState {
+            url: format!("http://{addr}"),
+            airtable: Default::default(),
+            sendgrid: Default::default(),
+            workspace: Default::default(),
+        };
+
+        let app = Router::new()
+            .merge(airtable::router())
+            .merge(sendgrid::router())
+            .merge(workspace::router())
+            .with_state(state.clone());
+
+        let (shutdown, stopped) = oneshot::channel::<()>();
+        let thread = thread::spawn(move || {
+            let runtime = Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .expect("error creating fake server runtime");
+
+            runtime.block_on(async move {
+                let listener = tokio::net::TcpListener::from_std(listener)
+                    .expect("error registering fake server listener");
+
+                // Open connections are dropped with the server instead of being drained
+                tokio::select! {
+                    res = axum::serve(listener, app) => res.expect("fake server failed"),
+                    _ = stopped => {}
+                }
+            });
+        });
+
+        Self { state, shutdown: Some(shutdown), thread: Some(thread) }
+    }
+
+    /// The URL the server is listening on, e.g. `http://127.0.0.1:8080`. This is the base URL for
+    /// every API the server fakes.
+    pub fn url(&self) -> &str {
+        &self.state.url
+    }
+
+    /// Add a base to the fake Airtable API, and return its ID.
+    ///
+    /// * `name`: The name of the base
+    pub fn add_airtable_base(&self, name: &str) -> String {
+        self.state.airtable.lock().unwrap().add_base(name)
+    }
+
+    /// Add a table to a base in the fake Airtable API, and return its ID. Every field holds text.
+    ///
+    /// * `base_id`: The ID of the base
+    /// * `name`: The name of the table
+    /// * `fields`: The names of the fields in the table. The first field is the primary field.
+    /// * `views`: The names of the views of the table. Every view shows every record.
+    pub fn add_airtable_table(
+        &self,
+        base_id: &str,
+        name: &str,
+        fields: &[&str],
+        views: &[&str],
+    ) -> String {
+        self.state.airtable.lock().unwrap().add_table(base_id, name, fields, views)
+    }
+
+    /// Add records to a table in the fake Airtable API, and return their IDs.
+    ///
+    /// * `base_id`: The ID of the base
+    /// * `table`: The ID or name of the table
+    /// * `records`: The fields of each record
+    pub fn add_airtable_records(
+        &self,
+        base_id: &str,
+        table: &str,
+        records: Vec<Map<String, Value>>,
+    ) -> Vec<String> {
+        self.state.airtable.lock().unwrap().add_records(base_id, table, records)
+    }
+
+    /// The body of each mail sent through the fake SendGrid API, oldest first.
+    pub fn sent_mail(&self) -> Vec<Value> {
+        self.state.sendgrid.lock().unwrap().sent.clone()
+    }
+
+    /// Service account JSON which the fake token endpoint accepts, in the format of the file
+    /// Google provides.
+    pub fn workspace_service_account_json(&self) -> String {
+        json!({
+            "type": "service_account",
+            "client_email": workspace::CLIENT_EMAIL,
+            "private_key_id": workspace::PRIVATE_KEY_ID,
+            "private_key": workspace::PRIVATE_KEY,
+            "token_uri": format!("{}{}", self.state.url, workspace::TOKEN_PATH),
+        })
+        .to_string()
+    }
+
+    /// The users in the fake Workspace Admin SDK API, in the order they were created.
+    pub fn workspace_users(&self) -> Vec<Value> {
+        self.state.workspace.lock().unwrap().users.clone()
+    }
+}
+
+impl Drop for FakeServer {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// The token in the `Authorization` header of a request, if it has a bearer token.
+fn bearer_token(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get(header::AUTHORIZATION)?
+        .to_str()
+        .ok()?
+        .strip_prefix("Bearer ")
+        .filter(|token| !token.is_empty())
+}
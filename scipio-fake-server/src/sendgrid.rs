@@ -0,0 +1,70 @@
+//! A fake of the SendGrid v3 Mail Send API.
+//!
+//! More information about the real API can be found
+//! [here](https://www.twilio.com/docs/sendgrid/api-reference/mail-send/mail-send)
+
+use axum::body::Bytes;
+use axum::extract::State;
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::post;
+use axum::{Json, Router};
+use serde_json::{json, Value};
+
+use crate::{bearer_token, AppState};
+
+pub(crate) fn router() -> Router<AppState> {
+    Router::new().route("/v3/mail/send", post(send_mail))
+}
+
+#[derive(Default)]
+pub(crate) struct SendgridData {
+    pub(crate) sent: Vec<Value>,
+}
+
+/// An error response, in the format of the SendGrid API.
+fn error(status: StatusCode, field: Option<&str>, message: &str) -> Response {
+    (status, Json(json!({ "errors": [{ "field": field, "message": message }] }))).into_response()
+}
+
+/// Check that a mail has the parts SendGrid requires.
+fn validate_mail(mail: &Value) -> Result<(), (&'static str, &'static str)> {
+    let personalizations = mail["personalizations"].as_array().filter(|p| !p.is_empty());
+    let Some(personalizations) = personalizations else {
+        return Err(("personalizations", "The personalizations field is required."));
+    };
+    if personalizations.iter().any(|p| p["to"].as_array().is_none_or(Vec::is_empty)) {
+        return Err(("personalizations.to", "The to array is required for all personalizations."));
+    }
+    if !mail["from"]["email"].is_string() {
+        return Err(("from.email", "The from email does not contain a valid address."));
+    }
+    if !mail["subject"].is_string() && personalizations.iter().any(|p| !p["subject"].is_string()) {
+        return Err(("subject", "The subject is required."));
+    }
+    if mail["content"].as_array().is_none_or(Vec::is_empty) && !mail["template_id"].is_string() {
+        return Err(("content", "Unless a valid template_id is provided, content is required."));
+    }
+    Ok(())
+}
+
+async fn send_mail(State(state): State<AppState>, headers: HeaderMap, body: Bytes) -> Response {
+    if bearer_token(&headers).is_none() {
+        return error(
+            StatusCode::UNAUTHORIZED,
+            None,
+            "The provided authorization grant is invalid, expired, or revoked",
+        );
+    }
+
+    let Ok(mail) = serde_json::from_slice::<Value>(&body) else {
+        return error(StatusCode::BAD_REQUEST, None, "Bad Request");
+    };
+    if let Err((field, message)) = validate_mail(&mail) {
+        return error(StatusCode::BAD_REQUEST, Some(field), message);
+    }
+
+    state.sendgrid.lock().unwrap().sent.push(mail);
+
+    StatusCode::ACCEPTED.into_response()
+}
@@ -0,0 +1,203 @@
+//! A fake of the Google Workspace Admin SDK Directory API, and of the endpoint which exchanges a
+//! service account's signed assertion for an access token.
+//!
+//! More information about the real APIs can be found
+//! [here](https://developers.google.com/admin-sdk/directory/reference/rest) and
+//! [here](https://developers.google.com/identity/protocols/oauth2/service-account)
+
+use std::collections::HashMap;
+
+use axum::body::Bytes;
+use axum::extract::{Path, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{delete, post};
+use axum::{Json, Router};
+use chrono::{SecondsFormat, Utc};
+use jsonwebtoken::{Algorithm, DecodingKey, Validation};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::{bearer_token, AppState};
+
+pub(crate) const TOKEN_PATH: &str = "/token";
+const USERS_PATH: &str = "/admin/directory/v1/users";
+
+pub(crate) const CLIENT_EMAIL: &str = "scipio@scipio-fake.iam.gserviceaccount.com";
+pub(crate) const PRIVATE_KEY_ID: &str = "scipio-fake-key";
+/// A key pair which is only used to sign and check assertions for the fake server.
+pub(crate) const PRIVATE_KEY: &str = include_str!("keys/service_account.pem");
+const PUBLIC_KEY: &str = include_str!("keys/service_account.pub.pem");
+
+const BEARER_TOKEN_GRANT_TYPE: &str = "urn:ietf:params:oauth:grant-type:jwt-bearer";
+const USER_SCOPE: &str = "https://www.googleapis.com/auth/admin.directory.user";
+
+pub(crate) fn router() -> Router<AppState> {
+    Router::new()
+        .route(TOKEN_PATH, post(get_access_token))
+        .route(USERS_PATH, post(create_user))
+        .route(&format!("{USERS_PATH}/:user_key"), delete(delete_user))
+}
+
+/// * `users`: The users which have been created and not deleted
+/// * `access_tokens`: The scopes of each access token which has been issued
+#[derive(Default)]
+pub(crate) struct WorkspaceData {
+    last_id: u64,
+    pub(crate) users: Vec<Value>,
+    access_tokens: HashMap<String, String>,
+}
+
+/// An error response from the token endpoint, in the format of RFC 6749.
+fn token_error(error: &str, description: &str) -> Response {
+    (StatusCode::BAD_REQUEST, Json(json!({ "error": error, "error_description": description })))
+        .into_response()
+}
+
+/// An error response from the Directory API, in the format of Google APIs.
+fn api_error(status: StatusCode, message: &str) -> Response {
+    let body = json!({
+        "error": {
+            "code": status.as_u16(),
+            "message": message,
+            "errors": [{ "message": message, "domain": "global" }],
+        }
+    });
+    (status, Json(body)).into_response()
+}
+
+#[derive(Deserialize)]
+struct TokenRequest {
+    grant_type: String,
+    assertion: String,
+}
+
+#[derive(Deserialize)]
+struct AssertionClaims {
+    scope: String,
+}
+
+/// Exchange an assertion signed with the fake service account's key for an access token.
+async fn get_access_token(State(state): State<AppState>, body: Bytes) -> Response {
+    let Ok(req) = serde_json::from_slice::<TokenRequest>(&body) else {
+        return token_error("invalid_request", "Invalid request");
+    };
+    if req.grant_type != BEARER_TOKEN_GRANT_TYPE {
+        return token_error("unsupported_grant_type", "Invalid grant_type");
+    }
+
+    let mut validation = Validation::new(Algorithm::RS256);
+    validation.set_audience(&[format!("{}{TOKEN_PATH}", state.url)]);
+    validation.set_issuer(&[CLIENT_EMAIL]);
+    validation.set_required_spec_claims(&["exp", "iss", "aud", "sub"]);
+
+    let key = DecodingKey::from_rsa_pem(PUBLIC_KEY.as_bytes()).expect("invalid fake public key");
+    let claims = match jsonwebtoken::decode::<AssertionClaims>(&req.assertion, &key, &validation) {
+        Ok(data) => data.claims,
+        Err(e) => return token_error("invalid_grant", &format!("Invalid JWT: {e}")),
+    };
+
+    let mut data = state.workspace.lock().unwrap();
+    data.last_id += 1;
+    let access_token = format!("ya29.scipio-fake-{}", data.last_id);
+    data.access_tokens.insert(access_token.clone(), claims.scope);
+
+    Json(json!({ "access_token": access_token, "expires_in": 3599, "token_type": "Bearer" }))
+        .into_response()
+}
+
+/// The error response for a request, unless it has an access token which was issued for managing
+/// users.
+fn check_access_token(headers: &HeaderMap, state: &AppState) -> Option<Response> {
+    let data = state.workspace.lock().unwrap();
+    let scope = bearer_token(headers).and_then(|token| data.access_tokens.get(token));
+
+    match scope {
+        Some(scope) if scope.split(' ').any(|s| s == USER_SCOPE) => None,
+        Some(_) => Some(api_error(StatusCode::FORBIDDEN, "Request had insufficient scopes.")),
+        None => Some(api_error(
+            StatusCode::UNAUTHORIZED,
+            "Request had invalid authentication credentials.",
+        )),
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UserName {
+    given_name: String,
+    family_name: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CreateUser {
+    primary_email: String,
+    name: UserName,
+    password: String,
+    change_password_at_next_login: Option<bool>,
+    org_unit_path: Option<String>,
+    recovery_email: Option<String>,
+}
+
+async fn create_user(State(state): State<AppState>, headers: HeaderMap, body: Bytes) -> Response {
+    if let Some(res) = check_access_token(&headers, &state) {
+        return res;
+    }
+
+    let Ok(user) = serde_json::from_slice::<CreateUser>(&body) else {
+        return api_error(StatusCode::BAD_REQUEST, "Invalid Input");
+    };
+    if user.password.is_empty() {
+        return api_error(StatusCode::BAD_REQUEST, "Invalid Password");
+    }
+
+    let mut data = state.workspace.lock().unwrap();
+    if data.users.iter().any(|u| u["primaryEmail"] == user.primary_email) {
+        return api_error(StatusCode::CONFLICT, "Entity already exists.");
+    }
+
+    data.last_id += 1;
+    let user = json!({
+        "kind": "admin#directory#user",
+        "id": format!("{:021}", data.last_id),
+        "etag": format!("\"scipio-fake-{}\"", data.last_id),
+        "primaryEmail": user.primary_email,
+        "name": {
+            "givenName": user.name.given_name,
+            "familyName": user.name.family_name,
+            "fullName": format!("{} {}", user.name.given_name, user.name.family_name),
+        },
+        "isAdmin": false,
+        "isDelegatedAdmin": false,
+        "changePasswordAtNextLogin": user.change_password_at_next_login.unwrap_or(false),
+        "customerId": "C00scipio",
+        "orgUnitPath": user.org_unit_path.unwrap_or_else(|| "/".to_owned()),
+        "recoveryEmail": user.recovery_email,
+        "isMailboxSetup": false,
+        "creationTime": Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true),
+    });
+    data.users.push(user.clone());
+
+    Json(user).into_response()
+}
+
+async fn delete_user(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(user_key): Path<String>,
+) -> Response {
+    if let Some(res) = check_access_token(&headers, &state) {
+        return res;
+    }
+
+    let mut data = state.workspace.lock().unwrap();
+    let count = data.users.len();
+    data.users.retain(|u| u["primaryEmail"] != user_key.as_str() && u["id"] != user_key.as_str());
+
+    if data.users.len() == count {
+        return api_error(StatusCode::NOT_FOUND, "Resource Not Found: userKey");
+    }
+
+    StatusCode::NO_CONTENT.into_response()
+}
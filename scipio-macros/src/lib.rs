@@ -1,8 +1,9 @@
 use proc_macro::TokenStream;
 use quote::quote;
+use syn::punctuated::Punctuated;
 use syn::{
-    parse_macro_input, Data, DeriveInput, Fields, GenericArgument, Ident, ItemStruct,
-    PathArguments, Type,
+    parse_macro_input, Data, DeriveInput, Fields, GenericArgument, Ident, ItemStruct, Meta,
+    PathArguments, Token, Type,
 };
 
 const SUPPORTED_OPTION_TYPES: [&str; 5] = [
@@ -135,7 +136,12 @@ pub fn to_query_string_derive(input: TokenStream) -> TokenStream {
     TokenStream::from(expanded)
 }
 
-#[proc_macro_derive(Partial)]
+/// Derive a copy of a struct, named `Partial<Struct>`, in which every field is wrapped in `Option`.
+///
+/// Attributes for the generated struct are listed in `#[partial(...)]` on the original struct. For
+/// example, `#[partial(derive(Debug, Default))]` derives `Debug` and `Default` for the generated
+/// struct.
+#[proc_macro_derive(Partial, attributes(partial))]
 pub fn derive_partial(input: TokenStream) -> TokenStream {
     // Parse the input token stream as a DeriveInput (struct definition)
     let input = parse_macro_input!(input as DeriveInput);
@@ -147,6 +153,15 @@ pub fn derive_partial(input: TokenStream) -> TokenStream {
     let partial_struct_name =
         syn::Ident::new(&format!("Partial{}", struct_name), struct_name.span());
 
+    // Forward the contents of each `#[partial(...)]` attribute to the new struct
+    let mut forwarded_attrs = Vec::new();
+    for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("partial")) {
+        match attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated) {
+            Ok(metas) => forwarded_attrs.extend(metas.into_iter().map(|meta| quote! { #[#meta] })),
+            Err(e) => return e.to_compile_error().into(),
+        }
+    }
+
     // Generate field wrapping in Option<T> (for each field in the original struct)
     let fields = match input.data {
         Data::Struct(ref data_struct) => {
@@ -174,6 +189,7 @@ pub fn derive_partial(input: TokenStream) -> TokenStream {
     // Generate the new struct definition with Option<T> fields
     let expanded = quote! {
         // Define the new struct with Option-wrapped fields
+        #(#forwarded_attrs)*
         pub struct #partial_struct_name {
             #(#fields),*
         }
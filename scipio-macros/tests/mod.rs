@@ -2,4 +2,5 @@
 pub fn test_macros() {
     let t = trybuild::TestCases::new();
     t.pass("tests/test_build_query.rs");
+    t.pass("tests/test_partial.rs");
 }
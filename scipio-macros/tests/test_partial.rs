@@ -1,9 +1,16 @@
 use scipio_macros::Partial;
 
 #[derive(Partial)]
+#[partial(derive(Debug, Default, Clone), allow(dead_code))]
+#[allow(dead_code)] // Only the partial struct is used
 struct S {
     pub field: String,
-    pub direction: String,
+    pub direction: Option<String>,
 }
 
-fn main() {}
+fn main() {
+    let partial = PartialS { field: Some("field".to_owned()), ..Default::default() };
+    assert_eq!(partial.clone().field.as_deref(), Some("field"));
+    assert!(partial.direction.is_none());
+    let _ = format!("{partial:?}");
+}
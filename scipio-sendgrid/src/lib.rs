@@ -13,8 +13,12 @@ use reqwest_retry::policies::ExponentialBackoff;
 use reqwest_retry::RetryTransientMiddleware;
 use retry::DefaultRetryStrategy;
 
+/// The URL of the SendGrid API, which requests are made to unless another is given.
+pub const DEFAULT_BASE_URL: &str = "https://api.sendgrid.com";
+
 pub struct Sendgrid {
     http: ClientWithMiddleware,
+    base_url: String,
 }
 
 impl Sendgrid {
@@ -35,6 +39,14 @@ impl Sendgrid {
             .with(retry_strategy)
             .build();
 
-        Ok(Self { http })
+        Ok(Self { http, base_url: DEFAULT_BASE_URL.to_owned() })
+    }
+
+    /// Make requests to another URL instead of `DEFAULT_BASE_URL`, e.g. a local fake of the API.
+    ///
+    /// * `base_url`: The URL to make requests to, without the API version
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_owned();
+        self
     }
 }
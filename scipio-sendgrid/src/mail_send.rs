@@ -7,7 +7,7 @@ use crate::Sendgrid;
 impl Sendgrid {
     pub async fn send_mail(&self, mail: Mail) -> Result<()> {
         self.http
-            .post("https://api.sendgrid.com/v3/mail/send")
+            .post(format!("{}/v3/mail/send", self.base_url))
             .header(header::CONTENT_TYPE, "application/json")
             .body(Vec::try_from(mail)?)
             .send()
@@ -1,13 +1,36 @@
-use std::env;
-
 use rstest::fixture;
+use scipio_fake_server::FakeServer;
 
 use crate::Sendgrid;
 
+/// * `sendgrid`: The client to test
+/// * `fake`: The fake SendGrid API the client makes requests to, unless the real API is used
+pub struct TestContext {
+    pub sendgrid: Sendgrid,
+    pub fake: Option<FakeServer>,
+}
+
+/// A context which uses the SendGrid API.
+#[cfg(feature = "integration")]
 #[fixture]
-pub fn sendgrid() -> Sendgrid {
+pub fn context() -> TestContext {
+    use std::env;
+
     dotenvy::dotenv().expect("Failed to load .env file");
     let api_key = env::var("SENDGRID_API_KEY").expect("SENDGRID_API_KEY must be set");
 
-    Sendgrid::new(&api_key, 3).expect("Failed to create Sendgrid instance")
+    let sendgrid = Sendgrid::new(&api_key, 3).expect("Failed to create Sendgrid instance");
+    TestContext { sendgrid, fake: None }
+}
+
+/// A context which uses a fake SendGrid API.
+#[cfg(not(feature = "integration"))]
+#[fixture]
+pub fn context() -> TestContext {
+    let fake = FakeServer::start();
+    let sendgrid = Sendgrid::new("test-api-key", 0)
+        .expect("Failed to create Sendgrid instance")
+        .with_base_url(fake.url());
+
+    TestContext { sendgrid, fake: Some(fake) }
 }
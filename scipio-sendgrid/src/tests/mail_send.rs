@@ -1,16 +1,14 @@
 use anyhow::Result;
 use rstest::rstest;
 
-use super::fixtures::sendgrid;
+use super::fixtures::{context, TestContext};
 use crate::entities::{
     AddressBuilder, MailBuilder, MailContentBuilder, MailContentMime, PersonalizationBuilder,
 };
-use crate::Sendgrid;
 
-#[cfg(feature = "integration")]
 #[rstest]
 #[tokio::test]
-pub async fn test_send_mail(sendgrid: Sendgrid) -> Result<()> {
+pub async fn test_send_mail(context: TestContext) -> Result<()> {
     let personalization = PersonalizationBuilder::default()
         .to(vec![AddressBuilder::default()
             .email("anish@developforgood.org")
@@ -33,6 +31,14 @@ pub async fn test_send_mail(sendgrid: Sendgrid) -> Result<()> {
             .build()?])
         .build()?;
 
-    sendgrid.send_mail(mail).await?;
+    context.sendgrid.send_mail(mail).await?;
+
+    if let Some(fake) = &context.fake {
+        let sent = fake.sent_mail();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0]["subject"], "Test email");
+        assert_eq!(sent[0]["personalizations"][0]["to"][0]["email"], "anish@developforgood.org");
+    }
+
     Ok(())
 }
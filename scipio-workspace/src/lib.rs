@@ -25,6 +25,9 @@ use user::{CreateWorkspaceUser, WorkspaceUser};
 /// [RFC 7523 Bearer Token Grant Type](https://datatracker.ietf.org/doc/html/rfc7523#section-8.1)
 const BEARER_TOKEN_GRANT_TYPE: &str = "urn:ietf:params:oauth:grant-type:jwt-bearer";
 
+/// The URL of the Admin SDK API, which requests are made to unless another is given.
+pub const DEFAULT_BASE_URL: &str = "https://admin.googleapis.com";
+
 #[derive(Deserialize, Debug)]
 /// A subset of the data from the service account JSON file. We need this to create and sign JWTs.
 ///
@@ -88,9 +91,11 @@ pub struct GoogleAccessTokenResponse {
 /// * `json`: The JSON data of the service account.
 /// * `http`: The HTTP client that will be used to make requests to the token endpoint. By default,
 ///   it is configured to backoff and retry on status codes 412, 429, as well as on network errors.
+/// * `base_url`: The URL of the Admin SDK API. Tokens are requested from the `token_uri` in `json`.
 pub struct ServiceAccount {
     json: ServiceAccountJson,
     http: ClientWithMiddleware,
+    base_url: String,
 }
 
 impl ServiceAccount {
@@ -108,7 +113,15 @@ impl ServiceAccount {
 
         let http = ClientBuilder::new(Client::new()).with(retry_strategy).build();
 
-        Self { json, http }
+        Self { json, http, base_url: DEFAULT_BASE_URL.to_owned() }
+    }
+
+    /// Make requests to another URL instead of `DEFAULT_BASE_URL`, e.g. a local fake of the API.
+    ///
+    /// * `base_url`: The URL to make requests to, without the API path.
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_owned();
+        self
     }
 
     /// Request an assertion token from the Google Workspace API on the behalf of a principal.
@@ -169,12 +182,12 @@ impl ServiceAccount {
         data: CreateWorkspaceUser,
     ) -> Result<WorkspaceUser> {
         let scope = "https://www.googleapis.com/auth/admin.directory.user";
-        let url = "https://admin.googleapis.com/admin/directory/v1/users";
+        let url = format!("{}/admin/directory/v1/users", self.base_url);
         let access_token = self.get_access_token(principal, scope).await?;
 
         let user = self
             .http
-            .post(url)
+            .post(&url)
             .bearer_auth(&access_token)
             .body(Vec::<u8>::try_from(data)?)
             .send()
@@ -196,9 +209,7 @@ impl ServiceAccount {
         let access_token = self.get_access_token(principal, scope).await?;
 
         self.http
-            .delete(format!(
-                "https://admin.googleapis.com/admin/directory/v1/users/{email_of_user_to_delete}"
-            ))
+            .delete(format!("{}/admin/directory/v1/users/{email_of_user_to_delete}", self.base_url))
             .bearer_auth(&access_token)
             .send()
             .await?;
@@ -1,12 +1,22 @@
-use std::env;
-
 use rstest::fixture;
+use scipio_fake_server::FakeServer;
 
 use crate::{ServiceAccount, ServiceAccountJson};
 
+/// * `service_account`: The service account to test
+/// * `fake`: The fake token endpoint and Admin SDK API the service account makes requests to,
+///   unless the real ones are used
+pub struct TestContext {
+    pub service_account: ServiceAccount,
+    pub fake: Option<FakeServer>,
+}
+
 /// Fixture to create a service account for use in other tests
+#[cfg(feature = "integration")]
 #[fixture]
-pub fn service_account() -> ServiceAccount {
+pub fn context() -> TestContext {
+    use std::env;
+
     dotenvy::dotenv().expect("Failed to load .env file");
 
     let service_account_json_str =
@@ -16,5 +26,19 @@ pub fn service_account() -> ServiceAccount {
         serde_json::from_str::<ServiceAccountJson>(&service_account_json_str)
             .expect("Failed to deserialize WORKSPACE_SERVICE_ACCOUNT_JSON");
 
-    ServiceAccount::new(service_account_json, 5)
+    TestContext { service_account: ServiceAccount::new(service_account_json, 5), fake: None }
+}
+
+/// Fixture to create a service account which uses a fake token endpoint and Admin SDK API
+#[cfg(not(feature = "integration"))]
+#[fixture]
+pub fn context() -> TestContext {
+    let fake = FakeServer::start();
+
+    let service_account_json =
+        serde_json::from_str::<ServiceAccountJson>(&fake.workspace_service_account_json())
+            .expect("Failed to deserialize the fake service account JSON");
+
+    let service_account = ServiceAccount::new(service_account_json, 0).with_base_url(fake.url());
+    TestContext { service_account, fake: Some(fake) }
 }
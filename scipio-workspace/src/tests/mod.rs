@@ -5,14 +5,14 @@ use rand::distributions::Alphanumeric;
 use rand::Rng;
 use rstest::rstest;
 
-use super::tests::fixtures::service_account;
+use super::tests::fixtures::{context, TestContext};
 use crate::user::{CreateWorkspaceUserBuilder, UserNameBuilder};
-use crate::ServiceAccount;
 
-#[cfg(feature = "integration")]
 #[rstest]
 #[tokio::test]
-async fn test_create_and_delete_user(service_account: ServiceAccount) -> Result<()> {
+async fn test_create_and_delete_user(context: TestContext) -> Result<()> {
+    let service_account = &context.service_account;
+
     let random_suffix =
         rand::thread_rng().sample_iter(&Alphanumeric).take(6).map(char::from).collect::<String>();
 
@@ -35,7 +35,15 @@ async fn test_create_and_delete_user(service_account: ServiceAccount) -> Result<
     let user = service_account.create_user("anish@developforgood.org", data).await?;
     dbg!(&user);
 
+    if let Some(fake) = &context.fake {
+        assert_eq!(fake.workspace_users().len(), 1);
+    }
+
     service_account.delete_user("anish@developforgood.org", &user.primary_email).await?;
 
+    if let Some(fake) = &context.fake {
+        assert!(fake.workspace_users().is_empty());
+    }
+
     Ok(())
 }
@@ -4,6 +4,7 @@ use anyhow::Result;
 use axum::extract::State;
 use axum::http::StatusCode;
 use axum::response::Response;
+use sqlx::Database;
 
 use crate::app::api_response;
 use crate::app::errors::AppError;
@@ -34,6 +35,8 @@ pub async fn health() -> Result<Response, AppError> {
             (status = 200, description = "Check configured services")
         )
     )]
-pub async fn services(State(services): State<Arc<Services>>) -> Result<Response, AppError> {
+pub async fn services<DB: Database>(
+    State(services): State<Arc<Services<DB>>>,
+) -> Result<Response, AppError> {
     Ok(api_response::success(StatusCode::OK, services.get_info())?)
 }
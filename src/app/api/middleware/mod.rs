@@ -13,6 +13,7 @@ use axum::response::Response;
 use axum_extra::headers::authorization::Bearer;
 use axum_extra::headers::Authorization;
 use axum_extra::TypedHeader;
+use sqlx::Database;
 
 use crate::app::api_response;
 use crate::app::errors::AppError;
@@ -25,8 +26,8 @@ use crate::services::auth::AuthData;
 /// * `request`: The request object from axum
 /// * `next`: The next middleware in the chain
 /// * `permissions`: The permissions required to access the route (such as `["read:volunteers"]`)
-pub async fn rbac(
-    State(ctx): State<Arc<Services>>,
+pub async fn rbac<DB: Database>(
+    State(ctx): State<Arc<Services<DB>>>,
     header: TypedHeader<Authorization<Bearer>>,
     mut request: Request,
     next: Next,
@@ -53,10 +54,10 @@ pub async fn rbac(
 /// Higher order function to create a partially applied RBAC middleware with the given permissions.
 ///
 /// * `permissions`: A vector of permissions required to access the route
-pub async fn make_rbac(
+pub async fn make_rbac<DB: Database>(
     permissions: Vec<String>,
 ) -> impl Fn(
-    State<Arc<Services>>,
+    State<Arc<Services<DB>>>,
     TypedHeader<Authorization<Bearer>>,
     Request,
     Next,
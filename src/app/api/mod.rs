@@ -7,6 +7,7 @@ use std::sync::Arc;
 use axum::middleware::from_fn_with_state;
 use axum::{routing, Router};
 use middleware::make_rbac;
+use sqlx::Database;
 use utoipa::OpenApi;
 
 use crate::app::api::v1::V1Api;
@@ -27,12 +28,12 @@ pub struct Api;
 /// Builds the API router.
 ///
 /// * `services`: The application services
-pub async fn build(services: Arc<Services>) -> Router<()> {
+pub async fn build<DB: Database>(services: Arc<Services<DB>>) -> Router<()> {
     let guard1 = make_rbac(vec![]).await;
 
     let v1_routes = v1::build(services.clone()).await;
     Router::new()
-        .route("/services", routing::get(controllers::services))
+        .route("/services", routing::get(controllers::services::<DB>))
         .route_layer(from_fn_with_state(services.clone(), guard1))
         .route("/health", routing::get(controllers::health))
         .with_state(services.clone())
@@ -0,0 +1,66 @@
+//! Controllers for the audit API.
+
+use std::sync::Arc;
+
+use axum::extract::{Query, State};
+use axum::Json;
+use sqlx::Database;
+
+use crate::app::api::v1::audit::requests::FetchAuditEventsQuery;
+use crate::app::api::v1::audit::responses::AuditEventsResponse;
+use crate::app::errors::AppError;
+use crate::app::state::Services;
+use crate::services::storage::audit::AuditFilter;
+use crate::services::storage::pagination::PageOptions;
+use crate::services::storage::ExecOptsBuilder;
+
+/// Search the audit log
+///
+/// * `ctx`: The application context extracted as Axum state
+/// * `query`: Filters and pagination options
+#[utoipa::path(
+    get,
+    path = "",
+    operation_id = "Get audit events",
+    responses(
+        (status = 200, description = "Successfully fetched a page of audit events, newest first"),
+        (status = 400, description = "Invalid query parameters"),
+        (status = 401, description = "Unauthorized: invalid JWT"),
+        (status = 403, description = "Forbidden: insufficient permissions (requires `read:audit`)"),
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT. NOTE: Prefix with Bearer"),
+        ("principal" = Option<String>, Query, description = "Only events performed by the user with this email"),
+        ("action" = Option<String>, Query, description = "Only events for this action, e.g. `cycle.delete`"),
+        ("targetId" = Option<Uuid>, Query, description = "Only events which touched the record with this ID"),
+        ("outcome" = Option<String>, Query, description = "`succeeded`, `failed`, or `cancelled`"),
+        ("createdAfter" = Option<String>, Query, description = "Only events recorded at or after this RFC 3339 timestamp"),
+        ("createdBefore" = Option<String>, Query, description = "Only events recorded before this RFC 3339 timestamp"),
+        ("cursor" = Option<Uuid>, Query, description = "The `nextCursor` of the previous page"),
+        ("limit" = Option<i64>, Query, description = "The maximum number of events to return (at most 500)"),
+    ),
+)]
+pub async fn fetch_audit_events<DB: Database>(
+    State(ctx): State<Arc<Services<DB>>>,
+    Query(query): Query<FetchAuditEventsQuery>,
+) -> Result<Json<AuditEventsResponse>, AppError> {
+    let filter = AuditFilter {
+        principal: query.principal,
+        action: query.action,
+        target_id: query.target_id,
+        outcome: query.outcome,
+        created_after: query.created_after,
+        created_before: query.created_before,
+    };
+
+    let page = ctx
+        .storage_layer
+        .fetch_audit_events(
+            filter,
+            PageOptions::new(query.cursor, query.limit),
+            &mut ExecOptsBuilder::default().build()?,
+        )
+        .await?;
+
+    Ok(Json(AuditEventsResponse { events: page.items, next_cursor: page.next_cursor }))
+}
@@ -0,0 +1,42 @@
+//! Audit API.
+//!
+//! The audit log records who performed each privileged action, what it touched, and how it turned
+//! out.
+
+use std::sync::Arc;
+
+use axum::middleware::from_fn_with_state;
+use axum::{routing, Router};
+use sqlx::Database;
+use utoipa::OpenApi;
+
+use crate::app::api::middleware::make_rbac;
+use crate::app::state::Services;
+
+mod controllers;
+mod requests;
+mod responses;
+
+/// Documents the API for searching the audit log
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        controllers::fetch_audit_events,
+    ),
+    security(("http" = ["JWT"]))
+)]
+pub struct AuditApi;
+
+/// Builds the audit API.
+///
+/// * `ctx`: The application context
+pub async fn build<DB: Database>(ctx: Arc<Services<DB>>) -> Router<()> {
+    let read_guard = make_rbac(vec!["read:audit".to_owned()]).await;
+
+    let fetch_audit_events = routing::get(controllers::fetch_audit_events::<DB>);
+
+    Router::new()
+        .route("/", fetch_audit_events)
+        .route_layer(from_fn_with_state(ctx.clone(), read_guard))
+        .with_state(ctx.clone())
+}
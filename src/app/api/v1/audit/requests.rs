@@ -0,0 +1,32 @@
+//! Requests for the audit API.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::services::storage::types::{AuditAction, AuditOutcome};
+
+/// Query parameters for searching the audit log.
+///
+/// Every filter is optional.
+///
+/// * `principal`: Only events performed by this user
+/// * `action`: Only events for this action
+/// * `target_id`: Only events which touched this record
+/// * `outcome`: Only events with this outcome
+/// * `created_after`: Only events recorded at or after this time
+/// * `created_before`: Only events recorded before this time
+/// * `cursor`: The `nextCursor` of the previous page
+/// * `limit`: The maximum number of events to return (defaults to 50, at most 500)
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FetchAuditEventsQuery {
+    pub principal: Option<String>,
+    pub action: Option<AuditAction>,
+    pub target_id: Option<Uuid>,
+    pub outcome: Option<AuditOutcome>,
+    pub created_after: Option<DateTime<Utc>>,
+    pub created_before: Option<DateTime<Utc>>,
+    pub cursor: Option<Uuid>,
+    pub limit: Option<i64>,
+}
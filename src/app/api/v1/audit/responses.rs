@@ -0,0 +1,17 @@
+//! Responses from the audit API.
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::services::storage::entities::AuditEvent;
+
+/// A page of the audit log.
+///
+/// * `events`: The events on this page, newest first
+/// * `next_cursor`: Pass this as `cursor` to fetch the next page. It is `null` on the last page.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditEventsResponse {
+    pub events: Vec<AuditEvent>,
+    pub next_cursor: Option<Uuid>,
+}
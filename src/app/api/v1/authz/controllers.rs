@@ -6,6 +6,7 @@ use axum::extract::State;
 use axum::http::StatusCode;
 use axum::response::Response;
 use axum::Extension;
+use sqlx::Database;
 
 use crate::app::api_response;
 use crate::app::errors::AppError;
@@ -30,8 +31,8 @@ use crate::services::auth::AuthData;
         ("Authorization" = String, Header, description = "JWT. NOTE: Prefix with Bearer")
     ),
 )]
-pub async fn user(
-    State(ctx): State<Arc<Services>>,
+pub async fn user<DB: Database>(
+    State(ctx): State<Arc<Services<DB>>>,
     Extension(auth): Extension<AuthData>,
 ) -> Result<Response, AppError> {
     let authenticator = &ctx.authenticator;
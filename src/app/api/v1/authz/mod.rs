@@ -4,6 +4,7 @@ use std::sync::Arc;
 
 use axum::middleware::from_fn_with_state;
 use axum::{routing, Router};
+use sqlx::Database;
 use utoipa::OpenApi;
 
 use crate::app::api::middleware::make_rbac;
@@ -25,10 +26,10 @@ pub struct AuthzApi;
 /// Builds the authorization service API.
 ///
 /// * `ctx`: The application context
-pub async fn build(ctx: Arc<Services>) -> Router<()> {
+pub async fn build<DB: Database>(ctx: Arc<Services<DB>>) -> Router<()> {
     let guard = make_rbac(vec![]).await;
 
-    let user = routing::post(controllers::user);
+    let user = routing::post(controllers::user::<DB>);
     let permissions = routing::post(controllers::permissions);
 
     Router::new()
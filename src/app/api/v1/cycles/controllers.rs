@@ -6,12 +6,20 @@ use anyhow::Result;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::response::Response;
+use axum::{Extension, Json};
+use serde_json::json;
+use sqlx::Database;
 use uuid::Uuid;
 
-use crate::app::api::v1::cycles::responses::CyclesResponse;
+use crate::app::api::v1::cycles::requests::{CreateCycleRequest, EditCycleRequest};
+use crate::app::api::v1::cycles::responses::{CycleResponse, CyclesResponse};
 use crate::app::api_response;
-use crate::app::errors::AppError;
+use crate::app::audit::{edited_fields, Audit};
+use crate::app::errors::{is_unique_violation, AppError};
 use crate::app::state::Services;
+use crate::services::auth::AuthData;
+use crate::services::storage::cycles::{CreateCycle, EditCycle};
+use crate::services::storage::types::AuditAction;
 use crate::services::storage::ExecOptsBuilder;
 
 /// Fetch cycles
@@ -30,7 +38,9 @@ use crate::services::storage::ExecOptsBuilder;
         ("Authorization" = String, Header, description = "JWT. NOTE: Prefix with Bearer")
     ),
 )]
-pub async fn fetch_cycles(State(ctx): State<Arc<Services>>) -> Result<Response, AppError> {
+pub async fn fetch_cycles<DB: Database>(
+    State(ctx): State<Arc<Services<DB>>>,
+) -> Result<Response, AppError> {
     let storage_layer = &ctx.storage_layer;
     let cycles = storage_layer.fetch_cycles(&mut ExecOptsBuilder::default().build()?).await?;
     let res = CyclesResponse { cycles };
@@ -38,14 +48,177 @@ pub async fn fetch_cycles(State(ctx): State<Arc<Services>>) -> Result<Response,
     Ok(api_response::success(StatusCode::OK, res)?)
 }
 
+/// Fetch a cycle
+///
+/// * `ctx`: The application context extracted as Axum state
+/// * `id`: The ID of the cycle to fetch
+#[utoipa::path(
+    get,
+    path = "/{id}",
+    operation_id = "Get cycle",
+    responses(
+        (status = 200, description = "Successfully fetched cycle"),
+        (status = 401, description = "Unauthorized: invalid JWT"),
+        (status = 403, description = "Forbidden: insufficient permissions (requires `read:cycles`)"),
+        (status = 404, description = "Cycle not found"),
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT. NOTE: Prefix with Bearer"),
+        ("id" = Uuid, Path, description = "The ID of the cycle")
+    ),
+)]
+pub async fn fetch_cycle<DB: Database>(
+    State(ctx): State<Arc<Services<DB>>>,
+    Path(id): Path<Uuid>,
+) -> Result<Response, AppError> {
+    let storage_layer = &ctx.storage_layer;
+    let Some(cycle) =
+        storage_layer.fetch_cycle_by_id(id, &mut ExecOptsBuilder::default().build()?).await?
+    else {
+        return Ok(api_response::error(StatusCode::NOT_FOUND, "Cycle not found"));
+    };
+
+    Ok(api_response::success(StatusCode::OK, CycleResponse { cycle })?)
+}
+
+/// Create a cycle
+///
+/// * `ctx`: The application context extracted as Axum state
+/// * `auth`: Auth data about the user
+/// * `request`: The cycle to create
+#[utoipa::path(
+    post,
+    path = "",
+    operation_id = "Create cycle",
+    responses(
+        (status = 201, description = "Successfully created cycle"),
+        (status = 400, description = "Invalid cycle"),
+        (status = 401, description = "Unauthorized: invalid JWT"),
+        (status = 403, description = "Forbidden: insufficient permissions (requires `create:cycles`)"),
+        (status = 409, description = "A cycle with the same name already exists"),
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT. NOTE: Prefix with Bearer")
+    ),
+)]
+pub async fn create_cycle<DB: Database>(
+    State(ctx): State<Arc<Services<DB>>>,
+    Extension(auth): Extension<AuthData>,
+    Json(request): Json<CreateCycleRequest>,
+) -> Result<Response, AppError> {
+    let storage_layer = &ctx.storage_layer;
+
+    let name = request.name.trim();
+    if name.is_empty() {
+        return Ok(api_response::error(StatusCode::BAD_REQUEST, "Cycle name cannot be empty"));
+    }
+
+    let mut tx = storage_layer.acquire().await?;
+    let mut exec_opts = ExecOptsBuilder::default().tx(&mut tx).build()?;
+
+    let mut audit = Audit::new(storage_layer, &auth, AuditAction::CreateCycle)?
+        .summary(json!({ "name": name }));
+
+    let data = CreateCycle { name: name.to_owned(), description: request.description };
+    let res = storage_layer.create_cycle(data, &mut exec_opts).await;
+    if let Ok(id) = &res {
+        audit = audit.target(*id);
+    }
+    audit.record(&res, &mut exec_opts).await?;
+
+    let id = match res {
+        Ok(id) => id,
+        Err(e) if is_unique_violation(&e) => {
+            return Ok(api_response::error(
+                StatusCode::CONFLICT,
+                "A cycle with the same name already exists",
+            ));
+        }
+        Err(e) => return Err(e.into()),
+    };
+
+    let cycle = storage_layer
+        .fetch_cycle_by_id(id, &mut exec_opts)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("cycle {id} not found after it was created"))?;
+
+    tx.commit().await?;
+
+    Ok(api_response::success(StatusCode::CREATED, CycleResponse { cycle })?)
+}
+
+/// Edit a cycle
+///
+/// * `ctx`: The application context extracted as Axum state
+/// * `auth`: Auth data about the user
+/// * `id`: The ID of the cycle to edit
+/// * `request`: The changes to make to the cycle
+#[utoipa::path(
+    patch,
+    path = "/{id}",
+    operation_id = "Edit cycle",
+    responses(
+        (status = 200, description = "Successfully edited cycle"),
+        (status = 400, description = "Invalid changes"),
+        (status = 401, description = "Unauthorized: invalid JWT"),
+        (status = 403, description = "Forbidden: insufficient permissions (requires `edit:cycles`)"),
+        (status = 404, description = "Cycle not found"),
+        (status = 409, description = "A cycle with the same name already exists"),
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT. NOTE: Prefix with Bearer"),
+        ("id" = Uuid, Path, description = "The ID of the cycle")
+    ),
+)]
+pub async fn edit_cycle<DB: Database>(
+    State(ctx): State<Arc<Services<DB>>>,
+    Extension(auth): Extension<AuthData>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<EditCycleRequest>,
+) -> Result<Response, AppError> {
+    let storage_layer = &ctx.storage_layer;
+    let audit = Audit::new(storage_layer, &auth, AuditAction::EditCycle)?
+        .target(id)
+        .summary(edited_fields(&request));
+
+    let name = request.name.map(|name| name.trim().to_owned());
+    if name.as_deref().is_some_and(str::is_empty) {
+        return Ok(api_response::error(StatusCode::BAD_REQUEST, "Cycle name cannot be empty"));
+    }
+
+    let data = EditCycle { name, description: request.description, archived: request.archived };
+
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+    let res = storage_layer.edit_cycle(id, data, &mut exec_opts).await;
+    // Nothing happened if the cycle does not exist
+    if !matches!(res, Ok(None)) {
+        audit.record(&res, &mut exec_opts).await?;
+    }
+
+    let cycle = match res {
+        Ok(Some(cycle)) => cycle,
+        Ok(None) => return Ok(api_response::error(StatusCode::NOT_FOUND, "Cycle not found")),
+        Err(e) if is_unique_violation(&e) => {
+            return Ok(api_response::error(
+                StatusCode::CONFLICT,
+                "A cycle with the same name already exists",
+            ));
+        }
+        Err(e) => return Err(e.into()),
+    };
+
+    Ok(api_response::success(StatusCode::OK, CycleResponse { cycle })?)
+}
+
 /// Delete a cycle
 ///
 /// * `ctx`: The application context extracted as Axum state
+/// * `auth`: Auth data about the user
 /// * `id`: The ID of the cycle to delete
 #[utoipa::path(
     delete,
     path = "/{id}",
-    operation_id = "Get cycles",
+    operation_id = "Delete cycle",
     responses(
         (status = 204, description = "Successfully deleted cycle"),
         (status = 401, description = "Unauthorized: invalid JWT"),
@@ -55,11 +228,18 @@ pub async fn fetch_cycles(State(ctx): State<Arc<Services>>) -> Result<Response,
         ("Authorization" = String, Header, description = "JWT. NOTE: Prefix with Bearer")
     ),
 )]
-pub async fn delete_cycle(
-    State(ctx): State<Arc<Services>>,
+pub async fn delete_cycle<DB: Database>(
+    State(ctx): State<Arc<Services<DB>>>,
+    Extension(auth): Extension<AuthData>,
     Path(id): Path<Uuid>,
 ) -> Result<Response, AppError> {
     let storage_layer = &ctx.storage_layer;
-    storage_layer.delete_cycle(id, &mut ExecOptsBuilder::default().build()?).await?;
+    let audit = Audit::new(storage_layer, &auth, AuditAction::DeleteCycle)?.target(id);
+
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+    let res = storage_layer.delete_cycle(id, &mut exec_opts).await;
+    audit.record(&res, &mut exec_opts).await?;
+    res?;
+
     Ok(api_response::no_content())
 }
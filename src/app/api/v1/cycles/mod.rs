@@ -4,12 +4,14 @@ use std::sync::Arc;
 
 use axum::middleware::from_fn_with_state;
 use axum::{routing, Router};
+use sqlx::Database;
 use utoipa::OpenApi;
 
 use crate::app::api::middleware::make_rbac;
 use crate::app::state::Services;
 
 mod controllers;
+mod requests;
 mod responses;
 
 /// Documents the API for managing cycles
@@ -17,6 +19,9 @@ mod responses;
 #[openapi(
     paths(
         controllers::fetch_cycles,
+        controllers::fetch_cycle,
+        controllers::create_cycle,
+        controllers::edit_cycle,
         controllers::delete_cycle,
     ),
     security(("http" = ["JWT"]))
@@ -26,17 +31,36 @@ pub struct CyclesApi;
 /// Builds the cycles API.
 ///
 /// * `ctx`: The application context
-pub async fn build(ctx: Arc<Services>) -> Router<()> {
+pub async fn build<DB: Database>(ctx: Arc<Services<DB>>) -> Router<()> {
     let read_cycles_guard = make_rbac(vec!["read:cycles".to_owned()]).await;
-    let write_cycles_guard = make_rbac(vec!["delete:cycles".to_owned()]).await;
+    let create_cycles_guard = make_rbac(vec!["create:cycles".to_owned()]).await;
+    let edit_cycles_guard = make_rbac(vec!["edit:cycles".to_owned()]).await;
+    let delete_cycles_guard = make_rbac(vec!["delete:cycles".to_owned()]).await;
 
-    let fetch_cycles = routing::get(controllers::fetch_cycles);
-    let delete_cycle = routing::delete(controllers::delete_cycle);
+    let fetch_cycles = routing::get(controllers::fetch_cycles::<DB>);
+    let fetch_cycle = routing::get(controllers::fetch_cycle::<DB>);
+    let create_cycle = routing::post(controllers::create_cycle::<DB>);
+    let edit_cycle = routing::patch(controllers::edit_cycle::<DB>);
+    let delete_cycle = routing::delete(controllers::delete_cycle::<DB>);
+
+    let create_routes = Router::new()
+        .route("/", create_cycle)
+        .route_layer(from_fn_with_state(ctx.clone(), create_cycles_guard));
+
+    let edit_routes = Router::new()
+        .route("/:id", edit_cycle)
+        .route_layer(from_fn_with_state(ctx.clone(), edit_cycles_guard));
+
+    let delete_routes = Router::new()
+        .route("/:id", delete_cycle)
+        .route_layer(from_fn_with_state(ctx.clone(), delete_cycles_guard));
 
     Router::new()
         .route("/", fetch_cycles)
+        .route("/:id", fetch_cycle)
         .route_layer(from_fn_with_state(ctx.clone(), read_cycles_guard))
-        .route("/:id", delete_cycle)
-        .route_layer(from_fn_with_state(ctx.clone(), write_cycles_guard))
+        .merge(create_routes)
+        .merge(edit_routes)
+        .merge(delete_routes)
         .with_state(ctx.clone())
 }
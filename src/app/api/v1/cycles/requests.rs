@@ -0,0 +1,30 @@
+//! Requests for the cycles API.
+
+use serde::{Deserialize, Serialize};
+
+/// Request to create a cycle.
+///
+/// * `name`: The name of the cycle. It must be unique.
+/// * `description`: A description of the cycle
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateCycleRequest {
+    pub name: String,
+    pub description: String,
+}
+
+/// Request to edit a cycle.
+///
+/// Fields which are omitted are left unchanged.
+///
+/// * `name`: The new name of the cycle. It must be unique.
+/// * `description`: The new description of the cycle
+/// * `archived`: Whether the cycle is archived
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EditCycleRequest {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub archived: Option<bool>,
+}
@@ -9,3 +9,11 @@ use crate::services::storage::entities::ProjectCycle;
 pub struct CyclesResponse {
     pub cycles: Vec<ProjectCycle>,
 }
+
+/// A single cycle returned from the API.
+///
+/// * `cycle`: The cycle
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CycleResponse {
+    pub cycle: ProjectCycle,
+}
@@ -1,23 +1,31 @@
 //! Controllers for the data exports API.
 
+use std::collections::HashSet;
+
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::response::Response;
 use axum::{Extension, Json};
 use chrono::Utc;
-use tokio::task;
+use serde_json::json;
+use sqlx::Database;
 use uuid::Uuid;
 
-use super::workspace::policies::{EmailPolicy, PasswordPolicy};
-use super::workspace::{export_task, ExportParams};
 use super::ExportServices;
-use crate::app::api::v1::data_exports::requests::ExportUsersToWorkspaceRequest;
-use crate::app::api::v1::data_exports::responses::ExportUsersToWorkspaceResponse;
+use crate::app::api::v1::data_exports::requests::{
+    ExportUsersToWorkspaceRequest, UndoWorkspaceExportRequest,
+};
+use crate::app::api::v1::data_exports::responses::{
+    ExportUsersToWorkspaceResponse, UndoWorkspaceExportResponse,
+};
 use crate::app::api_response;
+use crate::app::audit::Audit;
 use crate::app::errors::AppError;
 use crate::services::auth::AuthData;
 use crate::services::storage::jobs::CreateJobBuilder;
-use crate::services::storage::types::{ExportDesination, JobData, JobDetails, JobType};
+use crate::services::storage::types::{
+    AuditAction, ExportDesination, JobData, JobDetails, JobType, WorkspaceExportPolicy,
+};
 use crate::services::storage::ExecOptsBuilder;
 
 /// Start a job to export users to Google Workspace.
@@ -27,8 +35,10 @@ use crate::services::storage::ExecOptsBuilder;
 /// * `auth`: Auth data about the user
 /// * `request`: The request data
 ///
-/// This endpoint starts a job, records it in the database, and returns immediately. The task it
-/// spawns does not block.
+/// This endpoint records a job in the database and returns immediately. The export itself is run
+/// by a job worker. If `airtableWriteBack` is set, the worker writes each volunteer's Workspace
+/// email back to Airtable once they are exported, and records any it couldn't write in the job's
+/// details.
 #[utoipa::path(
     post,
     path = "/{project_cycle_id}/workspace",
@@ -41,8 +51,8 @@ use crate::services::storage::ExecOptsBuilder;
         ("Authorization" = String, Header, description = "JWT. NOTE: Prefix with Bearer")
     ),
 )]
-pub async fn export_users_to_workspace(
-    State(services): State<ExportServices>,
+pub async fn export_users_to_workspace<DB: Database>(
+    State(services): State<ExportServices<DB>>,
     Path(project_cycle_id): Path<Uuid>,
     Extension(auth): Extension<AuthData>,
     Json(request): Json<ExportUsersToWorkspaceRequest>,
@@ -50,70 +60,191 @@ pub async fn export_users_to_workspace(
     let current_time = Utc::now();
     let time_only = current_time.format("%H:%M:%S").to_string();
 
+    let already_exported = services
+        .storage_layer
+        .fetch_exported_volunteer_details_by_project_cycle(
+            project_cycle_id,
+            &mut ExecOptsBuilder::default().build()?,
+        )
+        .await?
+        .iter()
+        .map(|v| v.volunteer_id)
+        .collect::<Vec<Uuid>>();
+
+    let volunteer_ids = if request.skip_users_on_conflict {
+        log::info!("Skipping users that have already been exported");
+        request
+            .volunteers
+            .iter()
+            .map(|v| v.volunteer_id)
+            .filter(|id| !already_exported.contains(id))
+            .collect::<Vec<Uuid>>()
+    } else {
+        for v in &request.volunteers {
+            if already_exported.contains(&v.volunteer_id) {
+                log::error!("One or more users have already been exported");
+                return Ok(api_response::error(
+                    StatusCode::BAD_REQUEST,
+                    "One or more users have already been exported",
+                ));
+            }
+        }
+        request.volunteers.iter().map(|v| v.volunteer_id).collect::<Vec<Uuid>>()
+    };
+
+    let mut audit = Audit::new(&services.storage_layer, &auth, AuditAction::ExportToWorkspace)?
+        .target(project_cycle_id)
+        .summary(json!({
+            "volunteers": volunteer_ids.len(),
+            "skipUsersOnConflict": request.skip_users_on_conflict,
+            "airtableWriteBack": request.airtable_write_back.is_some(),
+        }));
+
     let data = CreateJobBuilder::default()
         .label("Export Users")
         .description(Some("Export users to Google Workspace".to_owned()))
         .data(JobDetails {
             job_type: JobType::AirtableExportUsers,
             error: None,
+            summary: None,
+            diff: None,
+            write_back: None,
             data: JobData::AirtableExportUsers {
                 export_destination: ExportDesination::GoogleWorkspace,
+                principal: Some(auth.email()?),
+                volunteer_ids,
+                policy: Some(WorkspaceExportPolicy::from(&request)),
+                retry_of: None,
+                airtable_write_back: request.airtable_write_back,
             },
         })
         .build()?;
 
-    let job_id = services
-        .storage_layer
-        .create_job(Some(project_cycle_id), data, &mut ExecOptsBuilder::default().build()?)
-        .await?;
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+    let res = services.storage_layer.create_job(Some(project_cycle_id), data, &mut exec_opts).await;
+    if let Ok(job_id) = &res {
+        audit = audit.target(*job_id);
+    }
+    audit.record(&res, &mut exec_opts).await?;
+    let job_id = res?;
 
-    log::info!("Started import job {job_id} @ {time_only}");
+    log::info!("Queued export job {job_id} @ {time_only}");
 
-    let email_policy = EmailPolicy::from(&request);
-    let password_policy = PasswordPolicy::from(&request);
+    Ok(api_response::success(StatusCode::OK, ExportUsersToWorkspaceResponse { job_id })?)
+}
 
-    let already_exported = services
+/// Start a job to undo an export of users to Google Workspace.
+///
+/// * `ctx`:  The application context
+/// * `project_cycle_id`: The ID of the project cycle
+/// * `auth`: Auth data about the user
+/// * `request`: The request data
+///
+/// The job deletes the Workspace account of each volunteer and forgets that they were exported.
+/// Either every volunteer exported by an export job or a list of exported volunteers can be
+/// removed.
+#[utoipa::path(
+    post,
+    path = "/{project_cycle_id}/workspace/undo",
+    responses(
+        (status = 200, description = "Successfully started job to undo an export of users to Google Workspace"),
+        (status = 400, description = "Neither or both of `exportJobId` and `volunteerIds` were provided, or a volunteer has not been exported"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "The export job did not export any volunteers in this project cycle")
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT. NOTE: Prefix with Bearer")
+    ),
+)]
+pub async fn undo_workspace_export<DB: Database>(
+    State(services): State<ExportServices<DB>>,
+    Path(project_cycle_id): Path<Uuid>,
+    Extension(auth): Extension<AuthData>,
+    Json(request): Json<UndoWorkspaceExportRequest>,
+) -> Result<Response, AppError> {
+    let exported = services
         .storage_layer
         .fetch_exported_volunteer_details_by_project_cycle(
             project_cycle_id,
             &mut ExecOptsBuilder::default().build()?,
         )
-        .await?
-        .iter()
-        .map(|v| v.volunteer_id)
-        .collect::<Vec<Uuid>>();
+        .await?;
 
-    let volunteers = if request.skip_users_on_conflict {
-        log::info!("Skipping users that have already been exported");
-        request
-            .volunteers
-            .into_iter()
-            .filter(|v| !already_exported.contains(&v.volunteer_id))
-            .collect()
-    } else {
-        for v in &request.volunteers {
-            if already_exported.contains(&v.volunteer_id) {
-                log::error!("One or more users have already been exported");
+    let volunteers = match (request.export_job_id, &request.volunteer_ids) {
+        (Some(export_job_id), None) => {
+            let volunteers = exported
+                .into_iter()
+                .filter(|v| v.job_id == export_job_id)
+                .map(|v| (v.volunteer_id, v.workspace_email))
+                .collect::<Vec<(Uuid, String)>>();
+
+            if volunteers.is_empty() {
+                return Ok(api_response::error(
+                    StatusCode::NOT_FOUND,
+                    "The export job did not export any volunteers in this project cycle",
+                ));
+            }
+            volunteers
+        }
+        (None, Some(volunteer_ids)) => {
+            let volunteer_ids = volunteer_ids.iter().copied().collect::<HashSet<Uuid>>();
+            let volunteers = exported
+                .into_iter()
+                .filter(|v| volunteer_ids.contains(&v.volunteer_id))
+                .map(|v| (v.volunteer_id, v.workspace_email))
+                .collect::<Vec<(Uuid, String)>>();
+
+            if volunteers.len() != volunteer_ids.len() {
+                log::error!("One or more users have not been exported");
                 return Ok(api_response::error(
                     StatusCode::BAD_REQUEST,
-                    "One or more users have already been exported",
+                    "One or more users have not been exported",
                 ));
             }
+            volunteers
+        }
+        _ => {
+            return Ok(api_response::error(
+                StatusCode::BAD_REQUEST,
+                "Exactly one of exportJobId and volunteerIds must be provided",
+            ))
         }
-        request.volunteers
     };
 
-    let params = ExportParams {
-        job_id,
-        email_policy,
-        password_policy,
-        principal: auth.email()?,
-        volunteers,
-    };
+    let mut audit = Audit::new(&services.storage_layer, &auth, AuditAction::UndoWorkspaceExport)?
+        .target(project_cycle_id)
+        .summary(json!({
+            "volunteers": volunteers.len(),
+            "exportJobId": request.export_job_id,
+        }));
+
+    let data = CreateJobBuilder::default()
+        .label("Undo Export")
+        .description(Some("Remove exported users from Google Workspace".to_owned()))
+        .data(JobDetails {
+            job_type: JobType::UndoWorkspaceExport,
+            error: None,
+            summary: None,
+            diff: None,
+            write_back: None,
+            data: JobData::UndoWorkspaceExport {
+                volunteers,
+                principal: Some(auth.email()?),
+                export_job_id: request.export_job_id,
+            },
+        })
+        .build()?;
 
-    task::spawn(async move {
-        let _ = export_task(&services, params).await;
-    });
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+    let res = services.storage_layer.create_job(Some(project_cycle_id), data, &mut exec_opts).await;
+    if let Ok(job_id) = &res {
+        audit = audit.target(*job_id);
+    }
+    audit.record(&res, &mut exec_opts).await?;
+    let job_id = res?;
 
-    Ok(api_response::success(StatusCode::OK, ExportUsersToWorkspaceResponse { job_id })?)
+    log::info!("Queued job {job_id} to undo workspace export");
+
+    Ok(api_response::success(StatusCode::OK, UndoWorkspaceExportResponse { job_id })?)
 }
@@ -7,35 +7,103 @@ mod workspace;
 
 use std::sync::Arc;
 
+use anyhow::{bail, Result};
 use axum::extract::FromRef;
 use axum::middleware::from_fn_with_state;
 use axum::{routing, Router};
+use sqlx::{Database, Postgres};
+use tokio_util::sync::CancellationToken;
 use utoipa::OpenApi;
+use uuid::Uuid;
+use workspace::policies::{EmailPolicy, PasswordPolicy};
+use workspace::undo::{undo_task, UndoParams};
+use workspace::{export_task, ExportParams};
 
 use crate::app::api::middleware::make_rbac;
 use crate::app::state::Services;
+use crate::services::storage::types::JobData;
+use crate::services::storage::ExecOptsBuilder;
 
-struct ExportServices {
-    pub storage_layer: Arc<dyn crate::services::storage::StorageService>,
+struct ExportServices<DB: Database = Postgres> {
+    pub storage_layer: Arc<dyn crate::services::storage::StorageService<DB>>,
     pub workspace: Arc<dyn crate::services::workspace::WorkspaceService>,
     pub mail: Arc<dyn crate::services::mail::MailService>,
+    pub airtable: Arc<dyn crate::services::airtable::AirtableService>,
+    pub job_events: Arc<crate::app::events::JobEvents>,
 }
 
-impl FromRef<Arc<Services>> for ExportServices {
-    fn from_ref(ctx: &Arc<Services>) -> Self {
+impl<DB: Database> FromRef<Arc<Services<DB>>> for ExportServices<DB> {
+    fn from_ref(ctx: &Arc<Services<DB>>) -> Self {
         Self {
             storage_layer: ctx.storage_layer.clone(),
             workspace: ctx.workspace.clone(),
             mail: ctx.mail.clone(),
+            airtable: ctx.airtable.clone(),
+            job_events: ctx.job_events.clone(),
         }
     }
 }
 
+/// Runs a queued export job, or a job to undo an export.
+///
+/// * `ctx`: The application context
+/// * `job_id`: The ID of the job
+/// * `data`: The data the job was queued with
+/// * `cancellation`: Cancelled when the job should stop
+pub(super) async fn run_job<DB: Database>(
+    ctx: &Arc<Services<DB>>,
+    job_id: Uuid,
+    data: JobData,
+    cancellation: CancellationToken,
+) -> Result<()> {
+    let services = ExportServices::from_ref(ctx);
+
+    let (principal, volunteer_ids, policy, airtable_write_back) = match data {
+        JobData::AirtableExportUsers {
+            principal: Some(principal),
+            volunteer_ids,
+            policy: Some(policy),
+            airtable_write_back,
+            ..
+        } => (principal, volunteer_ids, policy, airtable_write_back),
+        JobData::UndoWorkspaceExport { volunteers, principal: Some(principal), .. } => {
+            let params = UndoParams { job_id, principal, volunteers, cancellation };
+            return undo_task(&services, params).await;
+        }
+        _ => bail!("job {job_id} is missing the parameters needed to run"),
+    };
+
+    let mut volunteers = services
+        .storage_layer
+        .fetch_volunteers_by_ids(volunteer_ids, &mut ExecOptsBuilder::default().build()?)
+        .await?;
+
+    // Volunteers who left the project cycle since the job was queued aren't exported
+    let queued = volunteers.len();
+    volunteers.retain(|v| v.active);
+    if volunteers.len() != queued {
+        log::info!("Skipping {} inactive volunteers", queued - volunteers.len());
+    }
+
+    let params = ExportParams {
+        job_id,
+        principal,
+        email_policy: EmailPolicy::from(&policy),
+        password_policy: PasswordPolicy::from(&policy),
+        volunteers,
+        airtable_write_back,
+        cancellation,
+    };
+
+    export_task(&services, params).await
+}
+
 /// Documents the API for data exports
 #[derive(OpenApi)]
 #[openapi(
     paths(
         controllers::export_users_to_workspace,
+        controllers::undo_workspace_export,
     ),
     security(("http" = ["JWT"]))
 )]
@@ -44,13 +112,15 @@ pub struct DataExportsApi;
 /// Builds the data exports API.
 ///
 /// * `ctx`: The application context
-pub async fn build(ctx: Arc<Services>) -> Router<()> {
+pub async fn build<DB: Database>(ctx: Arc<Services<DB>>) -> Router<()> {
     let export_workspace_guard = make_rbac(vec!["export:volunteers-workspace".to_owned()]).await;
 
-    let export_users_to_workspace = routing::post(controllers::export_users_to_workspace);
+    let export_users_to_workspace = routing::post(controllers::export_users_to_workspace::<DB>);
+    let undo_workspace_export = routing::post(controllers::undo_workspace_export::<DB>);
 
     Router::new()
         .route("/:project_cycle_id/workspace", export_users_to_workspace)
+        .route("/:project_cycle_id/workspace/undo", undo_workspace_export)
         .route_layer(from_fn_with_state(ctx.clone(), export_workspace_guard))
         .with_state(ctx.clone())
 }
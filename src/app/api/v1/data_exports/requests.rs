@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 use crate::services::storage::entities::VolunteerDetails;
+use crate::services::storage::types::AirtableWriteBack;
 
 /// Request to export users to a workspace.
 ///
@@ -13,6 +15,8 @@ use crate::services::storage::entities::VolunteerDetails;
 /// * `skip_users_on_conflict`: Whether to skip users on conflict. THIS IS CURRENTLY IGNORED.
 /// * `use_first_and_last_name`: Whether to use the first and last names for the email handle.
 /// * `volunteers`: The volunteers to export.
+/// * `airtable_write_back`: Where to write the Workspace email of each exported volunteer back to
+///   in Airtable, if anywhere.
 // TODO: Either remove `skip_users_on_conflict` or implement it. If it is implemented, its
 // semantics need to be crystal clear.
 #[derive(Debug, Serialize, Deserialize)]
@@ -25,4 +29,19 @@ pub struct ExportUsersToWorkspaceRequest {
     pub skip_users_on_conflict: bool,
     pub use_first_and_last_name: bool,
     pub volunteers: Vec<VolunteerDetails>,
+    #[serde(default)]
+    pub airtable_write_back: Option<AirtableWriteBack>,
+}
+
+/// Request to undo an export of users to a workspace.
+///
+/// Exactly one of `export_job_id` and `volunteer_ids` must be provided.
+///
+/// * `export_job_id`: The ID of an export job. Every volunteer it exported is removed.
+/// * `volunteer_ids`: The IDs of exported volunteers to remove.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UndoWorkspaceExportRequest {
+    pub export_job_id: Option<Uuid>,
+    pub volunteer_ids: Option<Vec<Uuid>>,
 }
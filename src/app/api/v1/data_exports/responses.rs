@@ -6,3 +6,9 @@ use uuid::Uuid;
 pub struct ExportUsersToWorkspaceResponse {
     pub job_id: Uuid,
 }
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UndoWorkspaceExportResponse {
+    pub job_id: Uuid,
+}
@@ -1,14 +1,24 @@
 pub mod policies;
+pub mod undo;
+pub mod write_back;
+#[cfg(test)]
+mod tests;
 
 use std::env;
 
 use anyhow::Result;
+use chrono::Utc;
 use policies::{EmailPolicy, PasswordPolicy};
+use sqlx::Database;
+use tokio_util::sync::CancellationToken;
 use uuid::Uuid;
+use write_back::write_back_to_airtable;
 
 use super::ExportServices;
 use crate::services::mail::{OnboardingEmailParams, OnboardingEmailParamsBuilder};
 use crate::services::storage::entities::VolunteerDetails;
+use crate::services::storage::jobs::{CreateJobItemBuilder, UpdateJobItemBuilder};
+use crate::services::storage::types::{AirtableWriteBack, JobItemKind, JobItemStatus};
 use crate::services::storage::volunteers::InsertVolunteerExportedToWorkspace;
 use crate::services::storage::ExecOptsBuilder;
 use crate::services::workspace::entities::CreateWorkspaceVolunteer;
@@ -19,6 +29,8 @@ pub struct ExportParams {
     pub email_policy: EmailPolicy,
     pub password_policy: PasswordPolicy,
     pub volunteers: Vec<VolunteerDetails>,
+    pub airtable_write_back: Option<AirtableWriteBack>,
+    pub cancellation: CancellationToken,
 }
 
 struct ProcessedVolunteers {
@@ -78,46 +90,79 @@ fn process_volunteers(params: &ExportParams) -> Result<ProcessedVolunteers> {
     Ok(ProcessedVolunteers { export_data, pantheon_data, onboarding_email_data })
 }
 
-async fn export_volunteers_to_workspace(
-    services: &ExportServices,
-    principal: &str,
+/// Export volunteers to Workspace, recording the outcome for each volunteer.
+///
+/// Export stops at the first volunteer that fails to export, or once the job is cancelled, and
+/// every remaining volunteer is recorded as skipped. Returns the ID of each volunteer that was
+/// exported, paired with their Workspace email.
+async fn export_volunteers_to_workspace<DB: Database>(
+    services: &ExportServices<DB>,
+    params: &ExportParams,
     export_data: Vec<CreateWorkspaceVolunteer>,
-) -> usize {
-    let mut successfully_exported = 0usize;
-    for user in export_data {
-        let name = format!("{} {}", &user.first_name, &user.last_name);
-        match services.workspace.create_volunteer(principal, user).await {
-            Ok(_) => {
-                log::info!("Successfully exported user {} to workspace", name);
-                successfully_exported += 1;
-            }
-            Err(e) => {
-                log::error!("Failed to export user to workspace: {}", e);
-                break;
-            }
+    pantheon_data: Vec<InsertVolunteerExportedToWorkspace>,
+) -> Result<Vec<(Uuid, String)>> {
+    let mut successfully_exported = Vec::<(Uuid, String)>::with_capacity(export_data.len());
+    let mut skip_reason: Option<&str> = None;
+
+    for (user, save_data) in export_data.into_iter().zip(pantheon_data) {
+        let item_key = save_data.volunteer_id.to_string();
+
+        if skip_reason.is_none() && params.cancellation.is_cancelled() {
+            log::info!("Export job {} was cancelled", params.job_id);
+            skip_reason = Some("Skipped because the job was cancelled");
         }
-    }
 
-    successfully_exported
-}
+        let outcome = if let Some(reason) = skip_reason {
+            UpdateJobItemBuilder::default()
+                .status(JobItemStatus::Skipped)
+                .error(reason.to_owned())
+                .build()?
+        } else {
+            let name = format!("{} {}", &user.first_name, &user.last_name);
+            match services.workspace.create_volunteer(&params.principal, user).await {
+                Ok(_) => {
+                    log::info!("Successfully exported user {} to workspace", name);
+                    let exported = (save_data.volunteer_id, save_data.workspace_email.clone());
+                    services
+                        .storage_layer
+                        .insert_volunteer_exported_to_workspace(
+                            save_data,
+                            &mut ExecOptsBuilder::default().build()?,
+                        )
+                        .await?;
+                    successfully_exported.push(exported);
+                    UpdateJobItemBuilder::default().status(JobItemStatus::Succeeded).build()?
+                }
+                Err(e) => {
+                    log::error!("Failed to export user to workspace: {}", e);
+                    skip_reason = Some("Skipped because an earlier volunteer failed to export");
+                    UpdateJobItemBuilder::default()
+                        .status(JobItemStatus::Failed)
+                        .error(e.to_string())
+                        .build()?
+                }
+            }
+        };
 
-async fn save_exported_volunteers<'a>(
-    services: &ExportServices,
-    save_data: Vec<InsertVolunteerExportedToWorkspace>,
-) -> Result<()> {
-    services
-        .storage_layer
-        .batch_insert_volunteers_exported_to_workspace(
-            save_data,
-            &mut ExecOptsBuilder::default().build()?,
-        )
-        .await?;
+        services
+            .storage_layer
+            .update_job_item(
+                params.job_id,
+                JobItemKind::Volunteer,
+                &item_key,
+                outcome,
+                &mut ExecOptsBuilder::default().build()?,
+            )
+            .await?;
 
-    Ok(())
+        services.job_events.publish(params.job_id);
+    }
+
+    Ok(successfully_exported)
 }
 
-async fn send_onboarding_emails(
-    services: &ExportServices,
+async fn send_onboarding_emails<DB: Database>(
+    services: &ExportServices<DB>,
     onboarding_data: Vec<OnboardingEmailParams>,
 ) -> Result<()> {
     for email in onboarding_data {
@@ -133,12 +178,97 @@ async fn send_onboarding_emails(
     Ok(())
 }
 
-pub async fn export_task(services: &ExportServices, params: ExportParams) -> Result<()> {
+/// Record every volunteer in an export as a pending job item.
+///
+/// Returns the IDs of volunteers which were already exported by an earlier attempt at the job.
+async fn record_job_items<DB: Database>(
+    services: &ExportServices<DB>,
+    params: &ExportParams,
+) -> Result<Vec<Uuid>> {
+    let items = params
+        .volunteers
+        .iter()
+        .map(|v| {
+            CreateJobItemBuilder::default()
+                .kind(JobItemKind::Volunteer)
+                .item_key(v.volunteer_id.to_string())
+                .entity_id(v.volunteer_id)
+                .label(format!("{} {}", v.first_name, v.last_name))
+                .build()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+    services.storage_layer.batch_create_job_items(params.job_id, items, &mut exec_opts).await?;
+
+    let already_exported = services
+        .storage_layer
+        .fetch_job_items(params.job_id, &mut exec_opts)
+        .await?
+        .into_iter()
+        .filter(|item| item.status == JobItemStatus::Succeeded)
+        .filter_map(|item| item.entity_id)
+        .collect();
+
+    Ok(already_exported)
+}
+
+/// Fetch the Workspace emails of volunteers which were exported by an earlier attempt at the job.
+///
+/// * `services`: The services an export uses
+/// * `params`: The export, before volunteers which were already exported are dropped from it
+/// * `already_exported`: The IDs of volunteers which were already exported
+async fn fetch_already_exported<DB: Database>(
+    services: &ExportServices<DB>,
+    params: &ExportParams,
+    already_exported: &[Uuid],
+) -> Result<Vec<(Uuid, String)>> {
+    let Some(project_cycle_id) = params.volunteers.first().map(|v| v.project_cycle_id) else {
+        return Ok(vec![]);
+    };
+    if already_exported.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let exported = services
+        .storage_layer
+        .fetch_exported_volunteer_details_by_project_cycle(
+            project_cycle_id,
+            &mut ExecOptsBuilder::default().build()?,
+        )
+        .await?
+        .into_iter()
+        .filter(|v| v.job_id == params.job_id && already_exported.contains(&v.volunteer_id))
+        .map(|v| (v.volunteer_id, v.workspace_email))
+        .collect();
+
+    Ok(exported)
+}
+
+pub async fn export_task<DB: Database>(
+    services: &ExportServices<DB>,
+    mut params: ExportParams,
+) -> Result<()> {
+    // A job which is re-run after its worker died must not export anyone twice
+    let already_exported = record_job_items(services, &params).await?;
+    // An earlier attempt may have died before writing back the volunteers it exported
+    let mut written_back = match params.airtable_write_back {
+        Some(_) => fetch_already_exported(services, &params, &already_exported).await?,
+        None => vec![],
+    };
+    params.volunteers.retain(|v| !already_exported.contains(&v.volunteer_id));
+
     let mut processed = process_volunteers(&params)?;
 
     let number_of_users_to_export = processed.export_data.len();
-    let exported_count =
-        export_volunteers_to_workspace(services, &params.principal, processed.export_data).await;
+    let exported = export_volunteers_to_workspace(
+        services,
+        &params,
+        processed.export_data,
+        processed.pantheon_data,
+    )
+    .await?;
+    let exported_count = exported.len();
 
     if exported_count != number_of_users_to_export {
         log::error!(
@@ -146,29 +276,43 @@ pub async fn export_task(services: &ExportServices, params: ExportParams) -> Res
             exported_count,
             number_of_users_to_export
         );
-        processed.pantheon_data.truncate(exported_count);
         processed.onboarding_email_data.truncate(exported_count);
     }
 
-    match save_exported_volunteers(services, processed.pantheon_data).await {
-        Ok(_) => match send_onboarding_emails(services, processed.onboarding_email_data).await {
-            Ok(_) => {
-                services
-                    .storage_layer
-                    .mark_job_complete(params.job_id, &mut ExecOptsBuilder::default().build()?)
-                    .await?
-            }
-            Err(e) => {
-                services
-                    .storage_layer
-                    .mark_job_errored(
-                        params.job_id,
-                        e.to_string(),
-                        &mut ExecOptsBuilder::default().build()?,
-                    )
-                    .await?
-            }
-        },
+    let emails = send_onboarding_emails(services, processed.onboarding_email_data).await;
+
+    // Accounts which were created exist even if the job was cancelled, so their emails are
+    // written back regardless
+    if let Some(write_back) = &params.airtable_write_back {
+        written_back.extend(exported);
+        if let Err(e) =
+            write_back_to_airtable(services, params.job_id, write_back, written_back, Utc::now())
+                .await
+        {
+            log::error!("Failed to record the Airtable write back of job {}: {e}", params.job_id);
+        }
+    }
+
+    if params.cancellation.is_cancelled() {
+        let summary = format!(
+            "Cancelled after exporting {} out of {} volunteers. The rest were not exported",
+            exported_count + already_exported.len(),
+            number_of_users_to_export + already_exported.len()
+        );
+        services
+            .storage_layer
+            .mark_job_cancelled(params.job_id, summary, &mut ExecOptsBuilder::default().build()?)
+            .await?;
+        return Ok(());
+    }
+
+    match emails {
+        Ok(_) => {
+            services
+                .storage_layer
+                .mark_job_complete(params.job_id, &mut ExecOptsBuilder::default().build()?)
+                .await?
+        }
         Err(e) => {
             services
                 .storage_layer
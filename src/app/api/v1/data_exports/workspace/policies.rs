@@ -3,6 +3,7 @@ use rand::Rng;
 use serde::{Deserialize, Serialize};
 
 use crate::app::api::v1::data_exports::requests::ExportUsersToWorkspaceRequest;
+use crate::services::storage::types::WorkspaceExportPolicy;
 
 pub struct EmailPolicy {
     pub add_unique_numeric_suffix: bool,
@@ -69,21 +70,33 @@ impl PasswordPolicy {
     }
 }
 
-impl From<&ExportUsersToWorkspaceRequest> for EmailPolicy {
+impl From<&ExportUsersToWorkspaceRequest> for WorkspaceExportPolicy {
     fn from(request: &ExportUsersToWorkspaceRequest) -> Self {
         Self {
             add_unique_numeric_suffix: request.add_unique_numeric_suffix,
+            change_password_at_next_login: request.change_password_at_next_login,
+            generated_password_length: request.generated_password_length,
             separator: request.separator.clone(),
             use_first_and_last_name: request.use_first_and_last_name,
         }
     }
 }
 
-impl From<&ExportUsersToWorkspaceRequest> for PasswordPolicy {
-    fn from(request: &ExportUsersToWorkspaceRequest) -> Self {
+impl From<&WorkspaceExportPolicy> for EmailPolicy {
+    fn from(policy: &WorkspaceExportPolicy) -> Self {
         Self {
-            change_password_at_next_login: request.change_password_at_next_login,
-            generated_password_length: request.generated_password_length,
+            add_unique_numeric_suffix: policy.add_unique_numeric_suffix,
+            separator: policy.separator.clone(),
+            use_first_and_last_name: policy.use_first_and_last_name,
+        }
+    }
+}
+
+impl From<&WorkspaceExportPolicy> for PasswordPolicy {
+    fn from(policy: &WorkspaceExportPolicy) -> Self {
+        Self {
+            change_password_at_next_login: policy.change_password_at_next_login,
+            generated_password_length: policy.generated_password_length,
         }
     }
 }
@@ -0,0 +1,61 @@
+mod undo;
+mod write_back;
+
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+
+use anyhow::{bail, Result};
+use async_trait::async_trait;
+use scipio_airtable::Airtable;
+use sqlx::PgPool;
+
+use crate::app::api::v1::data_exports::ExportServices;
+use crate::app::events::JobEvents;
+use crate::services::mail::noop::NoopEmailClient;
+use crate::services::storage::PgBackend;
+use crate::services::workspace::entities::CreateWorkspaceVolunteer;
+use crate::services::workspace::WorkspaceClient;
+use crate::services::Service;
+
+/// A Workspace client which remembers the users it deleted, and fails for the emails it is
+/// given.
+#[derive(Default)]
+pub struct FakeWorkspaceClient {
+    pub failing: HashSet<String>,
+    pub deleted: Mutex<Vec<String>>,
+}
+
+#[async_trait]
+impl WorkspaceClient for FakeWorkspaceClient {
+    async fn create_volunteer(
+        &self,
+        _principal: &str,
+        _volunteer: CreateWorkspaceVolunteer,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    async fn delete_user(&self, _principal: &str, email_of_user_to_delete: &str) -> Result<()> {
+        if self.failing.contains(email_of_user_to_delete) {
+            bail!("error deleting {email_of_user_to_delete}");
+        }
+        self.deleted.lock().unwrap().push(email_of_user_to_delete.to_owned());
+        Ok(())
+    }
+}
+
+impl Service for FakeWorkspaceClient {
+    fn get_id(&self) -> &'static str {
+        "fake"
+    }
+}
+
+pub fn export_services(pool: PgPool, workspace: Arc<FakeWorkspaceClient>) -> ExportServices {
+    ExportServices {
+        storage_layer: Arc::new(PgBackend { pool }),
+        workspace,
+        mail: Arc::new(NoopEmailClient),
+        airtable: Arc::new(Airtable::new("test", 5).expect("error creating Airtable client")),
+        job_events: Arc::new(JobEvents::default()),
+    }
+}
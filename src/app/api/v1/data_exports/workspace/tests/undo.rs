@@ -0,0 +1,152 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use anyhow::Result;
+use sqlx::PgPool;
+use tokio_util::sync::CancellationToken;
+use uuid::{uuid, Uuid};
+
+use super::{export_services, FakeWorkspaceClient};
+use crate::app::api::v1::data_exports::workspace::undo::{undo_task, UndoParams};
+use crate::app::api::v1::data_exports::ExportServices;
+use crate::services::storage::types::{JobItemStatus, JobStatus};
+use crate::services::storage::volunteers::InsertVolunteerExportedToWorkspaceBuilder;
+use crate::services::storage::ExecOptsBuilder;
+
+const EXPORT_JOB_ID: Uuid = uuid!("413eed73-3c6f-456a-b9f0-ae72d136c742");
+const EXPORT_PROJECT_CYCLE_ID: Uuid = uuid!("76ed64a0-d88f-4148-9b02-331ea888d5d1");
+const UNDO_JOB_ID: Uuid = uuid!("bc080e0d-8b14-46e0-9268-4bbb370035ec");
+
+const FEDERER: (Uuid, &str) =
+    (uuid!("9edc52d8-8cc7-4d44-80c1-7efcce246e90"), "rogerfederer@developforgood.org");
+const NADAL: (Uuid, &str) =
+    (uuid!("1b1b5e16-d0d6-4ad1-8fdc-80df15b18b67"), "rafanadal@developforgood.org");
+
+async fn export(services: &ExportServices) -> Result<()> {
+    let data = [FEDERER, NADAL]
+        .into_iter()
+        .map(|(volunteer_id, workspace_email)| {
+            InsertVolunteerExportedToWorkspaceBuilder::default()
+                .job_id(EXPORT_JOB_ID)
+                .volunteer_id(volunteer_id)
+                .workspace_email(workspace_email)
+                .org_unit("/Programs/PantheonUsers")
+                .build()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    services
+        .storage_layer
+        .batch_insert_volunteers_exported_to_workspace(
+            data,
+            &mut ExecOptsBuilder::default().build()?,
+        )
+        .await
+}
+
+fn params(cancellation: CancellationToken) -> UndoParams {
+    UndoParams {
+        job_id: UNDO_JOB_ID,
+        principal: "admin@developforgood.org".to_owned(),
+        volunteers: [FEDERER, NADAL]
+            .into_iter()
+            .map(|(id, email)| (id, email.to_owned()))
+            .collect(),
+        cancellation,
+    }
+}
+
+async fn still_exported(services: &ExportServices) -> Result<Vec<Uuid>> {
+    Ok(services
+        .storage_layer
+        .fetch_exported_volunteer_details_by_project_cycle(
+            EXPORT_PROJECT_CYCLE_ID,
+            &mut ExecOptsBuilder::default().build()?,
+        )
+        .await?
+        .into_iter()
+        .map(|v| v.volunteer_id)
+        .collect())
+}
+
+async fn job_status(services: &ExportServices) -> Result<JobStatus> {
+    let job = services
+        .storage_layer
+        .fetch_job(UNDO_JOB_ID, &mut ExecOptsBuilder::default().build()?)
+        .await?
+        .expect("job not found");
+    Ok(job.status)
+}
+
+#[sqlx::test(fixtures(
+    path = "../../../../../../services/storage/tests/fixtures",
+    scripts("setup")
+))]
+pub async fn test_undo_task(pool: PgPool) -> Result<()> {
+    let workspace = Arc::new(FakeWorkspaceClient {
+        failing: HashSet::from([NADAL.1.to_owned()]),
+        ..Default::default()
+    });
+    let services = export_services(pool.clone(), workspace.clone());
+    export(&services).await?;
+
+    undo_task(&services, params(CancellationToken::new())).await?;
+
+    assert_eq!(*workspace.deleted.lock().unwrap(), vec![FEDERER.1.to_owned()]);
+    assert_eq!(still_exported(&services).await?, vec![NADAL.0]);
+    // Removing some of the volunteers completes the job, with the rest reported as failed items
+    assert_eq!(job_status(&services).await?, JobStatus::Complete);
+
+    let items = services
+        .storage_layer
+        .fetch_job_items(UNDO_JOB_ID, &mut ExecOptsBuilder::default().build()?)
+        .await?;
+    let status_of =
+        |id: Uuid| items.iter().find(|item| item.entity_id == Some(id)).map(|item| item.status);
+    assert_eq!(status_of(FEDERER.0), Some(JobItemStatus::Succeeded));
+    assert_eq!(status_of(NADAL.0), Some(JobItemStatus::Failed));
+
+    // Re-running the job only retries the volunteers which weren't removed
+    let workspace = Arc::new(FakeWorkspaceClient::default());
+    let services = export_services(pool, workspace.clone());
+    undo_task(&services, params(CancellationToken::new())).await?;
+
+    assert_eq!(*workspace.deleted.lock().unwrap(), vec![NADAL.1.to_owned()]);
+    assert!(still_exported(&services).await?.is_empty());
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures(
+    path = "../../../../../../services/storage/tests/fixtures",
+    scripts("setup")
+))]
+pub async fn test_undo_task_removes_no_one(pool: PgPool) -> Result<()> {
+    let workspace = Arc::new(FakeWorkspaceClient {
+        failing: HashSet::from([FEDERER.1.to_owned(), NADAL.1.to_owned()]),
+        ..Default::default()
+    });
+    let services = export_services(pool.clone(), workspace.clone());
+    export(&services).await?;
+
+    undo_task(&services, params(CancellationToken::new())).await?;
+    assert_eq!(job_status(&services).await?, JobStatus::Error);
+    assert_eq!(still_exported(&services).await?.len(), 2);
+
+    // A cancelled job skips every volunteer it hasn't removed yet
+    let workspace = Arc::new(FakeWorkspaceClient::default());
+    let services = export_services(pool, workspace.clone());
+    let cancellation = CancellationToken::new();
+    cancellation.cancel();
+    undo_task(&services, params(cancellation)).await?;
+
+    assert!(workspace.deleted.lock().unwrap().is_empty());
+    assert_eq!(job_status(&services).await?, JobStatus::Cancelled);
+    let items = services
+        .storage_layer
+        .fetch_job_items(UNDO_JOB_ID, &mut ExecOptsBuilder::default().build()?)
+        .await?;
+    assert!(items.iter().all(|item| item.status == JobItemStatus::Skipped));
+
+    Ok(())
+}
@@ -0,0 +1,266 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use chrono::Utc;
+use scipio_airtable::base_data::records::UpdateMethod;
+use scipio_airtable::Airtable;
+use scipio_fake_server::FakeServer;
+use serde_json::{json, Map, Value};
+use sqlx::PgPool;
+use tokio_util::sync::CancellationToken;
+use uuid::{uuid, Uuid};
+
+use super::{export_services, FakeWorkspaceClient};
+use crate::app::api::v1::data_exports::workspace::policies::{EmailPolicy, PasswordPolicy};
+use crate::app::api::v1::data_exports::workspace::write_back::write_back_to_airtable;
+use crate::app::api::v1::data_exports::workspace::{export_task, ExportParams};
+use crate::app::api::v1::data_exports::ExportServices;
+use crate::services::storage::types::{
+    AirtableWriteBack, ExternalSource, JobStatus, WorkspaceExportPolicy, WriteBackFailure,
+    WriteBackReport,
+};
+use crate::services::storage::ExecOptsBuilder;
+
+const EXPORT_JOB_ID: Uuid = uuid!("413eed73-3c6f-456a-b9f0-ae72d136c742");
+
+const FEDERER: Uuid = uuid!("9edc52d8-8cc7-4d44-80c1-7efcce246e90");
+const NADAL: Uuid = uuid!("1b1b5e16-d0d6-4ad1-8fdc-80df15b18b67");
+const MURRAY: Uuid = uuid!("0ef67e25-543c-4f0d-9a96-8cb71b3c0f60");
+const DJOKOVIC: Uuid = uuid!("5e7b3f35-2b84-46e7-8b7b-73e2716d42c9");
+
+/// A fake Airtable base with a table of volunteers, one for each name. The base is removed when
+/// this is dropped.
+struct FakeBase {
+    _fake: FakeServer,
+    airtable: Airtable,
+    write_back: AirtableWriteBack,
+    record_ids: Vec<String>,
+}
+
+impl FakeBase {
+    fn new(names: &[&str]) -> Result<Self> {
+        let fake = FakeServer::start();
+        let airtable = Airtable::new("test-api-token", 0)?.with_base_url(fake.url());
+
+        let base_id = fake.add_airtable_base("Scipio Test");
+        fake.add_airtable_table(
+            &base_id,
+            "Volunteers",
+            &["Name", "WorkspaceEmail", "WorkspaceExportedAt"],
+            &["Grid"],
+        );
+        let records = names
+            .iter()
+            .map(|name| Map::from_iter([("Name".to_owned(), json!(name))]))
+            .collect::<Vec<_>>();
+        let record_ids = fake.add_airtable_records(&base_id, "Volunteers", records);
+
+        let write_back = serde_json::from_value(json!({ "baseId": base_id }))?;
+
+        Ok(Self { _fake: fake, airtable, write_back, record_ids })
+    }
+
+    /// The Workspace email of each record, in the order the records were added.
+    async fn workspace_emails(&self) -> Result<Vec<Option<String>>> {
+        let records = self
+            .airtable
+            .collect_all_records::<Map<String, Value>>(&self.write_back.base_id, "Volunteers", None)
+            .await?;
+
+        Ok(self
+            .record_ids
+            .iter()
+            .map(|id| {
+                records
+                    .iter()
+                    .find(|record| &record.id == id)
+                    .and_then(|record| record.fields.get("WorkspaceEmail"))
+                    .and_then(Value::as_str)
+                    .map(str::to_owned)
+            })
+            .collect())
+    }
+}
+
+fn services_with_airtable(pool: PgPool, base: &FakeBase) -> ExportServices {
+    ExportServices {
+        airtable: Arc::new(base.airtable.clone()),
+        ..export_services(pool, Arc::new(FakeWorkspaceClient::default()))
+    }
+}
+
+async fn set_external_id(
+    pool: &PgPool,
+    volunteer_id: Uuid,
+    source: ExternalSource,
+    external_id: &str,
+) -> Result<()> {
+    sqlx::query("update volunteers set external_source = $1, external_id = $2 where id = $3")
+        .bind(source)
+        .bind(external_id)
+        .bind(volunteer_id)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+/// An export of Djokovic which writes back to a base.
+async fn export_params(
+    services: &ExportServices,
+    write_back: &AirtableWriteBack,
+) -> Result<ExportParams> {
+    let policy = WorkspaceExportPolicy {
+        add_unique_numeric_suffix: false,
+        change_password_at_next_login: true,
+        generated_password_length: 8,
+        separator: None,
+        use_first_and_last_name: true,
+    };
+    let volunteers = services
+        .storage_layer
+        .fetch_volunteers_by_ids(vec![DJOKOVIC], &mut ExecOptsBuilder::default().build()?)
+        .await?;
+
+    Ok(ExportParams {
+        job_id: EXPORT_JOB_ID,
+        principal: "admin@developforgood.org".to_owned(),
+        email_policy: EmailPolicy::from(&policy),
+        password_policy: PasswordPolicy::from(&policy),
+        volunteers,
+        airtable_write_back: Some(write_back.clone()),
+        cancellation: CancellationToken::new(),
+    })
+}
+
+async fn job_status(services: &ExportServices) -> Result<JobStatus> {
+    let job = services
+        .storage_layer
+        .fetch_job(EXPORT_JOB_ID, &mut ExecOptsBuilder::default().build()?)
+        .await?
+        .expect("job not found");
+    Ok(job.status)
+}
+
+async fn write_back_report(services: &ExportServices) -> Result<WriteBackReport> {
+    let job = services
+        .storage_layer
+        .fetch_job(EXPORT_JOB_ID, &mut ExecOptsBuilder::default().build()?)
+        .await?
+        .expect("job not found");
+    Ok(serde_json::from_value(job.details["writeBack"].clone())?)
+}
+
+#[sqlx::test(fixtures(
+    path = "../../../../../../services/storage/tests/fixtures",
+    scripts("setup")
+))]
+pub async fn test_write_back_to_airtable(pool: PgPool) -> Result<()> {
+    let base = FakeBase::new(&["Roger Federer"])?;
+    let services = services_with_airtable(pool.clone(), &base);
+
+    set_external_id(&pool, FEDERER, ExternalSource::Airtable, &base.record_ids[0]).await?;
+    set_external_id(&pool, NADAL, ExternalSource::Airtable, "recMissing").await?;
+    set_external_id(&pool, DJOKOVIC, ExternalSource::Csv, "novak@example.com").await?;
+
+    let exported = vec![
+        (FEDERER, "rogerfederer@developforgood.org".to_owned()),
+        (NADAL, "rafaelnadal@developforgood.org".to_owned()),
+        (MURRAY, "andymurray@developforgood.org".to_owned()),
+        (DJOKOVIC, "novakdjokovic@developforgood.org".to_owned()),
+    ];
+    write_back_to_airtable(&services, EXPORT_JOB_ID, &base.write_back, exported, Utc::now())
+        .await?;
+
+    assert_eq!(
+        base.workspace_emails().await?,
+        vec![Some("rogerfederer@developforgood.org".to_owned())]
+    );
+
+    // Each failure is attributed to the volunteer whose record wasn't written
+    let report = write_back_report(&services).await?;
+    assert_eq!(report.written, 1);
+    assert_eq!(report.failures.len(), 3);
+    let failure_of = |id: Uuid| report.failures.iter().find(|f| f.volunteer_id == id);
+
+    let nadal = failure_of(NADAL).expect("missing failure for Nadal");
+    assert_eq!(nadal.workspace_email, "rafaelnadal@developforgood.org");
+    assert!(nadal.error.contains("could not find"), "{}", nadal.error);
+
+    assert_eq!(
+        failure_of(MURRAY),
+        Some(&WriteBackFailure {
+            volunteer_id: MURRAY,
+            workspace_email: "andymurray@developforgood.org".to_owned(),
+            error: "The volunteer was not imported from Airtable".to_owned(),
+        })
+    );
+
+    // Djokovic's external ID is his email, so it isn't sent to Airtable as a record ID
+    let djokovic = failure_of(DJOKOVIC).expect("missing failure for Djokovic");
+    assert_eq!(djokovic.error, "The volunteer was not imported from Airtable");
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures(
+    path = "../../../../../../services/storage/tests/fixtures",
+    scripts("setup")
+))]
+pub async fn test_export_task_writes_back_earlier_attempts(pool: PgPool) -> Result<()> {
+    let base = FakeBase::new(&["Novak Djokovic"])?;
+    let services = services_with_airtable(pool.clone(), &base);
+    set_external_id(&pool, DJOKOVIC, ExternalSource::Airtable, &base.record_ids[0]).await?;
+
+    export_task(&services, export_params(&services, &base.write_back).await?).await?;
+    assert_eq!(
+        base.workspace_emails().await?,
+        vec![Some("novakdjokovic@developforgood.org".to_owned())]
+    );
+
+    // A re-run of the job doesn't export Djokovic again, but still writes him back in case the
+    // earlier attempt died before it could
+    base.airtable
+        .update_record(
+            &base.write_back.base_id,
+            "Volunteers",
+            &base.record_ids[0],
+            &json!({ "WorkspaceEmail": null }),
+            UpdateMethod::Merge,
+            &Default::default(),
+        )
+        .await?;
+    export_task(&services, export_params(&services, &base.write_back).await?).await?;
+    assert_eq!(
+        base.workspace_emails().await?,
+        vec![Some("novakdjokovic@developforgood.org".to_owned())]
+    );
+
+    let report = write_back_report(&services).await?;
+    assert_eq!(report, WriteBackReport { written: 1, failures: vec![] });
+
+    assert_eq!(job_status(&services).await?, JobStatus::Complete);
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures(
+    path = "../../../../../../services/storage/tests/fixtures",
+    scripts("setup")
+))]
+pub async fn test_export_task_completes_when_write_back_fails(pool: PgPool) -> Result<()> {
+    let base = FakeBase::new(&[])?;
+    let services = services_with_airtable(pool.clone(), &base);
+    // The record was deleted from the base after the volunteer was imported
+    set_external_id(&pool, DJOKOVIC, ExternalSource::Airtable, "recDeleted").await?;
+
+    export_task(&services, export_params(&services, &base.write_back).await?).await?;
+
+    assert_eq!(job_status(&services).await?, JobStatus::Complete);
+
+    let report = write_back_report(&services).await?;
+    assert_eq!(report.written, 0);
+    assert_eq!(report.failures.len(), 1);
+    assert_eq!(report.failures[0].volunteer_id, DJOKOVIC);
+
+    Ok(())
+}
@@ -0,0 +1,141 @@
+//! Undoing exports of volunteers to Workspace.
+
+use anyhow::Result;
+use sqlx::Database;
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+use crate::app::api::v1::data_exports::ExportServices;
+use crate::services::storage::jobs::{CreateJobItemBuilder, UpdateJobItemBuilder};
+use crate::services::storage::types::{JobItemKind, JobItemStatus};
+use crate::services::storage::ExecOptsBuilder;
+
+pub struct UndoParams {
+    pub job_id: Uuid,
+    pub principal: String,
+    /// The ID of each volunteer to remove, paired with their Workspace email
+    pub volunteers: Vec<(Uuid, String)>,
+    pub cancellation: CancellationToken,
+}
+
+/// Record every volunteer being removed as a pending job item.
+///
+/// Returns the IDs of volunteers which were already removed by an earlier attempt at the job.
+async fn record_job_items<DB: Database>(
+    services: &ExportServices<DB>,
+    params: &UndoParams,
+) -> Result<Vec<Uuid>> {
+    let items = params
+        .volunteers
+        .iter()
+        .map(|(volunteer_id, workspace_email)| {
+            CreateJobItemBuilder::default()
+                .kind(JobItemKind::Volunteer)
+                .item_key(volunteer_id.to_string())
+                .entity_id(*volunteer_id)
+                .label(workspace_email.clone())
+                .build()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+    services.storage_layer.batch_create_job_items(params.job_id, items, &mut exec_opts).await?;
+
+    let already_removed = services
+        .storage_layer
+        .fetch_job_items(params.job_id, &mut exec_opts)
+        .await?
+        .into_iter()
+        .filter(|item| item.status == JobItemStatus::Succeeded)
+        .filter_map(|item| item.entity_id)
+        .collect();
+
+    Ok(already_removed)
+}
+
+/// Delete a volunteer's Workspace account and forget that they were exported.
+async fn remove_volunteer<DB: Database>(
+    services: &ExportServices<DB>,
+    principal: &str,
+    volunteer_id: Uuid,
+    workspace_email: &str,
+) -> Result<()> {
+    services.workspace.delete_user(principal, workspace_email).await?;
+    services
+        .storage_layer
+        .batch_remove_volunteers_exported_to_workspace(
+            vec![volunteer_id],
+            &mut ExecOptsBuilder::default().build()?,
+        )
+        .await?;
+    Ok(())
+}
+
+pub async fn undo_task<DB: Database>(
+    services: &ExportServices<DB>,
+    mut params: UndoParams,
+) -> Result<()> {
+    // A job which is re-run after its worker died must not try to remove anyone twice
+    let already_removed = record_job_items(services, &params).await?;
+    params.volunteers.retain(|(volunteer_id, _)| !already_removed.contains(volunteer_id));
+
+    let mut removed_count = already_removed.len();
+    let mut failed_count = 0usize;
+
+    for (volunteer_id, workspace_email) in &params.volunteers {
+        let outcome = if params.cancellation.is_cancelled() {
+            UpdateJobItemBuilder::default()
+                .status(JobItemStatus::Skipped)
+                .error("Skipped because the job was cancelled".to_owned())
+                .build()?
+        } else {
+            match remove_volunteer(services, &params.principal, *volunteer_id, workspace_email)
+                .await
+            {
+                Ok(_) => {
+                    log::info!("Removed {workspace_email} from workspace");
+                    removed_count += 1;
+                    UpdateJobItemBuilder::default().status(JobItemStatus::Succeeded).build()?
+                }
+                Err(e) => {
+                    log::error!("Failed to remove {workspace_email} from workspace: {e}");
+                    failed_count += 1;
+                    UpdateJobItemBuilder::default()
+                        .status(JobItemStatus::Failed)
+                        .error(e.to_string())
+                        .build()?
+                }
+            }
+        };
+
+        services
+            .storage_layer
+            .update_job_item(
+                params.job_id,
+                JobItemKind::Volunteer,
+                &volunteer_id.to_string(),
+                outcome,
+                &mut ExecOptsBuilder::default().build()?,
+            )
+            .await?;
+
+        services.job_events.publish(params.job_id);
+    }
+
+    let total = params.volunteers.len() + already_removed.len();
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+
+    if params.cancellation.is_cancelled() {
+        let summary = format!(
+            "Cancelled after removing {removed_count} out of {total} volunteers from workspace"
+        );
+        services.storage_layer.mark_job_cancelled(params.job_id, summary, &mut exec_opts).await?;
+    } else if failed_count > 0 && removed_count == 0 {
+        let error = format!("Failed to remove any of the {total} volunteers from workspace");
+        services.storage_layer.mark_job_errored(params.job_id, error, &mut exec_opts).await?;
+    } else {
+        services.storage_layer.mark_job_complete(params.job_id, &mut exec_opts).await?;
+    }
+
+    Ok(())
+}
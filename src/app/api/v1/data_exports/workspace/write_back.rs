@@ -0,0 +1,127 @@
+//! Writing the Workspace emails of exported volunteers back to Airtable.
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use scipio_airtable::base_data::entities::RecordUpdate;
+use serde_json::{Map, Value};
+use sqlx::Database;
+use uuid::Uuid;
+
+use crate::app::api::v1::data_exports::ExportServices;
+use crate::services::storage::types::{
+    AirtableWriteBack, ExternalSource, WriteBackFailure, WriteBackReport,
+};
+use crate::services::storage::ExecOptsBuilder;
+
+/// The fields to write to a volunteer's Airtable record.
+fn write_back_fields(
+    write_back: &AirtableWriteBack,
+    workspace_email: &str,
+    exported_at: DateTime<Utc>,
+) -> Map<String, Value> {
+    let mut fields = Map::new();
+    fields.insert(write_back.workspace_email_field.clone(), Value::from(workspace_email));
+    if let Some(exported_at_field) = &write_back.exported_at_field {
+        fields.insert(exported_at_field.clone(), Value::from(exported_at.to_rfc3339()));
+    }
+    fields
+}
+
+/// Write the Workspace email of each exported volunteer back to the Airtable record they were
+/// imported from, and record the outcome in the job's details.
+///
+/// A volunteer whose record can't be written doesn't stop the others from being written, and is
+/// recorded as a failure instead of failing the export. So is a volunteer who wasn't imported from
+/// Airtable, whose external ID isn't the ID of an Airtable record. This only fails if the outcome
+/// can't be recorded.
+///
+/// * `services`: The services an export uses
+/// * `job_id`: The ID of the export job
+/// * `write_back`: Where to write the emails to
+/// * `exported`: The ID of each exported volunteer, paired with their Workspace email
+/// * `exported_at`: When the volunteers were exported
+pub async fn write_back_to_airtable<DB: Database>(
+    services: &ExportServices<DB>,
+    job_id: Uuid,
+    write_back: &AirtableWriteBack,
+    exported: Vec<(Uuid, String)>,
+    exported_at: DateTime<Utc>,
+) -> Result<()> {
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+
+    let volunteer_ids = exported.iter().map(|(id, _)| *id).collect::<Vec<_>>();
+    let mut report = WriteBackReport::default();
+
+    let record_ids = match services
+        .storage_layer
+        .fetch_volunteer_external_ids(volunteer_ids, ExternalSource::Airtable, &mut exec_opts)
+        .await
+    {
+        Ok(record_ids) => record_ids.into_iter().collect::<HashMap<Uuid, String>>(),
+        Err(e) => {
+            log::error!("Failed to fetch the Airtable records of exported volunteers: {e}");
+            report.failures = exported
+                .into_iter()
+                .map(|(volunteer_id, workspace_email)| WriteBackFailure {
+                    volunteer_id,
+                    workspace_email,
+                    error: "The volunteer's Airtable record could not be found".to_owned(),
+                })
+                .collect();
+            services.storage_layer.set_job_write_back(job_id, report, &mut exec_opts).await?;
+            services.job_events.publish(job_id);
+            return Ok(());
+        }
+    };
+
+    let mut records = Vec::<RecordUpdate<Map<String, Value>>>::with_capacity(exported.len());
+    let mut volunteers = HashMap::<String, (Uuid, String)>::with_capacity(exported.len());
+
+    for (volunteer_id, workspace_email) in exported {
+        let Some(record_id) = record_ids.get(&volunteer_id) else {
+            report.failures.push(WriteBackFailure {
+                volunteer_id,
+                workspace_email,
+                error: "The volunteer was not imported from Airtable".to_owned(),
+            });
+            continue;
+        };
+
+        records.push(RecordUpdate {
+            id: record_id.clone(),
+            fields: write_back_fields(write_back, &workspace_email, exported_at),
+        });
+        volunteers.insert(record_id.clone(), (volunteer_id, workspace_email));
+    }
+
+    let attempted = records.len();
+    let failures = match services
+        .airtable
+        .update_record_fields(&write_back.base_id, &write_back.table, records)
+        .await
+    {
+        Ok(failures) => failures,
+        Err(e) => volunteers.keys().map(|record_id| (record_id.clone(), e.to_string())).collect(),
+    };
+
+    report.written = (attempted - failures.len()) as u64;
+    for (record_id, error) in failures {
+        if let Some((volunteer_id, workspace_email)) = volunteers.remove(&record_id) {
+            report.failures.push(WriteBackFailure { volunteer_id, workspace_email, error });
+        }
+    }
+
+    if !report.failures.is_empty() {
+        log::error!(
+            "Failed to write {} Workspace emails back to Airtable for export job {job_id}",
+            report.failures.len()
+        );
+    }
+
+    services.storage_layer.set_job_write_back(job_id, report, &mut exec_opts).await?;
+    services.job_events.publish(job_id);
+
+    Ok(())
+}
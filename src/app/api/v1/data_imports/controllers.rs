@@ -1,22 +1,42 @@
 use std::sync::Arc;
 
 use anyhow::Result;
-use axum::extract::{Path, State};
-use axum::http::StatusCode;
+use axum::body::Bytes;
+use axum::extract::{Multipart, Path, Query, State};
+use axum::http::{HeaderMap, StatusCode};
 use axum::response::Response;
-use axum::Json;
+use axum::{Extension, Json};
 use chrono::Utc;
-use tokio::task;
+use scipio_airtable::webhooks::notifications::{
+    verify_notification, WebhookNotification, MAC_HEADER,
+};
+use serde_json::json;
+use sqlx::Database;
+use uuid::Uuid;
 
-use super::ImportServices;
-use crate::app::api::v1::data_imports::airtable::{import_task, ImportParams};
-use crate::app::api::v1::data_imports::requests::ImportAirtableBase;
-use crate::app::api::v1::data_imports::responses::AvailableBases;
+use super::import::preview_task;
+use super::sources::CsvSource;
+use super::{load_mapping, ImportServices};
+use crate::app::api::v1::data_imports::requests::{
+    CreateMappingProfileRequest, ImportAirtableBase, ImportCsv, PreviewAirtableBaseQuery,
+    RegisterAirtableWebhook, SyncAirtableBase,
+};
+use crate::app::api::v1::data_imports::responses::{
+    AirtableWebhookResponse, AirtableWebhooksResponse, AvailableBases, MappingProfileResponse,
+    MappingProfilesResponse,
+};
 use crate::app::api_response;
-use crate::app::errors::AppError;
+use crate::app::audit::Audit;
+use crate::app::errors::{is_unique_violation, AppError};
 use crate::app::state::Services;
-use crate::services::storage::jobs::CreateJobBuilder;
-use crate::services::storage::types::{JobData, JobDetails, JobType};
+use crate::services::auth::AuthData;
+use crate::services::storage::jobs::{CreateJobBuilder, JobFilterBuilder};
+use crate::services::storage::mapping_profiles::CreateMappingProfile;
+use crate::services::storage::pagination::PageOptions;
+use crate::services::storage::types::{
+    AuditAction, CsvImportFiles, JobData, JobDetails, JobStatus, JobType,
+};
+use crate::services::storage::webhooks::CreateWebhookBuilder;
 use crate::services::storage::ExecOptsBuilder;
 
 #[utoipa::path(
@@ -28,8 +48,8 @@ use crate::services::storage::ExecOptsBuilder;
         (status = 403, description = "Forbidden")
     )
 )]
-pub async fn list_available_airtable_bases(
-    State(ctx): State<Arc<Services>>,
+pub async fn list_available_airtable_bases<DB: Database>(
+    State(ctx): State<Arc<Services<DB>>>,
 ) -> Result<Response, AppError> {
     let airtable = &ctx.airtable;
 
@@ -45,53 +65,64 @@ pub async fn list_available_airtable_bases(
     request_body= ImportAirtableBase,
         responses(
             (status = 200, description = "Successfully imported a base"),
-            (status = 400, description = "Malformed request body")
+            (status = 400, description = "Malformed request body or invalid base schema"),
+            (status = 404, description = "Mapping profile not found")
         )
     )
 ]
-pub async fn import_airtable_base(
-    State(services): State<ImportServices>,
+pub async fn import_airtable_base<DB: Database>(
+    State(services): State<ImportServices<DB>>,
     Path(base_id): Path<String>,
+    Extension(auth): Extension<AuthData>,
     Json(payload): Json<ImportAirtableBase>,
 ) -> Result<Response, AppError> {
     let storage_layer = &services.storage_layer;
 
-    if !services.airtable.validate_schema(&base_id).await? {
+    let Some(mapping) = load_mapping(storage_layer, payload.mapping_profile_id).await? else {
+        return Ok(api_response::error(StatusCode::NOT_FOUND, "Mapping profile not found"));
+    };
+
+    let report = services.airtable.validate_schema(&base_id, &mapping).await?;
+    if !report.is_valid() {
         log::error!("Invalid schema for airtable base");
-        return Ok(api_response::error(
-            StatusCode::BAD_REQUEST,
-            "Invalid schema for airtable base",
-        ));
+        return Ok(api_response::error_with_data(StatusCode::BAD_REQUEST, report)?);
     }
 
     let current_time = Utc::now();
     let time_only = current_time.format("%H:%M:%S").to_string();
 
+    let mut audit = Audit::new(storage_layer, &auth, AuditAction::ImportAirtableBase)?
+        .summary(json!({ "baseId": base_id, "name": payload.name }));
+
     let data = CreateJobBuilder::default()
         .label("Import Airtable Base")
         .description(Some(format!("Import airtable base with id {base_id} ASDF")))
         .data(JobDetails {
             job_type: JobType::AirtableImportBase,
             error: None,
-            data: JobData::AirtableImportBase { base_id: base_id.clone() },
+            summary: None,
+            diff: None,
+            write_back: None,
+            data: JobData::AirtableImportBase {
+                base_id: base_id.clone(),
+                name: Some(payload.name),
+                description: Some(payload.description),
+                principal: Some(auth.email()?),
+                project_cycle_id: None,
+                mapping_profile_id: payload.mapping_profile_id,
+            },
         })
         .build()?;
 
-    let job_id =
-        storage_layer.create_job(None, data, &mut ExecOptsBuilder::default().build()?).await?;
-
-    log::info!("Started import job {job_id} @ {time_only}");
-
-    let params = ImportParams {
-        name: payload.name,
-        description: payload.description,
-        job_id,
-        base_id: base_id.clone(),
-    };
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+    let res = storage_layer.create_job(None, data, &mut exec_opts).await;
+    if let Ok(job_id) = &res {
+        audit = audit.target(*job_id);
+    }
+    audit.record(&res, &mut exec_opts).await?;
+    let job_id = res?;
 
-    task::spawn(async move {
-        let _ = import_task(&services, &params).await;
-    });
+    log::info!("Queued import job {job_id} @ {time_only}");
 
     Ok(api_response::success(
         StatusCode::OK,
@@ -100,3 +131,719 @@ pub async fn import_airtable_base(
         }),
     )?)
 }
+
+#[utoipa::path(
+    post,
+    path = "/airtable/base/{base_id}/preview",
+    responses(
+        (status = 200, description = "Successfully previewed an import of the base"),
+        (status = 400, description = "Invalid base schema"),
+        (status = 404, description = "Mapping profile not found")
+    ),
+    params(
+        ("base_id" = String, Path, description = "The ID of the Airtable base"),
+        ("mappingProfileId" = Option<Uuid>, Query, description = "The mapping profile to read the base with")
+    ),
+)]
+pub async fn preview_airtable_base<DB: Database>(
+    State(services): State<ImportServices<DB>>,
+    Path(base_id): Path<String>,
+    Query(query): Query<PreviewAirtableBaseQuery>,
+) -> Result<Response, AppError> {
+    let Some(mapping) = load_mapping(&services.storage_layer, query.mapping_profile_id).await?
+    else {
+        return Ok(api_response::error(StatusCode::NOT_FOUND, "Mapping profile not found"));
+    };
+
+    let report = services.airtable.validate_schema(&base_id, &mapping).await?;
+    if !report.is_valid() {
+        log::error!("Invalid schema for airtable base");
+        return Ok(api_response::error_with_data(StatusCode::BAD_REQUEST, report)?);
+    }
+
+    let preview = preview_task(&services, &base_id, &mapping).await?;
+
+    Ok(api_response::success(StatusCode::OK, preview)?)
+}
+
+#[utoipa::path(
+    post,
+    path = "/airtable/base/{base_id}/sync",
+    request_body = SyncAirtableBase,
+    responses(
+        (status = 200, description = "Successfully queued a sync of the base"),
+        (status = 400, description = "Malformed request body or invalid base schema"),
+        (status = 404, description = "Project cycle or mapping profile not found")
+    ),
+    params(
+        ("base_id" = String, Path, description = "The ID of the Airtable base")
+    ),
+)]
+pub async fn sync_airtable_base<DB: Database>(
+    State(services): State<ImportServices<DB>>,
+    Path(base_id): Path<String>,
+    Extension(auth): Extension<AuthData>,
+    Json(payload): Json<SyncAirtableBase>,
+) -> Result<Response, AppError> {
+    let storage_layer = &services.storage_layer;
+    let project_cycle_id = payload.project_cycle_id;
+
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+
+    let Some(cycle) = storage_layer.fetch_cycle_by_id(project_cycle_id, &mut exec_opts).await?
+    else {
+        return Ok(api_response::error(StatusCode::NOT_FOUND, "Project cycle not found"));
+    };
+
+    let Some(mapping) = load_mapping(storage_layer, payload.mapping_profile_id).await? else {
+        return Ok(api_response::error(StatusCode::NOT_FOUND, "Mapping profile not found"));
+    };
+
+    let report = services.airtable.validate_schema(&base_id, &mapping).await?;
+    if !report.is_valid() {
+        log::error!("Invalid schema for airtable base");
+        return Ok(api_response::error_with_data(StatusCode::BAD_REQUEST, report)?);
+    }
+
+    let mut audit = Audit::new(storage_layer, &auth, AuditAction::SyncAirtableBase)?
+        .target(project_cycle_id)
+        .summary(json!({ "baseId": base_id }));
+
+    let data = CreateJobBuilder::default()
+        .label("Sync Airtable Base")
+        .description(Some(format!("Sync airtable base with id {base_id} into {}", cycle.name)))
+        .data(JobDetails {
+            job_type: JobType::AirtableImportBase,
+            error: None,
+            summary: None,
+            diff: None,
+            write_back: None,
+            data: JobData::AirtableImportBase {
+                base_id: base_id.clone(),
+                name: None,
+                description: None,
+                principal: Some(auth.email()?),
+                project_cycle_id: Some(project_cycle_id),
+                mapping_profile_id: payload.mapping_profile_id,
+            },
+        })
+        .build()?;
+
+    let res = storage_layer.create_job(Some(project_cycle_id), data, &mut exec_opts).await;
+    if let Ok(job_id) = &res {
+        audit = audit.target(*job_id);
+    }
+    audit.record(&res, &mut exec_opts).await?;
+    let job_id = res?;
+
+    log::info!("Queued sync job {job_id} for project cycle {project_cycle_id}");
+
+    Ok(api_response::success(StatusCode::OK, json!({ "jobId": job_id }))?)
+}
+
+/// Fetch the Airtable mapping profiles imports can read bases with
+///
+/// * `services`: The services an import uses
+#[utoipa::path(
+    get,
+    path = "/airtable/mapping-profiles",
+    responses(
+        (status = 200, description = "Successfully fetched mapping profiles"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden")
+    )
+)]
+pub async fn fetch_mapping_profiles<DB: Database>(
+    State(services): State<ImportServices<DB>>,
+) -> Result<Response, AppError> {
+    let profiles = services
+        .storage_layer
+        .fetch_mapping_profiles(&mut ExecOptsBuilder::default().build()?)
+        .await?;
+
+    Ok(api_response::success(StatusCode::OK, MappingProfilesResponse { profiles })?)
+}
+
+/// Fetch an Airtable mapping profile
+///
+/// * `services`: The services an import uses
+/// * `id`: The ID of the profile
+#[utoipa::path(
+    get,
+    path = "/airtable/mapping-profiles/{id}",
+    responses(
+        (status = 200, description = "Successfully fetched mapping profile"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Mapping profile not found")
+    ),
+    params(
+        ("id" = Uuid, Path, description = "The ID of the mapping profile")
+    ),
+)]
+pub async fn fetch_mapping_profile<DB: Database>(
+    State(services): State<ImportServices<DB>>,
+    Path(id): Path<Uuid>,
+) -> Result<Response, AppError> {
+    let Some(profile) = services
+        .storage_layer
+        .fetch_mapping_profile_by_id(id, &mut ExecOptsBuilder::default().build()?)
+        .await?
+    else {
+        return Ok(api_response::error(StatusCode::NOT_FOUND, "Mapping profile not found"));
+    };
+
+    Ok(api_response::success(StatusCode::OK, MappingProfileResponse { profile })?)
+}
+
+/// Create an Airtable mapping profile
+///
+/// * `services`: The services an import uses
+/// * `auth`: Auth data about the user
+/// * `request`: The profile to create
+#[utoipa::path(
+    post,
+    path = "/airtable/mapping-profiles",
+    responses(
+        (status = 201, description = "Successfully created mapping profile"),
+        (status = 400, description = "Invalid mapping profile"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 409, description = "A mapping profile with the same name already exists")
+    )
+)]
+pub async fn create_mapping_profile<DB: Database>(
+    State(services): State<ImportServices<DB>>,
+    Extension(auth): Extension<AuthData>,
+    Json(request): Json<CreateMappingProfileRequest>,
+) -> Result<Response, AppError> {
+    let storage_layer = &services.storage_layer;
+
+    let name = request.name.trim();
+    if name.is_empty() {
+        return Ok(api_response::error(
+            StatusCode::BAD_REQUEST,
+            "Mapping profile name cannot be empty",
+        ));
+    }
+
+    let mut tx = storage_layer.acquire().await?;
+    let mut exec_opts = ExecOptsBuilder::default().tx(&mut tx).build()?;
+
+    let mut audit = Audit::new(storage_layer, &auth, AuditAction::CreateMappingProfile)?
+        .summary(json!({ "name": name }));
+
+    let data = CreateMappingProfile {
+        name: name.to_owned(),
+        description: request.description,
+        mapping: serde_json::to_value(request.mapping)?,
+    };
+    let res = storage_layer.create_mapping_profile(data, &mut exec_opts).await;
+    if let Ok(id) = &res {
+        audit = audit.target(*id);
+    }
+    audit.record(&res, &mut exec_opts).await?;
+
+    let id = match res {
+        Ok(id) => id,
+        Err(e) if is_unique_violation(&e) => {
+            return Ok(api_response::error(
+                StatusCode::CONFLICT,
+                "A mapping profile with the same name already exists",
+            ));
+        }
+        Err(e) => return Err(e.into()),
+    };
+
+    let profile = storage_layer
+        .fetch_mapping_profile_by_id(id, &mut exec_opts)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("mapping profile {id} not found after it was created"))?;
+
+    tx.commit().await?;
+
+    Ok(api_response::success(StatusCode::CREATED, MappingProfileResponse { profile })?)
+}
+
+/// Delete an Airtable mapping profile
+///
+/// Jobs queued with the profile which haven't run yet will fail.
+///
+/// * `services`: The services an import uses
+/// * `auth`: Auth data about the user
+/// * `id`: The ID of the profile
+#[utoipa::path(
+    delete,
+    path = "/airtable/mapping-profiles/{id}",
+    responses(
+        (status = 204, description = "Successfully deleted mapping profile"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Mapping profile not found")
+    ),
+    params(
+        ("id" = Uuid, Path, description = "The ID of the mapping profile")
+    ),
+)]
+pub async fn delete_mapping_profile<DB: Database>(
+    State(services): State<ImportServices<DB>>,
+    Extension(auth): Extension<AuthData>,
+    Path(id): Path<Uuid>,
+) -> Result<Response, AppError> {
+    let storage_layer = &services.storage_layer;
+
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+
+    if storage_layer.fetch_mapping_profile_by_id(id, &mut exec_opts).await?.is_none() {
+        return Ok(api_response::error(StatusCode::NOT_FOUND, "Mapping profile not found"));
+    }
+
+    let audit = Audit::new(storage_layer, &auth, AuditAction::DeleteMappingProfile)?.target(id);
+
+    let res = storage_layer.delete_mapping_profile(id, &mut exec_opts).await;
+    audit.record(&res, &mut exec_opts).await?;
+    res?;
+
+    Ok(api_response::no_content())
+}
+
+/// Register an Airtable webhook which syncs a base into a project cycle when the base changes
+///
+/// Airtable notifies `notificationUrl`, which should reach `/airtable/webhooks`. Reading a
+/// webhook's changes extends its life, so a webhook expires once its base goes 7 days unchanged.
+///
+/// * `services`: The services an import uses
+/// * `base_id`: The ID of the Airtable base
+/// * `auth`: Auth data about the user
+/// * `payload`: Where to sync the base, and where Airtable should send notifications
+#[utoipa::path(
+    post,
+    path = "/airtable/base/{base_id}/webhooks",
+    request_body = RegisterAirtableWebhook,
+    responses(
+        (status = 201, description = "Successfully registered a webhook"),
+        (status = 400, description = "Malformed request body or invalid base schema"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Project cycle or mapping profile not found")
+    ),
+    params(
+        ("base_id" = String, Path, description = "The ID of the Airtable base")
+    ),
+)]
+pub async fn register_airtable_webhook<DB: Database>(
+    State(services): State<ImportServices<DB>>,
+    Path(base_id): Path<String>,
+    Extension(auth): Extension<AuthData>,
+    Json(payload): Json<RegisterAirtableWebhook>,
+) -> Result<Response, AppError> {
+    let storage_layer = &services.storage_layer;
+    let project_cycle_id = payload.project_cycle_id;
+
+    if !payload.notification_url.starts_with("https://") {
+        return Ok(api_response::error(
+            StatusCode::BAD_REQUEST,
+            "Airtable only notifies https URLs",
+        ));
+    }
+
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+
+    if storage_layer.fetch_cycle_by_id(project_cycle_id, &mut exec_opts).await?.is_none() {
+        return Ok(api_response::error(StatusCode::NOT_FOUND, "Project cycle not found"));
+    }
+
+    let Some(mapping) = load_mapping(storage_layer, payload.mapping_profile_id).await? else {
+        return Ok(api_response::error(StatusCode::NOT_FOUND, "Mapping profile not found"));
+    };
+
+    let report = services.airtable.validate_schema(&base_id, &mapping).await?;
+    if !report.is_valid() {
+        log::error!("Invalid schema for airtable base");
+        return Ok(api_response::error_with_data(StatusCode::BAD_REQUEST, report)?);
+    }
+
+    let mut audit = Audit::new(storage_layer, &auth, AuditAction::CreateAirtableWebhook)?
+        .target(project_cycle_id)
+        .summary(json!({ "baseId": base_id, "notificationUrl": payload.notification_url }));
+
+    let res = async {
+        let registered =
+            services.airtable.register_webhook(&base_id, &payload.notification_url).await?;
+
+        let data = CreateWebhookBuilder::default()
+            .base_id(base_id.clone())
+            .webhook_id(registered.id.clone())
+            .mac_secret_base64(registered.mac_secret_base64)
+            .project_cycle_id(project_cycle_id)
+            .mapping_profile_id(payload.mapping_profile_id)
+            .expiration_time(registered.expiration_time)
+            .build()?;
+
+        let res = storage_layer.create_webhook(data, &mut exec_opts).await;
+        if res.is_err() {
+            // Nothing would receive the webhook's notifications
+            if let Err(e) = services.airtable.unregister_webhook(&base_id, &registered.id).await {
+                log::warn!("Error deleting webhook {} from Airtable: {e}", registered.id);
+            }
+        }
+        res
+    }
+    .await;
+    if let Ok(id) = &res {
+        audit = audit.target(*id);
+    }
+    audit.record(&res, &mut exec_opts).await?;
+    let id = res?;
+
+    let webhook = storage_layer
+        .fetch_webhook_by_id(id, &mut exec_opts)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("webhook {id} not found after it was created"))?;
+
+    Ok(api_response::success(StatusCode::CREATED, AirtableWebhookResponse { webhook })?)
+}
+
+/// Fetch the registered Airtable webhooks
+///
+/// * `services`: The services an import uses
+#[utoipa::path(
+    get,
+    path = "/airtable/webhooks",
+    responses(
+        (status = 200, description = "Successfully fetched webhooks"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden")
+    )
+)]
+pub async fn fetch_airtable_webhooks<DB: Database>(
+    State(services): State<ImportServices<DB>>,
+) -> Result<Response, AppError> {
+    let webhooks =
+        services.storage_layer.fetch_webhooks(&mut ExecOptsBuilder::default().build()?).await?;
+
+    Ok(api_response::success(StatusCode::OK, AirtableWebhooksResponse { webhooks })?)
+}
+
+/// Delete a registered Airtable webhook
+///
+/// The webhook is deleted from Airtable too. It is deleted here even if Airtable already deleted
+/// it, e.g. because it expired.
+///
+/// * `services`: The services an import uses
+/// * `auth`: Auth data about the user
+/// * `id`: The ID of the webhook
+#[utoipa::path(
+    delete,
+    path = "/airtable/webhooks/{id}",
+    responses(
+        (status = 204, description = "Successfully deleted webhook"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Webhook not found")
+    ),
+    params(
+        ("id" = Uuid, Path, description = "The ID of the webhook")
+    ),
+)]
+pub async fn delete_airtable_webhook<DB: Database>(
+    State(services): State<ImportServices<DB>>,
+    Extension(auth): Extension<AuthData>,
+    Path(id): Path<Uuid>,
+) -> Result<Response, AppError> {
+    let storage_layer = &services.storage_layer;
+
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+
+    let Some(webhook) = storage_layer.fetch_webhook_by_id(id, &mut exec_opts).await? else {
+        return Ok(api_response::error(StatusCode::NOT_FOUND, "Webhook not found"));
+    };
+
+    let audit = Audit::new(storage_layer, &auth, AuditAction::DeleteAirtableWebhook)?
+        .target(id)
+        .summary(json!({ "baseId": webhook.base_id }));
+
+    if let Err(e) =
+        services.airtable.unregister_webhook(&webhook.base_id, &webhook.webhook_id).await
+    {
+        log::warn!("Error deleting webhook {} from Airtable: {e}", webhook.webhook_id);
+    }
+
+    let res = storage_layer.delete_webhook(id, &mut exec_opts).await;
+    audit.record(&res, &mut exec_opts).await?;
+    res?;
+
+    Ok(api_response::no_content())
+}
+
+/// Receive a notification that an Airtable base changed
+///
+/// This is called by Airtable rather than a user, so it is authenticated by the signature of the
+/// notification instead of a token. The changes since the last notification are read from the
+/// webhook, and if there are any, a sync of the webhook's project cycle is queued. A sync which
+/// hasn't started yet reads the changes too, so another one isn't queued.
+///
+/// * `services`: The services an import uses
+/// * `headers`: The headers of the notification
+/// * `body`: The raw body of the notification, which the signature is computed over
+#[utoipa::path(
+    post,
+    path = "/airtable/webhooks",
+    request_body(content = String, description = "The notification, as Airtable sent it"),
+    responses(
+        (status = 200, description = "Successfully received the notification"),
+        (status = 400, description = "Malformed notification"),
+        (status = 401, description = "Missing or invalid signature, or unknown webhook")
+    ),
+    params(
+        ("X-Airtable-Content-MAC" = String, Header, description = "The signature of the notification")
+    ),
+)]
+pub async fn receive_airtable_webhook<DB: Database>(
+    State(services): State<ImportServices<DB>>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<Response, AppError> {
+    let storage_layer = &services.storage_layer;
+
+    let Some(signature) = headers.get(MAC_HEADER).and_then(|value| value.to_str().ok()) else {
+        return Ok(api_response::error(StatusCode::UNAUTHORIZED, "Missing signature"));
+    };
+
+    let Ok(notification) = serde_json::from_slice::<WebhookNotification>(&body) else {
+        return Ok(api_response::error(StatusCode::BAD_REQUEST, "Malformed notification"));
+    };
+
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+
+    // An unknown webhook is rejected like a bad signature, so that callers can't probe for IDs
+    let webhook = storage_layer
+        .fetch_webhook_by_airtable_id(notification.webhook.id, &mut exec_opts)
+        .await?
+        .filter(|webhook| webhook.base_id == notification.base.id)
+        .filter(|webhook| verify_notification(&webhook.mac_secret_base64, &body, signature));
+    let Some(webhook) = webhook else {
+        return Ok(api_response::error(StatusCode::UNAUTHORIZED, "Invalid signature"));
+    };
+
+    let changes = services
+        .airtable
+        .read_webhook_changes(&webhook.base_id, &webhook.webhook_id, webhook.cursor)
+        .await?;
+    if changes.payloads == 0 {
+        return Ok(api_response::success(StatusCode::OK, json!({ "jobId": null }))?);
+    }
+
+    let project_cycle_id = webhook.project_cycle_id;
+    let cycle = storage_layer
+        .fetch_cycle_by_id(project_cycle_id, &mut exec_opts)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("project cycle {project_cycle_id} not found"))?;
+
+    let mut tx = storage_layer.acquire().await?;
+    let mut exec_opts = ExecOptsBuilder::default().tx(&mut tx).build()?;
+
+    let filter = JobFilterBuilder::default()
+        .status(JobStatus::Pending)
+        .job_type(JobType::AirtableImportBase)
+        .project_cycle_id(project_cycle_id)
+        .build()?;
+    let pending =
+        storage_layer.fetch_jobs(filter, PageOptions::new(None, Some(1)), &mut exec_opts).await?;
+
+    let job_id = match pending.items.first() {
+        Some(job) => job.id,
+        None => {
+            let data = CreateJobBuilder::default()
+                .label("Sync Airtable Base")
+                .description(Some(format!(
+                    "Sync airtable base with id {} into {} after it changed",
+                    webhook.base_id, cycle.name
+                )))
+                .data(JobDetails {
+                    job_type: JobType::AirtableImportBase,
+                    error: None,
+                    summary: None,
+                    diff: None,
+                    write_back: None,
+                    data: JobData::AirtableImportBase {
+                        base_id: webhook.base_id.clone(),
+                        name: None,
+                        description: None,
+                        principal: None,
+                        project_cycle_id: Some(project_cycle_id),
+                        mapping_profile_id: webhook.mapping_profile_id,
+                    },
+                })
+                .build()?;
+
+            storage_layer.create_job(Some(project_cycle_id), data, &mut exec_opts).await?
+        }
+    };
+
+    storage_layer.update_webhook_cursor(webhook.id, changes.cursor, &mut exec_opts).await?;
+
+    tx.commit().await?;
+
+    log::info!(
+        "Read {} changes from webhook {}, sync job {job_id} covers them",
+        changes.payloads,
+        webhook.webhook_id
+    );
+
+    Ok(api_response::success(StatusCode::OK, json!({ "jobId": job_id }))?)
+}
+
+/// Read the fields of a CSV import request.
+///
+/// Returns why the request is malformed if it is.
+///
+/// * `multipart`: The request body
+async fn read_import_csv(mut multipart: Multipart) -> Result<ImportCsv, String> {
+    let mut request = ImportCsv::default();
+
+    while let Some(field) = multipart.next_field().await.map_err(|e| e.body_text())? {
+        let name = field.name().unwrap_or_default().to_owned();
+        let value = field.text().await.map_err(|e| e.body_text())?;
+
+        match name.as_str() {
+            "name" => request.name = Some(value),
+            "description" => request.description = Some(value),
+            "projectCycleId" => {
+                let id = value.trim().parse().map_err(|_| "Invalid project cycle ID".to_owned())?;
+                request.project_cycle_id = Some(id);
+            }
+            "volunteers" => request.volunteers = Some(value),
+            "mentors" => request.mentors = Some(value),
+            "nonprofits" => request.nonprofits = Some(value),
+            _ => {}
+        }
+    }
+
+    Ok(request)
+}
+
+/// Queue an import of CSV files
+///
+/// The headers each file must have are documented in the `sources::csv` module. Syncing into a
+/// project cycle requires every file, since records which aren't in the files are marked inactive.
+///
+/// * `services`: The services an import uses
+/// * `auth`: Auth data about the user
+/// * `multipart`: The files, and where to import them
+#[utoipa::path(
+    post,
+    path = "/csv",
+    request_body(content = ImportCsv, content_type = "multipart/form-data"),
+    responses(
+        (status = 200, description = "Successfully queued an import of the files"),
+        (status = 400, description = "Malformed request body or files, or a sync missing a file"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Project cycle not found")
+    )
+)]
+pub async fn import_csv<DB: Database>(
+    State(services): State<ImportServices<DB>>,
+    Extension(auth): Extension<AuthData>,
+    multipart: Multipart,
+) -> Result<Response, AppError> {
+    let storage_layer = &services.storage_layer;
+
+    let request = match read_import_csv(multipart).await {
+        Ok(request) => request,
+        Err(e) => return Ok(api_response::error(StatusCode::BAD_REQUEST, &e)),
+    };
+
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+
+    let cycle_name = match (request.project_cycle_id, &request.name, &request.description) {
+        (Some(id), _, _) => {
+            let Some(cycle) = storage_layer.fetch_cycle_by_id(id, &mut exec_opts).await? else {
+                return Ok(api_response::error(StatusCode::NOT_FOUND, "Project cycle not found"));
+            };
+            cycle.name
+        }
+        (None, Some(name), Some(_)) => name.clone(),
+        _ => {
+            return Ok(api_response::error(
+                StatusCode::BAD_REQUEST,
+                "A name and description, or a project cycle ID, are required",
+            ))
+        }
+    };
+
+    // A sync marks every record which isn't in the files inactive, so a file which was left out
+    // would deactivate every record of its kind
+    let is_blank = |file: &Option<String>| file.as_deref().unwrap_or_default().trim().is_empty();
+    if request.project_cycle_id.is_some()
+        && [&request.volunteers, &request.mentors, &request.nonprofits].into_iter().any(is_blank)
+    {
+        return Ok(api_response::error(
+            StatusCode::BAD_REQUEST,
+            "Syncing a project cycle requires the volunteers, mentors, and nonprofits files",
+        ));
+    }
+
+    let files = CsvImportFiles {
+        volunteers: request.volunteers.unwrap_or_default(),
+        mentors: request.mentors.unwrap_or_default(),
+        nonprofits: request.nonprofits.unwrap_or_default(),
+    };
+
+    // The files are read here as well as by the job, so that malformed files are rejected before
+    // anything is queued
+    let (volunteers, mentors, nonprofits) = match CsvSource::read(&files) {
+        Ok(source) => source.counts(),
+        Err(e) => return Ok(api_response::error(StatusCode::BAD_REQUEST, &format!("{e:#}"))),
+    };
+
+    // The files hold personal information, so they are stored apart from the job's details. They
+    // are stored in the same transaction as the job, so that no upload is left without a job.
+    let mut tx = storage_layer.acquire().await?;
+    let mut exec_opts = ExecOptsBuilder::default().tx(&mut tx).build()?;
+
+    let upload_id = storage_layer.create_csv_upload(files, &mut exec_opts).await?;
+
+    let mut audit = Audit::new(storage_layer, &auth, AuditAction::ImportCsv)?.summary(json!({
+        "name": cycle_name,
+        "volunteers": volunteers,
+        "mentors": mentors,
+        "nonprofits": nonprofits,
+    }));
+    if let Some(project_cycle_id) = request.project_cycle_id {
+        audit = audit.target(project_cycle_id);
+    }
+
+    let data = CreateJobBuilder::default()
+        .label("Import CSV Files")
+        .description(Some(format!("Import CSV files into {cycle_name}")))
+        .data(JobDetails {
+            job_type: JobType::CsvImport,
+            error: None,
+            summary: None,
+            diff: None,
+            write_back: None,
+            data: JobData::CsvImport {
+                upload_id,
+                name: request.name,
+                description: request.description,
+                principal: Some(auth.email()?),
+                project_cycle_id: request.project_cycle_id,
+            },
+        })
+        .build()?;
+
+    let res = storage_layer.create_job(request.project_cycle_id, data, &mut exec_opts).await;
+    if let Ok(job_id) = &res {
+        audit = audit.target(*job_id);
+    }
+    audit.record(&res, &mut exec_opts).await?;
+    let job_id = res?;
+
+    tx.commit().await?;
+
+    log::info!("Queued CSV import job {job_id}");
+
+    Ok(api_response::success(StatusCode::OK, json!({ "jobId": job_id }))?)
+}
@@ -0,0 +1,487 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use anyhow::{bail, Result};
+use sqlx::Database;
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+use super::responses::{DroppedLinkage, DuplicateEmail, ImportPreview, LinkageKind};
+use super::sources::ImportSource;
+use super::ImportServices;
+use crate::services::airtable::entities::{Mentor, MentorMenteeLinkage, Volunteer};
+use crate::services::airtable::mapping::BaseMapping;
+use crate::services::storage::cycles::CreateCycleBuilder;
+use crate::services::storage::jobs::CreateJobItemBuilder;
+use crate::services::storage::mentors::CreateMentor;
+use crate::services::storage::nonprofits::CreateNonprofit;
+use crate::services::storage::types::{
+    ExternalSource, JobItemKind, JobItemStatus, SyncCounts, SyncDiff, SyncOutcome,
+};
+use crate::services::storage::volunteers::CreateVolunteer;
+use crate::services::storage::ExecOptsBuilder;
+
+/// Where an import stores the data it reads from its source.
+pub enum ImportTarget {
+    /// A new project cycle, created by the import
+    NewCycle { name: String, description: String },
+    /// An existing project cycle, which is synced with the source
+    ExistingCycle(Uuid),
+}
+
+pub struct ImportParams {
+    pub target: ImportTarget,
+    pub job_id: Uuid,
+    pub cancellation: CancellationToken,
+}
+
+/// Data read from an import source, along with the kind of source it was read from. Volunteers,
+/// mentors, and nonprofits are keyed by the ID of their record in the source. Linkages refer to
+/// volunteers and mentors by email, and to nonprofits by organization name.
+#[derive(Debug)]
+struct ImportBaseData {
+    source: ExternalSource,
+    nonprofits: Vec<(String, CreateNonprofit)>,
+    volunteers: Vec<(String, CreateVolunteer)>,
+    volunteer_nonprofit_linkage: Vec<(String, String)>,
+    mentors: Vec<(String, CreateMentor)>,
+    mentor_nonprofit_linkage: Vec<(String, String)>,
+    mentor_mentee_linkage: Vec<(String, String)>,
+}
+
+/// Pair each volunteer's email with the organization name of each nonprofit they work with.
+fn volunteer_nonprofit_linkage(volunteers: &[Volunteer]) -> Vec<(String, String)> {
+    volunteers
+        .iter()
+        .flat_map(|volunteer| {
+            volunteer.org_name.iter().map(|org_name| (volunteer.email.clone(), org_name.clone()))
+        })
+        .collect()
+}
+
+/// Pair each team mentor's email with the organization name of each nonprofit they mentor.
+fn mentor_nonprofit_linkage(mentors: &[Mentor]) -> Vec<(String, String)> {
+    mentors
+        .iter()
+        .filter_map(|mentor| {
+            if mentor.project_roles.contains(&"Team Mentor".to_owned()) {
+                Some(
+                    mentor.org_name.iter().map(|org_name| (mentor.email.clone(), org_name.clone())),
+                )
+            } else {
+                None
+            }
+        })
+        .flatten()
+        .collect()
+}
+
+/// Pair each mentor's email with the email of each of their mentees.
+fn mentor_mentee_linkage(linkages: &[MentorMenteeLinkage]) -> Vec<(String, String)> {
+    linkages
+        .iter()
+        .flat_map(|linkage| {
+            linkage
+                .mentee_email
+                .iter()
+                .map(|mentee_email| (linkage.mentor_email.clone(), mentee_email.clone()))
+        })
+        .collect()
+}
+
+/// Find emails shared by more than one record.
+///
+/// * `kind`: The kind of record
+/// * `records`: The record ID and email of each record
+fn duplicate_emails<'a>(
+    kind: JobItemKind,
+    records: impl Iterator<Item = (&'a str, &'a str)>,
+) -> Vec<DuplicateEmail> {
+    let mut record_ids = BTreeMap::<&str, Vec<String>>::new();
+    for (record_id, email) in records {
+        record_ids.entry(email).or_default().push(record_id.to_owned());
+    }
+
+    record_ids
+        .into_iter()
+        .filter(|(_, record_ids)| record_ids.len() > 1)
+        .map(|(email, record_ids)| DuplicateEmail { kind, email: email.to_owned(), record_ids })
+        .collect()
+}
+
+/// Count how many records were synced with each outcome.
+///
+/// * `synced`: The records that were synced
+/// * `deactivated`: How many records were marked inactive
+fn count_synced(synced: &[(String, Uuid, SyncOutcome)], deactivated: u64) -> SyncCounts {
+    let mut counts = SyncCounts { deactivated, ..Default::default() };
+
+    for (_, _, outcome) in synced {
+        match outcome {
+            SyncOutcome::Created => counts.created += 1,
+            SyncOutcome::Updated => counts.updated += 1,
+            SyncOutcome::Unchanged => counts.unchanged += 1,
+            SyncOutcome::Conflict => counts.failed += 1,
+        }
+    }
+
+    counts
+}
+
+/// Store the data read from a source, creating or updating records in the target project cycle.
+///
+/// Records which are in the project cycle but are no longer in the source are marked inactive, and
+/// what changed is recorded in the job's details.
+async fn store_base_data<DB: Database>(
+    services: &ImportServices<DB>,
+    data: ImportBaseData,
+    params: &ImportParams,
+) -> Result<()> {
+    let mut tx = services.storage_layer.acquire().await?;
+    let mut exec_opts = ExecOptsBuilder::default().tx(&mut tx).build()?;
+
+    let project_cycle_id = match &params.target {
+        ImportTarget::NewCycle { name, description } => {
+            services
+                .storage_layer
+                .create_cycle(
+                    CreateCycleBuilder::default()
+                        .name(name.clone())
+                        .description(description.clone())
+                        .build()?,
+                    &mut exec_opts,
+                )
+                .await?
+        }
+        ImportTarget::ExistingCycle(id) => {
+            if services.storage_layer.fetch_cycle_by_id(*id, &mut exec_opts).await?.is_none() {
+                bail!("project cycle {id} does not exist");
+            }
+            *id
+        }
+    };
+
+    // Linkages refer to records by email or organization name, but records are synced by the ID
+    // of their record in the source
+    let nonprofit_keys = data
+        .nonprofits
+        .iter()
+        .map(|(record_id, n)| (record_id.clone(), n.org_name.clone()))
+        .collect::<HashMap<_, _>>();
+    let volunteer_keys = data
+        .volunteers
+        .iter()
+        .map(|(record_id, v)| (record_id.clone(), v.email.clone()))
+        .collect::<HashMap<_, _>>();
+    let mentor_keys = data
+        .mentors
+        .iter()
+        .map(|(record_id, m)| (record_id.clone(), m.email.clone()))
+        .collect::<HashMap<_, _>>();
+
+    let nonprofits = services
+        .storage_layer
+        .batch_sync_nonprofits(project_cycle_id, data.nonprofits, &mut exec_opts)
+        .await?;
+
+    let volunteers = services
+        .storage_layer
+        .batch_sync_volunteers(project_cycle_id, data.source, data.volunteers, &mut exec_opts)
+        .await?;
+
+    let mentors = services
+        .storage_layer
+        .batch_sync_mentors(project_cycle_id, data.mentors, &mut exec_opts)
+        .await?;
+
+    let items = [
+        (JobItemKind::Nonprofit, &nonprofits, &nonprofit_keys),
+        (JobItemKind::Volunteer, &volunteers, &volunteer_keys),
+        (JobItemKind::Mentor, &mentors, &mentor_keys),
+    ]
+    .into_iter()
+    .flat_map(|(kind, synced, keys)| {
+        synced.iter().map(move |(record_id, id, outcome)| {
+            let key = keys.get(record_id).cloned().unwrap_or_else(|| record_id.clone());
+            let mut item = CreateJobItemBuilder::default();
+            item.kind(kind).item_key(key.clone()).label(key);
+
+            match outcome {
+                SyncOutcome::Created | SyncOutcome::Updated => {
+                    item.entity_id(*id).status(JobItemStatus::Succeeded)
+                }
+                SyncOutcome::Unchanged => item.entity_id(*id).status(JobItemStatus::Skipped),
+                // The ID is the other record's, which is not the record the item is about
+                SyncOutcome::Conflict => item
+                    .status(JobItemStatus::Failed)
+                    .error(format!("volunteer {id} already has the same email")),
+            };
+
+            item.build()
+        })
+    })
+    .collect::<Result<Vec<_>, _>>()?;
+
+    services.storage_layer.batch_create_job_items(params.job_id, items, &mut exec_opts).await?;
+
+    // Records which conflict with another record were not synced, so they are left out of what is
+    // kept active and linked
+    let ids = |synced: &[(String, Uuid, SyncOutcome)]| {
+        synced
+            .iter()
+            .filter(|(_, _, outcome)| *outcome != SyncOutcome::Conflict)
+            .map(|(_, id, _)| *id)
+            .collect::<Vec<_>>()
+    };
+
+    let deactivated_nonprofits = services
+        .storage_layer
+        .deactivate_missing_nonprofits(project_cycle_id, ids(&nonprofits), &mut exec_opts)
+        .await?;
+
+    let deactivated_volunteers = services
+        .storage_layer
+        .deactivate_missing_volunteers(project_cycle_id, ids(&volunteers), &mut exec_opts)
+        .await?;
+
+    let deactivated_mentors = services
+        .storage_layer
+        .deactivate_missing_mentors(project_cycle_id, ids(&mentors), &mut exec_opts)
+        .await?;
+
+    let mut diff = SyncDiff {
+        volunteers: count_synced(&volunteers, deactivated_volunteers),
+        mentors: count_synced(&mentors, deactivated_mentors),
+        nonprofits: count_synced(&nonprofits, deactivated_nonprofits),
+        ..Default::default()
+    };
+
+    let key_id_map = |synced: Vec<(String, Uuid, SyncOutcome)>, keys: &HashMap<String, String>| {
+        synced
+            .into_iter()
+            .filter(|(_, _, outcome)| *outcome != SyncOutcome::Conflict)
+            .filter_map(|(record_id, id, _)| Some((keys.get(&record_id)?.clone(), id)))
+            .collect::<HashMap<String, Uuid>>()
+    };
+
+    let nonprofit_name_id_map = key_id_map(nonprofits, &nonprofit_keys);
+    let volunteer_email_id_map = key_id_map(volunteers, &volunteer_keys);
+    let mentor_email_id_map = key_id_map(mentors, &mentor_keys);
+    let volunteer_nonprofit_linkage = data
+        .volunteer_nonprofit_linkage
+        .iter()
+        .filter_map(|(email, org_name)| {
+            let volunteer_id = volunteer_email_id_map.get(email)?;
+            let nonprofit_id = nonprofit_name_id_map.get(org_name)?;
+            Some((*volunteer_id, *nonprofit_id))
+        })
+        .collect::<Vec<(Uuid, Uuid)>>();
+
+    let mentor_nonprofit_linkage = data
+        .mentor_nonprofit_linkage
+        .iter()
+        .filter_map(|(email, org_name)| {
+            let mentor_id = mentor_email_id_map.get(email)?;
+            let nonprofit_id = nonprofit_name_id_map.get(org_name)?;
+            Some((*mentor_id, *nonprofit_id))
+        })
+        .collect::<Vec<(Uuid, Uuid)>>();
+
+    let volunteer_mentee_linkage = data
+        .mentor_mentee_linkage
+        .iter()
+        .filter_map(|(mentor_email, mentee_email)| {
+            let mentor_id = mentor_email_id_map.get(mentor_email);
+            let mentee_id = volunteer_email_id_map.get(mentee_email);
+            Some((*(mentee_id?), *(mentor_id?)))
+        })
+        .collect::<Vec<(Uuid, Uuid)>>();
+
+    for (added, removed) in [
+        services
+            .storage_layer
+            .sync_volunteer_nonprofit_links(
+                project_cycle_id,
+                volunteer_nonprofit_linkage,
+                &mut exec_opts,
+            )
+            .await?,
+        services
+            .storage_layer
+            .sync_mentor_nonprofit_links(project_cycle_id, mentor_nonprofit_linkage, &mut exec_opts)
+            .await?,
+        services
+            .storage_layer
+            .sync_volunteer_mentor_links(project_cycle_id, volunteer_mentee_linkage, &mut exec_opts)
+            .await?,
+    ] {
+        diff.links_added += added;
+        diff.links_removed += removed;
+    }
+
+    services.storage_layer.set_job_diff(params.job_id, diff, &mut exec_opts).await?;
+
+    services
+        .storage_layer
+        .set_job_project_cycle(params.job_id, project_cycle_id, &mut exec_opts)
+        .await?;
+
+    tx.commit().await?;
+
+    services.job_events.publish(params.job_id);
+
+    Ok(())
+}
+
+/// Read everything from a source and store it in the import's target project cycle.
+///
+/// * `services`: The services an import uses
+/// * `source`: The source to read from
+/// * `params`: The job running the import, and where to store what it reads
+pub async fn import_task<DB: Database>(
+    services: &ImportServices<DB>,
+    source: &dyn ImportSource,
+    params: &ImportParams,
+) -> Result<()> {
+    let volunteer_records = source.volunteers().await?;
+
+    let volunteer_nonprofit_linkage = volunteer_nonprofit_linkage(&volunteer_records);
+
+    let volunteers = volunteer_records
+        .into_iter()
+        .map(|volunteer| (volunteer.record_id.clone(), CreateVolunteer::from(volunteer)))
+        .collect::<Vec<_>>();
+
+    let mentor_records = source.mentors().await?;
+
+    let mentor_nonprofit_linkage = mentor_nonprofit_linkage(&mentor_records);
+
+    let mentors = mentor_records
+        .into_iter()
+        .map(|mentor| (mentor.record_id.clone(), CreateMentor::from(mentor)))
+        .collect::<Vec<_>>();
+
+    let nonprofits = source
+        .nonprofits()
+        .await?
+        .into_iter()
+        .map(|nonprofit| (nonprofit.record_id.clone(), CreateNonprofit::from(nonprofit)))
+        .collect::<Vec<_>>();
+
+    let mentor_mentee_linkage = mentor_mentee_linkage(&source.mentor_mentee_linkages().await?);
+
+    // Everything is stored in a single transaction, so this is the last point the import can stop
+    if params.cancellation.is_cancelled() {
+        log::info!("Import job {} was cancelled", params.job_id);
+        services
+            .storage_layer
+            .mark_job_cancelled(
+                params.job_id,
+                "Cancelled before any data was imported".to_owned(),
+                &mut ExecOptsBuilder::default().build()?,
+            )
+            .await?;
+        return Ok(());
+    }
+
+    let data = ImportBaseData {
+        source: source.kind(),
+        nonprofits,
+        volunteers,
+        volunteer_nonprofit_linkage,
+        mentors,
+        mentor_mentee_linkage,
+        mentor_nonprofit_linkage,
+    };
+
+    match store_base_data(services, data, params).await {
+        Ok(_) => {
+            services
+                .storage_layer
+                .mark_job_complete(params.job_id, &mut ExecOptsBuilder::default().build()?)
+                .await?
+        }
+        Err(e) => {
+            services
+                .storage_layer
+                .mark_job_errored(
+                    params.job_id,
+                    e.to_string(),
+                    &mut ExecOptsBuilder::default().build()?,
+                )
+                .await?;
+        }
+    };
+
+    Ok(())
+}
+
+/// Work out what importing a base would do, without writing anything to storage.
+///
+/// * `services`: The services an import uses
+/// * `base_id`: The ID of the base
+/// * `mapping`: How the base is laid out
+pub async fn preview_task<DB: Database>(
+    services: &ImportServices<DB>,
+    base_id: &str,
+    mapping: &BaseMapping,
+) -> Result<ImportPreview> {
+    let records = services.airtable.read_base(base_id, mapping).await?;
+
+    let volunteer_emails =
+        records.volunteers.iter().map(|v| v.email.as_str()).collect::<HashSet<_>>();
+    let mentor_emails = records.mentors.iter().map(|m| m.email.as_str()).collect::<HashSet<_>>();
+    let org_names = records.nonprofits.iter().map(|n| n.org_name.as_str()).collect::<HashSet<_>>();
+
+    let mut dropped_linkages = Vec::<DroppedLinkage>::new();
+
+    for (kind, linkage) in [
+        (LinkageKind::VolunteerNonprofit, volunteer_nonprofit_linkage(&records.volunteers)),
+        (LinkageKind::MentorNonprofit, mentor_nonprofit_linkage(&records.mentors)),
+    ] {
+        dropped_linkages.extend(
+            linkage.into_iter().filter(|(_, org_name)| !org_names.contains(org_name.as_str())).map(
+                |(email, org_name)| DroppedLinkage {
+                    kind,
+                    unresolved: org_name.clone(),
+                    from: email,
+                    to: org_name,
+                },
+            ),
+        );
+    }
+
+    for (mentor_email, mentee_email) in mentor_mentee_linkage(&records.mentor_mentee_linkages) {
+        let unresolved = if !mentor_emails.contains(mentor_email.as_str()) {
+            mentor_email.clone()
+        } else if !volunteer_emails.contains(mentee_email.as_str()) {
+            mentee_email.clone()
+        } else {
+            continue;
+        };
+
+        dropped_linkages.push(DroppedLinkage {
+            kind: LinkageKind::MentorMentee,
+            from: mentor_email,
+            to: mentee_email,
+            unresolved,
+        });
+    }
+
+    let mut duplicates = duplicate_emails(
+        JobItemKind::Volunteer,
+        records.volunteers.iter().map(|v| (v.record_id.as_str(), v.email.as_str())),
+    );
+    duplicates.extend(duplicate_emails(
+        JobItemKind::Mentor,
+        records.mentors.iter().map(|m| (m.record_id.as_str(), m.email.as_str())),
+    ));
+
+    Ok(ImportPreview {
+        volunteers: records.volunteers.len(),
+        mentors: records.mentors.len(),
+        nonprofits: records.nonprofits.len(),
+        dropped_linkages,
+        duplicate_emails: duplicates,
+        malformed_records: records.malformed,
+    })
+}
@@ -1,48 +1,215 @@
-mod airtable;
 mod controllers;
+mod import;
 mod requests;
 mod responses;
+mod sources;
+#[cfg(test)]
+mod tests;
 
 use std::sync::Arc;
 
+use anyhow::{bail, Result};
 use axum::extract::FromRef;
 use axum::middleware::from_fn_with_state;
 use axum::{routing, Router};
-use requests::ImportAirtableBase;
+use import::{import_task, ImportParams, ImportTarget};
+use requests::{ImportAirtableBase, ImportCsv, RegisterAirtableWebhook, SyncAirtableBase};
+use sources::{AirtableSource, CsvSource, ImportSource};
+use sqlx::{Database, Postgres};
+use tokio_util::sync::CancellationToken;
 use utoipa::OpenApi;
+use uuid::Uuid;
 
 use crate::app::api::middleware::make_rbac;
 use crate::app::state::Services;
+use crate::services::airtable::mapping::BaseMapping;
+use crate::services::storage::types::JobData;
+use crate::services::storage::{ExecOptsBuilder, StorageService};
 
 #[derive(OpenApi)]
 #[openapi(
-    paths(controllers::import_airtable_base, controllers::list_available_airtable_bases),
-    components(schemas(ImportAirtableBase))
+    paths(
+        controllers::import_airtable_base,
+        controllers::sync_airtable_base,
+        controllers::preview_airtable_base,
+        controllers::list_available_airtable_bases,
+        controllers::fetch_mapping_profiles,
+        controllers::fetch_mapping_profile,
+        controllers::create_mapping_profile,
+        controllers::delete_mapping_profile,
+        controllers::register_airtable_webhook,
+        controllers::fetch_airtable_webhooks,
+        controllers::delete_airtable_webhook,
+        controllers::receive_airtable_webhook,
+        controllers::import_csv
+    ),
+    components(schemas(ImportAirtableBase, SyncAirtableBase, RegisterAirtableWebhook, ImportCsv))
 )]
 pub struct DataImportsApi;
 
-pub struct ImportServices {
-    pub storage_layer: Arc<dyn crate::services::storage::StorageService>,
+pub struct ImportServices<DB: Database = Postgres> {
+    pub storage_layer: Arc<dyn crate::services::storage::StorageService<DB>>,
     pub airtable: Arc<dyn crate::services::airtable::AirtableService>,
+    pub job_events: Arc<crate::app::events::JobEvents>,
 }
 
-impl FromRef<Arc<Services>> for ImportServices {
-    fn from_ref(ctx: &Arc<Services>) -> Self {
-        Self { storage_layer: ctx.storage_layer.clone(), airtable: ctx.airtable.clone() }
+impl<DB: Database> FromRef<Arc<Services<DB>>> for ImportServices<DB> {
+    fn from_ref(ctx: &Arc<Services<DB>>) -> Self {
+        Self {
+            storage_layer: ctx.storage_layer.clone(),
+            airtable: ctx.airtable.clone(),
+            job_events: ctx.job_events.clone(),
+        }
     }
 }
 
-pub async fn build(ctx: Arc<Services>) -> Router<()> {
+/// Load the mapping an import reads a base with.
+///
+/// Returns the default mapping if no profile is chosen, and `None` if the chosen profile does not
+/// exist.
+///
+/// * `storage_layer`: The storage layer to load the profile from
+/// * `mapping_profile_id`: The ID of the chosen mapping profile, if there is one
+async fn load_mapping<DB: Database>(
+    storage_layer: &Arc<dyn StorageService<DB>>,
+    mapping_profile_id: Option<Uuid>,
+) -> Result<Option<BaseMapping>> {
+    let Some(id) = mapping_profile_id else {
+        return Ok(Some(BaseMapping::default()));
+    };
+
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+    let Some(profile) = storage_layer.fetch_mapping_profile_by_id(id, &mut exec_opts).await? else {
+        return Ok(None);
+    };
+
+    Ok(Some(serde_json::from_value(profile.mapping)?))
+}
+
+/// Runs a queued import job.
+///
+/// The files uploaded for a CSV import are deleted once the job has run, whether or not it
+/// succeeded.
+///
+/// * `ctx`: The application context
+/// * `job_id`: The ID of the job
+/// * `data`: The data the job was queued with
+/// * `cancellation`: Cancelled when the job should stop
+pub(super) async fn run_job<DB: Database>(
+    ctx: &Arc<Services<DB>>,
+    job_id: Uuid,
+    data: JobData,
+    cancellation: CancellationToken,
+) -> Result<()> {
+    let upload_id = match &data {
+        JobData::CsvImport { upload_id, .. } => Some(*upload_id),
+        _ => None,
+    };
+
+    let res = run_import(ctx, job_id, data, cancellation).await;
+
+    // Uploaded files hold personal information, so they are only kept until the job has run
+    if let Some(upload_id) = upload_id {
+        let mut exec_opts = ExecOptsBuilder::default().build()?;
+        if let Err(e) = ctx.storage_layer.delete_csv_upload(upload_id, &mut exec_opts).await {
+            log::error!("Failed to delete the CSV upload {upload_id} of job {job_id}: {e}");
+        }
+    }
+
+    res
+}
+
+/// Read an import job's source and store what it reads.
+///
+/// * `ctx`: The application context
+/// * `job_id`: The ID of the job
+/// * `data`: The data the job was queued with
+/// * `cancellation`: Cancelled when the job should stop
+async fn run_import<DB: Database>(
+    ctx: &Arc<Services<DB>>,
+    job_id: Uuid,
+    data: JobData,
+    cancellation: CancellationToken,
+) -> Result<()> {
+    let (source, name, description, project_cycle_id): (Box<dyn ImportSource>, _, _, _) = match data
+    {
+        JobData::AirtableImportBase {
+            base_id,
+            name,
+            description,
+            project_cycle_id,
+            mapping_profile_id,
+            ..
+        } => {
+            let Some(mapping) = load_mapping(&ctx.storage_layer, mapping_profile_id).await? else {
+                bail!("job {job_id} reads the base with a mapping profile which does not exist");
+            };
+
+            let source = AirtableSource { airtable: ctx.airtable.clone(), base_id, mapping };
+            (Box::new(source), name, description, project_cycle_id)
+        }
+        JobData::CsvImport { upload_id, name, description, project_cycle_id, .. } => {
+            let mut exec_opts = ExecOptsBuilder::default().build()?;
+            let Some(files) = ctx.storage_layer.fetch_csv_upload(upload_id, &mut exec_opts).await?
+            else {
+                bail!("job {job_id} imports CSV files which no longer exist");
+            };
+
+            (Box::new(CsvSource::read(&files)?), name, description, project_cycle_id)
+        }
+        _ => bail!("job {job_id} is missing the parameters needed to run an import"),
+    };
+
+    let target = match (project_cycle_id, name, description) {
+        (Some(project_cycle_id), _, _) => ImportTarget::ExistingCycle(project_cycle_id),
+        (None, Some(name), Some(description)) => ImportTarget::NewCycle { name, description },
+        _ => bail!("job {job_id} is missing the parameters needed to run an import"),
+    };
+
+    let services = ImportServices::from_ref(ctx);
+    let params = ImportParams { target, job_id, cancellation };
+
+    import_task(&services, source.as_ref(), &params).await
+}
+
+pub async fn build<DB: Database>(ctx: Arc<Services<DB>>) -> Router<()> {
     let read_guard = make_rbac(vec!["read:available-bases".to_owned()]).await;
-    // let import_guard = make_rbac(vec!["import:available-bases".to_owned()]).await;
+    let import_guard = make_rbac(vec!["import:available-bases".to_owned()]).await;
+
+    let import_airtable_base = routing::post(controllers::import_airtable_base::<DB>);
+    let sync_airtable_base = routing::post(controllers::sync_airtable_base::<DB>);
+    let preview_airtable_base = routing::post(controllers::preview_airtable_base::<DB>);
+    let list_available_airtable_bases =
+        routing::get(controllers::list_available_airtable_bases::<DB>);
+    let fetch_mapping_profiles = routing::get(controllers::fetch_mapping_profiles::<DB>);
+    let fetch_mapping_profile = routing::get(controllers::fetch_mapping_profile::<DB>);
+    let create_mapping_profile = routing::post(controllers::create_mapping_profile::<DB>);
+    let delete_mapping_profile = routing::delete(controllers::delete_mapping_profile::<DB>);
+    let register_airtable_webhook = routing::post(controllers::register_airtable_webhook::<DB>);
+    let fetch_airtable_webhooks = routing::get(controllers::fetch_airtable_webhooks::<DB>);
+    let delete_airtable_webhook = routing::delete(controllers::delete_airtable_webhook::<DB>);
+    let receive_airtable_webhook = routing::post(controllers::receive_airtable_webhook::<DB>);
+    let import_csv = routing::post(controllers::import_csv::<DB>);
 
-    let import_airtable_base = routing::post(controllers::import_airtable_base);
-    let list_available_airtable_bases = routing::get(controllers::list_available_airtable_bases);
+    let import_routes = Router::new()
+        .route("/airtable/mapping-profiles", create_mapping_profile)
+        .route("/airtable/mapping-profiles/:id", delete_mapping_profile)
+        .route("/airtable/base/:base_id/webhooks", register_airtable_webhook)
+        .route("/airtable/webhooks/:id", delete_airtable_webhook)
+        .route("/csv", import_csv)
+        .route_layer(from_fn_with_state(ctx.clone(), import_guard));
 
     Router::new()
         .route("/airtable/available-bases", list_available_airtable_bases)
         .route("/airtable/base/:base_id", import_airtable_base)
+        .route("/airtable/base/:base_id/sync", sync_airtable_base)
+        .route("/airtable/base/:base_id/preview", preview_airtable_base)
+        .route("/airtable/mapping-profiles", fetch_mapping_profiles)
+        .route("/airtable/mapping-profiles/:id", fetch_mapping_profile)
+        .route("/airtable/webhooks", fetch_airtable_webhooks)
         .route_layer(from_fn_with_state(ctx.clone(), read_guard))
-        // .route_layer(from_fn_with_state(ctx.clone(), import_guard))
+        .merge(import_routes)
+        // Airtable authenticates notifications by signing them, so the receiver isn't guarded
+        .route("/airtable/webhooks", receive_airtable_webhook)
         .with_state(ctx.clone())
 }
@@ -1,8 +1,91 @@
 use serde::Deserialize;
 use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::services::airtable::mapping::BaseMapping;
 
 #[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ImportAirtableBase {
     pub name: String,
     pub description: String,
+    /// The mapping profile to read the base with. The default mapping is used if it is omitted.
+    #[serde(default)]
+    pub mapping_profile_id: Option<Uuid>,
+}
+
+/// Sync an Airtable base into an existing project cycle.
+///
+/// * `project_cycle_id`: The project cycle to sync the base into
+/// * `mapping_profile_id`: The mapping profile to read the base with. The default mapping is used
+///   if it is omitted.
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncAirtableBase {
+    pub project_cycle_id: Uuid,
+    #[serde(default)]
+    pub mapping_profile_id: Option<Uuid>,
+}
+
+/// Query parameters for previewing an import of an Airtable base.
+///
+/// * `mapping_profile_id`: The mapping profile to read the base with. The default mapping is used
+///   if it is omitted.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PreviewAirtableBaseQuery {
+    pub mapping_profile_id: Option<Uuid>,
+}
+
+/// Request to create an Airtable mapping profile.
+///
+/// * `name`: The name of the profile. It must be unique.
+/// * `description`: A description of the profile
+/// * `mapping`: How to read a base with the profile
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateMappingProfileRequest {
+    pub name: String,
+    pub description: Option<String>,
+    pub mapping: BaseMapping,
+}
+
+/// Register an Airtable webhook which syncs a base into an existing project cycle when it changes.
+///
+/// * `project_cycle_id`: The project cycle to sync the base into
+/// * `mapping_profile_id`: The mapping profile to read the base with. The default mapping is used
+///   if it is omitted.
+/// * `notification_url`: The URL Airtable notifies, i.e. the public URL of
+///   `/data-imports/airtable/webhooks`. It must use https.
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RegisterAirtableWebhook {
+    pub project_cycle_id: Uuid,
+    #[serde(default)]
+    pub mapping_profile_id: Option<Uuid>,
+    pub notification_url: String,
+}
+
+/// Import CSV files, sent as `multipart/form-data`. Each file is sent as a field of its own. When
+/// creating a project cycle, a file which is omitted is read as having no rows. Syncing into a
+/// project cycle requires every file, since records which aren't in the files are marked inactive.
+///
+/// * `name`: The name of the project cycle to create
+/// * `description`: The description of the project cycle to create
+/// * `project_cycle_id`: The project cycle to sync the files into, instead of creating one
+/// * `volunteers`: The volunteers file
+/// * `mentors`: The mentors file
+/// * `nonprofits`: The nonprofits file
+#[derive(Debug, Default, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportCsv {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub project_cycle_id: Option<Uuid>,
+    #[schema(format = Binary)]
+    pub volunteers: Option<String>,
+    #[schema(format = Binary)]
+    pub mentors: Option<String>,
+    #[schema(format = Binary)]
+    pub nonprofits: Option<String>,
 }
@@ -1,7 +1,101 @@
 use scipio_airtable::base_data::entities::Base;
 use serde::{Deserialize, Serialize};
 
+use crate::services::airtable::entities::MalformedRecord;
+use crate::services::storage::entities::{AirtableWebhook, MappingProfile};
+use crate::services::storage::types::JobItemKind;
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct AvailableBases {
     pub bases: Vec<Base>,
 }
+
+/// Airtable mapping profiles returned from the API.
+///
+/// * `profiles`: The mapping profiles
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MappingProfilesResponse {
+    pub profiles: Vec<MappingProfile>,
+}
+
+/// A single Airtable mapping profile returned from the API.
+///
+/// * `profile`: The mapping profile
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MappingProfileResponse {
+    pub profile: MappingProfile,
+}
+
+/// Airtable webhooks returned from the API.
+///
+/// * `webhooks`: The webhooks
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AirtableWebhooksResponse {
+    pub webhooks: Vec<AirtableWebhook>,
+}
+
+/// A single Airtable webhook returned from the API.
+///
+/// * `webhook`: The webhook
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AirtableWebhookResponse {
+    pub webhook: AirtableWebhook,
+}
+
+/// What importing a base would do, worked out without writing anything.
+///
+/// * `volunteers`: How many volunteers would be imported
+/// * `mentors`: How many mentors would be imported
+/// * `nonprofits`: How many nonprofits would be imported
+/// * `dropped_linkages`: Linkages which would be dropped because one side doesn't resolve
+/// * `duplicate_emails`: Emails shared by more than one volunteer or mentor, which would fail the
+///   import
+/// * `malformed_records`: Records which could not be read
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportPreview {
+    pub volunteers: usize,
+    pub mentors: usize,
+    pub nonprofits: usize,
+    pub dropped_linkages: Vec<DroppedLinkage>,
+    pub duplicate_emails: Vec<DuplicateEmail>,
+    pub malformed_records: Vec<MalformedRecord>,
+}
+
+/// Kinds of linkages an import creates
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum LinkageKind {
+    VolunteerNonprofit,
+    MentorNonprofit,
+    MentorMentee,
+}
+
+/// A linkage which an import would drop.
+///
+/// * `kind`: The kind of linkage
+/// * `from`: The email of the volunteer or mentor
+/// * `to`: The organization name of the nonprofit, or the email of the mentee
+/// * `unresolved`: The side of the linkage which doesn't match any record
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DroppedLinkage {
+    pub kind: LinkageKind,
+    pub from: String,
+    pub to: String,
+    pub unresolved: String,
+}
+
+/// An email shared by more than one record. Emails must be unique, so importing the base would
+/// fail until all but one of the records is changed.
+///
+/// * `kind`: The kind of record sharing the email
+/// * `email`: The email
+/// * `record_ids`: The IDs of the Airtable records sharing the email
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DuplicateEmail {
+    pub kind: JobItemKind,
+    pub email: String,
+    pub record_ids: Vec<String>,
+}
@@ -0,0 +1,45 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+
+use super::ImportSource;
+use crate::services::airtable::entities::{Mentor, MentorMenteeLinkage, Nonprofit, Volunteer};
+use crate::services::airtable::mapping::BaseMapping;
+use crate::services::airtable::AirtableService;
+use crate::services::storage::types::ExternalSource;
+
+/// Reads an import from an Airtable base. Records are identified by the ID of their Airtable
+/// record.
+///
+/// * `airtable`: The Airtable service to read the base with
+/// * `base_id`: The ID of the base
+/// * `mapping`: How the base is laid out
+pub struct AirtableSource {
+    pub airtable: Arc<dyn AirtableService>,
+    pub base_id: String,
+    pub mapping: BaseMapping,
+}
+
+#[async_trait]
+impl ImportSource for AirtableSource {
+    fn kind(&self) -> ExternalSource {
+        ExternalSource::Airtable
+    }
+
+    async fn volunteers(&self) -> Result<Vec<Volunteer>> {
+        self.airtable.list_volunteers(&self.base_id, &self.mapping).await
+    }
+
+    async fn mentors(&self) -> Result<Vec<Mentor>> {
+        self.airtable.list_mentors(&self.base_id, &self.mapping).await
+    }
+
+    async fn nonprofits(&self) -> Result<Vec<Nonprofit>> {
+        self.airtable.list_nonprofits(&self.base_id, &self.mapping).await
+    }
+
+    async fn mentor_mentee_linkages(&self) -> Result<Vec<MentorMenteeLinkage>> {
+        self.airtable.get_mentor_mentee_linkages(&self.base_id, &self.mapping).await
+    }
+}
@@ -0,0 +1,364 @@
+//! Reads an import from CSV files, one for each kind of record.
+//!
+//! Each file starts with a row of headers. Headers may be in any order, and headers which aren't
+//! listed below are ignored. Cells which hold a list separate its items with semicolons, and cells
+//! which hold a choice take the same values as the options of the Airtable field of the same name.
+//!
+//! Volunteers are identified by `Email`, mentors by `Email`, and nonprofits by `OrgName`, so a file
+//! can't have two rows with the same one of these. Syncing files into a project cycle marks every
+//! record which isn't in them inactive, so a sync must include every file. A file with only a row
+//! of headers deactivates every record of its kind.
+//!
+//! ## Volunteers
+//!
+//! `FirstName`, `LastName`, `Email`, `Gender`, `Ethnicity` (list), `AgeRange`, `LGBT`,
+//! `Country`, `FLI` (list), `StudentStage`, `Majors`, and optionally `Phone`, `University`
+//! (list), `State`, `Minors`, `HearAbout` (list), and `OrgName` (list of the nonprofits the
+//! volunteer works with). `Majors` and `Minors` separate their items with commas.
+//!
+//! ## Mentors
+//!
+//! `FirstName`, `LastName`, `Email`, `Phone`, `Company`, `JobTitle`, `Country`,
+//! `YearsExperience`, `ExperienceLevel`, and optionally `State`, `PriorMentorship` (list),
+//! `PriorDFG` (list), `University` (list), `HearAbout` (list), `ProjectRole` (list), `OrgName`
+//! (list of the nonprofits the mentor mentors, if they are a team mentor), and `MenteeEmail`
+//! (list of the emails of the volunteers the mentor mentors).
+//!
+//! ## Nonprofits
+//!
+//! `FirstName`, `LastName`, `JobTitle`, `Email`, `Phone`, `OrgName`, `ProjectName`, `Address`,
+//! `Size`, and optionally `OrgWebsite`, `CountryHQ`, `StateHQ`, and `ImpactCauses` (list).
+
+use std::collections::HashSet;
+
+use anyhow::{bail, Context, Result};
+use async_trait::async_trait;
+use csv::{ReaderBuilder, Trim};
+use serde::de::{self, DeserializeOwned, Deserializer};
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::ImportSource;
+use crate::services::airtable::entities::{Mentor, MentorMenteeLinkage, Nonprofit, Volunteer};
+use crate::services::storage::types::{
+    AgeRange, ClientSize, CsvImportFiles, Ethnicity, ExternalSource, Fli, Gender, Lgbt,
+    MentorExperienceLevel, StudentStage, VolunteerHearAbout,
+};
+
+/// The headers the volunteers file must have.
+pub const VOLUNTEER_HEADERS: &[&str] = &[
+    "FirstName",
+    "LastName",
+    "Email",
+    "Gender",
+    "Ethnicity",
+    "AgeRange",
+    "LGBT",
+    "Country",
+    "FLI",
+    "StudentStage",
+    "Majors",
+];
+
+/// The headers the mentors file must have.
+pub const MENTOR_HEADERS: &[&str] = &[
+    "FirstName",
+    "LastName",
+    "Email",
+    "Phone",
+    "Company",
+    "JobTitle",
+    "Country",
+    "YearsExperience",
+    "ExperienceLevel",
+];
+
+/// The headers the nonprofits file must have.
+pub const NONPROFIT_HEADERS: &[&str] = &[
+    "FirstName",
+    "LastName",
+    "JobTitle",
+    "Email",
+    "Phone",
+    "OrgName",
+    "ProjectName",
+    "Address",
+    "Size",
+];
+
+/// Read a cell which holds a list. Each item is read like a cell of its own.
+fn list<'de, D: Deserializer<'de>, T: DeserializeOwned>(
+    deserializer: D,
+) -> Result<Vec<T>, D::Error> {
+    Option::<String>::deserialize(deserializer)?
+        .unwrap_or_default()
+        .split(';')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(|item| {
+            serde_json::from_value(Value::String(item.to_owned())).map_err(de::Error::custom)
+        })
+        .collect()
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct VolunteerRow {
+    first_name: String,
+    last_name: String,
+    email: String,
+    phone: Option<String>,
+    gender: Gender,
+    #[serde(deserialize_with = "list")]
+    ethnicity: Vec<Ethnicity>,
+    age_range: AgeRange,
+    #[serde(default, deserialize_with = "list")]
+    university: Vec<String>,
+    #[serde(rename = "LGBT")]
+    lgbt: Lgbt,
+    country: String,
+    state: Option<String>,
+    #[serde(rename = "FLI", deserialize_with = "list")]
+    fli: Vec<Fli>,
+    student_stage: StudentStage,
+    majors: String,
+    minors: Option<String>,
+    #[serde(default, deserialize_with = "list")]
+    hear_about: Vec<VolunteerHearAbout>,
+    #[serde(default, deserialize_with = "list")]
+    org_name: Vec<String>,
+}
+
+impl From<VolunteerRow> for Volunteer {
+    fn from(row: VolunteerRow) -> Self {
+        Volunteer {
+            record_id: row.email.clone(),
+            first_name: row.first_name,
+            last_name: row.last_name,
+            org_name: row.org_name,
+            email: row.email,
+            phone: row.phone,
+            volunteer_gender: row.gender,
+            volunteer_ethnicity: row.ethnicity,
+            volunteer_age_range: row.age_range,
+            university: row.university,
+            lgbt: row.lgbt,
+            country: row.country,
+            us_state: row.state,
+            fli: row.fli,
+            student_stage: row.student_stage,
+            majors: row.majors,
+            minors: row.minors,
+            hear_about: row.hear_about,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct MentorRow {
+    first_name: String,
+    last_name: String,
+    email: String,
+    phone: String,
+    company: String,
+    job_title: String,
+    country: String,
+    state: Option<String>,
+    years_experience: String,
+    experience_level: MentorExperienceLevel,
+    #[serde(default, deserialize_with = "list")]
+    prior_mentorship: Vec<String>,
+    #[serde(rename = "PriorDFG", default, deserialize_with = "list")]
+    prior_dfg: Vec<String>,
+    #[serde(default, deserialize_with = "list")]
+    university: Vec<String>,
+    #[serde(default, deserialize_with = "list")]
+    hear_about: Vec<VolunteerHearAbout>,
+    #[serde(default, deserialize_with = "list")]
+    project_role: Vec<String>,
+    #[serde(default, deserialize_with = "list")]
+    org_name: Vec<String>,
+    #[serde(default, deserialize_with = "list")]
+    mentee_email: Vec<String>,
+}
+
+impl From<MentorRow> for Mentor {
+    fn from(row: MentorRow) -> Self {
+        Mentor {
+            record_id: row.email.clone(),
+            first_name: row.first_name,
+            last_name: row.last_name,
+            email: row.email,
+            phone: row.phone,
+            company: row.company,
+            job_title: row.job_title,
+            org_name: row.org_name,
+            country: row.country,
+            us_state: row.state,
+            years_experience: row.years_experience,
+            experience_level: row.experience_level,
+            prior_mentorship: row.prior_mentorship,
+            prior_dfg: Some(row.prior_dfg),
+            university: Some(row.university),
+            hear_about: Some(row.hear_about).filter(|hear_about| !hear_about.is_empty()),
+            project_roles: row.project_role,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct NonprofitRow {
+    first_name: String,
+    last_name: String,
+    job_title: String,
+    email: String,
+    phone: String,
+    org_name: String,
+    project_name: String,
+    org_website: Option<String>,
+    #[serde(rename = "CountryHQ")]
+    country_hq: Option<String>,
+    #[serde(rename = "StateHQ")]
+    state_hq: Option<String>,
+    address: String,
+    size: ClientSize,
+    #[serde(default, deserialize_with = "list")]
+    impact_causes: Vec<String>,
+}
+
+impl From<NonprofitRow> for Nonprofit {
+    fn from(row: NonprofitRow) -> Self {
+        Nonprofit {
+            record_id: row.org_name.clone(),
+            representative_first_name: row.first_name,
+            representative_last_name: row.last_name,
+            representative_job_title: row.job_title,
+            email: row.email,
+            phone: row.phone,
+            org_name: row.org_name,
+            project_name: row.project_name,
+            org_website: row.org_website,
+            country_hq: row.country_hq,
+            us_state_hq: row.state_hq,
+            address: row.address,
+            size: row.size,
+            impact_causes: Some(row.impact_causes).filter(|causes| !causes.is_empty()),
+        }
+    }
+}
+
+/// Read the rows of a file, failing on the first row which can't be read.
+///
+/// * `file`: The name of the file, for errors
+/// * `contents`: The contents of the file. An empty file has no rows.
+/// * `headers`: The headers the file must have
+/// * `key`: The value which identifies the record a row is read into
+fn read_rows<R: DeserializeOwned>(
+    file: &str,
+    contents: &str,
+    headers: &[&str],
+    key: impl Fn(&R) -> &str,
+) -> Result<Vec<R>> {
+    if contents.trim().is_empty() {
+        return Ok(vec![]);
+    }
+
+    let mut reader = ReaderBuilder::new().trim(Trim::All).from_reader(contents.as_bytes());
+
+    let found = reader.headers().with_context(|| format!("error reading the {file} file"))?;
+    let missing = headers
+        .iter()
+        .filter(|header| !found.iter().any(|f| f == **header))
+        .copied()
+        .collect::<Vec<_>>();
+    if !missing.is_empty() {
+        bail!("the {file} file is missing the headers {}", missing.join(", "));
+    }
+
+    let mut keys = HashSet::new();
+    let mut rows = vec![];
+
+    for row in reader.deserialize::<R>() {
+        let row = row.with_context(|| format!("error reading the {file} file"))?;
+        if !keys.insert(key(&row).to_owned()) {
+            bail!("the {file} file has more than one row for {}", key(&row));
+        }
+        rows.push(row);
+    }
+
+    Ok(rows)
+}
+
+/// Reads an import from uploaded CSV files. The files are read when the source is created, so
+/// that malformed files can be rejected before an import is queued.
+#[derive(Debug, Default)]
+pub struct CsvSource {
+    volunteers: Vec<Volunteer>,
+    mentors: Vec<Mentor>,
+    nonprofits: Vec<Nonprofit>,
+    mentor_mentee_linkages: Vec<MentorMenteeLinkage>,
+}
+
+impl CsvSource {
+    /// Read the uploaded files.
+    ///
+    /// * `files`: The contents of the files
+    pub fn read(files: &CsvImportFiles) -> Result<Self> {
+        let volunteers =
+            read_rows::<VolunteerRow>("volunteers", &files.volunteers, VOLUNTEER_HEADERS, |v| {
+                &v.email
+            })?;
+        let mentors =
+            read_rows::<MentorRow>("mentors", &files.mentors, MENTOR_HEADERS, |m| &m.email)?;
+        let nonprofits =
+            read_rows::<NonprofitRow>("nonprofits", &files.nonprofits, NONPROFIT_HEADERS, |n| {
+                &n.org_name
+            })?;
+
+        let mentor_mentee_linkages = mentors
+            .iter()
+            .filter(|mentor| !mentor.mentee_email.is_empty())
+            .map(|mentor| MentorMenteeLinkage {
+                mentor_email: mentor.email.clone(),
+                mentee_email: mentor.mentee_email.clone(),
+            })
+            .collect();
+
+        Ok(Self {
+            volunteers: volunteers.into_iter().map(Volunteer::from).collect(),
+            mentors: mentors.into_iter().map(Mentor::from).collect(),
+            nonprofits: nonprofits.into_iter().map(Nonprofit::from).collect(),
+            mentor_mentee_linkages,
+        })
+    }
+
+    /// How many volunteers, mentors, and nonprofits were read.
+    pub fn counts(&self) -> (usize, usize, usize) {
+        (self.volunteers.len(), self.mentors.len(), self.nonprofits.len())
+    }
+}
+
+#[async_trait]
+impl ImportSource for CsvSource {
+    fn kind(&self) -> ExternalSource {
+        ExternalSource::Csv
+    }
+
+    async fn volunteers(&self) -> Result<Vec<Volunteer>> {
+        Ok(self.volunteers.clone())
+    }
+
+    async fn mentors(&self) -> Result<Vec<Mentor>> {
+        Ok(self.mentors.clone())
+    }
+
+    async fn nonprofits(&self) -> Result<Vec<Nonprofit>> {
+        Ok(self.nonprofits.clone())
+    }
+
+    async fn mentor_mentee_linkages(&self) -> Result<Vec<MentorMenteeLinkage>> {
+        Ok(self.mentor_mentee_linkages.clone())
+    }
+}
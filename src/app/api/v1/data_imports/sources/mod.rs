@@ -0,0 +1,37 @@
+//! Where an import reads the volunteers, mentors, and nonprofits of a project cycle from.
+
+mod airtable;
+pub mod csv;
+#[cfg(test)]
+mod tests;
+
+use anyhow::Result;
+use async_trait::async_trait;
+
+pub use self::airtable::AirtableSource;
+pub use self::csv::CsvSource;
+use crate::services::airtable::entities::{Mentor, MentorMenteeLinkage, Nonprofit, Volunteer};
+use crate::services::storage::types::ExternalSource;
+
+/// A source of the data an import stores in a project cycle.
+///
+/// Volunteers, mentors, and nonprofits are synced by their `record_id`, which must identify the
+/// same record each time the source is read. Linkages refer to volunteers and mentors by email,
+/// and to nonprofits by organization name.
+#[async_trait]
+pub trait ImportSource: Send + Sync {
+    /// The kind of source this is, which is recorded on the volunteers it imports.
+    fn kind(&self) -> ExternalSource;
+
+    /// Read the volunteers, along with the nonprofits each of them works with.
+    async fn volunteers(&self) -> Result<Vec<Volunteer>>;
+
+    /// Read the mentors, along with the nonprofits each team mentor mentors.
+    async fn mentors(&self) -> Result<Vec<Mentor>>;
+
+    /// Read the nonprofits.
+    async fn nonprofits(&self) -> Result<Vec<Nonprofit>>;
+
+    /// Read which volunteers each mentor mentors.
+    async fn mentor_mentee_linkages(&self) -> Result<Vec<MentorMenteeLinkage>>;
+}
@@ -0,0 +1,112 @@
+use anyhow::Result;
+
+use super::{CsvSource, ImportSource};
+use crate::services::storage::types::{
+    ClientSize, CsvImportFiles, Ethnicity, Gender, MentorExperienceLevel,
+};
+
+const VOLUNTEERS: &str = "\
+FirstName,LastName,Email,Gender,Ethnicity,AgeRange,LGBT,Country,FLI,StudentStage,Majors,OrgName,HearAbout
+Ada,Lovelace,ada@example.com,Woman,White or Caucasian; Asian,18 - 24,No,United Kingdom,,Junior,\"Math, CS\",Helping Hands,
+Alan,Turing,alan@example.com,Man,,25 - 29,Prefer not to say,United Kingdom,,Recent graduate,Math,,
+";
+
+const MENTORS: &str = "\
+FirstName,LastName,Email,Phone,Company,JobTitle,Country,YearsExperience,ExperienceLevel,ProjectRole,OrgName,MenteeEmail
+Grace,Hopper,grace@example.com,555-0100,Navy,Admiral,United States,21+,\"Senior, executive, or top-level management\",Team Mentor,Helping Hands,ada@example.com; alan@example.com
+";
+
+const NONPROFITS: &str = "\
+FirstName,LastName,JobTitle,Email,Phone,OrgName,ProjectName,Address,Size,ImpactCauses
+Jane,Doe,Director,jane@example.com,555-0101,Helping Hands,Website,1 Main St,1-5,
+";
+
+#[tokio::test]
+pub async fn test_read_csv_files() -> Result<()> {
+    let files = CsvImportFiles {
+        volunteers: VOLUNTEERS.to_owned(),
+        mentors: MENTORS.to_owned(),
+        nonprofits: NONPROFITS.to_owned(),
+    };
+    let source = CsvSource::read(&files)?;
+
+    let volunteers = source.volunteers().await?;
+    assert_eq!(volunteers.len(), 2);
+    assert_eq!(volunteers[0].record_id, "ada@example.com");
+    assert_eq!(volunteers[0].volunteer_gender, Gender::Woman);
+    assert_eq!(
+        volunteers[0].volunteer_ethnicity,
+        vec![Ethnicity::WhiteOrCaucasian, Ethnicity::Asian]
+    );
+    assert_eq!(volunteers[0].majors, "Math, CS");
+    assert_eq!(volunteers[0].org_name, vec!["Helping Hands".to_owned()]);
+    assert!(volunteers[1].org_name.is_empty());
+    assert!(volunteers[1].volunteer_ethnicity.is_empty());
+
+    let mentors = source.mentors().await?;
+    assert_eq!(mentors.len(), 1);
+    assert_eq!(mentors[0].experience_level, MentorExperienceLevel::SeniorOrExecutive);
+    assert_eq!(mentors[0].project_roles, vec!["Team Mentor".to_owned()]);
+    // An empty list is read as missing, so that it falls back like an empty Airtable field
+    assert!(mentors[0].hear_about.is_none());
+
+    let nonprofits = source.nonprofits().await?;
+    assert_eq!(nonprofits.len(), 1);
+    assert_eq!(nonprofits[0].record_id, "Helping Hands");
+    assert_eq!(nonprofits[0].size, ClientSize::S1_5);
+    assert!(nonprofits[0].impact_causes.is_none());
+
+    let linkages = source.mentor_mentee_linkages().await?;
+    assert_eq!(linkages.len(), 1);
+    assert_eq!(linkages[0].mentor_email, "grace@example.com");
+    assert_eq!(
+        linkages[0].mentee_email,
+        vec!["ada@example.com".to_owned(), "alan@example.com".to_owned()]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+pub async fn test_read_empty_csv_files() -> Result<()> {
+    let source = CsvSource::read(&CsvImportFiles::default())?;
+
+    assert!(source.volunteers().await?.is_empty());
+    assert!(source.mentors().await?.is_empty());
+    assert!(source.nonprofits().await?.is_empty());
+    assert!(source.mentor_mentee_linkages().await?.is_empty());
+
+    Ok(())
+}
+
+#[test]
+pub fn test_read_malformed_csv_files() {
+    let missing_headers = CsvImportFiles {
+        nonprofits: "OrgName,Size\nHelping Hands,1-5\n".to_owned(),
+        ..Default::default()
+    };
+    let err = CsvSource::read(&missing_headers).unwrap_err().to_string();
+    assert!(err.contains("nonprofits file is missing the headers"), "{err}");
+    assert!(err.contains("FirstName") && !err.contains("OrgName"), "{err}");
+
+    let duplicate = CsvImportFiles {
+        volunteers: VOLUNTEERS.replace("alan@example.com", "ada@example.com"),
+        ..Default::default()
+    };
+    let err = CsvSource::read(&duplicate).unwrap_err().to_string();
+    assert!(err.contains("more than one row for ada@example.com"), "{err}");
+
+    let bad_choice = CsvImportFiles {
+        volunteers: VOLUNTEERS.replace(",Man,", ",Unknown,"),
+        ..Default::default()
+    };
+    let err = format!("{:#}", CsvSource::read(&bad_choice).unwrap_err());
+    assert!(err.contains("volunteers file") && err.contains("Unknown"), "{err}");
+
+    let bad_list_item = CsvImportFiles {
+        volunteers: VOLUNTEERS.replace(",Helping Hands,", ",Helping Hands,Billboard"),
+        ..Default::default()
+    };
+    let err = format!("{:#}", CsvSource::read(&bad_list_item).unwrap_err());
+    assert!(err.contains("Billboard"), "{err}");
+}
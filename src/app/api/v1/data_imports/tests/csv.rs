@@ -0,0 +1,115 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use sqlx::{PgPool, Postgres};
+use tokio_util::sync::CancellationToken;
+use uuid::{uuid, Uuid};
+
+use super::FakeAirtableClient;
+use crate::app::api::v1::data_imports::run_job;
+use crate::app::state::{Services, ServicesBuilder};
+use crate::services::auth::noop::NoopAuthenticator;
+use crate::services::mail::noop::NoopEmailClient;
+use crate::services::storage::types::{CsvImportFiles, ExternalSource, JobData, JobItemStatus};
+use crate::services::storage::{ExecOptsBuilder, PgBackend};
+use crate::services::workspace::noop::NoopWorkspaceClient;
+
+const IMPORT_JOB_ID: Uuid = uuid!("bc080e0d-8b14-46e0-9268-4bbb370035ec");
+
+const VOLUNTEERS: &str = "\
+FirstName,LastName,Email,Gender,Ethnicity,AgeRange,LGBT,Country,FLI,StudentStage,Majors
+Ada,Lovelace,ada@example.com,Woman,White or Caucasian,18 - 24,No,United Kingdom,,Junior,Math
+";
+
+fn services(pool: PgPool) -> Result<Arc<Services<Postgres>>> {
+    Ok(Arc::new(
+        ServicesBuilder::default()
+            .authenticator(Arc::new(NoopAuthenticator))
+            .storage_layer(Arc::new(PgBackend { pool }))
+            .airtable(Arc::new(FakeAirtableClient::default()))
+            .workspace(Arc::new(NoopWorkspaceClient))
+            .mail(Arc::new(NoopEmailClient))
+            .build()?,
+    ))
+}
+
+#[sqlx::test(fixtures(path = "../../../../../services/storage/tests/fixtures", scripts("setup")))]
+pub async fn test_run_job_deletes_csv_upload(pool: PgPool) -> Result<()> {
+    let services = services(pool)?;
+    let storage_layer = &services.storage_layer;
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+
+    let files = CsvImportFiles { volunteers: VOLUNTEERS.to_owned(), ..Default::default() };
+    let upload_id = storage_layer.create_csv_upload(files, &mut exec_opts).await?;
+    let data = JobData::CsvImport {
+        upload_id,
+        name: Some("CSV".to_owned()),
+        description: Some("Imported from CSV files".to_owned()),
+        principal: None,
+        project_cycle_id: None,
+    };
+
+    run_job(&services, IMPORT_JOB_ID, data.clone(), CancellationToken::new()).await?;
+
+    assert!(storage_layer.fetch_csv_upload(upload_id, &mut exec_opts).await?.is_none());
+
+    let job = storage_layer.fetch_job(IMPORT_JOB_ID, &mut exec_opts).await?.expect("job not found");
+    let project_cycle_id = job.project_cycle_id.expect("job has no project cycle");
+    let volunteers =
+        storage_layer.fetch_volunteers_by_cycle(project_cycle_id, &mut exec_opts).await?;
+    assert_eq!(volunteers.len(), 1);
+    assert_eq!(volunteers[0].email, "ada@example.com");
+
+    // The volunteer records that it came from CSV files, so it isn't written back to Airtable
+    let id = volunteers[0].volunteer_id;
+    let external_ids = storage_layer
+        .fetch_volunteer_external_ids(vec![id], ExternalSource::Csv, &mut exec_opts)
+        .await?;
+    assert_eq!(external_ids, vec![(id, "ada@example.com".to_owned())]);
+
+    // The files are gone, so running the job again fails
+    assert!(run_job(&services, IMPORT_JOB_ID, data, CancellationToken::new()).await.is_err());
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures(path = "../../../../../services/storage/tests/fixtures", scripts("setup")))]
+pub async fn test_run_job_records_volunteers_in_other_cycles(pool: PgPool) -> Result<()> {
+    let services = services(pool)?;
+    let storage_layer = &services.storage_layer;
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+
+    // Djokovic is already a volunteer in another project cycle
+    let volunteers = format!(
+        "{VOLUNTEERS}Novak,Djokovic,novak.djokovic@gmail.com,Man,White or Caucasian,18 - 24,No,\
+         Serbia,,Junior,Math\n"
+    );
+    let files = CsvImportFiles { volunteers, ..Default::default() };
+    let upload_id = storage_layer.create_csv_upload(files, &mut exec_opts).await?;
+    let data = JobData::CsvImport {
+        upload_id,
+        name: Some("CSV".to_owned()),
+        description: Some("Imported from CSV files".to_owned()),
+        principal: None,
+        project_cycle_id: None,
+    };
+
+    run_job(&services, IMPORT_JOB_ID, data, CancellationToken::new()).await?;
+
+    let job = storage_layer.fetch_job(IMPORT_JOB_ID, &mut exec_opts).await?.expect("job not found");
+    let project_cycle_id = job.project_cycle_id.expect("job has no project cycle");
+    let volunteers =
+        storage_layer.fetch_volunteers_by_cycle(project_cycle_id, &mut exec_opts).await?;
+    assert_eq!(volunteers.len(), 1);
+    assert_eq!(volunteers[0].email, "ada@example.com");
+
+    let items = storage_layer.fetch_job_items(IMPORT_JOB_ID, &mut exec_opts).await?;
+    let djokovic = items
+        .iter()
+        .find(|item| item.item_key == "novak.djokovic@gmail.com")
+        .expect("no job item for Djokovic");
+    assert_eq!(djokovic.status, JobItemStatus::Failed);
+    assert!(djokovic.entity_id.is_none());
+
+    Ok(())
+}
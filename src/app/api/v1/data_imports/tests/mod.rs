@@ -0,0 +1,110 @@
+mod csv;
+mod preview;
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use sqlx::PgPool;
+
+use crate::app::api::v1::data_imports::ImportServices;
+use crate::app::events::JobEvents;
+use crate::services::airtable::entities::{BaseRecords, Mentor, Nonprofit, Volunteer};
+use crate::services::airtable::mapping::BaseMapping;
+use crate::services::airtable::AirtableClient;
+use crate::services::storage::PgBackend;
+use crate::services::Service;
+
+/// An Airtable client whose every base holds the same records.
+#[derive(Default)]
+pub struct FakeAirtableClient {
+    pub records: BaseRecords,
+}
+
+#[async_trait]
+impl AirtableClient for FakeAirtableClient {
+    async fn read_base(&self, _base_id: &str, _mapping: &BaseMapping) -> Result<BaseRecords> {
+        Ok(self.records.clone())
+    }
+}
+
+impl Service for FakeAirtableClient {
+    fn get_id(&self) -> &'static str {
+        "fake"
+    }
+}
+
+pub fn import_services(pool: PgPool, airtable: FakeAirtableClient) -> ImportServices {
+    ImportServices {
+        storage_layer: Arc::new(PgBackend { pool }),
+        airtable: Arc::new(airtable),
+        job_events: Arc::new(JobEvents::default()),
+    }
+}
+
+/// Read the fields of a record the way they are read from a base.
+///
+/// * `fields`: The fields of the record, by the names entities read them by
+fn read_fields<T: DeserializeOwned>(fields: Value) -> T {
+    serde_json::from_value(fields).expect("error reading record")
+}
+
+pub fn volunteer(record_id: &str, email: &str, org_names: &[&str]) -> Volunteer {
+    let volunteer = read_fields::<Volunteer>(json!({
+        "FirstName": "Roger",
+        "LastName": "Federer",
+        "OrgName (from ProjectRecordID)": org_names,
+        "Email": email,
+        "Gender": "Man",
+        "Ethnicity": ["White or Caucasian"],
+        "AgeRange": "18 - 24",
+        "University": [],
+        "LGBT": "No",
+        "Country": "Switzerland",
+        "FLI": [],
+        "StudentStage": "Junior",
+        "Majors": "Math",
+        "Minors": null,
+        "HearAbout": [],
+    }));
+    Volunteer { record_id: record_id.to_owned(), ..volunteer }
+}
+
+pub fn mentor(record_id: &str, email: &str, org_names: &[&str]) -> Mentor {
+    let mentor = read_fields::<Mentor>(json!({
+        "FirstName": "Martina",
+        "LastName": "Navratilova",
+        "Email": email,
+        "Phone": "555-0100",
+        "Company": "WTA",
+        "JobTitle": "Coach",
+        "OrgName (from ProjectRecordID)": org_names,
+        "Country": "United States",
+        "YearsExperience": "21+",
+        "ExperienceLevel": "Senior, executive, or top-level management",
+        "PriorMentorship": [],
+        "PriorDFG": null,
+        "University": null,
+        "HearAbout": null,
+        "ProjectRole": ["Team Mentor"],
+    }));
+    Mentor { record_id: record_id.to_owned(), ..mentor }
+}
+
+pub fn nonprofit(record_id: &str, org_name: &str) -> Nonprofit {
+    let nonprofit = read_fields::<Nonprofit>(json!({
+        "FirstName": "Jane",
+        "LastName": "Doe",
+        "JobTitle": "Director",
+        "NonprofitEmail": "jane@example.com",
+        "Phone": "555-0101",
+        "OrgName": org_name,
+        "ProjectName": "Website",
+        "Address": "1 Main St",
+        "Size": "1-5",
+        "ImpactCauses": null,
+    }));
+    Nonprofit { record_id: record_id.to_owned(), ..nonprofit }
+}
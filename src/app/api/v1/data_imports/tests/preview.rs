@@ -0,0 +1,121 @@
+use anyhow::Result;
+use sqlx::PgPool;
+
+use super::{import_services, mentor, nonprofit, volunteer, FakeAirtableClient};
+use crate::app::api::v1::data_imports::import::preview_task;
+use crate::app::api::v1::data_imports::responses::LinkageKind;
+use crate::services::airtable::entities::{BaseRecords, MalformedRecord, MentorMenteeLinkage};
+use crate::services::airtable::mapping::BaseMapping;
+use crate::services::storage::types::JobItemKind;
+
+#[sqlx::test]
+pub async fn test_preview_task(pool: PgPool) -> Result<()> {
+    let records = BaseRecords {
+        volunteers: vec![
+            volunteer("recFederer", "roger@example.com", &["Helping Hands"]),
+            volunteer("recNadal", "rafa@example.com", &["Missing Org"]),
+            volunteer("recFederer2", "roger@example.com", &[]),
+        ],
+        mentors: vec![
+            mentor("recNavratilova", "martina@example.com", &["Helping Hands"]),
+            mentor("recGraf", "steffi@example.com", &["Missing Org"]),
+        ],
+        nonprofits: vec![nonprofit("recHelpingHands", "Helping Hands")],
+        mentor_mentee_linkages: vec![
+            MentorMenteeLinkage {
+                mentor_email: "martina@example.com".to_owned(),
+                mentee_email: vec!["roger@example.com".to_owned(), "novak@example.com".to_owned()],
+            },
+            MentorMenteeLinkage {
+                mentor_email: "chris@example.com".to_owned(),
+                mentee_email: vec!["rafa@example.com".to_owned()],
+            },
+        ],
+        malformed: vec![MalformedRecord {
+            table: "Volunteers".to_owned(),
+            record_id: "recSinner".to_owned(),
+            error: "unknown variant `Unknown`".to_owned(),
+        }],
+    };
+    let services = import_services(pool, FakeAirtableClient { records });
+
+    let preview = preview_task(&services, "appTest", &BaseMapping::default()).await?;
+
+    assert_eq!((preview.volunteers, preview.mentors, preview.nonprofits), (3, 2, 1));
+    assert_eq!(preview.malformed_records.len(), 1);
+    assert_eq!(preview.malformed_records[0].record_id, "recSinner");
+
+    let dropped = preview
+        .dropped_linkages
+        .iter()
+        .map(|l| (l.kind, l.from.as_str(), l.to.as_str(), l.unresolved.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        dropped,
+        vec![
+            (LinkageKind::VolunteerNonprofit, "rafa@example.com", "Missing Org", "Missing Org"),
+            (LinkageKind::MentorNonprofit, "steffi@example.com", "Missing Org", "Missing Org"),
+            (
+                LinkageKind::MentorMentee,
+                "martina@example.com",
+                "novak@example.com",
+                "novak@example.com"
+            ),
+            (
+                LinkageKind::MentorMentee,
+                "chris@example.com",
+                "rafa@example.com",
+                "chris@example.com"
+            ),
+        ]
+    );
+
+    // Only the volunteer email is shared
+    assert_eq!(preview.duplicate_emails.len(), 1);
+    assert_eq!(preview.duplicate_emails[0].kind, JobItemKind::Volunteer);
+    assert_eq!(preview.duplicate_emails[0].email, "roger@example.com");
+    assert_eq!(preview.duplicate_emails[0].record_ids, vec!["recFederer", "recFederer2"]);
+
+    Ok(())
+}
+
+#[sqlx::test]
+pub async fn test_preview_task_finds_duplicate_emails_per_kind(pool: PgPool) -> Result<()> {
+    let records = BaseRecords {
+        // A volunteer may share an email with a mentor
+        volunteers: vec![
+            volunteer("recB", "b@example.com", &[]),
+            volunteer("recShared", "shared@example.com", &[]),
+            volunteer("recA", "b@example.com", &[]),
+            volunteer("recC", "b@example.com", &[]),
+        ],
+        mentors: vec![
+            mentor("recMentorShared", "shared@example.com", &[]),
+            mentor("recM1", "a@example.com", &[]),
+            mentor("recM2", "a@example.com", &[]),
+        ],
+        ..Default::default()
+    };
+    let services = import_services(pool, FakeAirtableClient { records });
+
+    let preview = preview_task(&services, "appTest", &BaseMapping::default()).await?;
+
+    let duplicates = preview
+        .duplicate_emails
+        .iter()
+        .map(|d| (d.kind, d.email.as_str(), d.record_ids.clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        duplicates,
+        vec![
+            (
+                JobItemKind::Volunteer,
+                "b@example.com",
+                vec!["recB".to_owned(), "recA".to_owned(), "recC".to_owned()]
+            ),
+            (JobItemKind::Mentor, "a@example.com", vec!["recM1".to_owned(), "recM2".to_owned()]),
+        ]
+    );
+
+    Ok(())
+}
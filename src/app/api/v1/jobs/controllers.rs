@@ -1,12 +1,30 @@
+use std::collections::HashSet;
 use std::sync::Arc;
 
-use axum::extract::State;
-use axum::Json;
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::response::sse::{KeepAlive, Sse};
+use axum::response::{IntoResponse, Response};
+use axum::{Extension, Json};
+use futures::stream;
+use serde_json::json;
+use sqlx::Database;
+use uuid::Uuid;
 
-use crate::app::api::v1::jobs::responses::{Job, JobsResponse};
+use crate::app::api::v1::jobs::events::JobEventStream;
+use crate::app::api::v1::jobs::requests::FetchJobsQuery;
+use crate::app::api::v1::jobs::responses::{
+    CancelJobResponse, Job, JobItemsResponse, JobProgress, JobResponse, JobsResponse,
+    RetryJobResponse,
+};
+use crate::app::api_response;
+use crate::app::audit::Audit;
 use crate::app::errors::AppError;
 use crate::app::state::Services;
-use crate::services::storage::types::JobDetails;
+use crate::services::auth::AuthData;
+use crate::services::storage::jobs::{CreateJobBuilder, JobFilter};
+use crate::services::storage::pagination::PageOptions;
+use crate::services::storage::types::{AuditAction, JobData, JobDetails, JobStatus, JobType};
 use crate::services::storage::ExecOptsBuilder;
 
 #[utoipa::path(
@@ -14,34 +32,336 @@ use crate::services::storage::ExecOptsBuilder;
     path = "",
     operation_id = "Get jobs",
     responses(
-        (status = 200, description = "Successfully fetched jobs"),
+        (status = 200, description = "Successfully fetched a page of jobs, newest first"),
+        (status = 400, description = "Invalid query parameters, or a cursor which is not the ID of a job"),
         (status = 401, description = "Unauthorized: invalid JWT"),
-        (status = 403, description = "Forbidden: insufficient permissions (requires `fetch:cycle`)"),
+        (status = 403, description = "Forbidden: insufficient permissions (requires `read:jobs`)"),
     ),
     params(
-        ("Authorization" = String, Header, description = "JWT. NOTE: Prefix with Bearer")
+        ("Authorization" = String, Header, description = "JWT. NOTE: Prefix with Bearer"),
+        ("status" = Option<String>, Query, description = "Only jobs with this status, e.g. `error`"),
+        ("jobType" = Option<String>, Query, description = "Only jobs of this type, e.g. `airtable_export_users`"),
+        ("cycleId" = Option<Uuid>, Query, description = "Only jobs associated with this project cycle"),
+        ("createdAfter" = Option<String>, Query, description = "Only jobs created at or after this RFC 3339 timestamp"),
+        ("createdBefore" = Option<String>, Query, description = "Only jobs created before this RFC 3339 timestamp"),
+        ("cursor" = Option<Uuid>, Query, description = "The `nextCursor` of the previous page"),
+        ("limit" = Option<i64>, Query, description = "The maximum number of jobs to return (at most 500)"),
     ),
 )]
-pub async fn fetch_jobs(State(ctx): State<Arc<Services>>) -> Result<Json<JobsResponse>, AppError> {
+pub async fn fetch_jobs<DB: Database>(
+    State(ctx): State<Arc<Services<DB>>>,
+    Query(query): Query<FetchJobsQuery>,
+) -> Result<Response, AppError> {
     let storage_layer = &ctx.storage_layer;
-    let jobs: Vec<Job> = storage_layer
-        .fetch_jobs(&mut ExecOptsBuilder::default().build()?)
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+
+    // Pages are found by comparing against the cursor's row, so an unknown cursor matches nothing
+    if let Some(cursor) = query.cursor {
+        if storage_layer.fetch_job(cursor, &mut exec_opts).await?.is_none() {
+            return Ok(api_response::error(
+                StatusCode::BAD_REQUEST,
+                "The cursor is not the ID of a job",
+            ));
+        }
+    }
+
+    let filter = JobFilter {
+        status: query.status,
+        job_type: query.job_type,
+        project_cycle_id: query.cycle_id,
+        created_after: query.created_after,
+        created_before: query.created_before,
+    };
+
+    let page = storage_layer
+        .fetch_jobs(filter, PageOptions::new(query.cursor, query.limit), &mut exec_opts)
+        .await?;
+
+    let jobs = page.items.into_iter().map(Job::try_from).collect::<Result<Vec<Job>, _>>()?;
+
+    Ok(Json(JobsResponse { jobs, next_cursor: page.next_cursor }).into_response())
+}
+
+#[utoipa::path(
+    get,
+    path = "/{id}",
+    operation_id = "Get job",
+    responses(
+        (status = 200, description = "Successfully fetched job and its progress"),
+        (status = 401, description = "Unauthorized: invalid JWT"),
+        (status = 403, description = "Forbidden: insufficient permissions (requires `read:jobs`)"),
+        (status = 404, description = "Job not found"),
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT. NOTE: Prefix with Bearer"),
+        ("id" = Uuid, Path, description = "The ID of the job")
+    ),
+)]
+pub async fn fetch_job<DB: Database>(
+    State(ctx): State<Arc<Services<DB>>>,
+    Path(id): Path<Uuid>,
+) -> Result<Response, AppError> {
+    let storage_layer = &ctx.storage_layer;
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+
+    let Some(job) = storage_layer.fetch_job(id, &mut exec_opts).await? else {
+        return Ok(api_response::error(StatusCode::NOT_FOUND, "Job not found"));
+    };
+
+    let counts = storage_layer.fetch_job_item_counts(id, &mut exec_opts).await?;
+    let progress = JobProgress::new(job.status, counts);
+
+    Ok(api_response::success(StatusCode::OK, JobResponse { job: Job::try_from(job)?, progress })?)
+}
+
+#[utoipa::path(
+    get,
+    path = "/{id}/items",
+    operation_id = "Get job items",
+    responses(
+        (status = 200, description = "Successfully fetched the outcome of a job for each record it touches"),
+        (status = 401, description = "Unauthorized: invalid JWT"),
+        (status = 403, description = "Forbidden: insufficient permissions (requires `read:jobs`)"),
+        (status = 404, description = "Job not found"),
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT. NOTE: Prefix with Bearer"),
+        ("id" = Uuid, Path, description = "The ID of the job")
+    ),
+)]
+pub async fn fetch_job_items<DB: Database>(
+    State(ctx): State<Arc<Services<DB>>>,
+    Path(id): Path<Uuid>,
+) -> Result<Response, AppError> {
+    let storage_layer = &ctx.storage_layer;
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+
+    let Some(job) = storage_layer.fetch_job(id, &mut exec_opts).await? else {
+        return Ok(api_response::error(StatusCode::NOT_FOUND, "Job not found"));
+    };
+
+    let counts = storage_layer.fetch_job_item_counts(id, &mut exec_opts).await?;
+    let items = storage_layer.fetch_job_items(id, &mut exec_opts).await?;
+
+    Ok(api_response::success(
+        StatusCode::OK,
+        JobItemsResponse { progress: JobProgress::new(job.status, counts), items },
+    )?)
+}
+
+#[utoipa::path(
+    get,
+    path = "/{id}/events",
+    operation_id = "Stream job events",
+    responses(
+        (status = 200, description = "A stream of server-sent events. A `status` event is sent whenever the job's status changes and a `progress` event whenever its progress changes. The stream ends when the job finishes"),
+        (status = 401, description = "Unauthorized: invalid JWT"),
+        (status = 403, description = "Forbidden: insufficient permissions (requires `read:jobs`)"),
+        (status = 404, description = "Job not found"),
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT. NOTE: Prefix with Bearer"),
+        ("id" = Uuid, Path, description = "The ID of the job")
+    ),
+)]
+pub async fn stream_job_events<DB: Database>(
+    State(ctx): State<Arc<Services<DB>>>,
+    Path(id): Path<Uuid>,
+) -> Result<Response, AppError> {
+    // Subscribe before checking that the job exists so that no changes are missed
+    let events = JobEventStream::new(ctx.clone(), id);
+
+    if ctx.storage_layer.fetch_job(id, &mut ExecOptsBuilder::default().build()?).await?.is_none() {
+        return Ok(api_response::error(StatusCode::NOT_FOUND, "Job not found"));
+    }
+
+    let stream = stream::unfold(events, JobEventStream::next);
+
+    Ok(Sse::new(stream).keep_alive(KeepAlive::default()).into_response())
+}
+
+#[utoipa::path(
+    post,
+    path = "/{id}/cancel",
+    operation_id = "Cancel job",
+    responses(
+        (status = 200, description = "Successfully cancelled a job which had not started yet"),
+        (status = 202, description = "Requested that a running job stop. The job is marked as cancelled once it stops"),
+        (status = 401, description = "Unauthorized: invalid JWT"),
+        (status = 403, description = "Forbidden: insufficient permissions (requires `write:jobs`)"),
+        (status = 404, description = "Job not found"),
+        (status = 409, description = "The job has already finished"),
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT. NOTE: Prefix with Bearer"),
+        ("id" = Uuid, Path, description = "The ID of the job")
+    ),
+)]
+pub async fn cancel_job<DB: Database>(
+    State(ctx): State<Arc<Services<DB>>>,
+    Path(id): Path<Uuid>,
+    Extension(auth): Extension<AuthData>,
+) -> Result<Response, AppError> {
+    let storage_layer = &ctx.storage_layer;
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+
+    let Some(job) = storage_layer.fetch_job(id, &mut exec_opts).await? else {
+        return Ok(api_response::error(StatusCode::NOT_FOUND, "Job not found"));
+    };
+
+    if !matches!(job.status, JobStatus::Pending | JobStatus::Running) {
+        return Ok(api_response::error(StatusCode::CONFLICT, "Job has already finished"));
+    }
+
+    let audit = Audit::new(storage_layer, &auth, AuditAction::CancelJob)?
+        .target(id)
+        .summary(json!({ "label": job.label }));
+
+    // A worker may claim the job between the two queries, so a pending job which can't be
+    // cancelled is asked to stop like any running job
+    let res = match storage_layer.cancel_job(id, &mut exec_opts).await {
+        Ok(true) => Ok(Some(JobStatus::Cancelled)),
+        Ok(false) => storage_layer
+            .request_job_cancellation(id, &mut exec_opts)
+            .await
+            .map(|requested| requested.then_some(JobStatus::Running)),
+        Err(e) => Err(e),
+    };
+    audit.record(&res, &mut exec_opts).await?;
+
+    let res = match res? {
+        Some(JobStatus::Running) => {
+            // The worker sees the request on its next heartbeat, but a job running in this
+            // process can be stopped right away
+            ctx.job_cancellations.cancel(id);
+            api_response::success(
+                StatusCode::ACCEPTED,
+                CancelJobResponse { job_id: id, status: JobStatus::Running },
+            )?
+        }
+        Some(status) => {
+            api_response::success(StatusCode::OK, CancelJobResponse { job_id: id, status })?
+        }
+        None => return Ok(api_response::error(StatusCode::CONFLICT, "Job has already finished")),
+    };
+
+    ctx.job_events.publish(id);
+
+    Ok(res)
+}
+
+#[utoipa::path(
+    post,
+    path = "/{id}/retry",
+    operation_id = "Retry export job",
+    responses(
+        (status = 200, description = "Successfully started a job to export the volunteers the original job did not export"),
+        (status = 400, description = "The job is not an export job, or it cannot be retried"),
+        (status = 401, description = "Unauthorized: invalid JWT"),
+        (status = 403, description = "Forbidden: insufficient permissions (requires `write:jobs` and `export:volunteers-workspace`)"),
+        (status = 404, description = "Job not found"),
+        (status = 409, description = "The job has not finished, or every volunteer has already been exported"),
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT. NOTE: Prefix with Bearer"),
+        ("id" = Uuid, Path, description = "The ID of the export job to retry")
+    ),
+)]
+pub async fn retry_export_job<DB: Database>(
+    State(ctx): State<Arc<Services<DB>>>,
+    Path(id): Path<Uuid>,
+    Extension(auth): Extension<AuthData>,
+) -> Result<Response, AppError> {
+    let storage_layer = &ctx.storage_layer;
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+
+    let Some(job) = storage_layer.fetch_job(id, &mut exec_opts).await? else {
+        return Ok(api_response::error(StatusCode::NOT_FOUND, "Job not found"));
+    };
+
+    if matches!(job.status, JobStatus::Pending | JobStatus::Running) {
+        return Ok(api_response::error(StatusCode::CONFLICT, "Job has not finished"));
+    }
+
+    let details = serde_json::from_value::<JobDetails>(job.details)?;
+    let JobData::AirtableExportUsers {
+        export_destination,
+        volunteer_ids,
+        policy,
+        airtable_write_back,
+        ..
+    } = details.data
+    else {
+        return Ok(api_response::error(StatusCode::BAD_REQUEST, "Only export jobs can be retried"));
+    };
+
+    // Jobs queued before export policies were stored with the job don't say how they were run
+    let Some(policy) = policy else {
+        return Ok(api_response::error(
+            StatusCode::BAD_REQUEST,
+            "The export job was queued without its export policies, so it cannot be retried",
+        ));
+    };
+
+    let Some(project_cycle_id) = job.project_cycle_id else {
+        return Ok(api_response::error(
+            StatusCode::BAD_REQUEST,
+            "The export job is not part of a project cycle, so it cannot be retried",
+        ));
+    };
+
+    let already_exported = storage_layer
+        .fetch_exported_volunteer_details_by_project_cycle(project_cycle_id, &mut exec_opts)
         .await?
-        .iter()
-        .map(|job| {
-            serde_json::from_value::<JobDetails>(job.details.clone()).map(|details| Job {
-                id: job.id,
-                label: job.label.clone(),
-                description: job.description.clone(),
-                created_at: job.created_at,
-                updated_at: job.updated_at,
-                project_cycle_id: job.project_cycle_id,
-                status: job.status,
-                details,
-            })
+        .into_iter()
+        .map(|v| v.volunteer_id)
+        .collect::<HashSet<Uuid>>();
+
+    let volunteer_ids =
+        volunteer_ids.into_iter().filter(|v| !already_exported.contains(v)).collect::<Vec<Uuid>>();
+
+    if volunteer_ids.is_empty() {
+        return Ok(api_response::error(
+            StatusCode::CONFLICT,
+            "Every volunteer in the job has already been exported",
+        ));
+    }
+
+    let volunteer_count = volunteer_ids.len();
+
+    let data = CreateJobBuilder::default()
+        .label(job.label)
+        .description(Some(format!("Retry of export job {id}")))
+        .data(JobDetails {
+            job_type: JobType::AirtableExportUsers,
+            error: None,
+            summary: None,
+            diff: None,
+            write_back: None,
+            data: JobData::AirtableExportUsers {
+                export_destination,
+                principal: Some(auth.email()?),
+                volunteer_ids,
+                policy: Some(policy),
+                retry_of: Some(id),
+                airtable_write_back,
+            },
         })
-        .collect::<Result<Vec<Job>, _>>()?;
+        .build()?;
+
+    let mut audit = Audit::new(storage_layer, &auth, AuditAction::RetryJob)?
+        .target(id)
+        .target(project_cycle_id)
+        .summary(json!({ "volunteers": volunteer_count }));
+
+    let res = storage_layer.create_job(Some(project_cycle_id), data, &mut exec_opts).await;
+    if let Ok(job_id) = &res {
+        audit = audit.target(*job_id);
+    }
+    audit.record(&res, &mut exec_opts).await?;
+    let job_id = res?;
+
+    log::info!("Queued export job {job_id} to retry export job {id}");
 
-    let res = JobsResponse { jobs };
-    Ok(Json(res))
+    Ok(api_response::success(StatusCode::OK, RetryJobResponse { job_id })?)
 }
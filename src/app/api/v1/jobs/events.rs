@@ -0,0 +1,135 @@
+//! Server-sent event streams for watching a job.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Result;
+use axum::response::sse::Event;
+use sqlx::{Database, Postgres};
+use tokio::sync::broadcast;
+use tokio::sync::broadcast::error::RecvError;
+use uuid::Uuid;
+
+use crate::app::api::v1::jobs::responses::{JobProgress, JobStatusEvent};
+use crate::app::state::Services;
+use crate::services::storage::types::{JobDetails, JobStatus};
+use crate::services::storage::ExecOptsBuilder;
+
+/// How long a stream waits for a notification before re-reading the job anyway.
+///
+/// Notifications are only delivered within a process, so this picks up changes made by workers
+/// running elsewhere.
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// The state of an event stream for a single job.
+///
+/// The stream sends a `status` event whenever the job's status changes and a `progress` event
+/// whenever its progress changes. The current status and progress are sent as soon as the stream
+/// opens. The stream ends once the job is complete, errored, or cancelled.
+pub struct JobEventStream<DB: Database = Postgres> {
+    ctx: Arc<Services<DB>>,
+    job_id: Uuid,
+    notifications: broadcast::Receiver<Uuid>,
+    status: Option<JobStatus>,
+    progress: Option<JobProgress>,
+    queued: VecDeque<Event>,
+    started: bool,
+    done: bool,
+}
+
+impl<DB: Database> JobEventStream<DB> {
+    /// Create a stream of events for a job.
+    ///
+    /// * `ctx`: The application context
+    /// * `job_id`: The ID of the job to watch
+    pub fn new(ctx: Arc<Services<DB>>, job_id: Uuid) -> Self {
+        let notifications = ctx.job_events.subscribe();
+        Self {
+            ctx,
+            job_id,
+            notifications,
+            status: None,
+            progress: None,
+            queued: VecDeque::new(),
+            started: false,
+            done: false,
+        }
+    }
+
+    /// Produce the next event, or `None` once the stream is over.
+    pub async fn next(mut self) -> Option<(Result<Event, axum::Error>, Self)> {
+        loop {
+            if let Some(event) = self.queued.pop_front() {
+                return Some((Ok(event), self));
+            }
+
+            if self.done {
+                return None;
+            }
+
+            if self.started && !self.changed().await {
+                return None;
+            }
+            self.started = true;
+
+            if let Err(e) = self.refresh().await {
+                log::error!("Failed to read job {} for its event stream: {e}", self.job_id);
+                return None;
+            }
+        }
+    }
+
+    /// Wait until the job may have changed. Returns `false` if no more notifications will arrive.
+    async fn changed(&mut self) -> bool {
+        let job_id = self.job_id;
+        let notifications = &mut self.notifications;
+
+        let notified = async {
+            loop {
+                match notifications.recv().await {
+                    Ok(id) if id == job_id => return true,
+                    Ok(_) => continue,
+                    // Some notifications were dropped, so one of them may have been for this job
+                    Err(RecvError::Lagged(_)) => return true,
+                    Err(RecvError::Closed) => return false,
+                }
+            }
+        };
+
+        tokio::time::timeout(POLL_INTERVAL, notified).await.unwrap_or(true)
+    }
+
+    /// Read the job and queue events for anything that changed since it was last read.
+    async fn refresh(&mut self) -> Result<()> {
+        let storage_layer = &self.ctx.storage_layer;
+        let mut exec_opts = ExecOptsBuilder::default().build()?;
+
+        let Some(job) = storage_layer.fetch_job(self.job_id, &mut exec_opts).await? else {
+            self.done = true;
+            return Ok(());
+        };
+
+        let counts = storage_layer.fetch_job_item_counts(self.job_id, &mut exec_opts).await?;
+        let progress = JobProgress::new(job.status, counts);
+
+        if self.status != Some(job.status) {
+            let error = serde_json::from_value::<JobDetails>(job.details)
+                .ok()
+                .and_then(|details| details.error);
+            let data = JobStatusEvent { job_id: self.job_id, status: job.status, error };
+            self.queued.push_back(Event::default().event("status").json_data(data)?);
+            self.status = Some(job.status);
+        }
+
+        if self.progress.as_ref() != Some(&progress) {
+            self.queued.push_back(Event::default().event("progress").json_data(&progress)?);
+            self.progress = Some(progress);
+        }
+
+        self.done =
+            matches!(job.status, JobStatus::Complete | JobStatus::Error | JobStatus::Cancelled);
+
+        Ok(())
+    }
+}
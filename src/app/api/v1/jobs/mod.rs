@@ -1,10 +1,13 @@
 mod controllers;
+mod events;
+mod requests;
 mod responses;
 
 use std::sync::Arc;
 
 use axum::middleware::from_fn_with_state;
 use axum::{routing, Router};
+use sqlx::Database;
 use utoipa::OpenApi;
 
 use crate::app::api::middleware::make_rbac;
@@ -14,18 +17,44 @@ use crate::app::state::Services;
 #[openapi(
     paths(
         controllers::fetch_jobs,
+        controllers::fetch_job,
+        controllers::fetch_job_items,
+        controllers::stream_job_events,
+        controllers::cancel_job,
+        controllers::retry_export_job,
     ),
     security(("http" = ["JWT"]))
 )]
 pub struct JobsApi;
 
-pub async fn build(ctx: Arc<Services>) -> Router<()> {
+pub async fn build<DB: Database>(ctx: Arc<Services<DB>>) -> Router<()> {
     let guard1 = make_rbac(vec!["read:jobs".to_owned()]).await;
+    let write_guard = make_rbac(vec!["write:jobs".to_owned()]).await;
+    let retry_export_guard =
+        make_rbac(vec!["write:jobs".to_owned(), "export:volunteers-workspace".to_owned()]).await;
 
-    let fetch_jobs = routing::get(controllers::fetch_jobs);
+    let fetch_jobs = routing::get(controllers::fetch_jobs::<DB>);
+    let fetch_job = routing::get(controllers::fetch_job::<DB>);
+    let fetch_job_items = routing::get(controllers::fetch_job_items::<DB>);
+    let stream_job_events = routing::get(controllers::stream_job_events::<DB>);
+    let cancel_job = routing::post(controllers::cancel_job::<DB>);
+    let retry_export_job = routing::post(controllers::retry_export_job::<DB>);
+
+    let write_routes = Router::new()
+        .route("/:id/cancel", cancel_job)
+        .route_layer(from_fn_with_state(ctx.clone(), write_guard));
+
+    let retry_export_routes = Router::new()
+        .route("/:id/retry", retry_export_job)
+        .route_layer(from_fn_with_state(ctx.clone(), retry_export_guard));
 
     Router::new()
         .route("/", fetch_jobs)
+        .route("/:id", fetch_job)
+        .route("/:id/items", fetch_job_items)
+        .route("/:id/events", stream_job_events)
         .route_layer(from_fn_with_state(ctx.clone(), guard1))
+        .merge(write_routes)
+        .merge(retry_export_routes)
         .with_state(ctx.clone())
 }
@@ -0,0 +1,28 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::services::storage::types::{JobStatus, JobType};
+
+/// Query parameters for fetching jobs.
+///
+/// Every filter is optional.
+///
+/// * `status`: Only jobs with this status
+/// * `job_type`: Only jobs of this type
+/// * `cycle_id`: Only jobs associated with this project cycle
+/// * `created_after`: Only jobs created at or after this time
+/// * `created_before`: Only jobs created before this time
+/// * `cursor`: The `nextCursor` of the previous page
+/// * `limit`: The maximum number of jobs to return (defaults to 50, at most 500)
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FetchJobsQuery {
+    pub status: Option<JobStatus>,
+    pub job_type: Option<JobType>,
+    pub cycle_id: Option<Uuid>,
+    pub created_after: Option<DateTime<Utc>>,
+    pub created_before: Option<DateTime<Utc>>,
+    pub cursor: Option<Uuid>,
+    pub limit: Option<i64>,
+}
@@ -2,7 +2,8 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::services::storage::types::{JobDetails, JobStatus};
+use crate::services::storage::entities::{self, JobItem, JobItemCount};
+use crate::services::storage::types::{JobDetails, JobItemStatus, JobStatus};
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -17,24 +18,128 @@ pub struct Job {
     pub details: JobDetails,
 }
 
-// impl TryFrom<Job> for JobTypeResponse {
-//     type Error = anyhow::Error;
-//
-//     fn try_from(job: Job) -> Result<Self, Self::Error> {
-//         Ok(Self {
-//             id: job.id,
-//             created_at: job.created_at,
-//             updated_at: job.updated_at,
-//             project_cycle_id: job.project_cycle_id,
-//             status: job.status,
-//             label: job.label,
-//             description: job.description,
-//             details: serde_json::from_value::<JobDetails>(job.details)?,
-//         })
-//     }
-// }
+impl TryFrom<entities::Job> for Job {
+    type Error = anyhow::Error;
 
+    fn try_from(job: entities::Job) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: job.id,
+            created_at: job.created_at,
+            updated_at: job.updated_at,
+            project_cycle_id: job.project_cycle_id,
+            status: job.status,
+            label: job.label,
+            description: job.description,
+            details: serde_json::from_value::<JobDetails>(job.details)?,
+        })
+    }
+}
+
+/// A page of jobs.
+///
+/// * `jobs`: The jobs on this page, newest first
+/// * `next_cursor`: Pass this as `cursor` to fetch the next page. It is `null` on the last page.
 #[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct JobsResponse {
     pub jobs: Vec<Job>,
+    pub next_cursor: Option<Uuid>,
+}
+
+/// The number of records touched by a job with each status.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct JobItemStatusCounts {
+    pub pending: i64,
+    pub succeeded: i64,
+    pub skipped: i64,
+    pub failed: i64,
+}
+
+/// How far along a job is.
+///
+/// * `total`: The number of records the job touches
+/// * `percent_complete`: The percentage of records the job has finished processing
+/// * `counts`: The number of records with each status
+/// * `breakdown`: The number of records with each status, broken down by the kind of record
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct JobProgress {
+    pub total: i64,
+    pub percent_complete: f64,
+    pub counts: JobItemStatusCounts,
+    pub breakdown: Vec<JobItemCount>,
+}
+
+impl JobProgress {
+    /// Compute the progress of a job.
+    ///
+    /// * `status`: The status of the job
+    /// * `breakdown`: The number of records touched by the job, by kind and status
+    pub fn new(status: JobStatus, breakdown: Vec<JobItemCount>) -> Self {
+        let mut counts = JobItemStatusCounts::default();
+        for c in &breakdown {
+            match c.status {
+                JobItemStatus::Pending => counts.pending += c.count,
+                JobItemStatus::Succeeded => counts.succeeded += c.count,
+                JobItemStatus::Skipped => counts.skipped += c.count,
+                JobItemStatus::Failed => counts.failed += c.count,
+            }
+        }
+
+        let total = counts.pending + counts.succeeded + counts.skipped + counts.failed;
+        let percent_complete = match (total, status) {
+            (0, JobStatus::Pending | JobStatus::Running) => 0.0,
+            (0, _) => 100.0,
+            _ => (total - counts.pending) as f64 / total as f64 * 100.0,
+        };
+
+        Self { total, percent_complete, counts, breakdown }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct JobResponse {
+    pub job: Job,
+    pub progress: JobProgress,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct JobItemsResponse {
+    pub progress: JobProgress,
+    pub items: Vec<JobItem>,
+}
+
+/// Sent over a job's event stream when its status changes.
+///
+/// * `job_id`: The ID of the job
+/// * `status`: The new status of the job
+/// * `error`: Information about the error, if the job has errored
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobStatusEvent {
+    pub job_id: Uuid,
+    pub status: JobStatus,
+    pub error: Option<String>,
+}
+
+/// The result of cancelling a job.
+///
+/// * `job_id`: The ID of the job
+/// * `status`: `cancelled` if the job was cancelled before it started, or `running` if it has been
+///   asked to stop
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelJobResponse {
+    pub job_id: Uuid,
+    pub status: JobStatus,
+}
+
+/// The result of retrying a job.
+///
+/// * `job_id`: The ID of the new job
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RetryJobResponse {
+    pub job_id: Uuid,
 }
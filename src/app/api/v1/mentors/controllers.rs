@@ -0,0 +1,204 @@
+//! Controllers for the mentors API.
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::Response;
+use axum::{Extension, Json};
+use sqlx::Database;
+use uuid::Uuid;
+
+use crate::app::api::v1::mentors::requests::EditMentorRequest;
+use crate::app::api::v1::mentors::responses::{MentorResponse, MentorsResponse};
+use crate::app::api_response;
+use crate::app::audit::{edited_fields, Audit};
+use crate::app::errors::{is_unique_violation, AppError};
+use crate::app::state::Services;
+use crate::services::auth::AuthData;
+use crate::services::storage::mentors::EditMentor;
+use crate::services::storage::types::AuditAction;
+use crate::services::storage::ExecOptsBuilder;
+
+/// Fetch the mentors in a cycle
+///
+/// * `ctx`: The application context extracted as Axum state
+/// * `project_cycle_id`: The ID of the cycle
+#[utoipa::path(
+    get,
+    path = "/cycle/{project_cycle_id}",
+    operation_id = "Get cycle mentors",
+    responses(
+        (status = 200, description = "Successfully fetched the active mentors in cycle"),
+        (status = 401, description = "Unauthorized: invalid JWT"),
+        (status = 403, description = "Forbidden: insufficient permissions (requires `read:mentors`)"),
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT. NOTE: Prefix with Bearer"),
+        ("project_cycle_id" = Uuid, Path, description = "The ID of the cycle")
+    ),
+)]
+pub async fn fetch_mentors_by_cycle<DB: Database>(
+    State(ctx): State<Arc<Services<DB>>>,
+    Path(project_cycle_id): Path<Uuid>,
+) -> Result<Response, AppError> {
+    let storage_layer = &ctx.storage_layer;
+    let mentors = storage_layer
+        .fetch_mentors_by_cycle(project_cycle_id, &mut ExecOptsBuilder::default().build()?)
+        .await?;
+
+    Ok(api_response::success(StatusCode::OK, MentorsResponse { mentors })?)
+}
+
+/// Fetch a mentor
+///
+/// * `ctx`: The application context extracted as Axum state
+/// * `id`: The ID of the mentor
+#[utoipa::path(
+    get,
+    path = "/{id}",
+    operation_id = "Get mentor",
+    responses(
+        (status = 200, description = "Successfully fetched mentor"),
+        (status = 401, description = "Unauthorized: invalid JWT"),
+        (status = 403, description = "Forbidden: insufficient permissions (requires `read:mentors`)"),
+        (status = 404, description = "Mentor not found"),
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT. NOTE: Prefix with Bearer"),
+        ("id" = Uuid, Path, description = "The ID of the mentor")
+    ),
+)]
+pub async fn fetch_mentor<DB: Database>(
+    State(ctx): State<Arc<Services<DB>>>,
+    Path(id): Path<Uuid>,
+) -> Result<Response, AppError> {
+    let storage_layer = &ctx.storage_layer;
+    let Some(mentor) =
+        storage_layer.fetch_mentor_by_id(id, &mut ExecOptsBuilder::default().build()?).await?
+    else {
+        return Ok(api_response::error(StatusCode::NOT_FOUND, "Mentor not found"));
+    };
+
+    Ok(api_response::success(StatusCode::OK, MentorResponse { mentor })?)
+}
+
+/// Edit a mentor
+///
+/// * `ctx`: The application context extracted as Axum state
+/// * `auth`: Auth data about the user
+/// * `id`: The ID of the mentor
+/// * `request`: The changes to make to the mentor
+#[utoipa::path(
+    patch,
+    path = "/{id}",
+    operation_id = "Edit mentor",
+    responses(
+        (status = 200, description = "Successfully edited mentor"),
+        (status = 400, description = "Invalid changes"),
+        (status = 401, description = "Unauthorized: invalid JWT"),
+        (status = 403, description = "Forbidden: insufficient permissions (requires `write:mentors`)"),
+        (status = 404, description = "Mentor not found"),
+        (status = 409, description = "A mentor with the same email already exists"),
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT. NOTE: Prefix with Bearer"),
+        ("id" = Uuid, Path, description = "The ID of the mentor")
+    ),
+)]
+pub async fn edit_mentor<DB: Database>(
+    State(ctx): State<Arc<Services<DB>>>,
+    Extension(auth): Extension<AuthData>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<EditMentorRequest>,
+) -> Result<Response, AppError> {
+    let storage_layer = &ctx.storage_layer;
+
+    let required = [&request.first_name, &request.last_name, &request.email];
+    if required.iter().any(|field| field.as_deref().is_some_and(|f| f.trim().is_empty())) {
+        return Ok(api_response::error(
+            StatusCode::BAD_REQUEST,
+            "First name, last name, and email cannot be empty",
+        ));
+    }
+
+    let audit = Audit::new(storage_layer, &auth, AuditAction::EditMentor)?
+        .target(id)
+        .summary(edited_fields(&request));
+
+    let mut tx = storage_layer.acquire().await?;
+    let mut exec_opts = ExecOptsBuilder::default().tx(&mut tx).build()?;
+
+    if storage_layer.fetch_mentor_by_id(id, &mut exec_opts).await?.is_none() {
+        return Ok(api_response::error(StatusCode::NOT_FOUND, "Mentor not found"));
+    }
+
+    let data = EditMentor {
+        first_name: request.first_name,
+        last_name: request.last_name,
+        email: request.email,
+        phone: request.phone,
+    };
+    let res = storage_layer.edit_mentor(id, data, &mut exec_opts).await;
+    audit.record(&res, &mut exec_opts).await?;
+    match res {
+        Ok(_) => {}
+        Err(e) if is_unique_violation(&e) => {
+            return Ok(api_response::error(
+                StatusCode::CONFLICT,
+                "A mentor with the same email already exists",
+            ));
+        }
+        Err(e) => return Err(e.into()),
+    }
+
+    let mentor = storage_layer
+        .fetch_mentor_by_id(id, &mut exec_opts)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("mentor {id} not found after it was edited"))?;
+
+    tx.commit().await?;
+
+    Ok(api_response::success(StatusCode::OK, MentorResponse { mentor })?)
+}
+
+/// Delete a mentor
+///
+/// * `ctx`: The application context extracted as Axum state
+/// * `auth`: Auth data about the user
+/// * `id`: The ID of the mentor
+#[utoipa::path(
+    delete,
+    path = "/{id}",
+    operation_id = "Delete mentor",
+    responses(
+        (status = 204, description = "Successfully deleted mentor"),
+        (status = 401, description = "Unauthorized: invalid JWT"),
+        (status = 403, description = "Forbidden: insufficient permissions (requires `write:mentors`)"),
+        (status = 404, description = "Mentor not found"),
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT. NOTE: Prefix with Bearer"),
+        ("id" = Uuid, Path, description = "The ID of the mentor")
+    ),
+)]
+pub async fn delete_mentor<DB: Database>(
+    State(ctx): State<Arc<Services<DB>>>,
+    Extension(auth): Extension<AuthData>,
+    Path(id): Path<Uuid>,
+) -> Result<Response, AppError> {
+    let storage_layer = &ctx.storage_layer;
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+
+    if storage_layer.fetch_mentor_by_id(id, &mut exec_opts).await?.is_none() {
+        return Ok(api_response::error(StatusCode::NOT_FOUND, "Mentor not found"));
+    }
+
+    let audit = Audit::new(storage_layer, &auth, AuditAction::DeleteMentor)?.target(id);
+    let res = storage_layer.delete_mentor(id, &mut exec_opts).await;
+    audit.record(&res, &mut exec_opts).await?;
+    res?;
+
+    Ok(api_response::no_content())
+}
@@ -0,0 +1,52 @@
+//! Mentors API.
+
+use std::sync::Arc;
+
+use axum::middleware::from_fn_with_state;
+use axum::{routing, Router};
+use sqlx::Database;
+use utoipa::OpenApi;
+
+use crate::app::api::middleware::make_rbac;
+use crate::app::state::Services;
+
+mod controllers;
+mod requests;
+mod responses;
+
+/// Documents the API for managing mentors
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        controllers::fetch_mentors_by_cycle,
+        controllers::fetch_mentor,
+        controllers::edit_mentor,
+        controllers::delete_mentor,
+    ),
+    security(("http" = ["JWT"]))
+)]
+pub struct MentorsApi;
+
+/// Builds the mentors API.
+///
+/// * `ctx`: The application context
+pub async fn build<DB: Database>(ctx: Arc<Services<DB>>) -> Router<()> {
+    let read_guard = make_rbac(vec!["read:mentors".to_owned()]).await;
+    let write_guard = make_rbac(vec!["write:mentors".to_owned()]).await;
+
+    let fetch_mentors_by_cycle = routing::get(controllers::fetch_mentors_by_cycle::<DB>);
+    let fetch_mentor = routing::get(controllers::fetch_mentor::<DB>);
+    let edit_mentor = routing::patch(controllers::edit_mentor::<DB>);
+    let delete_mentor = routing::delete(controllers::delete_mentor::<DB>);
+
+    let write_routes = Router::new()
+        .route("/:id", edit_mentor.merge(delete_mentor))
+        .route_layer(from_fn_with_state(ctx.clone(), write_guard));
+
+    Router::new()
+        .route("/cycle/:project_cycle_id", fetch_mentors_by_cycle)
+        .route("/:id", fetch_mentor)
+        .route_layer(from_fn_with_state(ctx.clone(), read_guard))
+        .merge(write_routes)
+        .with_state(ctx.clone())
+}
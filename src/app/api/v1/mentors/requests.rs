@@ -0,0 +1,21 @@
+//! Requests for the mentors API.
+
+use serde::{Deserialize, Serialize};
+
+/// Request to edit a mentor.
+///
+/// Fields which are omitted are left unchanged.
+///
+/// * `first_name`: The new first name of the mentor
+/// * `last_name`: The new last name of the mentor
+/// * `email`: The new email of the mentor
+/// * `phone`: The new phone number of the mentor
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EditMentorRequest {
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub email: Option<String>,
+    pub phone: Option<String>,
+}
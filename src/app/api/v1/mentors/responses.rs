@@ -0,0 +1,21 @@
+//! Responses from the mentors API.
+
+use serde::{Deserialize, Serialize};
+
+use crate::services::storage::entities::MentorDetails;
+
+/// Mentors returned from the API.
+///
+/// * `mentors`: The mentors
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MentorsResponse {
+    pub mentors: Vec<MentorDetails>,
+}
+
+/// A single mentor returned from the API.
+///
+/// * `mentor`: The mentor
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MentorResponse {
+    pub mentor: MentorDetails,
+}
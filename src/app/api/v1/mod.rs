@@ -1,26 +1,39 @@
 //! Defines and builds the API for version 1 of the Pantheon API.
 
+mod audit;
 mod authz;
 mod cycles;
 mod data_exports;
 mod data_imports;
 mod jobs;
+mod mentors;
+mod nonprofits;
 mod stats;
+mod team_roles;
 mod volunteers;
 
 use std::sync::Arc;
 
+use anyhow::Result;
+use audit::AuditApi;
 use authz::AuthzApi;
 use axum::Router;
 use cycles::CyclesApi;
 use data_exports::DataExportsApi;
 use data_imports::DataImportsApi;
 use jobs::JobsApi;
+use mentors::MentorsApi;
+use nonprofits::NonprofitsApi;
+use sqlx::Database;
 use stats::StatsApi;
+use team_roles::TeamRolesApi;
+use tokio_util::sync::CancellationToken;
 use utoipa::OpenApi;
 use volunteers::VolunteersApi;
 
 use crate::app::state::Services;
+use crate::services::storage::entities::Job;
+use crate::services::storage::types::{JobDetails, JobType};
 
 /// API Documentation for version 1 of the Pantheon API.
 #[derive(OpenApi)]
@@ -32,7 +45,11 @@ use crate::app::state::Services;
         (path = "/cycles", api = CyclesApi),
         (path = "/jobs", api = JobsApi),
         (path = "/volunteers", api = VolunteersApi),
+        (path = "/mentors", api = MentorsApi),
+        (path = "/nonprofits", api = NonprofitsApi),
         (path = "/stats", api = StatsApi),
+        (path = "/team-roles", api = TeamRolesApi),
+        (path = "/audit", api = AuditApi),
     ),
 )]
 pub struct V1Api;
@@ -40,14 +57,18 @@ pub struct V1Api;
 /// Builds the API for version 1 of the Pantheon API.
 ///
 /// * `ctx`: The application context
-pub async fn build(services: Arc<Services>) -> Router<()> {
+pub async fn build<DB: Database>(services: Arc<Services<DB>>) -> Router<()> {
     let data_import_routes = data_imports::build(services.clone()).await;
     let data_export_routes = data_exports::build(services.clone()).await;
     let authz_routes = authz::build(services.clone()).await;
     let cycles_routes = cycles::build(services.clone()).await;
     let jobs_routes = jobs::build(services.clone()).await;
     let volunteers_routes = volunteers::build(services.clone()).await;
+    let mentors_routes = mentors::build(services.clone()).await;
+    let nonprofits_routes = nonprofits::build(services.clone()).await;
     let stats_routes = stats::build(services.clone()).await;
+    let team_roles_routes = team_roles::build(services.clone()).await;
+    let audit_routes = audit::build(services.clone()).await;
 
     Router::new()
         .nest("/data-imports", data_import_routes)
@@ -56,5 +77,31 @@ pub async fn build(services: Arc<Services>) -> Router<()> {
         .nest("/cycles", cycles_routes)
         .nest("/jobs", jobs_routes)
         .nest("/volunteers", volunteers_routes)
+        .nest("/mentors", mentors_routes)
+        .nest("/nonprofits", nonprofits_routes)
         .nest("/stats", stats_routes)
+        .nest("/team-roles", team_roles_routes)
+        .nest("/audit", audit_routes)
+}
+
+/// Runs a job that a worker has claimed from the queue.
+///
+/// * `services`: The application context
+/// * `job`: The job to run
+/// * `cancellation`: Cancelled when the job should stop
+pub(in crate::app) async fn run_job<DB: Database>(
+    services: &Arc<Services<DB>>,
+    job: &Job,
+    cancellation: CancellationToken,
+) -> Result<()> {
+    let details = serde_json::from_value::<JobDetails>(job.details.clone())?;
+
+    match details.job_type {
+        JobType::AirtableImportBase | JobType::CsvImport => {
+            data_imports::run_job(services, job.id, details.data, cancellation).await
+        }
+        JobType::AirtableExportUsers | JobType::UndoWorkspaceExport => {
+            data_exports::run_job(services, job.id, details.data, cancellation).await
+        }
+    }
 }
@@ -0,0 +1,191 @@
+//! Controllers for the nonprofits API.
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::Response;
+use axum::{Extension, Json};
+use sqlx::Database;
+use uuid::Uuid;
+
+use crate::app::api::v1::nonprofits::requests::EditNonprofitRequest;
+use crate::app::api::v1::nonprofits::responses::{NonprofitResponse, NonprofitsResponse};
+use crate::app::api_response;
+use crate::app::audit::{edited_fields, Audit};
+use crate::app::errors::AppError;
+use crate::app::state::Services;
+use crate::services::auth::AuthData;
+use crate::services::storage::nonprofits::EditNonprofit;
+use crate::services::storage::types::AuditAction;
+use crate::services::storage::ExecOptsBuilder;
+
+/// Fetch the nonprofits in a cycle
+///
+/// * `ctx`: The application context extracted as Axum state
+/// * `project_cycle_id`: The ID of the cycle
+#[utoipa::path(
+    get,
+    path = "/cycle/{project_cycle_id}",
+    operation_id = "Get cycle nonprofits",
+    responses(
+        (status = 200, description = "Successfully fetched the active nonprofits in cycle"),
+        (status = 401, description = "Unauthorized: invalid JWT"),
+        (status = 403, description = "Forbidden: insufficient permissions (requires `read:nonprofits`)"),
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT. NOTE: Prefix with Bearer"),
+        ("project_cycle_id" = Uuid, Path, description = "The ID of the cycle")
+    ),
+)]
+pub async fn fetch_nonprofits_by_cycle<DB: Database>(
+    State(ctx): State<Arc<Services<DB>>>,
+    Path(project_cycle_id): Path<Uuid>,
+) -> Result<Response, AppError> {
+    let storage_layer = &ctx.storage_layer;
+    let nonprofits = storage_layer
+        .fetch_nonprofits_by_cycle(project_cycle_id, &mut ExecOptsBuilder::default().build()?)
+        .await?;
+
+    Ok(api_response::success(StatusCode::OK, NonprofitsResponse { nonprofits })?)
+}
+
+/// Fetch a nonprofit
+///
+/// * `ctx`: The application context extracted as Axum state
+/// * `id`: The ID of the nonprofit
+#[utoipa::path(
+    get,
+    path = "/{id}",
+    operation_id = "Get nonprofit",
+    responses(
+        (status = 200, description = "Successfully fetched nonprofit"),
+        (status = 401, description = "Unauthorized: invalid JWT"),
+        (status = 403, description = "Forbidden: insufficient permissions (requires `read:nonprofits`)"),
+        (status = 404, description = "Nonprofit not found"),
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT. NOTE: Prefix with Bearer"),
+        ("id" = Uuid, Path, description = "The ID of the nonprofit")
+    ),
+)]
+pub async fn fetch_nonprofit<DB: Database>(
+    State(ctx): State<Arc<Services<DB>>>,
+    Path(id): Path<Uuid>,
+) -> Result<Response, AppError> {
+    let storage_layer = &ctx.storage_layer;
+    let Some(nonprofit) =
+        storage_layer.fetch_nonprofit_by_id(id, &mut ExecOptsBuilder::default().build()?).await?
+    else {
+        return Ok(api_response::error(StatusCode::NOT_FOUND, "Nonprofit not found"));
+    };
+
+    Ok(api_response::success(StatusCode::OK, NonprofitResponse { nonprofit })?)
+}
+
+/// Edit a nonprofit
+///
+/// * `ctx`: The application context extracted as Axum state
+/// * `auth`: Auth data about the user
+/// * `id`: The ID of the nonprofit
+/// * `request`: The changes to make to the nonprofit
+#[utoipa::path(
+    patch,
+    path = "/{id}",
+    operation_id = "Edit nonprofit",
+    responses(
+        (status = 200, description = "Successfully edited nonprofit"),
+        (status = 400, description = "Invalid changes"),
+        (status = 401, description = "Unauthorized: invalid JWT"),
+        (status = 403, description = "Forbidden: insufficient permissions (requires `write:nonprofits`)"),
+        (status = 404, description = "Nonprofit not found"),
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT. NOTE: Prefix with Bearer"),
+        ("id" = Uuid, Path, description = "The ID of the nonprofit")
+    ),
+)]
+pub async fn edit_nonprofit<DB: Database>(
+    State(ctx): State<Arc<Services<DB>>>,
+    Extension(auth): Extension<AuthData>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<EditNonprofitRequest>,
+) -> Result<Response, AppError> {
+    let storage_layer = &ctx.storage_layer;
+
+    let required = [&request.email, &request.phone];
+    if required.iter().any(|field| field.as_deref().is_some_and(|f| f.trim().is_empty())) {
+        return Ok(api_response::error(StatusCode::BAD_REQUEST, "Email and phone cannot be empty"));
+    }
+
+    let audit = Audit::new(storage_layer, &auth, AuditAction::EditNonprofit)?
+        .target(id)
+        .summary(edited_fields(&request));
+
+    let mut tx = storage_layer.acquire().await?;
+    let mut exec_opts = ExecOptsBuilder::default().tx(&mut tx).build()?;
+
+    if storage_layer.fetch_nonprofit_by_id(id, &mut exec_opts).await?.is_none() {
+        return Ok(api_response::error(StatusCode::NOT_FOUND, "Nonprofit not found"));
+    }
+
+    let data = EditNonprofit {
+        email: request.email,
+        email_cc: request.email_cc,
+        phone: request.phone,
+        org_website: request.org_website,
+    };
+    let res = storage_layer.edit_nonprofit(id, data, &mut exec_opts).await;
+    audit.record(&res, &mut exec_opts).await?;
+    res?;
+
+    let nonprofit = storage_layer
+        .fetch_nonprofit_by_id(id, &mut exec_opts)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("nonprofit {id} not found after it was edited"))?;
+
+    tx.commit().await?;
+
+    Ok(api_response::success(StatusCode::OK, NonprofitResponse { nonprofit })?)
+}
+
+/// Delete a nonprofit
+///
+/// * `ctx`: The application context extracted as Axum state
+/// * `auth`: Auth data about the user
+/// * `id`: The ID of the nonprofit
+#[utoipa::path(
+    delete,
+    path = "/{id}",
+    operation_id = "Delete nonprofit",
+    responses(
+        (status = 204, description = "Successfully deleted nonprofit"),
+        (status = 401, description = "Unauthorized: invalid JWT"),
+        (status = 403, description = "Forbidden: insufficient permissions (requires `write:nonprofits`)"),
+        (status = 404, description = "Nonprofit not found"),
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT. NOTE: Prefix with Bearer"),
+        ("id" = Uuid, Path, description = "The ID of the nonprofit")
+    ),
+)]
+pub async fn delete_nonprofit<DB: Database>(
+    State(ctx): State<Arc<Services<DB>>>,
+    Extension(auth): Extension<AuthData>,
+    Path(id): Path<Uuid>,
+) -> Result<Response, AppError> {
+    let storage_layer = &ctx.storage_layer;
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+
+    if storage_layer.fetch_nonprofit_by_id(id, &mut exec_opts).await?.is_none() {
+        return Ok(api_response::error(StatusCode::NOT_FOUND, "Nonprofit not found"));
+    }
+
+    let audit = Audit::new(storage_layer, &auth, AuditAction::DeleteNonprofit)?.target(id);
+    let res = storage_layer.delete_nonprofit(id, &mut exec_opts).await;
+    audit.record(&res, &mut exec_opts).await?;
+    res?;
+
+    Ok(api_response::no_content())
+}
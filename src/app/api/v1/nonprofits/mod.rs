@@ -0,0 +1,52 @@
+//! Nonprofits API.
+
+use std::sync::Arc;
+
+use axum::middleware::from_fn_with_state;
+use axum::{routing, Router};
+use sqlx::Database;
+use utoipa::OpenApi;
+
+use crate::app::api::middleware::make_rbac;
+use crate::app::state::Services;
+
+mod controllers;
+mod requests;
+mod responses;
+
+/// Documents the API for managing nonprofits
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        controllers::fetch_nonprofits_by_cycle,
+        controllers::fetch_nonprofit,
+        controllers::edit_nonprofit,
+        controllers::delete_nonprofit,
+    ),
+    security(("http" = ["JWT"]))
+)]
+pub struct NonprofitsApi;
+
+/// Builds the nonprofits API.
+///
+/// * `ctx`: The application context
+pub async fn build<DB: Database>(ctx: Arc<Services<DB>>) -> Router<()> {
+    let read_guard = make_rbac(vec!["read:nonprofits".to_owned()]).await;
+    let write_guard = make_rbac(vec!["write:nonprofits".to_owned()]).await;
+
+    let fetch_nonprofits_by_cycle = routing::get(controllers::fetch_nonprofits_by_cycle::<DB>);
+    let fetch_nonprofit = routing::get(controllers::fetch_nonprofit::<DB>);
+    let edit_nonprofit = routing::patch(controllers::edit_nonprofit::<DB>);
+    let delete_nonprofit = routing::delete(controllers::delete_nonprofit::<DB>);
+
+    let write_routes = Router::new()
+        .route("/:id", edit_nonprofit.merge(delete_nonprofit))
+        .route_layer(from_fn_with_state(ctx.clone(), write_guard));
+
+    Router::new()
+        .route("/cycle/:project_cycle_id", fetch_nonprofits_by_cycle)
+        .route("/:id", fetch_nonprofit)
+        .route_layer(from_fn_with_state(ctx.clone(), read_guard))
+        .merge(write_routes)
+        .with_state(ctx.clone())
+}
@@ -0,0 +1,21 @@
+//! Requests for the nonprofits API.
+
+use serde::{Deserialize, Serialize};
+
+/// Request to edit a nonprofit.
+///
+/// Fields which are omitted are left unchanged.
+///
+/// * `email`: The new email of the nonprofit
+/// * `email_cc`: The new email to CC when emailing the nonprofit
+/// * `phone`: The new phone number of the nonprofit
+/// * `org_website`: The new website of the nonprofit
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EditNonprofitRequest {
+    pub email: Option<String>,
+    pub email_cc: Option<String>,
+    pub phone: Option<String>,
+    pub org_website: Option<String>,
+}
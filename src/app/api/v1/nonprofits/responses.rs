@@ -0,0 +1,21 @@
+//! Responses from the nonprofits API.
+
+use serde::{Deserialize, Serialize};
+
+use crate::services::storage::entities::NonprofitClientDetails;
+
+/// Nonprofits returned from the API.
+///
+/// * `nonprofits`: The nonprofits
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NonprofitsResponse {
+    pub nonprofits: Vec<NonprofitClientDetails>,
+}
+
+/// A single nonprofit returned from the API.
+///
+/// * `nonprofit`: The nonprofit
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NonprofitResponse {
+    pub nonprofit: NonprofitClientDetails,
+}
@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use axum::extract::{Path, State};
 use axum::Json;
+use sqlx::Database;
 use uuid::Uuid;
 
 use crate::app::errors::AppError;
@@ -22,8 +23,8 @@ use crate::services::storage::ExecOptsBuilder;
         ("Authorization" = String, Header, description = "JWT. NOTE: Prefix with Bearer")
     ),
 )]
-pub async fn fetch_basic_stats(
-    State(ctx): State<Arc<Services>>,
+pub async fn fetch_basic_stats<DB: Database>(
+    State(ctx): State<Arc<Services<DB>>>,
     Path(project_cycle_id): Path<Uuid>,
 ) -> Result<Json<BasicStats>, AppError> {
     let storage_layer = &ctx.storage_layer;
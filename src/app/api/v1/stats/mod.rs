@@ -5,6 +5,7 @@ use std::sync::Arc;
 
 use axum::middleware::from_fn_with_state;
 use axum::{routing, Router};
+use sqlx::Database;
 use utoipa::OpenApi;
 
 use crate::app::api::middleware::make_rbac;
@@ -19,10 +20,10 @@ use crate::app::state::Services;
 )]
 pub struct StatsApi;
 
-pub async fn build(ctx: Arc<Services>) -> Router<()> {
+pub async fn build<DB: Database>(ctx: Arc<Services<DB>>) -> Router<()> {
     let read_guard = make_rbac(vec!["read:stats".to_owned()]).await;
 
-    let fetch_basic_stats = routing::get(controllers::fetch_basic_stats);
+    let fetch_basic_stats = routing::get(controllers::fetch_basic_stats::<DB>);
 
     Router::new()
         .route("/:project_cycle_id/basic", fetch_basic_stats)
@@ -0,0 +1,433 @@
+//! Controllers for the team roles API.
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::response::Response;
+use axum::{Extension, Json};
+use serde_json::json;
+use sqlx::Database;
+use uuid::Uuid;
+
+use crate::app::api::v1::team_roles::requests::{
+    CreateTeamRoleRequest, EditTeamRoleRequest, FetchTeamRoleVolunteersQuery,
+};
+use crate::app::api::v1::team_roles::responses::{
+    TeamRoleResponse, TeamRoleVolunteerResponse, TeamRoleVolunteersResponse, TeamRolesResponse,
+};
+use crate::app::api_response;
+use crate::app::audit::{edited_fields, Audit};
+use crate::app::errors::{is_unique_violation, AppError};
+use crate::app::state::Services;
+use crate::services::auth::AuthData;
+use crate::services::storage::team_roles::{CreateTeamRole, EditTeamRole};
+use crate::services::storage::types::AuditAction;
+use crate::services::storage::ExecOptsBuilder;
+
+/// Fetch the catalog of team roles
+///
+/// * `ctx`: The application context extracted as Axum state
+#[utoipa::path(
+    get,
+    path = "",
+    operation_id = "Get team roles",
+    responses(
+        (status = 200, description = "Successfully fetched team roles"),
+        (status = 401, description = "Unauthorized: invalid JWT"),
+        (status = 403, description = "Forbidden: insufficient permissions (requires `read:team-roles`)"),
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT. NOTE: Prefix with Bearer")
+    ),
+)]
+pub async fn fetch_team_roles<DB: Database>(
+    State(ctx): State<Arc<Services<DB>>>,
+) -> Result<Response, AppError> {
+    let roles =
+        ctx.storage_layer.fetch_team_roles(&mut ExecOptsBuilder::default().build()?).await?;
+
+    Ok(api_response::success(StatusCode::OK, TeamRolesResponse { roles })?)
+}
+
+/// Fetch a team role
+///
+/// * `ctx`: The application context extracted as Axum state
+/// * `id`: The ID of the role
+#[utoipa::path(
+    get,
+    path = "/{id}",
+    operation_id = "Get team role",
+    responses(
+        (status = 200, description = "Successfully fetched team role"),
+        (status = 401, description = "Unauthorized: invalid JWT"),
+        (status = 403, description = "Forbidden: insufficient permissions (requires `read:team-roles`)"),
+        (status = 404, description = "Team role not found"),
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT. NOTE: Prefix with Bearer"),
+        ("id" = Uuid, Path, description = "The ID of the team role")
+    ),
+)]
+pub async fn fetch_team_role<DB: Database>(
+    State(ctx): State<Arc<Services<DB>>>,
+    Path(id): Path<Uuid>,
+) -> Result<Response, AppError> {
+    let Some(role) = ctx
+        .storage_layer
+        .fetch_team_role_by_id(id, &mut ExecOptsBuilder::default().build()?)
+        .await?
+    else {
+        return Ok(api_response::error(StatusCode::NOT_FOUND, "Team role not found"));
+    };
+
+    Ok(api_response::success(StatusCode::OK, TeamRoleResponse { role })?)
+}
+
+/// Create a team role
+///
+/// * `ctx`: The application context extracted as Axum state
+/// * `auth`: Auth data about the user
+/// * `request`: The role to create
+#[utoipa::path(
+    post,
+    path = "",
+    operation_id = "Create team role",
+    responses(
+        (status = 201, description = "Successfully created team role"),
+        (status = 400, description = "Invalid team role"),
+        (status = 401, description = "Unauthorized: invalid JWT"),
+        (status = 403, description = "Forbidden: insufficient permissions (requires `write:team-roles`)"),
+        (status = 409, description = "A team role with the same name already exists"),
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT. NOTE: Prefix with Bearer")
+    ),
+)]
+pub async fn create_team_role<DB: Database>(
+    State(ctx): State<Arc<Services<DB>>>,
+    Extension(auth): Extension<AuthData>,
+    Json(request): Json<CreateTeamRoleRequest>,
+) -> Result<Response, AppError> {
+    let storage_layer = &ctx.storage_layer;
+
+    let name = request.name.trim();
+    if name.is_empty() {
+        return Ok(api_response::error(StatusCode::BAD_REQUEST, "Team role name cannot be empty"));
+    }
+
+    let mut tx = storage_layer.acquire().await?;
+    let mut exec_opts = ExecOptsBuilder::default().tx(&mut tx).build()?;
+
+    let mut audit = Audit::new(storage_layer, &auth, AuditAction::CreateTeamRole)?
+        .summary(json!({ "name": name }));
+
+    let data = CreateTeamRole { name: name.to_owned(), description: request.description };
+    let res = storage_layer.create_team_role(data, &mut exec_opts).await;
+    if let Ok(id) = &res {
+        audit = audit.target(*id);
+    }
+    audit.record(&res, &mut exec_opts).await?;
+
+    let id = match res {
+        Ok(id) => id,
+        Err(e) if is_unique_violation(&e) => {
+            return Ok(api_response::error(
+                StatusCode::CONFLICT,
+                "A team role with the same name already exists",
+            ));
+        }
+        Err(e) => return Err(e.into()),
+    };
+
+    let role = storage_layer
+        .fetch_team_role_by_id(id, &mut exec_opts)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("team role {id} not found after it was created"))?;
+
+    tx.commit().await?;
+
+    Ok(api_response::success(StatusCode::CREATED, TeamRoleResponse { role })?)
+}
+
+/// Edit a team role
+///
+/// * `ctx`: The application context extracted as Axum state
+/// * `auth`: Auth data about the user
+/// * `id`: The ID of the role to edit
+/// * `request`: The changes to make to the role
+#[utoipa::path(
+    patch,
+    path = "/{id}",
+    operation_id = "Edit team role",
+    responses(
+        (status = 200, description = "Successfully edited team role"),
+        (status = 400, description = "Invalid changes"),
+        (status = 401, description = "Unauthorized: invalid JWT"),
+        (status = 403, description = "Forbidden: insufficient permissions (requires `write:team-roles`)"),
+        (status = 404, description = "Team role not found"),
+        (status = 409, description = "A team role with the same name already exists"),
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT. NOTE: Prefix with Bearer"),
+        ("id" = Uuid, Path, description = "The ID of the team role")
+    ),
+)]
+pub async fn edit_team_role<DB: Database>(
+    State(ctx): State<Arc<Services<DB>>>,
+    Extension(auth): Extension<AuthData>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<EditTeamRoleRequest>,
+) -> Result<Response, AppError> {
+    let storage_layer = &ctx.storage_layer;
+    let audit = Audit::new(storage_layer, &auth, AuditAction::EditTeamRole)?
+        .target(id)
+        .summary(edited_fields(&request));
+
+    let name = request.name.map(|name| name.trim().to_owned());
+    if name.as_deref().is_some_and(str::is_empty) {
+        return Ok(api_response::error(StatusCode::BAD_REQUEST, "Team role name cannot be empty"));
+    }
+
+    let data = EditTeamRole { name, description: request.description };
+
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+    let res = storage_layer.edit_team_role(id, data, &mut exec_opts).await;
+    // Nothing happened if the role does not exist
+    if !matches!(res, Ok(None)) {
+        audit.record(&res, &mut exec_opts).await?;
+    }
+
+    let role = match res {
+        Ok(Some(role)) => role,
+        Ok(None) => return Ok(api_response::error(StatusCode::NOT_FOUND, "Team role not found")),
+        Err(e) if is_unique_violation(&e) => {
+            return Ok(api_response::error(
+                StatusCode::CONFLICT,
+                "A team role with the same name already exists",
+            ));
+        }
+        Err(e) => return Err(e.into()),
+    };
+
+    Ok(api_response::success(StatusCode::OK, TeamRoleResponse { role })?)
+}
+
+/// Delete a team role
+///
+/// Deleting a role unassigns it from every volunteer who holds it.
+///
+/// * `ctx`: The application context extracted as Axum state
+/// * `auth`: Auth data about the user
+/// * `id`: The ID of the role to delete
+#[utoipa::path(
+    delete,
+    path = "/{id}",
+    operation_id = "Delete team role",
+    responses(
+        (status = 204, description = "Successfully deleted team role"),
+        (status = 401, description = "Unauthorized: invalid JWT"),
+        (status = 403, description = "Forbidden: insufficient permissions (requires `write:team-roles`)"),
+        (status = 404, description = "Team role not found"),
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT. NOTE: Prefix with Bearer"),
+        ("id" = Uuid, Path, description = "The ID of the team role")
+    ),
+)]
+pub async fn delete_team_role<DB: Database>(
+    State(ctx): State<Arc<Services<DB>>>,
+    Extension(auth): Extension<AuthData>,
+    Path(id): Path<Uuid>,
+) -> Result<Response, AppError> {
+    let storage_layer = &ctx.storage_layer;
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+
+    if storage_layer.fetch_team_role_by_id(id, &mut exec_opts).await?.is_none() {
+        return Ok(api_response::error(StatusCode::NOT_FOUND, "Team role not found"));
+    }
+
+    let audit = Audit::new(storage_layer, &auth, AuditAction::DeleteTeamRole)?.target(id);
+    let res = storage_layer.delete_team_role(id, &mut exec_opts).await;
+    audit.record(&res, &mut exec_opts).await?;
+    res?;
+
+    Ok(api_response::no_content())
+}
+
+/// Fetch the volunteers who hold a team role
+///
+/// * `ctx`: The application context extracted as Axum state
+/// * `id`: The ID of the role
+/// * `query`: Filters for the volunteers
+#[utoipa::path(
+    get,
+    path = "/{id}/volunteers",
+    operation_id = "Get team role volunteers",
+    responses(
+        (status = 200, description = "Successfully fetched volunteers with team role"),
+        (status = 401, description = "Unauthorized: invalid JWT"),
+        (status = 403, description = "Forbidden: insufficient permissions (requires `read:team-roles`)"),
+        (status = 404, description = "Team role not found"),
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT. NOTE: Prefix with Bearer"),
+        ("id" = Uuid, Path, description = "The ID of the team role"),
+        ("cycleId" = Option<Uuid>, Query, description = "Only volunteers holding the role in this project cycle"),
+    ),
+)]
+pub async fn fetch_team_role_volunteers<DB: Database>(
+    State(ctx): State<Arc<Services<DB>>>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<FetchTeamRoleVolunteersQuery>,
+) -> Result<Response, AppError> {
+    let storage_layer = &ctx.storage_layer;
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+
+    if storage_layer.fetch_team_role_by_id(id, &mut exec_opts).await?.is_none() {
+        return Ok(api_response::error(StatusCode::NOT_FOUND, "Team role not found"));
+    }
+
+    let volunteers =
+        storage_layer.fetch_volunteers_by_team_role(id, query.cycle_id, &mut exec_opts).await?;
+
+    Ok(api_response::success(StatusCode::OK, TeamRoleVolunteersResponse { volunteers })?)
+}
+
+/// Assign a team role to a volunteer
+///
+/// The volunteer holds the role in the project cycle they belong to. Assigning a role which the
+/// volunteer already holds is reported as a conflict, and is not audited.
+///
+/// * `ctx`: The application context extracted as Axum state
+/// * `auth`: Auth data about the user
+/// * `id`: The ID of the role
+/// * `volunteer_id`: The ID of the volunteer
+#[utoipa::path(
+    put,
+    path = "/{id}/volunteers/{volunteer_id}",
+    operation_id = "Assign team role",
+    responses(
+        (status = 200, description = "Successfully assigned team role"),
+        (status = 401, description = "Unauthorized: invalid JWT"),
+        (status = 403, description = "Forbidden: insufficient permissions (requires `write:volunteers`)"),
+        (status = 404, description = "Team role or volunteer not found"),
+        (status = 409, description = "The volunteer already holds the team role"),
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT. NOTE: Prefix with Bearer"),
+        ("id" = Uuid, Path, description = "The ID of the team role"),
+        ("volunteer_id" = Uuid, Path, description = "The ID of the volunteer"),
+    ),
+)]
+pub async fn assign_team_role<DB: Database>(
+    State(ctx): State<Arc<Services<DB>>>,
+    Extension(auth): Extension<AuthData>,
+    Path((id, volunteer_id)): Path<(Uuid, Uuid)>,
+) -> Result<Response, AppError> {
+    let storage_layer = &ctx.storage_layer;
+
+    let mut tx = storage_layer.acquire().await?;
+    let mut exec_opts = ExecOptsBuilder::default().tx(&mut tx).build()?;
+
+    if storage_layer.fetch_team_role_by_id(id, &mut exec_opts).await?.is_none() {
+        return Ok(api_response::error(StatusCode::NOT_FOUND, "Team role not found"));
+    }
+
+    let Some(volunteer) = storage_layer.fetch_volunteer_by_id(volunteer_id, &mut exec_opts).await?
+    else {
+        return Ok(api_response::error(StatusCode::NOT_FOUND, "Volunteer not found"));
+    };
+
+    let audit = Audit::new(storage_layer, &auth, AuditAction::AssignTeamRole)?
+        .target(id)
+        .target(volunteer_id)
+        .target(volunteer.project_cycle_id);
+    let res = storage_layer
+        .assign_team_role(volunteer.project_cycle_id, volunteer_id, id, &mut exec_opts)
+        .await;
+    // Nothing happened if the volunteer already held the role
+    if !matches!(res, Ok(false)) {
+        audit.record(&res, &mut exec_opts).await?;
+    }
+    let assigned = res?;
+
+    if !assigned {
+        return Ok(api_response::error(
+            StatusCode::CONFLICT,
+            "Volunteer already holds the team role",
+        ));
+    }
+
+    let volunteer =
+        storage_layer.fetch_volunteer_by_id(volunteer_id, &mut exec_opts).await?.ok_or_else(
+            || anyhow::anyhow!("volunteer {volunteer_id} not found after assigning role"),
+        )?;
+
+    tx.commit().await?;
+
+    Ok(api_response::success(StatusCode::OK, TeamRoleVolunteerResponse { volunteer })?)
+}
+
+/// Unassign a team role from a volunteer
+///
+/// * `ctx`: The application context extracted as Axum state
+/// * `auth`: Auth data about the user
+/// * `id`: The ID of the role
+/// * `volunteer_id`: The ID of the volunteer
+#[utoipa::path(
+    delete,
+    path = "/{id}/volunteers/{volunteer_id}",
+    operation_id = "Unassign team role",
+    responses(
+        (status = 204, description = "Successfully unassigned team role"),
+        (status = 401, description = "Unauthorized: invalid JWT"),
+        (status = 403, description = "Forbidden: insufficient permissions (requires `write:volunteers`)"),
+        (status = 404, description = "Team role or volunteer not found, or the volunteer does not hold the team role"),
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT. NOTE: Prefix with Bearer"),
+        ("id" = Uuid, Path, description = "The ID of the team role"),
+        ("volunteer_id" = Uuid, Path, description = "The ID of the volunteer"),
+    ),
+)]
+pub async fn unassign_team_role<DB: Database>(
+    State(ctx): State<Arc<Services<DB>>>,
+    Extension(auth): Extension<AuthData>,
+    Path((id, volunteer_id)): Path<(Uuid, Uuid)>,
+) -> Result<Response, AppError> {
+    let storage_layer = &ctx.storage_layer;
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+
+    if storage_layer.fetch_team_role_by_id(id, &mut exec_opts).await?.is_none() {
+        return Ok(api_response::error(StatusCode::NOT_FOUND, "Team role not found"));
+    }
+
+    let Some(volunteer) = storage_layer.fetch_volunteer_by_id(volunteer_id, &mut exec_opts).await?
+    else {
+        return Ok(api_response::error(StatusCode::NOT_FOUND, "Volunteer not found"));
+    };
+
+    let audit = Audit::new(storage_layer, &auth, AuditAction::UnassignTeamRole)?
+        .target(id)
+        .target(volunteer_id)
+        .target(volunteer.project_cycle_id);
+    let res = storage_layer
+        .unassign_team_role(volunteer.project_cycle_id, volunteer_id, id, &mut exec_opts)
+        .await;
+    // Nothing happened if the volunteer did not hold the role
+    if !matches!(res, Ok(false)) {
+        audit.record(&res, &mut exec_opts).await?;
+    }
+    let unassigned = res?;
+
+    if !unassigned {
+        return Ok(api_response::error(
+            StatusCode::NOT_FOUND,
+            "Volunteer does not hold the team role",
+        ));
+    }
+
+    Ok(api_response::no_content())
+}
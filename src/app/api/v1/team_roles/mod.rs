@@ -0,0 +1,71 @@
+//! Team roles API.
+//!
+//! Team roles are the roles a volunteer can hold on a project, e.g. `engineer` or
+//! `product_manager`. This API manages the catalog of roles and which volunteers hold them.
+
+use std::sync::Arc;
+
+use axum::middleware::from_fn_with_state;
+use axum::{routing, Router};
+use sqlx::Database;
+use utoipa::OpenApi;
+
+use crate::app::api::middleware::make_rbac;
+use crate::app::state::Services;
+
+mod controllers;
+mod requests;
+mod responses;
+
+/// Documents the API for managing team roles
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        controllers::fetch_team_roles,
+        controllers::fetch_team_role,
+        controllers::create_team_role,
+        controllers::edit_team_role,
+        controllers::delete_team_role,
+        controllers::fetch_team_role_volunteers,
+        controllers::assign_team_role,
+        controllers::unassign_team_role,
+    ),
+    security(("http" = ["JWT"]))
+)]
+pub struct TeamRolesApi;
+
+/// Builds the team roles API.
+///
+/// * `ctx`: The application context
+pub async fn build<DB: Database>(ctx: Arc<Services<DB>>) -> Router<()> {
+    let read_guard = make_rbac(vec!["read:team-roles".to_owned()]).await;
+    let write_guard = make_rbac(vec!["write:team-roles".to_owned()]).await;
+    let assign_guard = make_rbac(vec!["write:volunteers".to_owned()]).await;
+
+    let fetch_team_roles = routing::get(controllers::fetch_team_roles::<DB>);
+    let fetch_team_role = routing::get(controllers::fetch_team_role::<DB>);
+    let create_team_role = routing::post(controllers::create_team_role::<DB>);
+    let edit_team_role = routing::patch(controllers::edit_team_role::<DB>);
+    let delete_team_role = routing::delete(controllers::delete_team_role::<DB>);
+    let fetch_team_role_volunteers = routing::get(controllers::fetch_team_role_volunteers::<DB>);
+    let assign_team_role = routing::put(controllers::assign_team_role::<DB>);
+    let unassign_team_role = routing::delete(controllers::unassign_team_role::<DB>);
+
+    let write_routes = Router::new()
+        .route("/", create_team_role)
+        .route("/:id", edit_team_role.merge(delete_team_role))
+        .route_layer(from_fn_with_state(ctx.clone(), write_guard));
+
+    let assign_routes = Router::new()
+        .route("/:id/volunteers/:volunteer_id", assign_team_role.merge(unassign_team_role))
+        .route_layer(from_fn_with_state(ctx.clone(), assign_guard));
+
+    Router::new()
+        .route("/", fetch_team_roles)
+        .route("/:id", fetch_team_role)
+        .route("/:id/volunteers", fetch_team_role_volunteers)
+        .route_layer(from_fn_with_state(ctx.clone(), read_guard))
+        .merge(write_routes)
+        .merge(assign_routes)
+        .with_state(ctx.clone())
+}
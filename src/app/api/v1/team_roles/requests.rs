@@ -0,0 +1,38 @@
+//! Requests for the team roles API.
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Request to create a team role.
+///
+/// * `name`: The name of the role, e.g. `product_manager`. It must be unique.
+/// * `description`: A description of the role
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateTeamRoleRequest {
+    pub name: String,
+    pub description: String,
+}
+
+/// Request to edit a team role.
+///
+/// Fields which are omitted are left unchanged.
+///
+/// * `name`: The new name of the role. It must be unique.
+/// * `description`: The new description of the role
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EditTeamRoleRequest {
+    pub name: Option<String>,
+    pub description: Option<String>,
+}
+
+/// Query parameters for fetching the volunteers who hold a team role.
+///
+/// * `cycle_id`: Only volunteers holding the role in this project cycle
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FetchTeamRoleVolunteersQuery {
+    pub cycle_id: Option<Uuid>,
+}
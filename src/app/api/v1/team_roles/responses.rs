@@ -0,0 +1,37 @@
+//! Responses from the team roles API.
+
+use serde::{Deserialize, Serialize};
+
+use crate::services::storage::entities::{TeamRole, VolunteerDetails};
+
+/// Team roles returned from the API.
+///
+/// * `roles`: The team roles
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TeamRolesResponse {
+    pub roles: Vec<TeamRole>,
+}
+
+/// A single team role returned from the API.
+///
+/// * `role`: The team role
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TeamRoleResponse {
+    pub role: TeamRole,
+}
+
+/// Volunteers who hold a team role.
+///
+/// * `volunteers`: The volunteers
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TeamRoleVolunteersResponse {
+    pub volunteers: Vec<VolunteerDetails>,
+}
+
+/// A volunteer whose team roles were changed.
+///
+/// * `volunteer`: The volunteer, including their roles
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TeamRoleVolunteerResponse {
+    pub volunteer: VolunteerDetails,
+}
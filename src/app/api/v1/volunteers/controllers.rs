@@ -1,20 +1,99 @@
 use std::sync::Arc;
 
-use axum::extract::{Path, State};
-use axum::Json;
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::response::Response;
+use axum::{Extension, Json};
+use serde_json::Value;
+use sqlx::Database;
 use uuid::Uuid;
 
-use crate::app::api::v1::volunteers::responses::Volunteers;
-use crate::app::errors::AppError;
+use crate::app::api::v1::volunteers::requests::{EditVolunteerRequest, FetchVolunteersQuery};
+use crate::app::api::v1::volunteers::responses::{VolunteerResponse, Volunteers, VolunteersPage};
+use crate::app::api_response;
+use crate::app::audit::{edited_fields, Audit};
+use crate::app::errors::{is_unique_violation, AppError};
 use crate::app::state::Services;
+use crate::services::auth::AuthData;
+use crate::services::storage::pagination::PageOptions;
+use crate::services::storage::types::AuditAction;
+use crate::services::storage::volunteers::VolunteerFilter;
 use crate::services::storage::ExecOptsBuilder;
 
 #[utoipa::path(
     get,
-    path = "/{project_cycle_id}",
+    path = "/",
+    operation_id = "Get volunteers",
+    responses(
+        (status = 200, description = "Successfully fetched a page of volunteers"),
+        (status = 400, description = "Invalid query parameters, or a cursor which is not the ID of a volunteer"),
+        (status = 401, description = "Unauthorized: invalid JWT"),
+        (status = 403, description = "Forbidden: insufficient permissions (requires `read:volunteers`)"),
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT. NOTE: Prefix with Bearer"),
+        ("cycleId" = Option<Uuid>, Query, description = "Only volunteers in this project cycle"),
+        ("nonprofitId" = Option<Uuid>, Query, description = "Only volunteers working with this nonprofit"),
+        ("mentorId" = Option<Uuid>, Query, description = "Only volunteers mentored by this mentor"),
+        ("role" = Option<String>, Query, description = "Only volunteers with this team role, e.g. `productManager`"),
+        ("exported" = Option<bool>, Query, description = "Only volunteers who have (or have not) been exported to a workspace"),
+        ("university" = Option<String>, Query, description = "Only volunteers who attend this university"),
+        ("studentStage" = Option<String>, Query, description = "Only volunteers at this student stage"),
+        ("search" = Option<String>, Query, description = "Only volunteers whose name, email, or workspace email contains this text"),
+        ("sortBy" = Option<String>, Query, description = "One of `createdAt`, `firstName`, `lastName`, or `email`"),
+        ("sortDirection" = Option<String>, Query, description = "`asc` or `desc`"),
+        ("includeInactive" = Option<bool>, Query, description = "Also return volunteers which a sync marked inactive"),
+        ("cursor" = Option<Uuid>, Query, description = "The `nextCursor` of the previous page"),
+        ("limit" = Option<i64>, Query, description = "The maximum number of volunteers to return (at most 500)"),
+    ),
+)]
+pub async fn fetch_volunteers<DB: Database>(
+    State(ctx): State<Arc<Services<DB>>>,
+    Query(query): Query<FetchVolunteersQuery>,
+) -> Result<Response, AppError> {
+    let storage_layer = &ctx.storage_layer;
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+
+    // Pages are found by comparing against the cursor's row, so an unknown cursor matches nothing
+    if let Some(cursor) = query.cursor {
+        if storage_layer.fetch_volunteer_by_id(cursor, &mut exec_opts).await?.is_none() {
+            return Ok(api_response::error(
+                StatusCode::BAD_REQUEST,
+                "The cursor is not the ID of a volunteer",
+            ));
+        }
+    }
+
+    let filter = VolunteerFilter {
+        project_cycle_id: query.cycle_id,
+        nonprofit_id: query.nonprofit_id,
+        mentor_id: query.mentor_id,
+        role: query.role,
+        exported: query.exported,
+        university: query.university,
+        student_stage: query.student_stage,
+        search: query.search,
+        sort_by: query.sort_by,
+        sort_direction: query.sort_direction,
+        include_inactive: query.include_inactive,
+    };
+
+    let page = PageOptions::new(query.cursor, query.limit);
+
+    let page = storage_layer.fetch_volunteers_filtered(filter, page, &mut exec_opts).await?;
+
+    Ok(api_response::success(
+        StatusCode::OK,
+        VolunteersPage { volunteers: page.items, next_cursor: page.next_cursor },
+    )?)
+}
+
+#[utoipa::path(
+    get,
+    path = "/cycle/{project_cycle_id}",
     operation_id = "Get cycle volunteers",
     responses(
-        (status = 200, description = "Successfully fetched volunteers in cycle"),
+        (status = 200, description = "Successfully fetched the active volunteers in cycle"),
         (status = 401, description = "Unauthorized: invalid JWT"),
         (status = 403, description = "Forbidden: insufficient permissions (requires `fetch:cycle`)"),
     ),
@@ -22,8 +101,8 @@ use crate::services::storage::ExecOptsBuilder;
         ("Authorization" = String, Header, description = "JWT. NOTE: Prefix with Bearer")
     ),
 )]
-pub async fn fetch_volunteers_by_cycle(
-    State(ctx): State<Arc<Services>>,
+pub async fn fetch_volunteers_by_cycle<DB: Database>(
+    State(ctx): State<Arc<Services<DB>>>,
     Path(project_cycle_id): Path<Uuid>,
 ) -> Result<Json<Volunteers>, AppError> {
     let storage_layer = &ctx.storage_layer;
@@ -33,3 +112,113 @@ pub async fn fetch_volunteers_by_cycle(
 
     Ok(Json(Volunteers { volunteers: data }))
 }
+
+#[utoipa::path(
+    patch,
+    path = "/{id}",
+    operation_id = "Edit volunteer",
+    responses(
+        (status = 200, description = "Successfully edited volunteer"),
+        (status = 400, description = "Invalid changes, e.g. an unknown value for an enum field"),
+        (status = 401, description = "Unauthorized: invalid JWT"),
+        (status = 403, description = "Forbidden: insufficient permissions (requires `write:volunteers`)"),
+        (status = 404, description = "Volunteer not found"),
+        (status = 409, description = "A volunteer with the same email already exists"),
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT. NOTE: Prefix with Bearer"),
+        ("id" = Uuid, Path, description = "The ID of the volunteer")
+    ),
+)]
+pub async fn edit_volunteer<DB: Database>(
+    State(ctx): State<Arc<Services<DB>>>,
+    Extension(auth): Extension<AuthData>,
+    Path(id): Path<Uuid>,
+    Json(body): Json<Value>,
+) -> Result<Response, AppError> {
+    let storage_layer = &ctx.storage_layer;
+
+    // Deserialize by hand so that an unknown enum value is reported as a bad request, along with
+    // the values which are allowed
+    let request = match serde_json::from_value::<EditVolunteerRequest>(body) {
+        Ok(request) => request,
+        Err(e) => {
+            return Ok(api_response::error(
+                StatusCode::BAD_REQUEST,
+                &format!("Invalid volunteer: {e}"),
+            ))
+        }
+    };
+
+    if let Err(msg) = request.validate() {
+        return Ok(api_response::error(StatusCode::BAD_REQUEST, &msg));
+    }
+
+    let audit = Audit::new(storage_layer, &auth, AuditAction::EditVolunteer)?
+        .target(id)
+        .summary(edited_fields(&request));
+
+    let mut tx = storage_layer.acquire().await?;
+    let mut exec_opts = ExecOptsBuilder::default().tx(&mut tx).build()?;
+
+    if storage_layer.fetch_volunteer_by_id(id, &mut exec_opts).await?.is_none() {
+        return Ok(api_response::error(StatusCode::NOT_FOUND, "Volunteer not found"));
+    }
+
+    let res = storage_layer.edit_volunteer(id, request.into(), &mut exec_opts).await;
+    audit.record(&res, &mut exec_opts).await?;
+    match res {
+        Ok(_) => {}
+        Err(e) if is_unique_violation(&e) => {
+            return Ok(api_response::error(
+                StatusCode::CONFLICT,
+                "A volunteer with the same email already exists",
+            ));
+        }
+        Err(e) => return Err(e.into()),
+    }
+
+    let volunteer = storage_layer
+        .fetch_volunteer_by_id(id, &mut exec_opts)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("volunteer {id} not found after it was edited"))?;
+
+    tx.commit().await?;
+
+    Ok(api_response::success(StatusCode::OK, VolunteerResponse { volunteer })?)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/{id}",
+    operation_id = "Delete volunteer",
+    responses(
+        (status = 204, description = "Successfully deleted volunteer"),
+        (status = 401, description = "Unauthorized: invalid JWT"),
+        (status = 403, description = "Forbidden: insufficient permissions (requires `write:volunteers`)"),
+        (status = 404, description = "Volunteer not found"),
+    ),
+    params(
+        ("Authorization" = String, Header, description = "JWT. NOTE: Prefix with Bearer"),
+        ("id" = Uuid, Path, description = "The ID of the volunteer")
+    ),
+)]
+pub async fn delete_volunteer<DB: Database>(
+    State(ctx): State<Arc<Services<DB>>>,
+    Extension(auth): Extension<AuthData>,
+    Path(id): Path<Uuid>,
+) -> Result<Response, AppError> {
+    let storage_layer = &ctx.storage_layer;
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+
+    if storage_layer.fetch_volunteer_by_id(id, &mut exec_opts).await?.is_none() {
+        return Ok(api_response::error(StatusCode::NOT_FOUND, "Volunteer not found"));
+    }
+
+    let audit = Audit::new(storage_layer, &auth, AuditAction::DeleteVolunteer)?.target(id);
+    let res = storage_layer.delete_volunteer(id, &mut exec_opts).await;
+    audit.record(&res, &mut exec_opts).await?;
+    res?;
+
+    Ok(api_response::no_content())
+}
@@ -2,30 +2,45 @@ use std::sync::Arc;
 
 use axum::middleware::from_fn_with_state;
 use axum::{routing, Router};
+use sqlx::Database;
 use utoipa::OpenApi;
 
 use crate::app::api::middleware::make_rbac;
 use crate::app::state::Services;
 
 mod controllers;
+mod requests;
 mod responses;
 
 #[derive(OpenApi)]
 #[openapi(
     paths(
-        controllers::fetch_volunteers_by_cycle
+        controllers::fetch_volunteers,
+        controllers::fetch_volunteers_by_cycle,
+        controllers::edit_volunteer,
+        controllers::delete_volunteer,
     ),
     security(("http" = ["JWT"]))
 )]
 pub struct VolunteersApi;
 
-pub async fn build(ctx: Arc<Services>) -> Router<()> {
+pub async fn build<DB: Database>(ctx: Arc<Services<DB>>) -> Router<()> {
     let guard1 = make_rbac(vec!["read:volunteers".to_owned()]).await;
+    let write_guard = make_rbac(vec!["write:volunteers".to_owned()]).await;
 
-    let fetch_volunteers_by_cycle = routing::get(controllers::fetch_volunteers_by_cycle);
+    let fetch_volunteers = routing::get(controllers::fetch_volunteers::<DB>);
+    let fetch_volunteers_by_cycle = routing::get(controllers::fetch_volunteers_by_cycle::<DB>);
+    let edit_volunteer = routing::patch(controllers::edit_volunteer::<DB>);
+    let delete_volunteer = routing::delete(controllers::delete_volunteer::<DB>);
+
+    let write_routes = Router::new()
+        .route("/:id", edit_volunteer.merge(delete_volunteer))
+        .route_layer(from_fn_with_state(ctx.clone(), write_guard));
 
     Router::new()
-        .route("/:project_cycle_id", fetch_volunteers_by_cycle)
+        .route("/", fetch_volunteers)
+        .route("/cycle/:project_cycle_id", fetch_volunteers_by_cycle)
         .route_layer(from_fn_with_state(ctx.clone(), guard1))
+        .merge(write_routes)
         .with_state(ctx.clone())
 }
@@ -0,0 +1,124 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::services::storage::pagination::SortDirection;
+use crate::services::storage::types::{
+    AgeRange, Ethnicity, Fli, Gender, Lgbt, StudentStage, VolunteerHearAbout, VolunteerRole,
+};
+use crate::services::storage::volunteers::{EditVolunteer, VolunteerSortKey};
+
+/// Query parameters for fetching volunteers.
+///
+/// Every filter is optional.
+///
+/// * `cycle_id`: Only volunteers in this project cycle
+/// * `nonprofit_id`: Only volunteers working with this nonprofit
+/// * `mentor_id`: Only volunteers mentored by this mentor
+/// * `role`: Only volunteers with this team role
+/// * `exported`: Only volunteers who have (or have not) been exported to a workspace
+/// * `university`: Only volunteers who attend this university
+/// * `student_stage`: Only volunteers at this student stage
+/// * `search`: Only volunteers whose name, email, or workspace email contains this text
+/// * `sort_by`: The field to sort by (defaults to `createdAt`)
+/// * `sort_direction`: `asc` or `desc` (defaults to `asc`)
+/// * `include_inactive`: Also return volunteers which a sync marked inactive
+/// * `cursor`: The `nextCursor` of the previous page
+/// * `limit`: The maximum number of volunteers to return (defaults to 50, at most 500)
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FetchVolunteersQuery {
+    pub cycle_id: Option<Uuid>,
+    pub nonprofit_id: Option<Uuid>,
+    pub mentor_id: Option<Uuid>,
+    pub role: Option<VolunteerRole>,
+    pub exported: Option<bool>,
+    pub university: Option<String>,
+    pub student_stage: Option<StudentStage>,
+    pub search: Option<String>,
+    #[serde(default)]
+    pub sort_by: VolunteerSortKey,
+    #[serde(default)]
+    pub sort_direction: SortDirection,
+    #[serde(default)]
+    pub include_inactive: bool,
+    pub cursor: Option<Uuid>,
+    pub limit: Option<i64>,
+}
+
+/// Request to edit a volunteer.
+///
+/// Fields which are omitted are left unchanged. `phone` and `usState` are cleared when they are
+/// `null`. Enum fields take the same values as the corresponding Airtable fields (e.g.
+/// `"Prefer not to say"`).
+#[serde_with::skip_serializing_none]
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct EditVolunteerRequest {
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub email: Option<String>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub phone: Option<Option<String>>,
+    pub volunteer_gender: Option<Gender>,
+    pub volunteer_ethnicity: Option<Vec<Ethnicity>>,
+    pub volunteer_age_range: Option<AgeRange>,
+    pub university: Option<Vec<String>>,
+    pub lgbt: Option<Lgbt>,
+    pub country: Option<String>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub us_state: Option<Option<String>>,
+    pub fli: Option<Vec<Fli>>,
+    pub student_stage: Option<StudentStage>,
+    pub majors: Option<Vec<String>>,
+    pub minors: Option<Vec<String>>,
+    pub hear_about: Option<Vec<VolunteerHearAbout>>,
+}
+
+impl EditVolunteerRequest {
+    /// Check the request for values which are well-formed but not allowed.
+    ///
+    /// Returns a message describing the first problem found.
+    pub fn validate(&self) -> Result<(), String> {
+        let required = [
+            ("firstName", &self.first_name),
+            ("lastName", &self.last_name),
+            ("email", &self.email),
+            ("country", &self.country),
+        ];
+
+        for (name, value) in required {
+            if value.as_deref().is_some_and(|v| v.trim().is_empty()) {
+                return Err(format!("{name} cannot be empty"));
+            }
+        }
+
+        if self.email.as_deref().is_some_and(|email| !email.contains('@')) {
+            return Err("email must be a valid email address".to_owned());
+        }
+
+        Ok(())
+    }
+}
+
+impl From<EditVolunteerRequest> for EditVolunteer {
+    fn from(request: EditVolunteerRequest) -> Self {
+        Self {
+            first_name: request.first_name,
+            last_name: request.last_name,
+            email: request.email,
+            phone: request.phone,
+            volunteer_gender: request.volunteer_gender,
+            volunteer_ethnicity: request.volunteer_ethnicity,
+            volunteer_age_range: request.volunteer_age_range,
+            university: request.university,
+            lgbt: request.lgbt,
+            country: request.country,
+            us_state: request.us_state,
+            fli: request.fli,
+            student_stage: request.student_stage,
+            majors: request.majors,
+            minors: request.minors,
+            hear_about: request.hear_about,
+        }
+    }
+}
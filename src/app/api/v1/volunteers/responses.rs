@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 use crate::services::storage::entities::VolunteerDetails;
 
@@ -6,3 +7,22 @@ use crate::services::storage::entities::VolunteerDetails;
 pub struct Volunteers {
     pub volunteers: Vec<VolunteerDetails>,
 }
+
+/// A page of volunteers.
+///
+/// * `volunteers`: The volunteers on this page
+/// * `next_cursor`: Pass this as `cursor` to fetch the next page. It is `null` on the last page.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VolunteersPage {
+    pub volunteers: Vec<VolunteerDetails>,
+    pub next_cursor: Option<Uuid>,
+}
+
+/// A single volunteer.
+///
+/// * `volunteer`: The volunteer
+#[derive(Debug, Serialize, Deserialize)]
+pub struct VolunteerResponse {
+    pub volunteer: VolunteerDetails,
+}
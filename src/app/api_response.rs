@@ -38,3 +38,11 @@ pub fn no_content() -> Response {
 pub fn error(code: StatusCode, msg: &str) -> Response {
     (code, Json(serde_json::json!({"error": msg}))).into_response()
 }
+
+/// Build a response to a failed api request which describes the failure in detail
+///
+/// * `code`: A HTTP 4xx or 5xx status code
+/// * `data`: A description of the failure to send back to the client
+pub fn error_with_data<T: Serialize>(code: StatusCode, data: T) -> Result<Response> {
+    Ok((code, Json(serde_json::to_value(data)?)).into_response())
+}
@@ -0,0 +1,127 @@
+//! This module contains helpers for recording privileged actions in the audit log.
+//!
+//! Controllers describe an action before performing it, then record the action along with its
+//! outcome. A successful action is recorded with the same execution options as the change itself,
+//! so that when the change is made in a transaction, the audit event is committed (or rolled back)
+//! with it. A failed action is recorded on its own, since its transaction will be rolled back.
+
+#[cfg(test)]
+mod tests;
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use serde::Serialize;
+use serde_json::Value;
+use sqlx::{Database, Postgres};
+use uuid::Uuid;
+
+use crate::services::auth::AuthData;
+use crate::services::storage::audit::{CreateAuditEvent, CreateAuditEventBuilder};
+use crate::services::storage::types::{AuditAction, AuditOutcome};
+use crate::services::storage::{ExecOpts, ExecOptsBuilder, StorageService};
+
+/// A privileged action which will be recorded in the audit log once its outcome is known.
+pub struct Audit<DB: Database = Postgres> {
+    storage_layer: Arc<dyn StorageService<DB>>,
+    event: CreateAuditEvent,
+}
+
+impl<DB: Database> Audit<DB> {
+    /// Describe an action performed by an authenticated user.
+    ///
+    /// * `storage_layer`: The storage layer to record the action in
+    /// * `auth`: Auth data about the user performing the action
+    /// * `action`: What the user is doing
+    pub fn new(
+        storage_layer: &Arc<dyn StorageService<DB>>,
+        auth: &AuthData,
+        action: AuditAction,
+    ) -> Result<Self> {
+        let event =
+            CreateAuditEventBuilder::default().principal(auth.email()?).action(action).build()?;
+
+        Ok(Self { storage_layer: storage_layer.clone(), event })
+    }
+
+    /// Add a record that the action touches.
+    ///
+    /// * `id`: The ID of the record
+    pub fn target(mut self, id: Uuid) -> Self {
+        self.event.target_ids.push(id);
+        self
+    }
+
+    /// Summarize the request which asked for the action.
+    ///
+    /// * `summary`: The summary
+    pub fn summary(mut self, summary: Value) -> Self {
+        self.event.summary = summary;
+        self
+    }
+
+    /// Record the action in the audit log.
+    ///
+    /// If the action succeeded, it is recorded using `exec_opts` and an error recording it is
+    /// returned. If it failed, it is recorded on its own and an error recording it is only logged,
+    /// since the caller is already handling the failure.
+    ///
+    /// * `res`: The outcome of the action
+    /// * `exec_opts`: The execution options the action was performed with
+    pub async fn record<T>(self, res: &Result<T>, exec_opts: &mut ExecOpts<'_, DB>) -> Result<()> {
+        let Audit { storage_layer, mut event } = self;
+
+        match res {
+            Ok(_) => {
+                storage_layer.create_audit_event(event, exec_opts).await?;
+            }
+            Err(e) => {
+                event.outcome = AuditOutcome::Failed;
+                event.error = Some(describe_error(e));
+
+                let res = match ExecOptsBuilder::default().build() {
+                    Ok(mut exec_opts) => {
+                        storage_layer.create_audit_event(event, &mut exec_opts).await.map(|_| ())
+                    }
+                    Err(e) => Err(e.into()),
+                };
+
+                if let Err(e) = res {
+                    log::error!("Failed to record a failed action in the audit log: {e}");
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Describe why an action failed, to be stored in the audit log.
+///
+/// The outermost message of an error is our own description of what failed, such as "error
+/// editing mentor". Errors from the database which were returned without one are not stored as
+/// is, since they can contain values from the query and details of the schema.
+///
+/// * `e`: The error the action failed with
+fn describe_error(e: &anyhow::Error) -> String {
+    if e.chain().count() == 1 && e.downcast_ref::<sqlx::Error>().is_some() {
+        return "database error".to_owned();
+    }
+    e.to_string()
+}
+
+/// Summarize an edit by listing the fields it changes.
+///
+/// Values are left out of the summary, since they may contain personal information. Fields which
+/// are not serialized (e.g. because they are `None` in a request with `skip_serializing_none`) are
+/// not listed.
+///
+/// * `request`: The request to edit a record
+pub fn edited_fields<T: Serialize>(request: &T) -> Value {
+    let fields = match serde_json::to_value(request) {
+        Ok(Value::Object(fields)) => fields.keys().cloned().collect(),
+        _ => Vec::new(),
+    };
+
+    serde_json::json!({ "fields": fields })
+}
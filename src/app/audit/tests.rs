@@ -0,0 +1,17 @@
+use anyhow::Context;
+
+use super::describe_error;
+
+#[test]
+pub fn test_describe_error() {
+    let db_error = || sqlx::Error::Protocol("duplicate key value (email)=(ada@example.com)".into());
+
+    let err = anyhow::Error::from(db_error());
+    assert_eq!(describe_error(&err), "database error");
+
+    let err = Err::<(), _>(db_error()).context("error editing mentor").unwrap_err();
+    assert_eq!(describe_error(&err), "error editing mentor");
+
+    let err = anyhow::anyhow!("job is missing the parameters needed to run");
+    assert_eq!(describe_error(&err), "job is missing the parameters needed to run");
+}
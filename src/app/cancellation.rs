@@ -0,0 +1,52 @@
+//! This module contains the registry of cancellation tokens for running jobs.
+//!
+//! Jobs are cancelled cooperatively. A job's task checks its token at points where it is safe to
+//! stop, and finishes the job as `cancelled` once it sees that the token has been cancelled.
+//!
+//! The registry only knows about jobs running in this process. Cancelling a running job is also
+//! recorded in the `jobs` table, and the worker running the job cancels its token when it sees the
+//! request while sending a heartbeat, so jobs running in other processes stop too.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+/// Tracks a cancellation token for each job that is running in this process.
+#[derive(Default)]
+pub struct JobCancellations {
+    tokens: Mutex<HashMap<Uuid, CancellationToken>>,
+}
+
+impl JobCancellations {
+    /// Get the cancellation token for a job that is about to run in this process.
+    ///
+    /// * `job_id`: The ID of the job
+    pub fn register(&self, job_id: Uuid) -> CancellationToken {
+        let mut tokens = self.tokens.lock().unwrap_or_else(|e| e.into_inner());
+        tokens.entry(job_id).or_default().clone()
+    }
+
+    /// Cancel a job, if it is running in this process.
+    ///
+    /// Returns `false` if the job isn't running in this process.
+    ///
+    /// * `job_id`: The ID of the job to cancel
+    pub fn cancel(&self, job_id: Uuid) -> bool {
+        let tokens = self.tokens.lock().unwrap_or_else(|e| e.into_inner());
+        let Some(token) = tokens.get(&job_id) else {
+            return false;
+        };
+        token.cancel();
+        true
+    }
+
+    /// Stop tracking a job, once it has finished or can no longer run.
+    ///
+    /// * `job_id`: The ID of the job
+    pub fn remove(&self, job_id: Uuid) {
+        let mut tokens = self.tokens.lock().unwrap_or_else(|e| e.into_inner());
+        tokens.remove(&job_id);
+    }
+}
@@ -26,3 +26,12 @@ where
         Self(err.into())
     }
 }
+
+/// Whether an error was caused by a query violating a unique constraint.
+///
+/// * `e`: The error
+pub fn is_unique_violation(e: &Error) -> bool {
+    e.downcast_ref::<sqlx::Error>()
+        .and_then(|e| e.as_database_error())
+        .is_some_and(|e| e.is_unique_violation())
+}
@@ -0,0 +1,41 @@
+//! This module contains the event hub which lets running jobs notify listeners that they have
+//! changed.
+//!
+//! Events only say _which_ job changed. Listeners read the job's current state from the storage
+//! layer when they are notified, so an event that is missed or dropped never leaves a listener with
+//! stale data for longer than it takes for the next event to arrive.
+
+use tokio::sync::broadcast;
+use uuid::Uuid;
+
+/// How many events are buffered for listeners which have fallen behind.
+const JOB_EVENTS_CAPACITY: usize = 1024;
+
+/// Broadcasts notifications that a job's status or progress has changed.
+///
+/// Notifications are only delivered within a single process.
+pub struct JobEvents {
+    sender: broadcast::Sender<Uuid>,
+}
+
+impl Default for JobEvents {
+    fn default() -> Self {
+        let (sender, _) = broadcast::channel(JOB_EVENTS_CAPACITY);
+        Self { sender }
+    }
+}
+
+impl JobEvents {
+    /// Notify listeners that a job has changed.
+    ///
+    /// * `job_id`: The ID of the job which changed
+    pub fn publish(&self, job_id: Uuid) {
+        // Sending only fails when nobody is listening, which is fine
+        let _ = self.sender.send(job_id);
+    }
+
+    /// Listen for changes to jobs.
+    pub fn subscribe(&self) -> broadcast::Receiver<Uuid> {
+        self.sender.subscribe()
+    }
+}
@@ -1,12 +1,18 @@
 mod api;
 mod api_docs;
 mod api_response;
+mod audit;
+pub mod cancellation;
 mod errors;
+pub mod events;
 pub mod state;
+pub mod webhooks;
+pub mod worker;
 use std::sync::Arc;
 
 use api_docs::ApiDocs;
 use axum::Router;
+use sqlx::Database;
 use tower_http::cors::CorsLayer;
 use tower_http::trace::TraceLayer;
 use utoipa::OpenApi;
@@ -17,7 +23,7 @@ use crate::app::state::Services;
 /// Build the application router.
 ///
 /// * `ctx`: The application context
-pub async fn build(services: Arc<Services>) -> Router<()> {
+pub async fn build<DB: Database>(services: Arc<Services<DB>>) -> Router<()> {
     let api = api::build(services.clone()).await;
 
     Router::new()
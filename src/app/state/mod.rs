@@ -4,6 +4,8 @@ use derive_builder::Builder;
 use serde::Serialize;
 use sqlx::{Database, Postgres};
 
+use crate::app::cancellation::JobCancellations;
+use crate::app::events::JobEvents;
 use crate::services::airtable::AirtableService;
 use crate::services::auth::AuthenticatorService;
 use crate::services::mail::MailService;
@@ -18,6 +20,10 @@ pub struct Services<DB: Database = Postgres> {
     pub airtable: Arc<dyn AirtableService>,
     pub workspace: Arc<dyn WorkspaceService>,
     pub mail: Arc<dyn MailService>,
+    #[builder(default)]
+    pub job_events: Arc<JobEvents>,
+    #[builder(default)]
+    pub job_cancellations: Arc<JobCancellations>,
 }
 
 // pub struct ServiceInfo {
@@ -40,7 +46,7 @@ pub struct ApiServiceDetails<'a> {
     pub configured_services: ConfiguredServices<'a>,
 }
 
-impl Services {
+impl<DB: Database> Services<DB> {
     pub fn get_info(&self) -> ApiServiceDetails {
         ApiServiceDetails {
             configured_services: ConfiguredServices {
@@ -0,0 +1,112 @@
+//! This module contains the task which keeps Airtable webhooks alive.
+//!
+//! Airtable stops recording changes for a webhook 7 days after it was registered or last
+//! refreshed. The refresher periodically refreshes webhooks which are about to expire, and records
+//! when they now expire. A webhook which has already expired can't be refreshed, so it is logged
+//! to be registered again.
+
+#[cfg(test)]
+mod tests;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Result;
+use chrono::Utc;
+use sqlx::Database;
+use tokio::task::{self, JoinHandle};
+
+use crate::app::state::Services;
+use crate::services::storage::ExecOptsBuilder;
+
+/// Configuration for the webhook refresher.
+///
+/// * `interval`: How often to check for webhooks which are about to expire
+/// * `refresh_within`: How long before a webhook expires it is refreshed. This should be longer
+///   than `interval`, so that every webhook is checked at least once before it expires.
+#[derive(Debug, Clone)]
+pub struct WebhookRefreshConfig {
+    pub interval: Duration,
+    pub refresh_within: Duration,
+}
+
+/// Start the webhook refresher.
+///
+/// * `services`: The application services
+/// * `config`: Configuration for the refresher
+pub fn spawn<DB: Database>(
+    services: Arc<Services<DB>>,
+    config: WebhookRefreshConfig,
+) -> JoinHandle<()> {
+    task::spawn(async move {
+        let mut interval = tokio::time::interval(config.interval);
+
+        loop {
+            interval.tick().await;
+            match refresh_expiring_webhooks(&services, config.refresh_within).await {
+                Ok(0) => {}
+                Ok(refreshed) => log::info!("Refreshed {refreshed} Airtable webhooks"),
+                Err(e) => log::error!("Failed to refresh Airtable webhooks: {e}"),
+            }
+        }
+    })
+}
+
+/// Refresh every webhook which expires within `refresh_within`, and record when it now expires.
+/// Returns the number of webhooks which were refreshed.
+///
+/// A webhook which can't be refreshed doesn't stop the others from being refreshed.
+///
+/// * `services`: The application services
+/// * `refresh_within`: How long before a webhook expires it is refreshed
+pub async fn refresh_expiring_webhooks<DB: Database>(
+    services: &Services<DB>,
+    refresh_within: Duration,
+) -> Result<usize> {
+    let storage_layer = &services.storage_layer;
+    let now = Utc::now();
+    let refresh_before = now + chrono::Duration::from_std(refresh_within)?;
+
+    let webhooks = storage_layer.fetch_webhooks(&mut ExecOptsBuilder::default().build()?).await?;
+    let mut refreshed = 0;
+
+    for webhook in webhooks {
+        // Webhooks without an expiration time never expire
+        let Some(expiration_time) = webhook.expiration_time else {
+            continue;
+        };
+
+        if expiration_time > refresh_before {
+            continue;
+        }
+
+        if expiration_time <= now {
+            log::warn!(
+                "Airtable webhook {} on base {} expired at {expiration_time}. Register it again to \
+                 keep syncing project cycle {}",
+                webhook.webhook_id,
+                webhook.base_id,
+                webhook.project_cycle_id
+            );
+            continue;
+        }
+
+        match services.airtable.refresh_webhook(&webhook.base_id, &webhook.webhook_id).await {
+            Ok(expiration_time) => {
+                storage_layer
+                    .update_webhook_expiration_time(
+                        webhook.id,
+                        expiration_time,
+                        &mut ExecOptsBuilder::default().build()?,
+                    )
+                    .await?;
+                refreshed += 1;
+            }
+            Err(e) => {
+                log::error!("Failed to refresh Airtable webhook {}: {e}", webhook.webhook_id)
+            }
+        }
+    }
+
+    Ok(refreshed)
+}
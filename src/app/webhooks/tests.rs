@@ -0,0 +1,89 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use scipio_airtable::Airtable;
+use scipio_fake_server::FakeServer;
+use sqlx::PgPool;
+use uuid::{uuid, Uuid};
+
+use super::refresh_expiring_webhooks;
+use crate::app::state::{Services, ServicesBuilder};
+use crate::services::airtable::AirtableClient;
+use crate::services::auth::noop::NoopAuthenticator;
+use crate::services::mail::noop::NoopEmailClient;
+use crate::services::storage::webhooks::CreateWebhookBuilder;
+use crate::services::storage::{ExecOptsBuilder, PgBackend};
+use crate::services::workspace::noop::NoopWorkspaceClient;
+
+const PROJECT_CYCLE_ID: Uuid = uuid!("0e12b846-4de5-432e-8137-1bc2c92827b3");
+
+async fn store_webhook(
+    services: &Services,
+    base_id: &str,
+    webhook_id: &str,
+    expiration_time: Option<DateTime<Utc>>,
+) -> Result<Uuid> {
+    let data = CreateWebhookBuilder::default()
+        .base_id(base_id)
+        .webhook_id(webhook_id)
+        .mac_secret_base64("c2NpcGlvLXRlc3Qtc2VjcmV0")
+        .project_cycle_id(PROJECT_CYCLE_ID)
+        .expiration_time(expiration_time)
+        .build()?;
+    services.storage_layer.create_webhook(data, &mut ExecOptsBuilder::default().build()?).await
+}
+
+async fn expiration_time(services: &Services, id: Uuid) -> Result<Option<DateTime<Utc>>> {
+    let webhook = services
+        .storage_layer
+        .fetch_webhook_by_id(id, &mut ExecOptsBuilder::default().build()?)
+        .await?
+        .expect("webhook not found");
+    Ok(webhook.expiration_time)
+}
+
+#[sqlx::test(fixtures(path = "../../services/storage/tests/fixtures", scripts("setup")))]
+pub async fn test_refresh_expiring_webhooks(pool: PgPool) -> Result<()> {
+    let fake = FakeServer::start();
+    let airtable = Airtable::new("test-api-token", 0)?.with_base_url(fake.url());
+    let base_id = fake.add_airtable_base("Scipio Test");
+
+    let services = ServicesBuilder::default()
+        .authenticator(Arc::new(NoopAuthenticator))
+        .storage_layer(Arc::new(PgBackend { pool }))
+        .airtable(Arc::new(airtable.clone()))
+        .workspace(Arc::new(NoopWorkspaceClient))
+        .mail(Arc::new(NoopEmailClient))
+        .build()?;
+
+    let registered = airtable.register_webhook(&base_id, "https://example.com/webhooks").await?;
+    let now = Utc::now();
+    let hours = |n: i64| Some(now + chrono::Duration::hours(n));
+
+    // A webhook Airtable doesn't know about can't be refreshed, but doesn't stop the rest
+    let unknown = store_webhook(&services, &base_id, "achUnknown", hours(1)).await?;
+    let expiring = store_webhook(&services, &base_id, &registered.id, hours(1)).await?;
+    let not_expiring = store_webhook(&services, &base_id, "achNotExpiring", hours(72)).await?;
+    let expired = store_webhook(&services, &base_id, "achExpired", hours(-1)).await?;
+    let never_expiring = store_webhook(&services, &base_id, "achNeverExpiring", None).await?;
+
+    let refreshed = refresh_expiring_webhooks(&services, Duration::from_secs(24 * 60 * 60)).await?;
+    assert_eq!(refreshed, 1);
+
+    let refreshed_until = expiration_time(&services, expiring).await?.expect("no expiration time");
+    assert!(refreshed_until > now + chrono::Duration::days(6), "{refreshed_until}");
+
+    for (id, expected) in [
+        (unknown, hours(1)),
+        (not_expiring, hours(72)),
+        (expired, hours(-1)),
+        (never_expiring, None),
+    ] {
+        let timestamp = expiration_time(&services, id).await?.map(|t| t.timestamp());
+        assert_eq!(timestamp, expected.map(|t| t.timestamp()));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,236 @@
+//! This module contains the job workers which run queued jobs.
+//!
+//! Controllers do not run long-lived work themselves. Instead, they record a pending job whose
+//! details contain everything needed to run it. Workers poll the `jobs` table, claim pending jobs
+//! (concurrent workers never claim the same job), and hold a lease on each job they claim. While a
+//! job runs, its worker sends heartbeats to extend the lease. If a worker dies, its lease expires
+//! and the job is re-queued so that another worker can pick it up.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Result;
+use serde_json::json;
+use sqlx::Database;
+use tokio::task::{self, JoinHandle};
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+use crate::app::api::v1;
+use crate::app::state::Services;
+use crate::services::storage::audit::CreateAuditEventBuilder;
+use crate::services::storage::types::{AuditAction, AuditOutcome, JobDetails, JobStatus};
+use crate::services::storage::ExecOptsBuilder;
+
+/// Configuration for job workers.
+///
+/// * `concurrency`: The number of workers to run
+/// * `poll_interval`: How long an idle worker waits before checking for new jobs
+/// * `lease`: How long a worker holds a job without sending a heartbeat
+/// * `max_attempts`: How many times a job may be claimed before it is marked as errored
+#[derive(Debug, Clone)]
+pub struct WorkerConfig {
+    pub concurrency: usize,
+    pub poll_interval: Duration,
+    pub lease: Duration,
+    pub max_attempts: i32,
+}
+
+/// Start the job workers.
+///
+/// * `services`: The application services
+/// * `config`: Configuration for the workers
+pub fn spawn<DB: Database>(
+    services: Arc<Services<DB>>,
+    config: WorkerConfig,
+) -> Vec<JoinHandle<()>> {
+    (0..config.concurrency)
+        .map(|_| {
+            let worker_id = format!("scipio-worker-{}", Uuid::new_v4());
+            task::spawn(run_worker(services.clone(), worker_id, config.clone()))
+        })
+        .collect()
+}
+
+async fn run_worker<DB: Database>(
+    services: Arc<Services<DB>>,
+    worker_id: String,
+    config: WorkerConfig,
+) {
+    log::info!("Started job worker {worker_id}");
+
+    loop {
+        match poll(&services, &worker_id, &config).await {
+            // Look for more work right away after finishing a job
+            Ok(true) => continue,
+            Ok(false) => {}
+            Err(e) => log::error!("Job worker {worker_id} failed to poll for jobs: {e}"),
+        }
+        tokio::time::sleep(config.poll_interval).await;
+    }
+}
+
+/// Claim and run a single job. Returns `false` if there were no jobs to run.
+async fn poll<DB: Database>(
+    services: &Arc<Services<DB>>,
+    worker_id: &str,
+    config: &WorkerConfig,
+) -> Result<bool> {
+    let storage_layer = &services.storage_layer;
+
+    let requeued = storage_layer
+        .requeue_expired_jobs(config.max_attempts, &mut ExecOptsBuilder::default().build()?)
+        .await?;
+
+    for id in requeued {
+        log::warn!("Lease on job {id} expired. Its worker has likely died");
+    }
+
+    let lease_seconds = config.lease.as_secs() as i64;
+    let Some(job) = storage_layer
+        .claim_job(worker_id, lease_seconds, &mut ExecOptsBuilder::default().build()?)
+        .await?
+    else {
+        return Ok(false);
+    };
+
+    log::info!("Job worker {worker_id} claimed job {}", job.id);
+    services.job_events.publish(job.id);
+
+    let cancellation = services.job_cancellations.register(job.id);
+
+    // A job whose worker died after it was asked to stop is re-queued with the request still set
+    if storage_layer
+        .is_job_cancellation_requested(job.id, &mut ExecOptsBuilder::default().build()?)
+        .await?
+    {
+        cancellation.cancel();
+    }
+
+    let heartbeat = task::spawn(heartbeat(
+        services.clone(),
+        job.id,
+        worker_id.to_owned(),
+        config.lease,
+        cancellation.clone(),
+    ));
+
+    // Run the job on its own task so that a panic fails the job rather than the worker
+    let ctx = services.clone();
+    let job_id = job.id;
+    let res = task::spawn(async move { v1::run_job(&ctx, &job, cancellation).await }).await;
+
+    heartbeat.abort();
+    services.job_cancellations.remove(job_id);
+
+    let error = match res {
+        Ok(Ok(_)) => None,
+        Ok(Err(e)) => Some(e.to_string()),
+        Err(e) => Some(format!("Job panicked: {e}")),
+    };
+
+    if let Some(error) = error {
+        log::error!("Job {job_id} failed: {error}");
+        storage_layer
+            .mark_job_errored(job_id, error, &mut ExecOptsBuilder::default().build()?)
+            .await?;
+    }
+
+    if let Err(e) = audit_job_run(services, worker_id, job_id).await {
+        log::error!("Failed to record job {job_id} in the audit log: {e}");
+    }
+
+    services.job_events.publish(job_id);
+
+    Ok(true)
+}
+
+/// Record a job which has finished running in the audit log, on behalf of the user who queued it.
+///
+/// Jobs recorded before their principal was tracked are attributed to the worker which ran them.
+async fn audit_job_run<DB: Database>(
+    services: &Arc<Services<DB>>,
+    worker_id: &str,
+    job_id: Uuid,
+) -> Result<()> {
+    let storage_layer = &services.storage_layer;
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+
+    let Some(job) = storage_layer.fetch_job(job_id, &mut exec_opts).await? else {
+        return Ok(());
+    };
+
+    let outcome = match job.status {
+        JobStatus::Complete => AuditOutcome::Succeeded,
+        JobStatus::Error => AuditOutcome::Failed,
+        JobStatus::Cancelled => AuditOutcome::Cancelled,
+        // Another worker took over the job, so it has not finished yet
+        JobStatus::Pending | JobStatus::Running => return Ok(()),
+    };
+
+    let details = serde_json::from_value::<JobDetails>(job.details)?;
+    let counts = storage_layer.fetch_job_item_counts(job_id, &mut exec_opts).await?;
+
+    let mut event = CreateAuditEventBuilder::default()
+        .principal(details.data.principal().unwrap_or(worker_id))
+        .action(AuditAction::RunJob)
+        .target_ids(std::iter::once(job.id).chain(job.project_cycle_id).collect::<Vec<_>>())
+        .summary(json!({ "jobType": details.job_type, "label": job.label, "items": counts }))
+        .outcome(outcome)
+        .build()?;
+    event.error = details.error;
+
+    storage_layer.create_audit_event(event, &mut exec_opts).await?;
+
+    Ok(())
+}
+
+/// Periodically extend a worker's lease on a job until the task is aborted.
+///
+/// The job's token is cancelled once cancelling the job has been requested, which may have
+/// happened in another process.
+async fn heartbeat<DB: Database>(
+    services: Arc<Services<DB>>,
+    job_id: Uuid,
+    worker_id: String,
+    lease: Duration,
+    cancellation: CancellationToken,
+) {
+    let mut interval = tokio::time::interval((lease / 3).max(Duration::from_secs(1)));
+    // The first tick completes immediately, and the lease was just taken out
+    interval.tick().await;
+
+    loop {
+        interval.tick().await;
+
+        let Ok(mut exec_opts) = ExecOptsBuilder::default().build() else {
+            continue;
+        };
+
+        match services
+            .storage_layer
+            .heartbeat_job(job_id, &worker_id, lease.as_secs() as i64, &mut exec_opts)
+            .await
+        {
+            Ok(true) => {}
+            Ok(false) => {
+                log::warn!("Job worker {worker_id} no longer holds the lease on job {job_id}");
+                return;
+            }
+            Err(e) => log::error!("Failed to send heartbeat for job {job_id}: {e}"),
+        }
+
+        if cancellation.is_cancelled() {
+            continue;
+        }
+
+        match services.storage_layer.is_job_cancellation_requested(job_id, &mut exec_opts).await {
+            Ok(true) => {
+                log::info!("Cancelling job {job_id}, which was asked to stop");
+                cancellation.cancel();
+            }
+            Ok(false) => {}
+            Err(e) => log::error!("Failed to check whether job {job_id} was cancelled: {e}"),
+        }
+    }
+}
@@ -14,7 +14,8 @@
 //! The services that Scipio requires to run include:
 //! - An authentication provider (currently Auth0, although anything which implements
 //!   `Authenticator` will work).
-//! - A database (currently PostgreSQL, although anything which implements `StorageLayer` will work).
+//! - A database (PostgreSQL, or SQLite for local development, although anything which implements
+//!   `StorageLayer` will work).
 //! - An email provider (currently Sendgrid, although anything which implements `EmailClient` will
 //!   suffice.
 //! - A Workspace client. This is a custom service that interacts with the Google Workspace API,
@@ -39,12 +40,17 @@ mod cli;
 mod services;
 
 use std::env;
+use std::sync::Arc;
 
 use anyhow::Result;
 use clap::Parser;
+use sqlx::{Database, Postgres, Sqlite};
 use tokio::net::TcpListener;
 
-use crate::cli::Args;
+use crate::app::state::Services;
+use crate::cli::{Args, StorageServiceImpl};
+use crate::services::storage::sqlite::SqliteBackend;
+use crate::services::storage::{PgBackend, StorageService};
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -56,7 +62,7 @@ async fn main() -> Result<()> {
     };
 
     let templates_dir = env::var("MAIL_TEMPLATES_DIR").unwrap_or_else(|_| "templates".to_owned());
-     log::info!("Loading templates from {}", templates_dir);
+    log::info!("Loading templates from {}", templates_dir);
 
     let args = Args::parse();
     log::info!(
@@ -64,9 +70,26 @@ async fn main() -> Result<()> {
         env::var("MAIL_RECIPIENT_OVERRIDE").unwrap_or_default()
     );
 
-    let addr = format!("{}:{}", args.host, args.port);
+    match args.storage {
+        StorageServiceImpl::Postgres => {
+            let storage_layer: Arc<dyn StorageService<Postgres>> =
+                Arc::new(PgBackend::new(&args.database_url).await?);
+            serve(&args, args.init_services(storage_layer).await?).await
+        }
+        StorageServiceImpl::Sqlite => {
+            let storage_layer: Arc<dyn StorageService<Sqlite>> =
+                Arc::new(SqliteBackend::new(&args.sqlite_url).await?);
+            serve(&args, args.init_services(storage_layer).await?).await
+        }
+    }
+}
 
-    let services = args.init_services().await?;
+/// Run migrations, start the job workers and the webhook refresher, and serve the API.
+///
+/// * `args`: The command line arguments
+/// * `services`: The application services
+async fn serve<DB: Database>(args: &Args, services: Arc<Services<DB>>) -> Result<()> {
+    let addr = format!("{}:{}", args.host, args.port);
 
     services.storage_layer.migrate().await?;
 
@@ -74,6 +97,9 @@ async fn main() -> Result<()> {
 
     log::info!("{:?}", services.get_info());
 
+    app::worker::spawn(services.clone(), args.worker_config());
+    app::webhooks::spawn(services.clone(), args.webhook_refresh_config());
+
     let srv = app::build(services).await;
 
     let listener = TcpListener::bind(&addr).await?;
@@ -1,5 +1,6 @@
 use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 use crate::services::storage::mentors::CreateMentor;
 use crate::services::storage::nonprofits::CreateNonprofit;
@@ -12,6 +13,10 @@ use crate::services::storage::volunteers::CreateVolunteer;
 #[derive(Debug, Builder, Deserialize, Serialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct Nonprofit {
+    /// The ID of the Airtable record this was read from
+    #[serde(skip)]
+    #[builder(setter(into), default)]
+    pub record_id: String,
     #[builder(setter(into))]
     #[serde(rename = "FirstName")]
     pub representative_first_name: String,
@@ -60,25 +65,12 @@ impl From<Nonprofit> for CreateNonprofit {
             us_state_hq: value.us_state_hq,
             address: value.address,
             size: value.size,
+            // Impact causes are linked records, which are read by the names of the causes
             impact_causes: value
                 .impact_causes
                 .unwrap_or(vec!["Other".to_owned()])
-                .iter()
-                .map(|c| match c.as_str() {
-                    "reco1zHRYv8lTQDaI" => ImpactCause::Animals,
-                    "recXhhTPsuQ2PMjU4" => ImpactCause::CareerAndProfessionalDevelopment,
-                    "recvWKilRRABCcHuI" => ImpactCause::DisasterRelief,
-                    "recYfRNFDpm2nedjM" => ImpactCause::Education,
-                    "recOlWiJTppnQwnll" => ImpactCause::EnvironmentAndSustainability,
-                    "recix0Y5qCXYfZGRz" => ImpactCause::FaithAndReligion,
-                    "recKs8kboTORruStC" => ImpactCause::HealthAndMedicine,
-                    "recEmtYMgeOlPeOVQ" => ImpactCause::GlobalRelations,
-                    "reczSSbvdW2NoOX2p" => ImpactCause::PovertyAndHunger,
-                    "rec5dt6EVyUeIaCR7" => ImpactCause::SeniorServices,
-                    "recMt9349gwuRAQXf" => ImpactCause::JusticeAndEquity,
-                    "rec8cH6YTQMeYqXUh" => ImpactCause::VeteransAndMilitaryFamilies,
-                    _ => ImpactCause::Other,
-                })
+                .into_iter()
+                .map(|c| serde_json::from_value(Value::String(c)).unwrap_or(ImpactCause::Other))
                 .collect(),
         }
     }
@@ -87,6 +79,10 @@ impl From<Nonprofit> for CreateNonprofit {
 #[derive(Debug, Builder, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct Volunteer {
+    /// The ID of the Airtable record this was read from
+    #[serde(skip)]
+    #[builder(setter(into), default)]
+    pub record_id: String,
     #[builder(setter(into))]
     pub first_name: String,
     #[builder(setter(into))]
@@ -155,6 +151,10 @@ impl From<Volunteer> for CreateVolunteer {
 #[derive(Debug, Builder, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "PascalCase")]
 pub struct Mentor {
+    /// The ID of the Airtable record this was read from
+    #[serde(skip)]
+    #[builder(setter(into), default)]
+    pub record_id: String,
     #[builder(setter(into))]
     pub first_name: String,
     #[builder(setter(into))]
@@ -229,3 +229,84 @@ pub struct VolunteerNonprofitLinkage {
     pub email: String,
     pub org_name: String,
 }
+
+/// A record which could not be read into one of the entities above.
+///
+/// * `table`: The table the record is in
+/// * `record_id`: The ID of the record
+/// * `error`: Why the record could not be read
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MalformedRecord {
+    pub table: String,
+    pub record_id: String,
+    pub error: String,
+}
+
+/// Everything an import reads from a base, along with the records which could not be read.
+#[derive(Debug, Default, Clone)]
+pub struct BaseRecords {
+    pub volunteers: Vec<Volunteer>,
+    pub mentors: Vec<Mentor>,
+    pub nonprofits: Vec<Nonprofit>,
+    pub mentor_mentee_linkages: Vec<MentorMenteeLinkage>,
+    pub malformed: Vec<MalformedRecord>,
+}
+
+/// The changes a webhook recorded after a cursor.
+///
+/// * `cursor`: The cursor to read the next changes from
+/// * `payloads`: How many payloads were read. Each payload holds changes made together.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct WebhookChanges {
+    pub cursor: i64,
+    pub payloads: usize,
+}
+
+/// A field which a table is missing.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct MissingField {
+    pub table: String,
+    pub field: String,
+}
+
+/// A view which a table is missing.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct MissingView {
+    pub table: String,
+    pub view: String,
+}
+
+/// A field whose type can't be read into the entity that uses it.
+///
+/// * `table`: The table the field is in
+/// * `field`: The name of the field
+/// * `expected`: The Airtable field types which can be read
+/// * `found`: The type of the field
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct FieldTypeMismatch {
+    pub table: String,
+    pub field: String,
+    pub expected: Vec<String>,
+    pub found: Option<String>,
+}
+
+/// Everything wrong with a base's schema which would stop an import from reading it.
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaReport {
+    pub missing_tables: Vec<String>,
+    pub missing_fields: Vec<MissingField>,
+    pub missing_views: Vec<MissingView>,
+    pub field_type_mismatches: Vec<FieldTypeMismatch>,
+}
+
+impl SchemaReport {
+    /// Whether an import can read the base.
+    pub fn is_valid(&self) -> bool {
+        self.missing_tables.is_empty()
+            && self.missing_fields.is_empty()
+            && self.missing_views.is_empty()
+            && self.field_type_mismatches.is_empty()
+    }
+}
@@ -0,0 +1,159 @@
+//! This module describes how an Airtable base is laid out, so that bases whose tables, views,
+//! fields, or values are named differently can still be imported.
+//!
+//! The entities in `entities` name the fields they read. A `ViewMapping` translates those names to
+//! the names used in a particular base. The default mapping describes the layout Develop for Good
+//! has used so far, and mapping profiles stored in the database override it.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+const VOLUNTEERS_VIEW: &str = "All Committed Student Volunteers - Active";
+const MENTORS_VIEW: &str = "All Committed Mentor Volunteers";
+const NONPROFITS_VIEW_PREFIX: &str = "Finalized";
+const NONPROFITS_VIEW_SUFFIX: &str = "Nonprofit Projects";
+const MENTOR_MENTEE_LINKAGE_VIEW: &str =
+    "All Committed Mentor Volunteers - 1:1 Mentor-Mentee Pairings";
+
+/// How to read each kind of record an import needs from a base.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BaseMapping {
+    pub volunteers: ViewMapping,
+    pub mentors: ViewMapping,
+    pub nonprofits: ViewMapping,
+    pub mentor_mentee_linkages: ViewMapping,
+}
+
+/// How to find a view in a table.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum ViewSelector {
+    /// The view with this name
+    Name(String),
+    /// The first view whose name starts with `prefix` and ends with `suffix`. This is useful for
+    /// views which are renamed every project cycle.
+    Affixes { prefix: String, suffix: String },
+}
+
+/// How to read one kind of record from a view.
+///
+/// * `table`: The name of the table the records are in
+/// * `view`: The view the records are listed from
+/// * `fields`: The names of fields in the base, by the name the entity reads. Fields which aren't
+///   listed have the same name in the base.
+/// * `values`: Values to replace before the entity reads them, by the name of the field they are
+///   in. Values which aren't listed are read as they are.
+/// * `linked_fields`: Fields which link to records in another table. The IDs of the linked records
+///   are replaced with the value of the linked table's primary field.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ViewMapping {
+    pub table: String,
+    pub view: ViewSelector,
+    #[serde(default)]
+    pub fields: BTreeMap<String, String>,
+    #[serde(default)]
+    pub values: BTreeMap<String, BTreeMap<String, String>>,
+    #[serde(default)]
+    pub linked_fields: Vec<String>,
+}
+
+impl ViewSelector {
+    /// Whether a view with this name is selected.
+    ///
+    /// * `name`: The name of the view
+    pub fn matches(&self, name: &str) -> bool {
+        match self {
+            ViewSelector::Name(view) => view == name,
+            ViewSelector::Affixes { prefix, suffix } => {
+                name.starts_with(prefix) && name.ends_with(suffix)
+            }
+        }
+    }
+}
+
+impl Display for ViewSelector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ViewSelector::Name(view) => write!(f, "{view}"),
+            ViewSelector::Affixes { prefix, suffix } => write!(f, "{prefix} ... {suffix}"),
+        }
+    }
+}
+
+impl ViewMapping {
+    fn new(table: &str, view: ViewSelector) -> Self {
+        Self {
+            table: table.to_owned(),
+            view,
+            fields: BTreeMap::new(),
+            values: BTreeMap::new(),
+            linked_fields: vec![],
+        }
+    }
+
+    /// The name of a field in the base.
+    ///
+    /// * `field`: The name the entity reads the field by
+    pub fn field_name<'a>(&'a self, field: &'a str) -> &'a str {
+        self.fields.get(field).map(String::as_str).unwrap_or(field)
+    }
+
+    /// Whether a field links to records in another table.
+    ///
+    /// * `field`: The name the entity reads the field by
+    pub fn is_linked(&self, field: &str) -> bool {
+        self.linked_fields.iter().any(|linked_field| linked_field == field)
+    }
+
+    /// Replace the values of a field which are listed in `values`. Lists are replaced element by
+    /// element.
+    ///
+    /// * `field`: The name the entity reads the field by
+    /// * `value`: The value of the field in the base
+    pub fn map_value(&self, field: &str, value: Value) -> Value {
+        let Some(values) = self.values.get(field) else {
+            return value;
+        };
+
+        let map_string = |value: Value| match value {
+            Value::String(s) => Value::String(values.get(&s).cloned().unwrap_or(s)),
+            value => value,
+        };
+
+        match value {
+            Value::Array(elements) => Value::Array(elements.into_iter().map(map_string).collect()),
+            value => map_string(value),
+        }
+    }
+}
+
+impl Default for BaseMapping {
+    fn default() -> Self {
+        let mut nonprofits = ViewMapping::new(
+            "Nonprofits",
+            ViewSelector::Affixes {
+                prefix: NONPROFITS_VIEW_PREFIX.to_owned(),
+                suffix: NONPROFITS_VIEW_SUFFIX.to_owned(),
+            },
+        );
+        nonprofits.linked_fields.push("ImpactCauses".to_owned());
+
+        Self {
+            volunteers: ViewMapping::new(
+                "Volunteers",
+                ViewSelector::Name(VOLUNTEERS_VIEW.to_owned()),
+            ),
+            mentors: ViewMapping::new("Volunteers", ViewSelector::Name(MENTORS_VIEW.to_owned())),
+            nonprofits,
+            mentor_mentee_linkages: ViewMapping::new(
+                "Volunteers",
+                ViewSelector::Name(MENTOR_MENTEE_LINKAGE_VIEW.to_owned()),
+            ),
+        }
+    }
+}
@@ -1,81 +1,123 @@
 pub mod entities;
+pub mod mapping;
 
 #[cfg(test)]
 mod tests;
 
+use std::collections::HashMap;
+use std::pin::pin;
+
 use anyhow::{bail, Result};
 use async_trait::async_trait;
-use entities::{Mentor, MentorMenteeLinkage, Nonprofit, Volunteer};
-use scipio_airtable::base_data::entities::Base;
-use scipio_airtable::base_data::records::ListRecordsQueryBuilder;
+use chrono::{DateTime, Utc};
+use entities::{
+    BaseRecords, FieldTypeMismatch, MalformedRecord, Mentor, MentorMenteeLinkage, MissingField,
+    MissingView, Nonprofit, SchemaReport, Volunteer, WebhookChanges,
+};
+use futures::TryStreamExt;
+use mapping::{BaseMapping, ViewMapping};
+use scipio_airtable::base_data::entities::{Base, Record, RecordUpdate, Table};
+use scipio_airtable::base_data::records::{
+    ListRecordsQuery, ListRecordsQueryBuilder, UpdateMethod, WriteRecordsOptionsBuilder,
+    MAX_RECORDS_PER_REQUEST,
+};
+use scipio_airtable::base_data::responses::SchemaResponse;
+use scipio_airtable::webhooks::responses::CreateWebhookResponse;
+use scipio_airtable::webhooks::WebhookFiltersBuilder;
 use scipio_airtable::Airtable;
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
 
 use super::Service;
 
-const VOLUNTEERS_VIEW: &str = "All Committed Student Volunteers - Active";
-const MENTORS_VIEW: &str = "All Committed Mentor Volunteers";
-const NONPROFITS_VIEW_PREFIX: &str = "Finalized";
-const NONPROFITS_VIEW_SUFFIX: &str = "Nonprofit Projects";
-const MENTOR_MENTEE_LINKAGE_VIEW: &str =
-    "All Committed Mentor Volunteers - 1:1 Mentor-Mentee Pairings";
-
-const REQUIRED_VOLUNTEER_FIELDS: [&str; 17] = [
-    "FirstName",
-    "LastName",
-    "OrgName (from ProjectRecordID)",
-    "Email",
-    "Phone",
-    "Gender",
-    "Ethnicity",
-    "AgeRange",
-    "University",
-    "LGBT",
-    "Country",
-    "State",
-    "FLI",
-    "StudentStage",
-    "Majors",
-    "Minors",
-    "HearAbout",
+/// The shape of value an entity reads from a field, which decides the field types it can read.
+#[derive(Clone, Copy)]
+enum FieldShape {
+    Text,
+    List,
+}
+
+impl FieldShape {
+    fn field_types(self) -> &'static [&'static str] {
+        match self {
+            FieldShape::Text => &[
+                "singleLineText",
+                "multilineText",
+                "richText",
+                "email",
+                "phoneNumber",
+                "url",
+                "singleSelect",
+                "formula",
+                "rollup",
+            ],
+            FieldShape::List => &["multipleSelects", "multipleLookupValues", "rollup"],
+        }
+    }
+}
+
+/// The field types a linked field can have.
+const LINKED_FIELD_TYPES: &[&str] = &["multipleRecordLinks"];
+
+// The fields entities read are named by their default names in the base. A `ViewMapping` gives
+// their names in a particular base.
+const REQUIRED_VOLUNTEER_FIELDS: [(&str, FieldShape); 17] = [
+    ("FirstName", FieldShape::Text),
+    ("LastName", FieldShape::Text),
+    ("OrgName (from ProjectRecordID)", FieldShape::List),
+    ("Email", FieldShape::Text),
+    ("Phone", FieldShape::Text),
+    ("Gender", FieldShape::Text),
+    ("Ethnicity", FieldShape::List),
+    ("AgeRange", FieldShape::Text),
+    ("University", FieldShape::List),
+    ("LGBT", FieldShape::Text),
+    ("Country", FieldShape::Text),
+    ("State", FieldShape::Text),
+    ("FLI", FieldShape::List),
+    ("StudentStage", FieldShape::Text),
+    ("Majors", FieldShape::Text),
+    ("Minors", FieldShape::Text),
+    ("HearAbout", FieldShape::List),
 ];
 
-const REQUIRED_MENTOR_FIELDS: [&str; 16] = [
-    "FirstName",
-    "LastName",
-    "Email",
-    "Phone",
-    "Company",
-    "JobTitle",
-    "OrgName (from ProjectRecordID)",
-    "Country",
-    "State",
-    "YearsExperience",
-    "ExperienceLevel",
-    "PriorMentorship",
-    "PriorDFG",
-    "University",
-    "HearAbout",
-    "ProjectRole",
+const REQUIRED_MENTOR_FIELDS: [(&str, FieldShape); 16] = [
+    ("FirstName", FieldShape::Text),
+    ("LastName", FieldShape::Text),
+    ("Email", FieldShape::Text),
+    ("Phone", FieldShape::Text),
+    ("Company", FieldShape::Text),
+    ("JobTitle", FieldShape::Text),
+    ("OrgName (from ProjectRecordID)", FieldShape::List),
+    ("Country", FieldShape::Text),
+    ("State", FieldShape::Text),
+    ("YearsExperience", FieldShape::Text),
+    ("ExperienceLevel", FieldShape::Text),
+    ("PriorMentorship", FieldShape::List),
+    ("PriorDFG", FieldShape::List),
+    ("University", FieldShape::List),
+    ("HearAbout", FieldShape::List),
+    ("ProjectRole", FieldShape::List),
 ];
 
-const REQUIRED_NONPROFIT_FIELDS: [&str; 13] = [
-    "OrgName",
-    "ProjectName",
-    "OrgWebsite",
-    "FirstName",
-    "LastName",
-    "JobTitle",
-    "NonprofitEmail",
-    "Phone",
-    "CountryHQ",
-    "StateHQ",
-    "Address",
-    "Size",
-    "ImpactCauses",
+const REQUIRED_NONPROFIT_FIELDS: [(&str, FieldShape); 13] = [
+    ("OrgName", FieldShape::Text),
+    ("ProjectName", FieldShape::Text),
+    ("OrgWebsite", FieldShape::Text),
+    ("FirstName", FieldShape::Text),
+    ("LastName", FieldShape::Text),
+    ("JobTitle", FieldShape::Text),
+    ("NonprofitEmail", FieldShape::Text),
+    ("Phone", FieldShape::Text),
+    ("CountryHQ", FieldShape::Text),
+    ("StateHQ", FieldShape::Text),
+    ("Address", FieldShape::Text),
+    ("Size", FieldShape::Text),
+    ("ImpactCauses", FieldShape::List),
 ];
 
-const REQUIRED_MENTOR_MENTEE_LINKAGE_FIELDS: [&str; 2] =
-    ["Email", "Mentee Email (from Volunteers)"];
+const REQUIRED_MENTOR_MENTEE_LINKAGE_FIELDS: [(&str, FieldShape); 2] =
+    [("Email", FieldShape::Text), ("Mentee Email (from Volunteers)", FieldShape::List)];
 
 #[async_trait]
 #[allow(unused_variables)]
@@ -84,186 +126,657 @@ pub trait AirtableClient: Send + Sync {
         unimplemented!()
     }
 
-    async fn list_volunteers(&self, base_id: &str) -> Result<Vec<Volunteer>> {
+    async fn list_volunteers(
+        &self,
+        base_id: &str,
+        mapping: &BaseMapping,
+    ) -> Result<Vec<Volunteer>> {
         unimplemented!()
     }
 
-    async fn list_mentors(&self, base_id: &str) -> Result<Vec<Mentor>> {
+    async fn list_mentors(&self, base_id: &str, mapping: &BaseMapping) -> Result<Vec<Mentor>> {
         unimplemented!()
     }
 
-    async fn list_nonprofits(&self, base_id: &str) -> Result<Vec<Nonprofit>> {
+    async fn list_nonprofits(
+        &self,
+        base_id: &str,
+        mapping: &BaseMapping,
+    ) -> Result<Vec<Nonprofit>> {
         unimplemented!()
     }
 
-    async fn get_mentor_mentee_linkages(&self, base_id: &str) -> Result<Vec<MentorMenteeLinkage>> {
+    async fn get_mentor_mentee_linkages(
+        &self,
+        base_id: &str,
+        mapping: &BaseMapping,
+    ) -> Result<Vec<MentorMenteeLinkage>> {
         unimplemented!()
     }
 
-    async fn validate_schema(&self, base_id: &str) -> Result<bool> {
+    /// Check that a base has every table, field, and view an import reads, and that each field
+    /// has a type the import can read.
+    ///
+    /// * `base_id`: The ID of the base
+    /// * `mapping`: How the base is laid out
+    async fn validate_schema(&self, base_id: &str, mapping: &BaseMapping) -> Result<SchemaReport> {
         unimplemented!()
     }
-}
 
-#[async_trait]
-impl AirtableClient for Airtable {
-    async fn validate_schema(&self, base_id: &str) -> Result<bool> {
-        let schema = self.get_base_schema(base_id, vec![]).await?;
+    /// Read everything an import reads from a base.
+    ///
+    /// Unlike the `list_*` methods, a record which can't be read doesn't fail the whole read. It
+    /// is set aside in `BaseRecords::malformed` instead.
+    ///
+    /// * `base_id`: The ID of the base
+    /// * `mapping`: How the base is laid out
+    async fn read_base(&self, base_id: &str, mapping: &BaseMapping) -> Result<BaseRecords> {
+        unimplemented!()
+    }
 
-        let (Some(volunteers_table), Some(_)) = (
-            schema.tables.iter().find(|table| table.name == "Volunteers"),
-            schema.tables.iter().find(|table| table.name == "Nonprofits"),
-        ) else {
-            return Ok(false);
-        };
+    /// Write fields to records in a table. Fields which aren't written are left as they are.
+    ///
+    /// Records are written in batches. A batch Airtable rejects is retried one record at a time,
+    /// so that a record which can't be written doesn't stop the records sent with it. Returns the
+    /// ID of each record which could not be written, paired with why.
+    ///
+    /// * `base_id`: The ID of the base
+    /// * `table`: The name of the table
+    /// * `records`: The records to write
+    async fn update_record_fields(
+        &self,
+        base_id: &str,
+        table: &str,
+        records: Vec<RecordUpdate<Map<String, Value>>>,
+    ) -> Result<Vec<(String, String)>> {
+        unimplemented!()
+    }
 
-        let v_has_required_fields = REQUIRED_VOLUNTEER_FIELDS.iter().all(|required_field| {
-            volunteers_table
-                .fields
-                .iter()
-                .map(|f| f.name.as_ref())
-                .collect::<Vec<_>>()
-                .contains(required_field)
-        });
+    /// Register a webhook which notifies a URL when records in a base change.
+    ///
+    /// * `base_id`: The ID of the base
+    /// * `notification_url`: The URL Airtable notifies
+    async fn register_webhook(
+        &self,
+        base_id: &str,
+        notification_url: &str,
+    ) -> Result<CreateWebhookResponse> {
+        unimplemented!()
+    }
 
-        if !v_has_required_fields {
-            return Ok(false);
-        }
+    /// Delete a webhook from a base, so that Airtable stops notifying its URL.
+    ///
+    /// * `base_id`: The ID of the base
+    /// * `webhook_id`: The ID of the webhook
+    async fn unregister_webhook(&self, base_id: &str, webhook_id: &str) -> Result<()> {
+        unimplemented!()
+    }
+
+    /// Extend the life of a webhook, and return when it now expires.
+    ///
+    /// * `base_id`: The ID of the base
+    /// * `webhook_id`: The ID of the webhook
+    async fn refresh_webhook(
+        &self,
+        base_id: &str,
+        webhook_id: &str,
+    ) -> Result<Option<DateTime<Utc>>> {
+        unimplemented!()
+    }
+
+    /// Read every change a webhook recorded, starting at a cursor.
+    ///
+    /// * `base_id`: The ID of the base
+    /// * `webhook_id`: The ID of the webhook
+    /// * `cursor`: The number of the first payload to read
+    async fn read_webhook_changes(
+        &self,
+        base_id: &str,
+        webhook_id: &str,
+        cursor: i64,
+    ) -> Result<WebhookChanges> {
+        unimplemented!()
+    }
+}
 
-        let (Some(_), Some(_), Some(_)) = (
-            volunteers_table.views.iter().find(|view| view.name == VOLUNTEERS_VIEW),
-            volunteers_table.views.iter().find(|view| view.name == MENTORS_VIEW),
-            volunteers_table.views.iter().find(|view| view.name == MENTOR_MENTEE_LINKAGE_VIEW),
-        ) else {
-            return Ok(false);
+/// Check that a table has each field a view mapping needs, and that each field has a type which
+/// can be read.
+///
+/// * `table`: The table to check
+/// * `mapping`: How records are read from the table
+/// * `required_fields`: The fields the entity reads
+/// * `report`: Where to record problems
+fn check_fields(
+    table: &Table,
+    mapping: &ViewMapping,
+    required_fields: &[(&str, FieldShape)],
+    report: &mut SchemaReport,
+) {
+    for (name, shape) in required_fields {
+        let field_name = mapping.field_name(name);
+        let Some(field) = table.fields.iter().find(|field| field.name == field_name) else {
+            if !report.missing_fields.iter().any(|f| f.table == table.name && f.field == field_name)
+            {
+                report
+                    .missing_fields
+                    .push(MissingField { table: table.name.clone(), field: field_name.to_owned() });
+            }
+            continue;
         };
 
-        Ok(true)
+        let expected =
+            if mapping.is_linked(name) { LINKED_FIELD_TYPES } else { shape.field_types() };
+        let type_matches =
+            field._type.as_deref().is_some_and(|field_type| expected.contains(&field_type));
+
+        if !type_matches
+            && !report
+                .field_type_mismatches
+                .iter()
+                .any(|f| f.table == table.name && f.field == field_name)
+        {
+            report.field_type_mismatches.push(FieldTypeMismatch {
+                table: table.name.clone(),
+                field: field_name.to_owned(),
+                expected: expected.iter().map(ToString::to_string).collect(),
+                found: field._type.clone(),
+            });
+        }
     }
+}
 
-    async fn list_available_bases(&self) -> Result<Vec<Base>> {
-        let mut offset = Option::<String>::None;
+/// Check a view mapping against a base's schema.
+///
+/// * `schema`: The schema of the base
+/// * `mapping`: How records are read from the view
+/// * `required_fields`: The fields the entity reads
+/// * `report`: Where to record problems
+fn check_view(
+    schema: &SchemaResponse,
+    mapping: &ViewMapping,
+    required_fields: &[(&str, FieldShape)],
+    report: &mut SchemaReport,
+) {
+    let Some(table) = schema.tables.iter().find(|table| table.name == mapping.table) else {
+        if !report.missing_tables.contains(&mapping.table) {
+            report.missing_tables.push(mapping.table.clone());
+        }
+        return;
+    };
 
-        let mut bases = Vec::<Base>::with_capacity(10);
+    check_fields(table, mapping, required_fields, report);
 
-        while let Ok(mut res) = self.list_bases(offset).await {
-            bases.append(&mut res.bases);
+    if !table.views.iter().any(|view| mapping.view.matches(&view.name)) {
+        report
+            .missing_views
+            .push(MissingView { table: table.name.clone(), view: mapping.view.to_string() });
+    }
+}
 
-            match res.offset {
-                Some(next_offset) => offset = Some(next_offset),
-                None => break,
+/// A view mapping resolved against a base's schema, ready to read records with.
+///
+/// * `mapping`: How records are read from the view
+/// * `table_id`: The ID of the table the records are in
+/// * `query`: The query which lists the records
+/// * `required_fields`: The fields the entity reads
+/// * `linked_names`: The names of linked records by their ID, for each linked field
+struct ResolvedView<'a> {
+    mapping: &'a ViewMapping,
+    table_id: String,
+    query: ListRecordsQuery,
+    required_fields: &'a [(&'a str, FieldShape)],
+    linked_names: HashMap<String, HashMap<String, String>>,
+}
+
+impl ResolvedView<'_> {
+    /// Turn the fields of a record in the base into the fields an entity reads.
+    ///
+    /// * `fields`: The fields of the record, by their names in the base
+    fn map_fields(&self, mut fields: Map<String, Value>) -> Value {
+        let mut mapped = Map::new();
+
+        for (name, _) in self.required_fields {
+            let Some(mut value) = fields.remove(self.mapping.field_name(name)) else {
+                continue;
+            };
+
+            if let Some(names) = self.linked_names.get(*name) {
+                value = match value {
+                    Value::Array(ids) => Value::Array(
+                        ids.into_iter()
+                            .map(|id| match id.as_str().and_then(|id| names.get(id)) {
+                                Some(name) => Value::String(name.clone()),
+                                None => id,
+                            })
+                            .collect(),
+                    ),
+                    value => value,
+                };
             }
+
+            mapped.insert(name.to_string(), self.mapping.map_value(name, value));
         }
 
-        Ok(bases)
+        Value::Object(mapped)
     }
+}
 
-    async fn list_volunteers(&self, base_id: &str) -> Result<Vec<Volunteer>> {
-        let mut query = ListRecordsQueryBuilder::default()
-            .view(VOLUNTEERS_VIEW.to_owned())
-            .fields(REQUIRED_VOLUNTEER_FIELDS.map(ToString::to_string).to_vec())
-            .build()?;
-
-        let mut volunteers = Vec::<Volunteer>::with_capacity(300);
+/// Fetch the names of the records a field links to, by their ID. The name of a record is the
+/// value of its table's primary field.
+///
+/// * `airtable`: The Airtable client
+/// * `base_id`: The ID of the base
+/// * `schema`: The schema of the base
+/// * `table`: The table the field is in
+/// * `field_name`: The name of the field in the base
+async fn fetch_linked_names(
+    airtable: &Airtable,
+    base_id: &str,
+    schema: &SchemaResponse,
+    table: &Table,
+    field_name: &str,
+) -> Result<HashMap<String, String>> {
+    let Some(field) = table.fields.iter().find(|field| field.name == field_name) else {
+        bail!("{field_name} field not found in {} table", table.name)
+    };
+
+    let Some(linked_table) = field
+        .options
+        .as_ref()
+        .and_then(|options| options.get("linkedTableId"))
+        .and_then(Value::as_str)
+        .and_then(|linked_table_id| schema.tables.iter().find(|t| t.id == linked_table_id))
+    else {
+        bail!("{field_name} field in {} table does not link to another table", table.name)
+    };
+
+    let Some(primary_field) =
+        linked_table.fields.iter().find(|field| field.id == linked_table.primary_field_id)
+    else {
+        bail!("primary field not found in {} table", linked_table.name)
+    };
+
+    let query = ListRecordsQueryBuilder::default()
+        .fields(vec![primary_field.name.clone()])
+        .max_records(None)
+        .build()?;
+
+    let names = airtable
+        .list_all_records::<Map<String, Value>>(base_id, &linked_table.id, Some(&query))
+        .try_filter_map(|record| async move {
+            let name = record.fields.get(&primary_field.name).and_then(Value::as_str);
+            Ok(name.map(|name| (record.id, name.to_owned())))
+        })
+        .try_collect::<HashMap<String, String>>()
+        .await?;
+
+    Ok(names)
+}
 
-        loop {
-            let res = self.list_records::<Volunteer>(base_id, "Volunteers", Some(&query)).await?;
+/// Find the table and view a view mapping refers to, and fetch the names of any linked records.
+///
+/// * `airtable`: The Airtable client
+/// * `base_id`: The ID of the base
+/// * `schema`: The schema of the base
+/// * `mapping`: How records are read from the view
+/// * `required_fields`: The fields the entity reads
+async fn resolve_view<'a>(
+    airtable: &Airtable,
+    base_id: &str,
+    schema: &SchemaResponse,
+    mapping: &'a ViewMapping,
+    required_fields: &'a [(&'a str, FieldShape)],
+) -> Result<ResolvedView<'a>> {
+    let Some(table) = schema.tables.iter().find(|table| table.name == mapping.table) else {
+        bail!("{} table not found in base schema", mapping.table)
+    };
+
+    let Some(view) = table.views.iter().find(|view| mapping.view.matches(&view.name)) else {
+        bail!("{} view not found in {} table", mapping.view, mapping.table)
+    };
+
+    let query = ListRecordsQueryBuilder::default()
+        .view(view.id.to_owned())
+        .max_records(None)
+        .fields(
+            required_fields
+                .iter()
+                .map(|(name, _)| mapping.field_name(name).to_owned())
+                .collect::<Vec<_>>(),
+        )
+        .build()?;
+
+    let mut linked_names = HashMap::new();
+    for field in &mapping.linked_fields {
+        let names =
+            fetch_linked_names(airtable, base_id, schema, table, mapping.field_name(field)).await?;
+        linked_names.insert(field.clone(), names);
+    }
 
-            volunteers
-                .append(&mut res.records.into_iter().map(|data| data.fields).collect::<Vec<_>>());
+    Ok(ResolvedView { mapping, table_id: table.id.clone(), query, required_fields, linked_names })
+}
 
-            match res.offset {
-                Some(next_offset) => query.offset = Some(next_offset),
-                None => break,
+/// List every record in a view, setting aside records whose fields can't be read as `T`.
+///
+/// * `airtable`: The Airtable client
+/// * `base_id`: The ID of the base
+/// * `schema`: The schema of the base
+/// * `mapping`: How records are read from the view
+/// * `required_fields`: The fields `T` reads
+/// * `malformed`: Where to set aside records which can't be read
+async fn read_view<T: DeserializeOwned>(
+    airtable: &Airtable,
+    base_id: &str,
+    schema: &SchemaResponse,
+    mapping: &ViewMapping,
+    required_fields: &[(&str, FieldShape)],
+    malformed: &mut Vec<MalformedRecord>,
+) -> Result<Vec<Record<T>>> {
+    let view = resolve_view(airtable, base_id, schema, mapping, required_fields).await?;
+    let mut records = Vec::<Record<T>>::with_capacity(100);
+
+    let mut listed = pin!(airtable.list_all_records::<Map<String, Value>>(
+        base_id,
+        &view.table_id,
+        Some(&view.query)
+    ));
+
+    while let Some(record) = listed.try_next().await? {
+        match serde_json::from_value::<T>(view.map_fields(record.fields)) {
+            Ok(fields) => {
+                records.push(Record { id: record.id, fields, created_time: record.created_time })
             }
+            Err(e) => malformed.push(MalformedRecord {
+                table: mapping.table.clone(),
+                record_id: record.id,
+                error: e.to_string(),
+            }),
+        }
+    }
+
+    Ok(records)
+}
+
+/// Fail if any record could not be read.
+///
+/// * `malformed`: The records which could not be read
+fn ensure_readable(malformed: &[MalformedRecord]) -> Result<()> {
+    if let Some(record) = malformed.first() {
+        bail!(
+            "record {} in {} table could not be read: {}",
+            record.record_id,
+            record.table,
+            record.error
+        );
+    }
+
+    Ok(())
+}
+
+#[async_trait]
+impl AirtableClient for Airtable {
+    async fn validate_schema(&self, base_id: &str, mapping: &BaseMapping) -> Result<SchemaReport> {
+        let schema = self.get_base_schema(base_id, vec![]).await?;
+        let mut report = SchemaReport::default();
+
+        for (view_mapping, required_fields) in [
+            (&mapping.volunteers, REQUIRED_VOLUNTEER_FIELDS.as_slice()),
+            (&mapping.mentors, REQUIRED_MENTOR_FIELDS.as_slice()),
+            (&mapping.nonprofits, REQUIRED_NONPROFIT_FIELDS.as_slice()),
+            (&mapping.mentor_mentee_linkages, REQUIRED_MENTOR_MENTEE_LINKAGE_FIELDS.as_slice()),
+        ] {
+            check_view(&schema, view_mapping, required_fields, &mut report);
         }
 
-        Ok(volunteers)
+        Ok(report)
     }
 
-    async fn list_mentors(&self, base_id: &str) -> Result<Vec<Mentor>> {
-        let mut query = ListRecordsQueryBuilder::default()
-            .view(MENTORS_VIEW.to_owned())
-            .fields(REQUIRED_MENTOR_FIELDS.map(ToString::to_string).to_vec())
-            .build()?;
+    async fn list_available_bases(&self) -> Result<Vec<Base>> {
+        let mut offset = Option::<String>::None;
 
-        let mut mentors = Vec::<Mentor>::with_capacity(100);
+        let mut bases = Vec::<Base>::with_capacity(10);
 
         loop {
-            let res = self.list_records::<Mentor>(base_id, "Volunteers", Some(&query)).await?;
-
-            mentors
-                .append(&mut res.records.into_iter().map(|data| data.fields).collect::<Vec<_>>());
+            let mut res = self.list_bases(offset).await?;
+            bases.append(&mut res.bases);
 
             match res.offset {
-                Some(next_offset) => query.offset = Some(next_offset),
+                Some(next_offset) => offset = Some(next_offset),
                 None => break,
             }
         }
 
-        Ok(mentors)
+        Ok(bases)
     }
 
-    async fn list_nonprofits(&self, base_id: &str) -> Result<Vec<Nonprofit>> {
+    async fn list_volunteers(
+        &self,
+        base_id: &str,
+        mapping: &BaseMapping,
+    ) -> Result<Vec<Volunteer>> {
         let schema = self.get_base_schema(base_id, vec![]).await?;
-        let Some(nonprofits_table) = schema.tables.iter().find(|table| table.name == "Nonprofits")
-        else {
-            bail!("Nonprofits table not found in base schema")
-        };
+        let mut malformed = Vec::<MalformedRecord>::new();
+
+        let records = read_view::<Volunteer>(
+            self,
+            base_id,
+            &schema,
+            &mapping.volunteers,
+            &REQUIRED_VOLUNTEER_FIELDS,
+            &mut malformed,
+        )
+        .await?;
+        ensure_readable(&malformed)?;
+
+        Ok(records
+            .into_iter()
+            .map(|data| Volunteer { record_id: data.id, ..data.fields })
+            .collect())
+    }
 
-        let Some(finalized_nonprofits_view) = nonprofits_table.views.iter().find(|view| {
-            view.name.starts_with(NONPROFITS_VIEW_PREFIX)
-                && view.name.ends_with(NONPROFITS_VIEW_SUFFIX)
-        }) else {
-            bail!("Finalized nonprofits view not found in Nonprofits table")
-        };
+    async fn list_mentors(&self, base_id: &str, mapping: &BaseMapping) -> Result<Vec<Mentor>> {
+        let schema = self.get_base_schema(base_id, vec![]).await?;
+        let mut malformed = Vec::<MalformedRecord>::new();
+
+        let records = read_view::<Mentor>(
+            self,
+            base_id,
+            &schema,
+            &mapping.mentors,
+            &REQUIRED_MENTOR_FIELDS,
+            &mut malformed,
+        )
+        .await?;
+        ensure_readable(&malformed)?;
+
+        Ok(records.into_iter().map(|data| Mentor { record_id: data.id, ..data.fields }).collect())
+    }
 
-        let mut query = ListRecordsQueryBuilder::default()
-            .view(finalized_nonprofits_view.id.to_owned())
-            .fields(REQUIRED_NONPROFIT_FIELDS.map(ToString::to_string).to_vec())
-            .build()?;
+    async fn list_nonprofits(
+        &self,
+        base_id: &str,
+        mapping: &BaseMapping,
+    ) -> Result<Vec<Nonprofit>> {
+        let schema = self.get_base_schema(base_id, vec![]).await?;
+        let mut malformed = Vec::<MalformedRecord>::new();
+
+        let records = read_view::<Nonprofit>(
+            self,
+            base_id,
+            &schema,
+            &mapping.nonprofits,
+            &REQUIRED_NONPROFIT_FIELDS,
+            &mut malformed,
+        )
+        .await?;
+        ensure_readable(&malformed)?;
+
+        Ok(records
+            .into_iter()
+            .map(|data| Nonprofit { record_id: data.id, ..data.fields })
+            .collect())
+    }
 
-        let mut nonprofits = Vec::<Nonprofit>::with_capacity(100);
+    async fn get_mentor_mentee_linkages(
+        &self,
+        base_id: &str,
+        mapping: &BaseMapping,
+    ) -> Result<Vec<MentorMenteeLinkage>> {
+        let schema = self.get_base_schema(base_id, vec![]).await?;
+        let mut malformed = Vec::<MalformedRecord>::new();
+
+        let records = read_view::<MentorMenteeLinkage>(
+            self,
+            base_id,
+            &schema,
+            &mapping.mentor_mentee_linkages,
+            &REQUIRED_MENTOR_MENTEE_LINKAGE_FIELDS,
+            &mut malformed,
+        )
+        .await?;
+        ensure_readable(&malformed)?;
 
-        loop {
-            let res = self.list_records::<Nonprofit>(base_id, "Nonprofits", Some(&query)).await?;
+        log::info!("Retrieved mentor-mentee linkages from Airtable");
 
-            nonprofits
-                .append(&mut res.records.into_iter().map(|data| data.fields).collect::<Vec<_>>());
+        Ok(records.into_iter().map(|data| data.fields).collect())
+    }
 
-            match res.offset {
-                Some(next_offset) => query.offset = Some(next_offset),
-                None => break,
+    async fn read_base(&self, base_id: &str, mapping: &BaseMapping) -> Result<BaseRecords> {
+        let schema = self.get_base_schema(base_id, vec![]).await?;
+        let mut malformed = Vec::<MalformedRecord>::new();
+
+        let volunteers = read_view::<Volunteer>(
+            self,
+            base_id,
+            &schema,
+            &mapping.volunteers,
+            &REQUIRED_VOLUNTEER_FIELDS,
+            &mut malformed,
+        )
+        .await?
+        .into_iter()
+        .map(|data| Volunteer { record_id: data.id, ..data.fields })
+        .collect();
+
+        let mentors = read_view::<Mentor>(
+            self,
+            base_id,
+            &schema,
+            &mapping.mentors,
+            &REQUIRED_MENTOR_FIELDS,
+            &mut malformed,
+        )
+        .await?
+        .into_iter()
+        .map(|data| Mentor { record_id: data.id, ..data.fields })
+        .collect();
+
+        let nonprofits = read_view::<Nonprofit>(
+            self,
+            base_id,
+            &schema,
+            &mapping.nonprofits,
+            &REQUIRED_NONPROFIT_FIELDS,
+            &mut malformed,
+        )
+        .await?
+        .into_iter()
+        .map(|data| Nonprofit { record_id: data.id, ..data.fields })
+        .collect();
+
+        let mentor_mentee_linkages = read_view::<MentorMenteeLinkage>(
+            self,
+            base_id,
+            &schema,
+            &mapping.mentor_mentee_linkages,
+            &REQUIRED_MENTOR_MENTEE_LINKAGE_FIELDS,
+            &mut malformed,
+        )
+        .await?
+        .into_iter()
+        .map(|data| data.fields)
+        .collect();
+
+        Ok(BaseRecords { volunteers, mentors, nonprofits, mentor_mentee_linkages, malformed })
+    }
+
+    async fn update_record_fields(
+        &self,
+        base_id: &str,
+        table: &str,
+        records: Vec<RecordUpdate<Map<String, Value>>>,
+    ) -> Result<Vec<(String, String)>> {
+        let options = WriteRecordsOptionsBuilder::default().typecast(true).build()?;
+        let mut failures = Vec::<(String, String)>::new();
+
+        for batch in records.chunks(MAX_RECORDS_PER_REQUEST) {
+            let Err(e) =
+                self.update_records(base_id, table, batch, UpdateMethod::Merge, &options).await
+            else {
+                continue;
+            };
+
+            log::warn!("Airtable rejected a batch of records, retrying them one at a time: {e}");
+            for record in batch {
+                if let Err(e) = self
+                    .update_record(
+                        base_id,
+                        table,
+                        &record.id,
+                        &record.fields,
+                        UpdateMethod::Merge,
+                        &options,
+                    )
+                    .await
+                {
+                    failures.push((record.id.clone(), e.to_string()));
+                }
             }
         }
 
-        Ok(nonprofits)
+        Ok(failures)
     }
 
-    async fn get_mentor_mentee_linkages(&self, base_id: &str) -> Result<Vec<MentorMenteeLinkage>> {
-        let mut query = ListRecordsQueryBuilder::default()
-            .view(MENTOR_MENTEE_LINKAGE_VIEW.to_owned())
-            .fields(REQUIRED_MENTOR_MENTEE_LINKAGE_FIELDS.map(ToString::to_string).to_vec())
-            .build()?;
+    async fn register_webhook(
+        &self,
+        base_id: &str,
+        notification_url: &str,
+    ) -> Result<CreateWebhookResponse> {
+        let filters = WebhookFiltersBuilder::default().build()?;
+        Ok(self.create_webhook(base_id, Some(notification_url), &filters).await?)
+    }
 
-        let mut linkages = Vec::<MentorMenteeLinkage>::with_capacity(100);
-        loop {
-            let res = self
-                .list_records::<MentorMenteeLinkage>(base_id, "Volunteers", Some(&query))
-                .await?;
+    async fn unregister_webhook(&self, base_id: &str, webhook_id: &str) -> Result<()> {
+        Ok(self.delete_webhook(base_id, webhook_id).await?)
+    }
 
-            linkages
-                .append(&mut res.records.into_iter().map(|data| data.fields).collect::<Vec<_>>());
+    async fn refresh_webhook(
+        &self,
+        base_id: &str,
+        webhook_id: &str,
+    ) -> Result<Option<DateTime<Utc>>> {
+        Ok(Airtable::refresh_webhook(self, base_id, webhook_id).await?.expiration_time)
+    }
 
-            match res.offset {
-                Some(next_offset) => query.offset = Some(next_offset),
-                None => break,
+    async fn read_webhook_changes(
+        &self,
+        base_id: &str,
+        webhook_id: &str,
+        cursor: i64,
+    ) -> Result<WebhookChanges> {
+        let mut changes = WebhookChanges { cursor, payloads: 0 };
+
+        loop {
+            let res = self.list_webhook_payloads(base_id, webhook_id, changes.cursor, None).await?;
+            changes.cursor = res.cursor;
+            changes.payloads += res.payloads.len();
+
+            if !res.might_have_more {
+                break;
             }
         }
 
-        log::info!("Retrieved mentor-mentee linkages from Airtable");
-
-        Ok(linkages)
+        Ok(changes)
     }
 }
 
@@ -3,16 +3,218 @@ mod fixtures;
 use anyhow::Result;
 use fixtures::airtable;
 use rstest::rstest;
+use scipio_airtable::base_data::entities::{RecordUpdate, Table};
+use scipio_airtable::base_data::records::ListRecordsQuery;
 use scipio_airtable::Airtable;
+use scipio_fake_server::FakeServer;
+use serde_json::{json, Map, Value};
 
-use crate::services::airtable::AirtableClient;
+use crate::services::airtable::entities::{
+    MalformedRecord, MentorMenteeLinkage, MissingField, SchemaReport,
+};
+use crate::services::airtable::mapping::{BaseMapping, ViewSelector};
+use crate::services::airtable::{
+    check_fields, ensure_readable, read_view, AirtableClient, FieldShape, ResolvedView,
+    REQUIRED_MENTOR_MENTEE_LINKAGE_FIELDS,
+};
 
 #[rstest]
 #[tokio::test]
 pub async fn test_list_mentors(airtable: Airtable) -> Result<()> {
-    let records = airtable.list_mentors("appcOdqCMHAlxDqDZ").await?;
+    let records = airtable.list_mentors("appcOdqCMHAlxDqDZ", &BaseMapping::default()).await?;
 
     dbg!(&records);
 
     Ok(())
 }
+
+#[test]
+pub fn test_check_fields() -> Result<()> {
+    let table = serde_json::from_value::<Table>(json!({
+        "id": "tblNonprofits",
+        "primaryFieldId": "fldOrgName",
+        "name": "Nonprofits",
+        "fields": [
+            { "id": "fldOrgName", "type": "singleLineText", "name": "OrgName" },
+            { "id": "fldProject", "type": "singleLineText", "name": "Project" },
+            { "id": "fldImpactCauses", "type": "multipleSelects", "name": "ImpactCauses" },
+        ],
+        "views": [],
+    }))?;
+
+    let required_fields = [
+        ("OrgName", FieldShape::Text),
+        ("ProjectName", FieldShape::Text),
+        ("ImpactCauses", FieldShape::List),
+    ];
+
+    let mut report = SchemaReport::default();
+    let mapping = BaseMapping::default().nonprofits;
+    check_fields(&table, &mapping, &required_fields, &mut report);
+    check_fields(&table, &mapping, &required_fields, &mut report);
+
+    assert!(!report.is_valid());
+    assert_eq!(
+        report.missing_fields,
+        vec![MissingField { table: "Nonprofits".to_owned(), field: "ProjectName".to_owned() }]
+    );
+
+    // Impact causes are linked records by default
+    assert_eq!(report.field_type_mismatches.len(), 1);
+    assert_eq!(report.field_type_mismatches[0].field, "ImpactCauses");
+    assert_eq!(report.field_type_mismatches[0].expected, vec!["multipleRecordLinks"]);
+    assert_eq!(report.field_type_mismatches[0].found.as_deref(), Some("multipleSelects"));
+
+    let mut mapping = mapping;
+    mapping.fields.insert("ProjectName".to_owned(), "Project".to_owned());
+    mapping.linked_fields.clear();
+
+    let mut report = SchemaReport::default();
+    check_fields(&table, &mapping, &required_fields, &mut report);
+    assert!(report.is_valid());
+
+    Ok(())
+}
+
+#[test]
+pub fn test_map_fields() -> Result<()> {
+    let mut mapping = BaseMapping::default().nonprofits;
+    mapping.fields.insert("OrgName".to_owned(), "Organization".to_owned());
+    mapping
+        .values
+        .insert("Size".to_owned(), [("Small".to_owned(), "<20".to_owned())].into_iter().collect());
+
+    let required_fields = [
+        ("OrgName", FieldShape::Text),
+        ("Size", FieldShape::Text),
+        ("ImpactCauses", FieldShape::List),
+    ];
+
+    let view = ResolvedView {
+        mapping: &mapping,
+        table_id: "tblNonprofits".to_owned(),
+        query: ListRecordsQuery::default(),
+        required_fields: &required_fields,
+        linked_names: [(
+            "ImpactCauses".to_owned(),
+            [("recAnimals".to_owned(), "Animals".to_owned())].into_iter().collect(),
+        )]
+        .into_iter()
+        .collect(),
+    };
+
+    let fields = json!({
+        "Organization": "PeteOrg",
+        "Size": "Small",
+        "ImpactCauses": ["recAnimals", "recUnknown"],
+        "Unused": true,
+    });
+    let Value::Object(fields) = fields else { unreachable!() };
+
+    assert_eq!(
+        view.map_fields(fields),
+        json!({
+            "OrgName": "PeteOrg",
+            "Size": "<20",
+            "ImpactCauses": ["Animals", "recUnknown"],
+        })
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+pub async fn test_read_view_sets_aside_malformed_records() -> Result<()> {
+    let fake = FakeServer::start();
+    let airtable = Airtable::new("test-api-token", 0)?.with_base_url(fake.url());
+
+    let base_id = fake.add_airtable_base("Scipio Test");
+    fake.add_airtable_table(
+        &base_id,
+        "Volunteers",
+        &["Email", "Mentee Email (from Volunteers)"],
+        &["Linkages"],
+    );
+    let records = [
+        json!({ "Email": "martina@example.com", "Mentee Email (from Volunteers)": ["roger@example.com"] }),
+        json!({ "Email": 42 }),
+        json!({ "Email": "steffi@example.com" }),
+    ]
+    .into_iter()
+    .map(serde_json::from_value::<Map<String, Value>>)
+    .collect::<Result<Vec<_>, _>>()?;
+    let record_ids = fake.add_airtable_records(&base_id, "Volunteers", records);
+
+    let mut mapping = BaseMapping::default();
+    mapping.mentor_mentee_linkages.view = ViewSelector::Name("Linkages".to_owned());
+
+    let schema = airtable.get_base_schema(&base_id, vec![]).await?;
+    let mut malformed = Vec::<MalformedRecord>::new();
+    let linkages = read_view::<MentorMenteeLinkage>(
+        &airtable,
+        &base_id,
+        &schema,
+        &mapping.mentor_mentee_linkages,
+        &REQUIRED_MENTOR_MENTEE_LINKAGE_FIELDS,
+        &mut malformed,
+    )
+    .await?;
+
+    // The records on either side of the malformed record are still read
+    let emails = linkages.iter().map(|l| l.fields.mentor_email.as_str()).collect::<Vec<_>>();
+    assert_eq!(emails, vec!["martina@example.com", "steffi@example.com"]);
+    assert_eq!(linkages[0].fields.mentee_email, vec!["roger@example.com".to_owned()]);
+
+    assert_eq!(malformed.len(), 1);
+    assert_eq!(malformed[0].table, "Volunteers");
+    assert_eq!(malformed[0].record_id, record_ids[1]);
+
+    let err = ensure_readable(&malformed).unwrap_err().to_string();
+    assert!(err.contains(&record_ids[1]), "{err}");
+
+    // Listing isn't lenient, so the same view fails to list
+    assert!(airtable.get_mentor_mentee_linkages(&base_id, &mapping).await.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+pub async fn test_update_record_fields_retries_rejected_batches() -> Result<()> {
+    let fake = FakeServer::start();
+    let airtable = Airtable::new("test-api-token", 0)?.with_base_url(fake.url());
+
+    let base_id = fake.add_airtable_base("Scipio Test");
+    fake.add_airtable_table(&base_id, "Volunteers", &["Name", "Workspace Email"], &["Grid"]);
+    // More records than fit in one request, so that the second batch is the one rejected
+    let records = (0..12)
+        .map(|i| Map::from_iter([("Name".to_owned(), json!(format!("Volunteer {i}")))]))
+        .collect::<Vec<_>>();
+    let record_ids = fake.add_airtable_records(&base_id, "Volunteers", records);
+
+    let workspace_email = |id: &str| {
+        Map::from_iter([("Workspace Email".to_owned(), json!(format!("{id}@developforgood.org")))])
+    };
+    let mut updates = record_ids
+        .iter()
+        .map(|id| RecordUpdate { id: id.clone(), fields: workspace_email(id) })
+        .collect::<Vec<_>>();
+    updates.insert(11, RecordUpdate { id: "recMissing".to_owned(), fields: workspace_email("x") });
+
+    let failures = airtable.update_record_fields(&base_id, "Volunteers", updates).await?;
+
+    // Only the record which doesn't exist fails. The records sent with it are still written
+    assert_eq!(failures.len(), 1);
+    assert_eq!(failures[0].0, "recMissing");
+
+    let written =
+        airtable.collect_all_records::<Map<String, Value>>(&base_id, "Volunteers", None).await?;
+    assert_eq!(written.len(), record_ids.len());
+    for record in written {
+        assert_eq!(
+            record.fields.get("Workspace Email"),
+            Some(&json!(format!("{}@developforgood.org", record.id)))
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,186 @@
+//! This module contains the definition of the `QueryAudit` trait as well as the default
+//! implementation of the trait for the `PgBackend` struct.
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+use serde_json::Value;
+use sqlx::{Database, Postgres, QueryBuilder, Transaction};
+use uuid::Uuid;
+
+use super::exec_with_tx;
+use crate::services::storage::entities::AuditEvent;
+use crate::services::storage::pagination::{Page, PageOptions};
+use crate::services::storage::types::{AuditAction, AuditOutcome};
+use crate::services::storage::{Acquire, ExecOpts, PgBackend};
+
+/// Data needed to record an entry in the audit log.
+///
+/// * `principal`: The email of the user who performed the action
+/// * `action`: What the user did
+/// * `target_ids`: The ids of the records the action touched
+/// * `summary`: A summary of the request
+/// * `outcome`: Whether the action succeeded
+/// * `error`: Information about the error, if the action failed
+#[derive(Builder, Debug, Clone)]
+pub struct CreateAuditEvent {
+    #[builder(setter(into))]
+    pub principal: String,
+    pub action: AuditAction,
+    #[builder(default)]
+    pub target_ids: Vec<Uuid>,
+    #[builder(default = "Value::Object(Default::default())")]
+    pub summary: Value,
+    #[builder(default = "AuditOutcome::Succeeded")]
+    pub outcome: AuditOutcome,
+    #[builder(setter(into, strip_option), default)]
+    pub error: Option<String>,
+}
+
+/// Filters for searching the audit log.
+///
+/// Every filter is optional. Events are returned newest first.
+///
+/// * `principal`: Only events performed by this user
+/// * `action`: Only events for this action
+/// * `target_id`: Only events which touched this record
+/// * `outcome`: Only events with this outcome
+/// * `created_after`: Only events recorded at or after this time
+/// * `created_before`: Only events recorded before this time
+#[derive(Builder, Debug, Clone, Default)]
+#[builder(default)]
+pub struct AuditFilter {
+    #[builder(setter(into, strip_option))]
+    pub principal: Option<String>,
+    #[builder(setter(into, strip_option))]
+    pub action: Option<AuditAction>,
+    #[builder(setter(into, strip_option))]
+    pub target_id: Option<Uuid>,
+    #[builder(setter(into, strip_option))]
+    pub outcome: Option<AuditOutcome>,
+    #[builder(setter(into, strip_option))]
+    pub created_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option))]
+    pub created_before: Option<DateTime<Utc>>,
+}
+
+/// A trait for recording and searching the audit log.
+///
+/// If you implement a new storage backend, this trait is required for it to implement
+/// `StorageLayer`. The default implementation is for `Postgres`.
+#[async_trait]
+#[allow(unused)]
+pub trait QueryAudit<DB: Database> {
+    /// Record an entry in the audit log.
+    ///
+    /// * `data`: The entry to record
+    /// * `exec_opts`: Execution options for the query
+    async fn create_audit_event(
+        &self,
+        data: CreateAuditEvent,
+        exec_opts: &mut ExecOpts<DB>,
+    ) -> Result<Uuid> {
+        unimplemented!()
+    }
+
+    /// Fetch a page of the audit log, newest first.
+    ///
+    /// * `filter`: Which events to fetch
+    /// * `page`: Which page of events to fetch
+    /// * `exec_opts`: Execution options for the query
+    async fn fetch_audit_events(
+        &self,
+        filter: AuditFilter,
+        page: PageOptions,
+        exec_opts: &mut ExecOpts<DB>,
+    ) -> Result<Page<AuditEvent>> {
+        unimplemented!()
+    }
+}
+
+#[async_trait]
+impl QueryAudit<Postgres> for PgBackend {
+    async fn create_audit_event(
+        &self,
+        data: CreateAuditEvent,
+        exec_opts: &mut ExecOpts<Postgres>,
+    ) -> Result<Uuid> {
+        async fn exec(data: CreateAuditEvent, tx: &mut Transaction<'_, Postgres>) -> Result<Uuid> {
+            let query = include_str!("queries/audit/create_audit_event.sql");
+
+            let id = sqlx::query_scalar::<_, Uuid>(query)
+                .bind(data.principal)
+                .bind(data.action.to_string())
+                .bind(data.target_ids)
+                .bind(data.summary)
+                .bind(data.outcome)
+                .bind(data.error)
+                .fetch_one(&mut **tx)
+                .await
+                .context("error recording audit event")?;
+            Ok(id)
+        }
+
+        exec_with_tx!(self, exec_opts, exec, data)
+    }
+
+    async fn fetch_audit_events(
+        &self,
+        filter: AuditFilter,
+        page: PageOptions,
+        exec_opts: &mut ExecOpts<Postgres>,
+    ) -> Result<Page<AuditEvent>> {
+        async fn exec(
+            filter: AuditFilter,
+            page: PageOptions,
+            tx: &mut Transaction<'_, Postgres>,
+        ) -> Result<Page<AuditEvent>> {
+            let fragment = include_str!("queries/audit/fetch_audit_events.fragment.sql");
+            let mut query = QueryBuilder::<Postgres>::new(fragment);
+
+            if let Some(principal) = filter.principal {
+                query.push(" and principal = ").push_bind(principal);
+            }
+
+            if let Some(action) = filter.action {
+                query.push(" and action = ").push_bind(action.to_string());
+            }
+
+            if let Some(target_id) = filter.target_id {
+                query.push(" and target_ids @> array[").push_bind(target_id).push("]");
+            }
+
+            if let Some(outcome) = filter.outcome {
+                query.push(" and outcome = ").push_bind(outcome);
+            }
+
+            if let Some(created_after) = filter.created_after {
+                query.push(" and created_at >= ").push_bind(created_after);
+            }
+
+            if let Some(created_before) = filter.created_before {
+                query.push(" and created_at < ").push_bind(created_before);
+            }
+
+            if let Some(cursor) = page.cursor {
+                query
+                    .push(" and (created_at, id) < (select created_at, id from audit_events where id = ")
+                    .push_bind(cursor)
+                    .push(")");
+            }
+
+            query.push(" order by created_at desc, id desc limit ").push_bind(page.limit + 1);
+
+            let events = query
+                .build_query_as::<AuditEvent>()
+                .fetch_all(&mut **tx)
+                .await
+                .context("error fetching audit events")?;
+
+            Ok(Page::from_rows(events, page.limit, |event| event.id))
+        }
+
+        exec_with_tx!(self, exec_opts, exec, filter, page)
+    }
+}
@@ -0,0 +1,111 @@
+//! This module contains the definition of the `QueryCsvUploads` trait as well as the default
+//! implementation of the trait for the `PgBackend` struct.
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use sqlx::{Database, Postgres, Transaction};
+use uuid::Uuid;
+
+use super::exec_with_tx;
+use crate::services::storage::types::CsvImportFiles;
+use crate::services::storage::{Acquire, ExecOpts, PgBackend};
+
+/// A trait for querying the CSV files uploaded for imports.
+///
+/// If you implement a new storage backend, this trait is required for it to implement
+/// `StorageLayer`. The default implementation is for `Postgres`.
+#[async_trait]
+#[allow(unused)]
+pub trait QueryCsvUploads<DB: Database> {
+    /// Store the CSV files uploaded for an import.
+    ///
+    /// * `files`: The contents of the files
+    /// * `exec_opts`: Execution options for the query
+    async fn create_csv_upload(
+        &self,
+        files: CsvImportFiles,
+        exec_opts: &mut ExecOpts<DB>,
+    ) -> Result<Uuid> {
+        unimplemented!()
+    }
+
+    /// Fetch the CSV files of an upload by ID.
+    ///
+    /// * `id`: The ID of the upload to fetch
+    /// * `exec_opts`: Execution options for the query
+    async fn fetch_csv_upload(
+        &self,
+        id: Uuid,
+        exec_opts: &mut ExecOpts<DB>,
+    ) -> Result<Option<CsvImportFiles>> {
+        unimplemented!()
+    }
+
+    /// Delete an upload by ID.
+    ///
+    /// * `id`: The ID of the upload to delete
+    /// * `exec_opts`: Execution options for the query
+    async fn delete_csv_upload(&self, id: Uuid, exec_opts: &mut ExecOpts<DB>) -> Result<()> {
+        unimplemented!()
+    }
+}
+
+#[async_trait]
+impl QueryCsvUploads<Postgres> for PgBackend {
+    async fn create_csv_upload(
+        &self,
+        files: CsvImportFiles,
+        exec_opts: &mut ExecOpts<Postgres>,
+    ) -> Result<Uuid> {
+        async fn exec(files: CsvImportFiles, tx: &mut Transaction<'_, Postgres>) -> Result<Uuid> {
+            let query = include_str!("queries/csv_uploads/create_csv_upload.sql");
+
+            let id = sqlx::query_scalar::<_, Uuid>(query)
+                .bind(files.volunteers)
+                .bind(files.mentors)
+                .bind(files.nonprofits)
+                .fetch_one(&mut **tx)
+                .await
+                .context("error creating csv upload")?;
+            Ok(id)
+        }
+
+        exec_with_tx!(self, exec_opts, exec, files)
+    }
+
+    async fn fetch_csv_upload(
+        &self,
+        id: Uuid,
+        exec_opts: &mut ExecOpts<Postgres>,
+    ) -> Result<Option<CsvImportFiles>> {
+        async fn exec(
+            id: Uuid,
+            tx: &mut Transaction<'_, Postgres>,
+        ) -> Result<Option<CsvImportFiles>> {
+            let query = include_str!("queries/csv_uploads/fetch_csv_upload.sql");
+
+            let files = sqlx::query_as::<_, CsvImportFiles>(query)
+                .bind(id)
+                .fetch_optional(&mut **tx)
+                .await
+                .context("error fetching csv upload")?;
+            Ok(files)
+        }
+
+        exec_with_tx!(self, exec_opts, exec, id)
+    }
+
+    async fn delete_csv_upload(&self, id: Uuid, exec_opts: &mut ExecOpts<Postgres>) -> Result<()> {
+        async fn exec(id: Uuid, tx: &mut Transaction<'_, Postgres>) -> Result<()> {
+            let query = include_str!("queries/csv_uploads/delete_csv_upload.sql");
+            sqlx::query(query)
+                .bind(id)
+                .execute(&mut **tx)
+                .await
+                .context("error deleting csv upload")?;
+            Ok(())
+        }
+
+        exec_with_tx!(self, exec_opts, exec, id)
+    }
+}
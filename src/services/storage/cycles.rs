@@ -25,19 +25,20 @@ pub struct CreateCycle {
 
 /// Data needed to edit a cycle.
 ///
-/// Every field should be changed to optional. The SQL query should be updated to use `coalesce`
+/// Fields which are `None` are left unchanged.
 ///
 /// * `name`: The new name of the cycle
 /// * `description`: The new description of the cycle
 /// * `archived`: The new archived state of the cycle
-// TODO: Update the EditCycle struct to make all fields optional
-#[derive(Builder)]
+#[derive(Builder, Default)]
+#[builder(default)]
 pub struct EditCycle {
-    #[builder(setter(into))]
-    pub name: String,
-    #[builder(setter(into))]
-    pub description: String,
-    pub archived: bool,
+    #[builder(setter(into, strip_option))]
+    pub name: Option<String>,
+    #[builder(setter(into, strip_option))]
+    pub description: Option<String>,
+    #[builder(setter(into, strip_option))]
+    pub archived: Option<bool>,
 }
 
 /// A trait for querying cycles.
@@ -71,7 +72,20 @@ pub trait QueryCycles<DB: Database> {
         id: Uuid,
         exec_opts: &mut ExecOpts<DB>,
     ) -> Result<Option<ProjectCycle>>;
-    async fn edit_cycle(&self, _: Uuid, _: EditCycle, _: &mut ExecOpts<DB>) -> Result<()>;
+
+    /// Edits a cycle.
+    ///
+    /// Returns the edited cycle, or `None` if there is no cycle with the given ID.
+    ///
+    /// * `id`: The ID of the cycle to edit
+    /// * `data`: The changes to make to the cycle
+    /// * `exec_opts`: Execution options for the query
+    async fn edit_cycle(
+        &self,
+        id: Uuid,
+        data: EditCycle,
+        exec_opts: &mut ExecOpts<DB>,
+    ) -> Result<Option<ProjectCycle>>;
 
     /// Deletes a cycle by ID.
     ///
@@ -119,7 +133,7 @@ impl QueryCycles<Postgres> for PgBackend {
             id: Uuid,
             tx: &mut Transaction<'_, Postgres>,
         ) -> Result<Option<ProjectCycle>> {
-            let query = "";
+            let query = include_str!("queries/cycles/fetch_cycle_by_id.sql");
             let cycle =
                 sqlx::query_as::<_, ProjectCycle>(query).bind(id).fetch_optional(&mut **tx).await?;
             Ok(cycle)
@@ -133,17 +147,21 @@ impl QueryCycles<Postgres> for PgBackend {
         id: Uuid,
         data: EditCycle,
         exec_opts: &mut ExecOpts<Postgres>,
-    ) -> Result<()> {
-        async fn exec(id: Uuid, data: EditCycle, tx: &mut Transaction<'_, Postgres>) -> Result<()> {
-            let query = "";
-            sqlx::query(query)
+    ) -> Result<Option<ProjectCycle>> {
+        async fn exec(
+            id: Uuid,
+            data: EditCycle,
+            tx: &mut Transaction<'_, Postgres>,
+        ) -> Result<Option<ProjectCycle>> {
+            let query = include_str!("queries/cycles/edit_cycle.sql");
+            let cycle = sqlx::query_as::<_, ProjectCycle>(query)
                 .bind(id)
                 .bind(data.name)
                 .bind(data.description)
                 .bind(data.archived)
-                .execute(&mut **tx)
+                .fetch_optional(&mut **tx)
                 .await?;
-            Ok(())
+            Ok(cycle)
         }
 
         exec_with_tx!(self, exec_opts, exec, id, data)
@@ -7,8 +7,9 @@ use sqlx::FromRow;
 use uuid::Uuid;
 
 use super::types::{
-    AgeRange, ClientSize, Ethnicity, Fli, Gender, ImpactCause, JobStatus, Lgbt,
-    MentorExperienceLevel, MentorYearsExperience, StudentStage, VolunteerHearAbout,
+    AgeRange, AuditOutcome, ClientSize, Ethnicity, Fli, Gender, ImpactCause, JobItemKind,
+    JobItemStatus, JobStatus, Lgbt, MentorExperienceLevel, MentorYearsExperience, StudentStage,
+    VolunteerHearAbout,
 };
 
 /// How a project cycle is represented in the database.
@@ -75,6 +76,9 @@ pub struct Volunteer {
 /// * `volunteer_ethnicity`: The volunteer's ethnicity
 /// * `volunteer_age_range`: The volunteer's age range
 /// * `workspace_email`: The volunteer's workspace email (@developforgood.org)
+/// * `active`: Whether the volunteer is still in the source the project cycle was last synced
+///   from. Defaults to `true` when it is missing, as it is from clients which send volunteers to
+///   be exported
 /// * `clients`: The clients the volunteer is associated with
 /// * `mentors`: The mentors the volunteer is associated with
 /// * `roles`: The roles the volunteer has on their project team. These are not authentication
@@ -117,6 +121,8 @@ pub struct VolunteerDetails {
     pub majors: Vec<String>,
     pub minors: Vec<String>,
     pub hear_about: Vec<VolunteerHearAbout>,
+    #[serde(default = "active_by_default")]
+    pub active: bool,
 
     // university,
     // lgbt,
@@ -135,6 +141,10 @@ pub struct VolunteerDetails {
     pub roles: Value,
 }
 
+fn active_by_default() -> bool {
+    true
+}
+
 /// NonprofitClientDetails corresponds to the `volunteer_details` view.
 ///
 /// * `client_id`: The id of the nonprofit client
@@ -155,6 +165,8 @@ pub struct VolunteerDetails {
 /// * `state_hq`: The state where the nonprofit client is headquartered
 /// * `address`: The nonprofit client's address
 /// * `size`: The size of the nonprofit client
+/// * `active`: Whether the nonprofit client is still in the source the project cycle was last
+///   synced from
 /// * `volunteers`: The volunteers associated with the nonprofit client. This is their project team
 /// * `mentors`: The mentors associated with the nonprofit client
 #[derive(FromRow, Serialize, Deserialize, Debug, Clone, PartialEq)]
@@ -179,6 +191,7 @@ pub struct NonprofitClientDetails {
     pub address: String,
     pub size: ClientSize,
     pub impact_causes: Vec<ImpactCause>,
+    pub active: bool,
     pub volunteers: Value,
     pub mentors: Value,
 }
@@ -190,10 +203,9 @@ pub struct NonprofitClientDetails {
 /// * `updated_at`: The time the team role was last updated, if it was ever updated
 /// * `name`: The name of the team role
 /// * `description`: The description of the team role, if it exists
-// NOTE: All of the team roles present in the database are hard coded based on the project roles in
-// Airtable. More can be added if Develop for Good adds roles. If this becomes common, we can add
-// an admin endpoint to add roles.
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+// NOTE: The team roles seeded in the database are based on the project roles in Airtable. Roles
+// can be added or edited through the team roles API if Develop for Good adds roles.
+#[derive(FromRow, Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct TeamRole {
     pub id: Uuid,
@@ -203,6 +215,54 @@ pub struct TeamRole {
     pub description: Option<String>,
 }
 
+/// How an Airtable mapping profile is represented in the database.
+///
+/// * `id`: The id of the profile
+/// * `created_at`: When the profile was created
+/// * `updated_at`: When the profile was last updated, if it was ever updated
+/// * `name`: The name of the profile
+/// * `description`: A description of the profile, if it exists
+/// * `mapping`: How to read a base with the profile. The Airtable service defines its shape.
+#[derive(FromRow, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MappingProfile {
+    pub id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub name: String,
+    pub description: Option<String>,
+    pub mapping: Value,
+}
+
+/// How an Airtable webhook is represented in the database.
+///
+/// * `id`: The id of the webhook
+/// * `created_at`: When the webhook was registered
+/// * `updated_at`: When the webhook was last updated, if it was ever updated
+/// * `base_id`: The ID of the Airtable base the webhook is registered on
+/// * `webhook_id`: The ID Airtable gave the webhook
+/// * `mac_secret_base64`: The secret Airtable signs notifications with. It is never serialized.
+/// * `project_cycle_id`: The id of the project cycle which is synced when the base changes
+/// * `mapping_profile_id`: The id of the profile to read the base with, if it isn't laid out the
+///   default way
+/// * `cursor`: The number of the first payload which hasn't been read yet
+/// * `expiration_time`: When Airtable stops recording payloads, unless the webhook is refreshed
+#[derive(FromRow, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AirtableWebhook {
+    pub id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub base_id: String,
+    pub webhook_id: String,
+    #[serde(skip_serializing)]
+    pub mac_secret_base64: String,
+    pub project_cycle_id: Uuid,
+    pub mapping_profile_id: Option<Uuid>,
+    pub cursor: i64,
+    pub expiration_time: Option<DateTime<Utc>>,
+}
+
 /// How a mentor is represented in the database.
 ///
 /// * `id`: The id of the mentor
@@ -308,6 +368,69 @@ pub struct Job {
     pub details: Value,
 }
 
+/// How the outcome of a job for a single record is represented in the database.
+///
+/// * `id`: The id of the job item
+/// * `created_at`: When the job item was created
+/// * `updated_at`: When the job item was last updated, if it was ever updated
+/// * `job_id`: The id of the job
+/// * `kind`: The kind of record
+/// * `item_key`: A key identifying the record within the job
+/// * `entity_id`: The id of the record in Pantheon, if it exists
+/// * `label`: A friendly label for the record (e.g. a volunteer's name), if it exists
+/// * `status`: The outcome of the job for the record
+/// * `error`: Information about the error, if the job failed to process the record
+#[derive(FromRow, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct JobItem {
+    pub id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub job_id: Uuid,
+    pub kind: JobItemKind,
+    pub item_key: String,
+    pub entity_id: Option<Uuid>,
+    pub label: Option<String>,
+    pub status: JobItemStatus,
+    pub error: Option<String>,
+}
+
+/// The number of records of a given kind with a given status in a job.
+///
+/// * `kind`: The kind of record
+/// * `status`: The outcome of the job for the records
+/// * `count`: The number of records
+#[derive(FromRow, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct JobItemCount {
+    pub kind: JobItemKind,
+    pub status: JobItemStatus,
+    pub count: i64,
+}
+
+/// How an entry in the audit log is represented in the database.
+///
+/// * `id`: The id of the audit event
+/// * `created_at`: When the action was performed
+/// * `principal`: The email of the user who performed the action
+/// * `action`: What the user did, e.g. `cycle.delete`
+/// * `target_ids`: The ids of the records the action touched
+/// * `summary`: A summary of the request, e.g. the fields that were edited
+/// * `outcome`: Whether the action succeeded
+/// * `error`: Information about the error, if the action failed
+#[derive(FromRow, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditEvent {
+    pub id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub principal: String,
+    pub action: String,
+    pub target_ids: Vec<Uuid>,
+    pub summary: Value,
+    pub outcome: AuditOutcome,
+    pub error: Option<String>,
+}
+
 /// How a `mentor_details` view is represented in the database.
 ///
 /// * `mentor_id`: The id of the mentor
@@ -321,6 +444,7 @@ pub struct Job {
 /// * `offer_letter_signature`: Whether the mentor has signed their offer letter
 /// * `company`: The company the mentor works for
 /// * `job_title`: The job title of the mentor at their company
+/// * `active`: Whether the mentor is still in the source the project cycle was last synced from
 /// * `volunteers`: The volunteers the mentor is associated with
 /// * `clients`: The cli ents the mentor is associated with
 #[derive(FromRow, Serialize, Deserialize, Debug, Clone, PartialEq)]
@@ -346,6 +470,7 @@ pub struct MentorDetails {
     pub prior_student: bool,
     pub university: Vec<String>,
     pub hear_about: Vec<VolunteerHearAbout>,
+    pub active: bool,
     pub volunteers: Value,
     pub clients: Value,
 }
@@ -3,13 +3,17 @@
 
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use derive_builder::Builder;
-use sqlx::{Database, Postgres, Transaction};
+use sqlx::{Database, Postgres, QueryBuilder, Transaction};
 use uuid::Uuid;
 
 use super::exec_with_tx;
-use crate::services::storage::entities::Job;
-use crate::services::storage::types::{JobDetails, JobStatus};
+use crate::services::storage::entities::{Job, JobItem, JobItemCount};
+use crate::services::storage::pagination::{Page, PageOptions};
+use crate::services::storage::types::{
+    JobDetails, JobItemKind, JobItemStatus, JobStatus, JobType, SyncDiff, WriteBackReport,
+};
 use crate::services::storage::{Acquire, ExecOpts, PgBackend};
 
 /// Data needed to record a new asynchronous job.
@@ -48,6 +52,67 @@ pub struct EditJob {
     pub description: Option<String>,
 }
 
+/// Filter jobs.
+///
+/// Every filter is optional, and only jobs matching all of the filters which are set are returned.
+///
+/// * `status`: Only jobs with this status
+/// * `job_type`: Only jobs of this type
+/// * `project_cycle_id`: Only jobs associated with this project cycle
+/// * `created_after`: Only jobs created at or after this time
+/// * `created_before`: Only jobs created before this time
+#[derive(Builder, Debug, Clone, Default)]
+#[builder(default)]
+pub struct JobFilter {
+    #[builder(setter(into, strip_option))]
+    pub status: Option<JobStatus>,
+    #[builder(setter(into, strip_option))]
+    pub job_type: Option<JobType>,
+    #[builder(setter(into, strip_option))]
+    pub project_cycle_id: Option<Uuid>,
+    #[builder(setter(into, strip_option))]
+    pub created_after: Option<DateTime<Utc>>,
+    #[builder(setter(into, strip_option))]
+    pub created_before: Option<DateTime<Utc>>,
+}
+
+/// Data needed to record a record touched by a job.
+///
+/// * `kind`: The kind of record
+/// * `item_key`: A key identifying the record within the job
+/// * `entity_id`: The id of the record in Pantheon, if it exists
+/// * `label`: A friendly label for the record
+/// * `status`: The outcome of the job for the record
+/// * `error`: Information about the error, if the job failed to process the record
+#[derive(Builder, Debug)]
+pub struct CreateJobItem {
+    pub kind: JobItemKind,
+    #[builder(setter(into))]
+    pub item_key: String,
+    #[builder(setter(into, strip_option), default)]
+    pub entity_id: Option<Uuid>,
+    #[builder(setter(into, strip_option), default)]
+    pub label: Option<String>,
+    #[builder(default = "JobItemStatus::Pending")]
+    pub status: JobItemStatus,
+    #[builder(setter(into, strip_option), default)]
+    pub error: Option<String>,
+}
+
+/// Data needed to update the outcome of a job for a record.
+///
+/// * `status`: The new status
+/// * `entity_id`: The id of the record in Pantheon, if it is now known
+/// * `error`: Information about the error, if the new status is `Failed`
+#[derive(Builder, Debug)]
+pub struct UpdateJobItem {
+    pub status: JobItemStatus,
+    #[builder(setter(into, strip_option), default)]
+    pub entity_id: Option<Uuid>,
+    #[builder(setter(into, strip_option), default)]
+    pub error: Option<String>,
+}
+
 /// A trait for querying jobs.
 ///
 /// If you implement a new storage backend, this trait is required for it to implement
@@ -70,10 +135,17 @@ pub trait QueryJobs<DB: Database> {
         unimplemented!()
     }
 
-    /// Fetch all jobs.
+    /// Fetch a page of jobs matching a filter, newest first.
     ///
+    /// * `filter`: Which jobs to fetch
+    /// * `page`: Which page of jobs to fetch
     /// * `exec_opts`: Execution options for the query
-    async fn fetch_jobs(&self, exec_opts: &mut ExecOpts<DB>) -> Result<Vec<Job>> {
+    async fn fetch_jobs(
+        &self,
+        filter: JobFilter,
+        page: PageOptions,
+        exec_opts: &mut ExecOpts<DB>,
+    ) -> Result<Page<Job>> {
         unimplemented!()
     }
 
@@ -81,7 +153,7 @@ pub trait QueryJobs<DB: Database> {
     ///
     /// * `id`: The id of the job to fetch
     /// * `exec_opts`: Execution options for the query
-    async fn fetch_job(&self, id: Uuid, exec_opts: &mut ExecOpts<DB>) -> Result<Job> {
+    async fn fetch_job(&self, id: Uuid, exec_opts: &mut ExecOpts<DB>) -> Result<Option<Job>> {
         unimplemented!()
     }
 
@@ -138,11 +210,193 @@ pub trait QueryJobs<DB: Database> {
         unimplemented!()
     }
 
-    /// Cancel a job.
+    /// Cancel a job which has not started yet.
+    ///
+    /// Returns `false` if the job is not pending (e.g. because a worker has already claimed it).
     ///
     /// * `id`: The id of the job to cancel
     /// * `opts`: Execution options for the query
-    async fn cancel_job(&self, id: Uuid, opts: &mut ExecOpts<DB>) -> Result<()> {
+    async fn cancel_job(&self, id: Uuid, opts: &mut ExecOpts<DB>) -> Result<bool> {
+        unimplemented!()
+    }
+
+    /// Mark a running job as cancelled.
+    ///
+    /// * `id`: The id of the job
+    /// * `summary`: A summary of what the job did before it was cancelled
+    /// * `exec_opts`: Execution options for the query
+    async fn mark_job_cancelled(
+        &self,
+        id: Uuid,
+        summary: String,
+        exec_opts: &mut ExecOpts<DB>,
+    ) -> Result<()> {
+        unimplemented!()
+    }
+
+    /// Ask the worker running a job to stop it.
+    ///
+    /// The worker sees the request when it next sends a heartbeat, and marks the job as cancelled
+    /// once it stops. Returns `false` if the job is not running.
+    ///
+    /// * `id`: The id of the job
+    /// * `exec_opts`: Execution options for the query
+    async fn request_job_cancellation(
+        &self,
+        id: Uuid,
+        exec_opts: &mut ExecOpts<DB>,
+    ) -> Result<bool> {
+        unimplemented!()
+    }
+
+    /// Whether cancelling a job has been requested while it was running.
+    ///
+    /// * `id`: The id of the job
+    /// * `exec_opts`: Execution options for the query
+    async fn is_job_cancellation_requested(
+        &self,
+        id: Uuid,
+        exec_opts: &mut ExecOpts<DB>,
+    ) -> Result<bool> {
+        unimplemented!()
+    }
+
+    /// Record what an import changed.
+    ///
+    /// * `id`: The id of the job
+    /// * `diff`: What the job changed
+    /// * `exec_opts`: Execution options for the query
+    async fn set_job_diff(
+        &self,
+        id: Uuid,
+        diff: SyncDiff,
+        exec_opts: &mut ExecOpts<DB>,
+    ) -> Result<()> {
+        unimplemented!()
+    }
+
+    /// Record what an export wrote back to Airtable.
+    ///
+    /// * `id`: The id of the job
+    /// * `report`: What the job wrote back
+    /// * `exec_opts`: Execution options for the query
+    async fn set_job_write_back(
+        &self,
+        id: Uuid,
+        report: WriteBackReport,
+        exec_opts: &mut ExecOpts<DB>,
+    ) -> Result<()> {
+        unimplemented!()
+    }
+
+    /// Record the records touched by a job.
+    ///
+    /// Records which have already been recorded for the job are left untouched.
+    ///
+    /// * `job_id`: The id of the job
+    /// * `data`: The records touched by the job
+    /// * `exec_opts`: Execution options for the query
+    async fn batch_create_job_items(
+        &self,
+        job_id: Uuid,
+        data: Vec<CreateJobItem>,
+        exec_opts: &mut ExecOpts<DB>,
+    ) -> Result<()> {
+        unimplemented!()
+    }
+
+    /// Update the outcome of a job for a record.
+    ///
+    /// * `job_id`: The id of the job
+    /// * `kind`: The kind of record
+    /// * `item_key`: The key identifying the record within the job
+    /// * `data`: Data required to update the outcome
+    /// * `exec_opts`: Execution options for the query
+    async fn update_job_item(
+        &self,
+        job_id: Uuid,
+        kind: JobItemKind,
+        item_key: &str,
+        data: UpdateJobItem,
+        exec_opts: &mut ExecOpts<DB>,
+    ) -> Result<()> {
+        unimplemented!()
+    }
+
+    /// Fetch the records touched by a job.
+    ///
+    /// * `job_id`: The id of the job
+    /// * `exec_opts`: Execution options for the query
+    async fn fetch_job_items(
+        &self,
+        job_id: Uuid,
+        exec_opts: &mut ExecOpts<DB>,
+    ) -> Result<Vec<JobItem>> {
+        unimplemented!()
+    }
+
+    /// Count the records touched by a job, grouped by kind and status.
+    ///
+    /// * `job_id`: The id of the job
+    /// * `exec_opts`: Execution options for the query
+    async fn fetch_job_item_counts(
+        &self,
+        job_id: Uuid,
+        exec_opts: &mut ExecOpts<DB>,
+    ) -> Result<Vec<JobItemCount>> {
+        unimplemented!()
+    }
+
+    /// Claim the oldest pending job for a worker.
+    ///
+    /// The job is marked as running and leased to the worker for `lease_seconds`. Jobs which are
+    /// already being claimed by another worker are skipped, so any number of workers can poll for
+    /// jobs concurrently. Returns `None` if there are no pending jobs.
+    ///
+    /// * `worker_id`: The ID of the worker claiming the job
+    /// * `lease_seconds`: How long the worker holds the job before it must send a heartbeat
+    /// * `exec_opts`: Execution options for the query
+    async fn claim_job(
+        &self,
+        worker_id: &str,
+        lease_seconds: i64,
+        exec_opts: &mut ExecOpts<DB>,
+    ) -> Result<Option<Job>> {
+        unimplemented!()
+    }
+
+    /// Extend a worker's lease on a running job.
+    ///
+    /// Returns `false` if the worker no longer holds the job (for example, because its lease
+    /// expired and the job was re-queued).
+    ///
+    /// * `id`: The id of the job
+    /// * `worker_id`: The ID of the worker holding the job
+    /// * `lease_seconds`: How long to extend the lease by, starting now
+    /// * `exec_opts`: Execution options for the query
+    async fn heartbeat_job(
+        &self,
+        id: Uuid,
+        worker_id: &str,
+        lease_seconds: i64,
+        exec_opts: &mut ExecOpts<DB>,
+    ) -> Result<bool> {
+        unimplemented!()
+    }
+
+    /// Re-queue running jobs whose lease has expired.
+    ///
+    /// A job whose lease has expired was abandoned by its worker. It is put back in the queue,
+    /// unless it has already been attempted `max_attempts` times, in which case it is marked as
+    /// errored. Returns the IDs of the affected jobs.
+    ///
+    /// * `max_attempts`: The number of times a job may be claimed before it is given up on
+    /// * `exec_opts`: Execution options for the query
+    async fn requeue_expired_jobs(
+        &self,
+        max_attempts: i32,
+        exec_opts: &mut ExecOpts<DB>,
+    ) -> Result<Vec<Uuid>> {
         unimplemented!()
     }
 }
@@ -173,19 +427,64 @@ impl QueryJobs<Postgres> for PgBackend {
         exec_with_tx!(self, exec_opts, exec, project_cycle_id, data)
     }
 
-    async fn fetch_jobs(&self, exec_opts: &mut ExecOpts) -> Result<Vec<Job>> {
-        async fn exec(tx: &mut Transaction<'_, Postgres>) -> Result<Vec<Job>> {
-            let query = include_str!("queries/jobs/fetch_jobs.sql");
-            let jobs = sqlx::query_as::<_, Job>(query).fetch_all(&mut **tx).await?;
-            Ok(jobs)
+    async fn fetch_jobs(
+        &self,
+        filter: JobFilter,
+        page: PageOptions,
+        exec_opts: &mut ExecOpts,
+    ) -> Result<Page<Job>> {
+        async fn exec(
+            filter: JobFilter,
+            page: PageOptions,
+            tx: &mut Transaction<'_, Postgres>,
+        ) -> Result<Page<Job>> {
+            let fragment = include_str!("queries/jobs/fetch_jobs.fragment.sql");
+            let mut query = QueryBuilder::<Postgres>::new(fragment);
+
+            if let Some(status) = filter.status {
+                query.push(" and status = ").push_bind(status);
+            }
+
+            if let Some(job_type) = filter.job_type {
+                // The job type is stored in the job details the same way it is serialized
+                let job_type = serde_json::to_value(job_type)?;
+                query
+                    .push(" and details->>'jobType' = ")
+                    .push_bind(job_type.as_str().map(str::to_owned));
+            }
+
+            if let Some(project_cycle_id) = filter.project_cycle_id {
+                query.push(" and project_cycle_id = ").push_bind(project_cycle_id);
+            }
+
+            if let Some(created_after) = filter.created_after {
+                query.push(" and created_at >= ").push_bind(created_after);
+            }
+
+            if let Some(created_before) = filter.created_before {
+                query.push(" and created_at < ").push_bind(created_before);
+            }
+
+            if let Some(cursor) = page.cursor {
+                query
+                    .push(" and (created_at, id) < (select created_at, id from jobs where id = ")
+                    .push_bind(cursor)
+                    .push(")");
+            }
+
+            query.push(" order by created_at desc, id desc limit ").push_bind(page.limit + 1);
+
+            let jobs = query.build_query_as::<Job>().fetch_all(&mut **tx).await?;
+
+            Ok(Page::from_rows(jobs, page.limit, |job| job.id))
         }
-        exec_with_tx!(self, exec_opts, exec)
+        exec_with_tx!(self, exec_opts, exec, filter, page)
     }
 
-    async fn fetch_job(&self, id: Uuid, exec_opts: &mut ExecOpts) -> Result<Job> {
-        async fn exec(id: Uuid, tx: &mut Transaction<'_, Postgres>) -> Result<Job> {
+    async fn fetch_job(&self, id: Uuid, exec_opts: &mut ExecOpts) -> Result<Option<Job>> {
+        async fn exec(id: Uuid, tx: &mut Transaction<'_, Postgres>) -> Result<Option<Job>> {
             let query = include_str!("queries/jobs/fetch_job.sql");
-            let job = sqlx::query_as::<_, Job>(query).bind(id).fetch_one(&mut **tx).await?;
+            let job = sqlx::query_as::<_, Job>(query).bind(id).fetch_optional(&mut **tx).await?;
             Ok(job)
         }
         exec_with_tx!(self, exec_opts, exec, id)
@@ -246,15 +545,87 @@ impl QueryJobs<Postgres> for PgBackend {
         exec_with_tx!(self, opts, exec, id, data)
     }
 
-    async fn cancel_job(&self, id: Uuid, exec_opts: &mut ExecOpts) -> Result<()> {
-        async fn exec(id: Uuid, tx: &mut Transaction<'_, Postgres>) -> Result<()> {
+    async fn cancel_job(&self, id: Uuid, exec_opts: &mut ExecOpts) -> Result<bool> {
+        async fn exec(id: Uuid, tx: &mut Transaction<'_, Postgres>) -> Result<bool> {
             let query = include_str!("queries/jobs/cancel_job.sql");
-            sqlx::query(query).bind(id).execute(&mut **tx).await?;
+            let res = sqlx::query(query).bind(id).execute(&mut **tx).await?;
+            Ok(res.rows_affected() > 0)
+        }
+        exec_with_tx!(self, exec_opts, exec, id)
+    }
+
+    async fn mark_job_cancelled(
+        &self,
+        id: Uuid,
+        summary: String,
+        exec_opts: &mut ExecOpts,
+    ) -> Result<()> {
+        async fn exec(id: Uuid, summary: String, tx: &mut Transaction<'_, Postgres>) -> Result<()> {
+            let query = include_str!("queries/jobs/mark_job_cancelled.sql");
+            sqlx::query(query).bind(id).bind(summary).execute(&mut **tx).await?;
             Ok(())
         }
+        exec_with_tx!(self, exec_opts, exec, id, summary)
+    }
+
+    async fn request_job_cancellation(&self, id: Uuid, exec_opts: &mut ExecOpts) -> Result<bool> {
+        async fn exec(id: Uuid, tx: &mut Transaction<'_, Postgres>) -> Result<bool> {
+            let query = include_str!("queries/jobs/request_job_cancellation.sql");
+            let res = sqlx::query(query).bind(id).execute(&mut **tx).await?;
+            Ok(res.rows_affected() > 0)
+        }
+        exec_with_tx!(self, exec_opts, exec, id)
+    }
+
+    async fn is_job_cancellation_requested(
+        &self,
+        id: Uuid,
+        exec_opts: &mut ExecOpts,
+    ) -> Result<bool> {
+        async fn exec(id: Uuid, tx: &mut Transaction<'_, Postgres>) -> Result<bool> {
+            let query = include_str!("queries/jobs/is_job_cancellation_requested.sql");
+            let requested =
+                sqlx::query_scalar::<_, bool>(query).bind(id).fetch_optional(&mut **tx).await?;
+            Ok(requested.unwrap_or(false))
+        }
         exec_with_tx!(self, exec_opts, exec, id)
     }
 
+    async fn set_job_diff(&self, id: Uuid, diff: SyncDiff, exec_opts: &mut ExecOpts) -> Result<()> {
+        async fn exec(id: Uuid, diff: SyncDiff, tx: &mut Transaction<'_, Postgres>) -> Result<()> {
+            let query = include_str!("queries/jobs/set_job_diff.sql");
+            sqlx::query(query)
+                .bind(id)
+                .bind(serde_json::to_value(diff)?)
+                .execute(&mut **tx)
+                .await?;
+            Ok(())
+        }
+        exec_with_tx!(self, exec_opts, exec, id, diff)
+    }
+
+    async fn set_job_write_back(
+        &self,
+        id: Uuid,
+        report: WriteBackReport,
+        exec_opts: &mut ExecOpts,
+    ) -> Result<()> {
+        async fn exec(
+            id: Uuid,
+            report: WriteBackReport,
+            tx: &mut Transaction<'_, Postgres>,
+        ) -> Result<()> {
+            let query = include_str!("queries/jobs/set_job_write_back.sql");
+            sqlx::query(query)
+                .bind(id)
+                .bind(serde_json::to_value(report)?)
+                .execute(&mut **tx)
+                .await?;
+            Ok(())
+        }
+        exec_with_tx!(self, exec_opts, exec, id, report)
+    }
+
     async fn mark_job_complete(&self, id: Uuid, exec_opts: &mut ExecOpts) -> Result<()> {
         async fn exec(id: Uuid, tx: &mut Transaction<'_, Postgres>) -> Result<()> {
             let query = include_str!("queries/jobs/update_job_status.sql");
@@ -287,4 +658,163 @@ impl QueryJobs<Postgres> for PgBackend {
         }
         exec_with_tx!(self, exec_opts, exec, id, error)
     }
+
+    async fn claim_job(
+        &self,
+        worker_id: &str,
+        lease_seconds: i64,
+        exec_opts: &mut ExecOpts,
+    ) -> Result<Option<Job>> {
+        async fn exec(
+            worker_id: &str,
+            lease_seconds: i64,
+            tx: &mut Transaction<'_, Postgres>,
+        ) -> Result<Option<Job>> {
+            let query = include_str!("queries/jobs/claim_job.sql");
+            let job = sqlx::query_as::<_, Job>(query)
+                .bind(worker_id)
+                .bind(lease_seconds as f64)
+                .fetch_optional(&mut **tx)
+                .await?;
+            Ok(job)
+        }
+        exec_with_tx!(self, exec_opts, exec, worker_id, lease_seconds)
+    }
+
+    async fn heartbeat_job(
+        &self,
+        id: Uuid,
+        worker_id: &str,
+        lease_seconds: i64,
+        exec_opts: &mut ExecOpts,
+    ) -> Result<bool> {
+        async fn exec(
+            id: Uuid,
+            worker_id: &str,
+            lease_seconds: i64,
+            tx: &mut Transaction<'_, Postgres>,
+        ) -> Result<bool> {
+            let query = include_str!("queries/jobs/heartbeat_job.sql");
+            let held = sqlx::query_scalar::<_, Uuid>(query)
+                .bind(id)
+                .bind(worker_id)
+                .bind(lease_seconds as f64)
+                .fetch_optional(&mut **tx)
+                .await?;
+            Ok(held.is_some())
+        }
+        exec_with_tx!(self, exec_opts, exec, id, worker_id, lease_seconds)
+    }
+
+    async fn requeue_expired_jobs(
+        &self,
+        max_attempts: i32,
+        exec_opts: &mut ExecOpts,
+    ) -> Result<Vec<Uuid>> {
+        async fn exec(max_attempts: i32, tx: &mut Transaction<'_, Postgres>) -> Result<Vec<Uuid>> {
+            let query = include_str!("queries/jobs/requeue_expired_jobs.sql");
+            let ids = sqlx::query_scalar::<_, Uuid>(query)
+                .bind(max_attempts)
+                .fetch_all(&mut **tx)
+                .await?;
+            Ok(ids)
+        }
+        exec_with_tx!(self, exec_opts, exec, max_attempts)
+    }
+
+    async fn batch_create_job_items(
+        &self,
+        job_id: Uuid,
+        data: Vec<CreateJobItem>,
+        exec_opts: &mut ExecOpts,
+    ) -> Result<()> {
+        async fn exec(
+            job_id: Uuid,
+            data: Vec<CreateJobItem>,
+            tx: &mut Transaction<'_, Postgres>,
+        ) -> Result<()> {
+            if data.is_empty() {
+                return Ok(());
+            }
+
+            let fragment = include_str!("queries/jobs/create_job_items.fragment.sql");
+            QueryBuilder::<Postgres>::new(fragment)
+                .push_values(data, |mut b, item| {
+                    b.push_bind(job_id)
+                        .push_bind(item.kind)
+                        .push_bind(item.item_key)
+                        .push_bind(item.entity_id)
+                        .push_bind(item.label)
+                        .push_bind(item.status)
+                        .push_bind(item.error);
+                })
+                .push(" on conflict (job_id, kind, item_key) do nothing")
+                .build()
+                .execute(&mut **tx)
+                .await?;
+            Ok(())
+        }
+        exec_with_tx!(self, exec_opts, exec, job_id, data)
+    }
+
+    async fn update_job_item(
+        &self,
+        job_id: Uuid,
+        kind: JobItemKind,
+        item_key: &str,
+        data: UpdateJobItem,
+        exec_opts: &mut ExecOpts,
+    ) -> Result<()> {
+        async fn exec(
+            job_id: Uuid,
+            kind: JobItemKind,
+            item_key: &str,
+            data: UpdateJobItem,
+            tx: &mut Transaction<'_, Postgres>,
+        ) -> Result<()> {
+            let query = include_str!("queries/jobs/update_job_item.sql");
+            sqlx::query(query)
+                .bind(job_id)
+                .bind(kind)
+                .bind(item_key)
+                .bind(data.status)
+                .bind(data.entity_id)
+                .bind(data.error)
+                .execute(&mut **tx)
+                .await?;
+            Ok(())
+        }
+        exec_with_tx!(self, exec_opts, exec, job_id, kind, item_key, data)
+    }
+
+    async fn fetch_job_items(
+        &self,
+        job_id: Uuid,
+        exec_opts: &mut ExecOpts,
+    ) -> Result<Vec<JobItem>> {
+        async fn exec(job_id: Uuid, tx: &mut Transaction<'_, Postgres>) -> Result<Vec<JobItem>> {
+            let query = include_str!("queries/jobs/fetch_job_items.sql");
+            let items =
+                sqlx::query_as::<_, JobItem>(query).bind(job_id).fetch_all(&mut **tx).await?;
+            Ok(items)
+        }
+        exec_with_tx!(self, exec_opts, exec, job_id)
+    }
+
+    async fn fetch_job_item_counts(
+        &self,
+        job_id: Uuid,
+        exec_opts: &mut ExecOpts,
+    ) -> Result<Vec<JobItemCount>> {
+        async fn exec(
+            job_id: Uuid,
+            tx: &mut Transaction<'_, Postgres>,
+        ) -> Result<Vec<JobItemCount>> {
+            let query = include_str!("queries/jobs/fetch_job_item_counts.sql");
+            let counts =
+                sqlx::query_as::<_, JobItemCount>(query).bind(job_id).fetch_all(&mut **tx).await?;
+            Ok(counts)
+        }
+        exec_with_tx!(self, exec_opts, exec, job_id)
+    }
 }
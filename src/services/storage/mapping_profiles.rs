@@ -0,0 +1,160 @@
+//! This module contains the definition of the `QueryMappingProfiles` trait as well as the default
+//! implementation of the trait for the `PgBackend` struct.
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use derive_builder::Builder;
+use serde_json::Value;
+use sqlx::{Database, Postgres, Transaction};
+use uuid::Uuid;
+
+use super::exec_with_tx;
+use crate::services::storage::entities::MappingProfile;
+use crate::services::storage::{Acquire, ExecOpts, PgBackend};
+
+/// Data needed to create a new Airtable mapping profile.
+///
+/// * `name`: The name of the profile. It must be unique.
+/// * `description`: A description of the profile
+/// * `mapping`: How to read a base with the profile
+#[derive(Debug, Builder, Clone)]
+pub struct CreateMappingProfile {
+    #[builder(setter(into))]
+    pub name: String,
+    #[builder(setter(into), default)]
+    pub description: Option<String>,
+    pub mapping: Value,
+}
+
+/// A trait for querying the profiles imports use to read Airtable bases.
+///
+/// If you implement a new storage backend, this trait is required for it to implement
+/// `StorageLayer`. The default implementation is for `Postgres`.
+#[async_trait]
+#[allow(unused)]
+pub trait QueryMappingProfiles<DB: Database> {
+    /// Create a new mapping profile.
+    ///
+    /// * `data`: Data required to create the profile
+    /// * `exec_opts`: Execution options for the query
+    async fn create_mapping_profile(
+        &self,
+        data: CreateMappingProfile,
+        exec_opts: &mut ExecOpts<DB>,
+    ) -> Result<Uuid> {
+        unimplemented!()
+    }
+
+    /// Fetch all mapping profiles, ordered by name.
+    ///
+    /// * `exec_opts`: Execution options for the query
+    async fn fetch_mapping_profiles(
+        &self,
+        exec_opts: &mut ExecOpts<DB>,
+    ) -> Result<Vec<MappingProfile>> {
+        unimplemented!()
+    }
+
+    /// Fetch a mapping profile by ID.
+    ///
+    /// * `id`: The ID of the profile to fetch
+    /// * `exec_opts`: Execution options for the query
+    async fn fetch_mapping_profile_by_id(
+        &self,
+        id: Uuid,
+        exec_opts: &mut ExecOpts<DB>,
+    ) -> Result<Option<MappingProfile>> {
+        unimplemented!()
+    }
+
+    /// Delete a mapping profile by ID.
+    ///
+    /// * `id`: The ID of the profile to delete
+    /// * `exec_opts`: Execution options for the query
+    async fn delete_mapping_profile(&self, id: Uuid, exec_opts: &mut ExecOpts<DB>) -> Result<()> {
+        unimplemented!()
+    }
+}
+
+#[async_trait]
+impl QueryMappingProfiles<Postgres> for PgBackend {
+    async fn create_mapping_profile(
+        &self,
+        data: CreateMappingProfile,
+        exec_opts: &mut ExecOpts<Postgres>,
+    ) -> Result<Uuid> {
+        async fn exec(
+            data: CreateMappingProfile,
+            tx: &mut Transaction<'_, Postgres>,
+        ) -> Result<Uuid> {
+            let query = include_str!("queries/mapping_profiles/create_mapping_profile.sql");
+
+            let id = sqlx::query_scalar::<_, Uuid>(query)
+                .bind(data.name)
+                .bind(data.description)
+                .bind(data.mapping)
+                .fetch_one(&mut **tx)
+                .await?;
+            Ok(id)
+        }
+
+        exec_with_tx!(self, exec_opts, exec, data)
+    }
+
+    async fn fetch_mapping_profiles(
+        &self,
+        exec_opts: &mut ExecOpts<Postgres>,
+    ) -> Result<Vec<MappingProfile>> {
+        async fn exec(tx: &mut Transaction<'_, Postgres>) -> Result<Vec<MappingProfile>> {
+            let query = include_str!("queries/mapping_profiles/fetch_mapping_profiles.sql");
+
+            let profiles = sqlx::query_as::<_, MappingProfile>(query)
+                .fetch_all(&mut **tx)
+                .await
+                .context("error fetching mapping profiles")?;
+            Ok(profiles)
+        }
+
+        exec_with_tx!(self, exec_opts, exec)
+    }
+
+    async fn fetch_mapping_profile_by_id(
+        &self,
+        id: Uuid,
+        exec_opts: &mut ExecOpts<Postgres>,
+    ) -> Result<Option<MappingProfile>> {
+        async fn exec(
+            id: Uuid,
+            tx: &mut Transaction<'_, Postgres>,
+        ) -> Result<Option<MappingProfile>> {
+            let query = include_str!("queries/mapping_profiles/fetch_mapping_profile_by_id.sql");
+
+            let profile = sqlx::query_as::<_, MappingProfile>(query)
+                .bind(id)
+                .fetch_optional(&mut **tx)
+                .await
+                .context("error fetching mapping profile by id")?;
+            Ok(profile)
+        }
+
+        exec_with_tx!(self, exec_opts, exec, id)
+    }
+
+    async fn delete_mapping_profile(
+        &self,
+        id: Uuid,
+        exec_opts: &mut ExecOpts<Postgres>,
+    ) -> Result<()> {
+        async fn exec(id: Uuid, tx: &mut Transaction<'_, Postgres>) -> Result<()> {
+            let query = include_str!("queries/mapping_profiles/delete_mapping_profile.sql");
+            sqlx::query(query)
+                .bind(id)
+                .execute(&mut **tx)
+                .await
+                .context("error deleting mapping profile")?;
+            Ok(())
+        }
+
+        exec_with_tx!(self, exec_opts, exec, id)
+    }
+}
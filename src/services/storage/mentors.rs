@@ -5,11 +5,11 @@ use anyhow::{Context, Result};
 use async_trait::async_trait;
 use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
-use sqlx::{Database, Postgres, QueryBuilder, Transaction};
+use sqlx::{Database, Postgres, QueryBuilder, Row, Transaction};
 use uuid::Uuid;
 
 use super::exec_with_tx;
-use super::types::{MentorExperienceLevel, MentorYearsExperience, VolunteerHearAbout};
+use super::types::{MentorExperienceLevel, MentorYearsExperience, SyncOutcome, VolunteerHearAbout};
 use crate::services::storage::entities::MentorDetails;
 use crate::services::storage::{Acquire, ExecOpts, PgBackend};
 
@@ -58,24 +58,23 @@ pub struct CreateMentor {
 
 /// Data needed to edit a mentor.
 ///
-/// All fields should be changed to optional. The SQL query should be updated to use `coalesce`.
+/// Fields which are `None` are left unchanged.
 ///
 /// * `first_name`: The new first name of the mentor
 /// * `last_name`: The new last name of the mentor
 /// * `email`: The new email of the mentor
 /// * `phone`: The new phone numbe of the mentor
-/// * `offer_letter_signature`: The new `offer_letter_signature` status of the mentor
-// TODO: Update the EditMentor struct to make all fields optional
-#[derive(Builder)]
+#[derive(Builder, Default)]
+#[builder(default)]
 pub struct EditMentor {
-    #[builder(setter(into))]
-    pub first_name: String,
-    #[builder(setter(into))]
-    pub last_name: String,
-    #[builder(setter(into))]
-    pub email: String,
-    #[builder(setter(into))]
-    pub phone: String,
+    #[builder(setter(into, strip_option))]
+    pub first_name: Option<String>,
+    #[builder(setter(into, strip_option))]
+    pub last_name: Option<String>,
+    #[builder(setter(into, strip_option))]
+    pub email: Option<String>,
+    #[builder(setter(into, strip_option))]
+    pub phone: Option<String>,
 }
 
 /// A trait for querying mentors.
@@ -124,6 +123,18 @@ pub trait QueryMentors<DB: Database> {
         unimplemented!()
     }
 
+    /// Fetch all mentors associated with a project cycle.
+    ///
+    /// * `project_cycle_id`: The ID of the project cycle to fetch mentors for
+    /// * `exec_opts`: Execution options for the query
+    async fn fetch_mentors_by_cycle(
+        &self,
+        project_cycle_id: Uuid,
+        exec_opts: &mut ExecOpts<DB>,
+    ) -> Result<Vec<MentorDetails>> {
+        unimplemented!()
+    }
+
     /// Fetch a mentor by ID.
     ///
     /// * `id`: The id of the mentor to fetch
@@ -171,6 +182,58 @@ pub trait QueryMentors<DB: Database> {
     ) -> Result<()> {
         unimplemented!()
     }
+
+    /// Create or update mentors synced from an external source (e.g. Airtable).
+    ///
+    /// Mentors are matched by their external ID, or by their email if they were imported before
+    /// external IDs were recorded. Matched mentors are updated and marked active. Returns the ID of
+    /// each mentor and how it was synced, keyed by external ID.
+    ///
+    /// * `project_cycle_id`: The ID of the project cycle to sync the mentors into
+    /// * `data`: The mentors to sync, keyed by external ID
+    /// * `exec_opts`: Execution options for the query
+    async fn batch_sync_mentors(
+        &self,
+        project_cycle_id: Uuid,
+        data: Vec<(String, CreateMentor)>,
+        exec_opts: &mut ExecOpts<DB>,
+    ) -> Result<Vec<(String, Uuid, SyncOutcome)>> {
+        unimplemented!()
+    }
+
+    /// Mark the active mentors in a project cycle which are not in `keep` as inactive.
+    ///
+    /// Returns the number of mentors marked inactive.
+    ///
+    /// * `project_cycle_id`: The ID of the project cycle
+    /// * `keep`: The IDs of the mentors to leave active
+    /// * `exec_opts`: Execution options for the query
+    async fn deactivate_missing_mentors(
+        &self,
+        project_cycle_id: Uuid,
+        keep: Vec<Uuid>,
+        exec_opts: &mut ExecOpts<DB>,
+    ) -> Result<u64> {
+        unimplemented!()
+    }
+
+    /// Make the links between mentors and nonprofits in a project cycle match `linkage`.
+    ///
+    /// Links which are missing are added, and links which are not in `linkage` are removed.
+    /// Returns the number of links added and removed.
+    ///
+    /// * `project_cycle_id`: The ID of the project cycle
+    /// * `linkage`: The linkage, represented as a vector of 2-tuples mapping mentor IDs to
+    ///   nonprofit IDs
+    /// * `exec_opts`: Execution options for the query
+    async fn sync_mentor_nonprofit_links(
+        &self,
+        project_cycle_id: Uuid,
+        linkage: Vec<(Uuid, Uuid)>,
+        exec_opts: &mut ExecOpts<DB>,
+    ) -> Result<(u64, u64)> {
+        unimplemented!()
+    }
 }
 
 #[async_trait]
@@ -275,6 +338,28 @@ impl QueryMentors<Postgres> for PgBackend {
         exec_with_tx!(self, exec_opts, exec)
     }
 
+    async fn fetch_mentors_by_cycle(
+        &self,
+        project_cycle_id: Uuid,
+        exec_opts: &mut ExecOpts<Postgres>,
+    ) -> Result<Vec<MentorDetails>> {
+        async fn exec(
+            project_cycle_id: Uuid,
+            tx: &mut Transaction<'_, Postgres>,
+        ) -> Result<Vec<MentorDetails>> {
+            let query = include_str!("queries/mentors/fetch_mentors_by_cycle.sql");
+
+            let mentors = sqlx::query_as::<_, MentorDetails>(query)
+                .bind(project_cycle_id)
+                .fetch_all(&mut **tx)
+                .await
+                .context("error fetching mentors by cycle")?;
+            Ok(mentors)
+        }
+
+        exec_with_tx!(self, exec_opts, exec, project_cycle_id)
+    }
+
     async fn fetch_mentor_by_id(
         &self,
         id: Uuid,
@@ -364,4 +449,150 @@ impl QueryMentors<Postgres> for PgBackend {
 
         exec_with_tx!(self, exec_opts, exec, project_cycle_id, data)
     }
+
+    async fn batch_sync_mentors(
+        &self,
+        project_cycle_id: Uuid,
+        data: Vec<(String, CreateMentor)>,
+        exec_opts: &mut ExecOpts<Postgres>,
+    ) -> Result<Vec<(String, Uuid, SyncOutcome)>> {
+        async fn exec(
+            project_cycle_id: Uuid,
+            data: Vec<(String, CreateMentor)>,
+            tx: &mut Transaction<'_, Postgres>,
+        ) -> Result<Vec<(String, Uuid, SyncOutcome)>> {
+            let fetch_id = include_str!("queries/mentors/fetch_synced_mentor_id.sql");
+            let create = include_str!("queries/mentors/create_synced_mentor.sql");
+            let sync = include_str!("queries/mentors/sync_mentor.sql");
+
+            let mut synced = Vec::with_capacity(data.len());
+
+            for (external_id, m) in data {
+                let id = sqlx::query_scalar::<_, Uuid>(fetch_id)
+                    .bind(project_cycle_id)
+                    .bind(&external_id)
+                    .bind(&m.email)
+                    .fetch_optional(&mut **tx)
+                    .await?;
+
+                // Both queries take the same parameters, except that the first is the ID of the
+                // mentor to update or the project cycle to create the mentor in
+                let (query, id_or_cycle) = match id {
+                    Some(id) => (sqlx::query(sync), id),
+                    None => (sqlx::query(create), project_cycle_id),
+                };
+
+                let query = query
+                    .bind(id_or_cycle)
+                    .bind(&external_id)
+                    .bind(m.first_name)
+                    .bind(m.last_name)
+                    .bind(&m.email)
+                    .bind(m.phone)
+                    .bind(m.company)
+                    .bind(m.job_title)
+                    .bind(m.country)
+                    .bind(m.us_state)
+                    .bind(m.years_experience)
+                    .bind(m.experience_level)
+                    .bind(m.prior_mentor)
+                    .bind(m.prior_mentee)
+                    .bind(m.prior_student)
+                    .bind(m.university)
+                    .bind(m.hear_about);
+
+                let (id, outcome) = match id {
+                    Some(id) => {
+                        let res = query
+                            .execute(&mut **tx)
+                            .await
+                            .with_context(|| format!("error syncing mentor {}", m.email))?;
+                        match res.rows_affected() {
+                            0 => (id, SyncOutcome::Unchanged),
+                            _ => (id, SyncOutcome::Updated),
+                        }
+                    }
+                    None => {
+                        let id = query
+                            .fetch_one(&mut **tx)
+                            .await
+                            .with_context(|| format!("error creating mentor {}", m.email))?
+                            .try_get("id")?;
+                        (id, SyncOutcome::Created)
+                    }
+                };
+
+                synced.push((external_id, id, outcome));
+            }
+
+            Ok(synced)
+        }
+
+        exec_with_tx!(self, exec_opts, exec, project_cycle_id, data)
+    }
+
+    async fn deactivate_missing_mentors(
+        &self,
+        project_cycle_id: Uuid,
+        keep: Vec<Uuid>,
+        exec_opts: &mut ExecOpts<Postgres>,
+    ) -> Result<u64> {
+        async fn exec(
+            project_cycle_id: Uuid,
+            keep: Vec<Uuid>,
+            tx: &mut Transaction<'_, Postgres>,
+        ) -> Result<u64> {
+            let query = include_str!("queries/mentors/deactivate_missing_mentors.sql");
+            let res =
+                sqlx::query(query).bind(project_cycle_id).bind(keep).execute(&mut **tx).await?;
+            Ok(res.rows_affected())
+        }
+
+        exec_with_tx!(self, exec_opts, exec, project_cycle_id, keep)
+    }
+
+    async fn sync_mentor_nonprofit_links(
+        &self,
+        project_cycle_id: Uuid,
+        linkage: Vec<(Uuid, Uuid)>,
+        exec_opts: &mut ExecOpts<Postgres>,
+    ) -> Result<(u64, u64)> {
+        async fn exec(
+            project_cycle_id: Uuid,
+            linkage: Vec<(Uuid, Uuid)>,
+            tx: &mut Transaction<'_, Postgres>,
+        ) -> Result<(u64, u64)> {
+            let (mentor_ids, nonprofit_ids): (Vec<Uuid>, Vec<Uuid>) =
+                linkage.iter().copied().unzip();
+
+            let query = include_str!("queries/mentors/unlink_missing_mentors_from_nonprofits.sql");
+            let removed = sqlx::query(query)
+                .bind(project_cycle_id)
+                .bind(mentor_ids)
+                .bind(nonprofit_ids)
+                .execute(&mut **tx)
+                .await?
+                .rows_affected();
+
+            if linkage.is_empty() {
+                return Ok((0, removed));
+            }
+
+            let fragment = include_str!("queries/mentors/link_mentors_to_nonprofits.fragment.sql");
+            let added = QueryBuilder::<Postgres>::new(fragment)
+                .push_values(linkage, |mut b, (mentor_id, nonprofit_id)| {
+                    b.push_bind(project_cycle_id).push_bind(mentor_id).push_bind(nonprofit_id);
+                })
+                .push(" on conflict do nothing")
+                .build()
+                .execute(&mut **tx)
+                .await
+                .context("error linking mentors to nonprofits")?
+                .rows_affected();
+
+            Ok((added, removed))
+        }
+
+        exec_with_tx!(self, exec_opts, exec, project_cycle_id, linkage)
+    }
 }
@@ -1,14 +1,21 @@
-//! This module contains traits for interacting with the database, as well as one concrete
-//! implementation (Postgres).
+//! This module contains traits for interacting with the database, as well as two concrete
+//! implementations (Postgres and SQLite).
 
+pub mod audit;
+pub mod csv_uploads;
 pub mod cycles;
 pub mod entities;
 pub mod jobs;
+pub mod mapping_profiles;
 pub mod mentors;
 pub mod nonprofits;
+pub mod pagination;
+pub mod sqlite;
 pub mod stats;
+pub mod team_roles;
 pub mod types;
 pub mod volunteers;
+pub mod webhooks;
 
 #[cfg(test)]
 mod tests;
@@ -19,19 +26,24 @@ use derive_builder::Builder;
 use sqlx::postgres::PgPoolOptions;
 use sqlx::{Database, PgPool, Postgres, Transaction};
 
+use crate::services::storage::audit::QueryAudit;
+use crate::services::storage::csv_uploads::QueryCsvUploads;
 use crate::services::storage::cycles::QueryCycles;
 use crate::services::storage::jobs::QueryJobs;
+use crate::services::storage::mapping_profiles::QueryMappingProfiles;
 use crate::services::storage::mentors::QueryMentors;
 use crate::services::storage::nonprofits::QueryNonprofits;
 use crate::services::storage::stats::QueryStats;
+use crate::services::storage::team_roles::QueryTeamRoles;
 use crate::services::storage::volunteers::QueryVolunteers;
+use crate::services::storage::webhooks::QueryWebhooks;
 
 /// Defines the storage layer for the application.
 ///
 /// This trait is an auto trait, which means that
 /// it's implemented for any type that implements the required traits. It is parameterized by a
 /// `sqlx::Database` type, which is used to specify the database backend. The default is
-/// `Postgres`, and it's the only one with a concrete implementation.
+/// `Postgres`. `PgBackend` implements it for Postgres and `SqliteBackend` for SQLite.
 pub trait StorageLayer<DB: Database = Postgres>:
     QueryVolunteers<DB>
     + QueryMentors<DB>
@@ -39,6 +51,11 @@ pub trait StorageLayer<DB: Database = Postgres>:
     + QueryCycles<DB>
     + QueryJobs<DB>
     + QueryStats<DB>
+    + QueryTeamRoles<DB>
+    + QueryAudit<DB>
+    + QueryMappingProfiles<DB>
+    + QueryWebhooks<DB>
+    + QueryCsvUploads<DB>
     + Acquire<DB>
     + Send
     + Sync
@@ -157,6 +174,11 @@ impl<T, DB: Database> StorageLayer<DB> for T where
         + QueryCycles<DB>
         + QueryJobs<DB>
         + QueryStats<DB>
+        + QueryTeamRoles<DB>
+        + QueryAudit<DB>
+        + QueryMappingProfiles<DB>
+        + QueryWebhooks<DB>
+        + QueryCsvUploads<DB>
         + Acquire<DB>
         + Migrator
         + Send
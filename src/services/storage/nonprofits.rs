@@ -5,11 +5,11 @@ use anyhow::{Context, Result};
 use async_trait::async_trait;
 use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
-use sqlx::{Database, Postgres, QueryBuilder, Transaction};
+use sqlx::{Database, Postgres, QueryBuilder, Row, Transaction};
 use uuid::Uuid;
 
 use super::entities::NonprofitClientDetails;
-use super::types::{ClientSize, ImpactCause};
+use super::types::{ClientSize, ImpactCause, SyncOutcome};
 use super::{exec_with_tx, PgBackend};
 use crate::services::storage::{Acquire, ExecOpts};
 
@@ -64,22 +64,22 @@ pub struct CreateNonprofit {
 
 /// Data needed to edit a nonprofit.
 ///
+/// Fields which are `None` are left unchanged.
+///
 /// * `email`: The new email for the nonprofit
 /// * `email_cc`: The new email to CC on emails when sending email to the nonprofit's email
 /// * `phone`: The new phone number for the nonprofit
 /// * `org_website`: The new website for the nonprofit
-///
-/// Every field should be changed to optional. The SQL query should be updated to use `coalesce`
-// TODO: Update the EditNonprofit struct to make all fields optional
-#[derive(Builder)]
+#[derive(Builder, Default)]
+#[builder(default)]
 pub struct EditNonprofit {
-    #[builder(setter(into))]
-    pub email: String,
-    #[builder(setter(into), default = "None")]
+    #[builder(setter(into, strip_option))]
+    pub email: Option<String>,
+    #[builder(setter(into, strip_option))]
     pub email_cc: Option<String>,
-    #[builder(setter(into))]
-    pub phone: String,
-    #[builder(setter(into), default = "None")]
+    #[builder(setter(into, strip_option))]
+    pub phone: Option<String>,
+    #[builder(setter(into, strip_option))]
     pub org_website: Option<String>,
 }
 
@@ -99,7 +99,7 @@ pub trait QueryNonprofits<DB: Database> {
         &self,
         project_cycle_id: Uuid,
         data: CreateNonprofit,
-        exec_opts: &mut ExecOpts<Postgres>,
+        exec_opts: &mut ExecOpts<DB>,
     ) -> Result<Uuid> {
         unimplemented!()
     }
@@ -113,7 +113,7 @@ pub trait QueryNonprofits<DB: Database> {
         &self,
         project_cycle_id: Uuid,
         data: Vec<CreateNonprofit>,
-        exec_opts: &mut ExecOpts<Postgres>,
+        exec_opts: &mut ExecOpts<DB>,
     ) -> Result<Vec<(String, Uuid)>> {
         unimplemented!()
     }
@@ -126,7 +126,19 @@ pub trait QueryNonprofits<DB: Database> {
     // TODO: Add a new function that fetches nonprofits with filtering and pagination options
     async fn fetch_nonprofits(
         &self,
-        exec_opts: &mut ExecOpts<Postgres>,
+        exec_opts: &mut ExecOpts<DB>,
+    ) -> Result<Vec<NonprofitClientDetails>> {
+        unimplemented!()
+    }
+
+    /// Fetch all nonprofits associated with a project cycle.
+    ///
+    /// * `project_cycle_id`: The ID of the project cycle to fetch nonprofits for
+    /// * `exec_opts`: Execution options for the query
+    async fn fetch_nonprofits_by_cycle(
+        &self,
+        project_cycle_id: Uuid,
+        exec_opts: &mut ExecOpts<DB>,
     ) -> Result<Vec<NonprofitClientDetails>> {
         unimplemented!()
     }
@@ -138,7 +150,7 @@ pub trait QueryNonprofits<DB: Database> {
     async fn fetch_nonprofit_by_id(
         &self,
         id: Uuid,
-        exec_opts: &mut ExecOpts<Postgres>,
+        exec_opts: &mut ExecOpts<DB>,
     ) -> Result<Option<NonprofitClientDetails>> {
         unimplemented!()
     }
@@ -150,7 +162,7 @@ pub trait QueryNonprofits<DB: Database> {
     async fn fetch_nonprofit_by_org_name(
         &self,
         org_name: &str,
-        exec_opts: &mut ExecOpts<Postgres>,
+        exec_opts: &mut ExecOpts<DB>,
     ) -> Result<Option<NonprofitClientDetails>> {
         unimplemented!()
     }
@@ -164,7 +176,7 @@ pub trait QueryNonprofits<DB: Database> {
         &self,
         id: Uuid,
         data: EditNonprofit,
-        exec_opts: &mut ExecOpts<Postgres>,
+        exec_opts: &mut ExecOpts<DB>,
     ) -> Result<()> {
         unimplemented!()
     }
@@ -173,7 +185,41 @@ pub trait QueryNonprofits<DB: Database> {
     ///
     /// * `id`: The ID of the nonprofit to delete
     /// * `exec_opts`: Execution options for the query
-    async fn delete_nonprofit(&self, id: Uuid, exec_opts: &mut ExecOpts<Postgres>) -> Result<()> {
+    async fn delete_nonprofit(&self, id: Uuid, exec_opts: &mut ExecOpts<DB>) -> Result<()> {
+        unimplemented!()
+    }
+
+    /// Create or update nonprofits synced from an external source (e.g. Airtable).
+    ///
+    /// Nonprofits are matched by their external ID, or by their organization and project names if
+    /// they were imported before external IDs were recorded. Matched nonprofits are updated and
+    /// marked active. Returns the ID of each nonprofit and how it was synced, keyed by external ID.
+    ///
+    /// * `project_cycle_id`: The ID of the project cycle to sync the nonprofits into
+    /// * `data`: The nonprofits to sync, keyed by external ID
+    /// * `exec_opts`: Execution options for the query
+    async fn batch_sync_nonprofits(
+        &self,
+        project_cycle_id: Uuid,
+        data: Vec<(String, CreateNonprofit)>,
+        exec_opts: &mut ExecOpts<DB>,
+    ) -> Result<Vec<(String, Uuid, SyncOutcome)>> {
+        unimplemented!()
+    }
+
+    /// Mark the active nonprofits in a project cycle which are not in `keep` as inactive.
+    ///
+    /// Returns the number of nonprofits marked inactive.
+    ///
+    /// * `project_cycle_id`: The ID of the project cycle
+    /// * `keep`: The IDs of the nonprofits to leave active
+    /// * `exec_opts`: Execution options for the query
+    async fn deactivate_missing_nonprofits(
+        &self,
+        project_cycle_id: Uuid,
+        keep: Vec<Uuid>,
+        exec_opts: &mut ExecOpts<DB>,
+    ) -> Result<u64> {
         unimplemented!()
     }
 }
@@ -286,6 +332,28 @@ impl QueryNonprofits<Postgres> for PgBackend {
         exec_with_tx!(self, exec_opts, exec)
     }
 
+    async fn fetch_nonprofits_by_cycle(
+        &self,
+        project_cycle_id: Uuid,
+        exec_opts: &mut ExecOpts<Postgres>,
+    ) -> Result<Vec<NonprofitClientDetails>> {
+        async fn exec(
+            project_cycle_id: Uuid,
+            tx: &mut Transaction<'_, Postgres>,
+        ) -> Result<Vec<NonprofitClientDetails>> {
+            let query = include_str!("queries/nonprofits/fetch_nonprofits_by_cycle.sql");
+            let nonprofits = sqlx::query_as::<_, NonprofitClientDetails>(query)
+                .bind(project_cycle_id)
+                .fetch_all(&mut **tx)
+                .await
+                .context("error fetching nonprofits by cycle")?;
+
+            Ok(nonprofits)
+        }
+
+        exec_with_tx!(self, exec_opts, exec, project_cycle_id)
+    }
+
     async fn fetch_nonprofit_by_id(
         &self,
         id: Uuid,
@@ -366,4 +434,105 @@ impl QueryNonprofits<Postgres> for PgBackend {
 
         exec_with_tx!(self, exec_opts, exec, id)
     }
+
+    async fn batch_sync_nonprofits(
+        &self,
+        project_cycle_id: Uuid,
+        data: Vec<(String, CreateNonprofit)>,
+        exec_opts: &mut ExecOpts<Postgres>,
+    ) -> Result<Vec<(String, Uuid, SyncOutcome)>> {
+        async fn exec(
+            project_cycle_id: Uuid,
+            data: Vec<(String, CreateNonprofit)>,
+            tx: &mut Transaction<'_, Postgres>,
+        ) -> Result<Vec<(String, Uuid, SyncOutcome)>> {
+            let fetch_id = include_str!("queries/nonprofits/fetch_synced_nonprofit_id.sql");
+            let create = include_str!("queries/nonprofits/create_synced_nonprofit.sql");
+            let sync = include_str!("queries/nonprofits/sync_nonprofit.sql");
+
+            let mut synced = Vec::with_capacity(data.len());
+
+            for (external_id, n) in data {
+                let id = sqlx::query_scalar::<_, Uuid>(fetch_id)
+                    .bind(project_cycle_id)
+                    .bind(&external_id)
+                    .bind(&n.org_name)
+                    .bind(&n.project_name)
+                    .fetch_optional(&mut **tx)
+                    .await?;
+
+                // Both queries take the same parameters, except that the first is the ID of the
+                // nonprofit to update or the project cycle to create the nonprofit in
+                let (query, id_or_cycle) = match id {
+                    Some(id) => (sqlx::query(sync), id),
+                    None => (sqlx::query(create), project_cycle_id),
+                };
+
+                let query = query
+                    .bind(id_or_cycle)
+                    .bind(&external_id)
+                    .bind(n.representative_first_name)
+                    .bind(n.representative_last_name)
+                    .bind(n.representative_job_title)
+                    .bind(n.email)
+                    .bind(n.email_cc)
+                    .bind(n.phone)
+                    .bind(&n.org_name)
+                    .bind(n.project_name)
+                    .bind(n.org_website)
+                    .bind(n.country_hq)
+                    .bind(n.us_state_hq)
+                    .bind(n.address)
+                    .bind(n.size)
+                    .bind(n.impact_causes);
+
+                let (id, outcome) = match id {
+                    Some(id) => {
+                        let res = query
+                            .execute(&mut **tx)
+                            .await
+                            .with_context(|| format!("error syncing nonprofit {}", n.org_name))?;
+                        match res.rows_affected() {
+                            0 => (id, SyncOutcome::Unchanged),
+                            _ => (id, SyncOutcome::Updated),
+                        }
+                    }
+                    None => {
+                        let id = query
+                            .fetch_one(&mut **tx)
+                            .await
+                            .with_context(|| format!("error creating nonprofit {}", n.org_name))?
+                            .try_get("id")?;
+                        (id, SyncOutcome::Created)
+                    }
+                };
+
+                synced.push((external_id, id, outcome));
+            }
+
+            Ok(synced)
+        }
+
+        exec_with_tx!(self, exec_opts, exec, project_cycle_id, data)
+    }
+
+    async fn deactivate_missing_nonprofits(
+        &self,
+        project_cycle_id: Uuid,
+        keep: Vec<Uuid>,
+        exec_opts: &mut ExecOpts<Postgres>,
+    ) -> Result<u64> {
+        async fn exec(
+            project_cycle_id: Uuid,
+            keep: Vec<Uuid>,
+            tx: &mut Transaction<'_, Postgres>,
+        ) -> Result<u64> {
+            let query = include_str!("queries/nonprofits/deactivate_missing_nonprofits.sql");
+            let res =
+                sqlx::query(query).bind(project_cycle_id).bind(keep).execute(&mut **tx).await?;
+            Ok(res.rows_affected())
+        }
+
+        exec_with_tx!(self, exec_opts, exec, project_cycle_id, keep)
+    }
 }
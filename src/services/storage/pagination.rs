@@ -0,0 +1,99 @@
+//! This module contains types shared by queries which return their results a page at a time.
+//!
+//! Pagination is keyset-based. A page is requested with a cursor, which is the ID of the last
+//! result on the previous page, so pages stay consistent while rows are inserted or deleted.
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// The number of results on a page when no limit is given.
+pub const DEFAULT_PAGE_SIZE: i64 = 50;
+
+/// The most results that can be fetched in a single page.
+pub const MAX_PAGE_SIZE: i64 = 500;
+
+/// The direction to sort results in.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SortDirection {
+    #[default]
+    Asc,
+    Desc,
+}
+
+impl SortDirection {
+    /// The SQL keyword for this direction.
+    pub fn keyword(&self) -> &'static str {
+        match self {
+            SortDirection::Asc => "asc",
+            SortDirection::Desc => "desc",
+        }
+    }
+
+    /// The comparison which selects rows after a cursor when sorting in this direction.
+    pub fn comparison(&self) -> &'static str {
+        match self {
+            SortDirection::Asc => ">",
+            SortDirection::Desc => "<",
+        }
+    }
+}
+
+/// Which page of results to fetch.
+///
+/// * `cursor`: The ID of the last result on the previous page, or `None` for the first page
+/// * `limit`: The maximum number of results on the page
+#[derive(Debug, Clone, Builder)]
+pub struct PageOptions {
+    #[builder(setter(into, strip_option), default)]
+    pub cursor: Option<Uuid>,
+    #[builder(default = "DEFAULT_PAGE_SIZE")]
+    pub limit: i64,
+}
+
+impl PageOptions {
+    /// Build page options from request parameters, keeping the limit between 1 and
+    /// `MAX_PAGE_SIZE`.
+    ///
+    /// * `cursor`: The ID of the last result on the previous page, if any
+    /// * `limit`: The requested page size, or `None` for `DEFAULT_PAGE_SIZE`
+    pub fn new(cursor: Option<Uuid>, limit: Option<i64>) -> Self {
+        Self { cursor, limit: limit.unwrap_or(DEFAULT_PAGE_SIZE).clamp(1, MAX_PAGE_SIZE) }
+    }
+}
+
+/// A page of results.
+///
+/// * `items`: The results on this page
+/// * `next_cursor`: The cursor for the next page, or `None` if this is the last page
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub next_cursor: Option<Uuid>,
+}
+
+impl<T> Page<T> {
+    /// Build a page from rows fetched with a limit one greater than the page size.
+    ///
+    /// The extra row only tells us whether there is another page, so it is dropped.
+    ///
+    /// * `items`: The rows that were fetched
+    /// * `limit`: The page size
+    /// * `id`: A function which returns the ID of a row
+    pub fn from_rows(mut items: Vec<T>, limit: i64, id: impl Fn(&T) -> Uuid) -> Self {
+        let limit = limit.max(0) as usize;
+        let next_cursor = if items.len() > limit {
+            items.truncate(limit);
+            items.last().map(id)
+        } else {
+            None
+        };
+        Self { items, next_cursor }
+    }
+}
+
+/// Escape a string so that it matches literally within a `like` or `ilike` pattern.
+pub fn escape_like(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_")
+}
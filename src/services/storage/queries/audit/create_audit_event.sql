@@ -0,0 +1,4 @@
+insert into audit_events(principal, action, target_ids, summary, outcome, error)
+  values ($1, $2, $3, $4, $5, $6)
+returning
+  id;
@@ -0,0 +1,13 @@
+select
+  id,
+  created_at,
+  principal,
+  action,
+  target_ids,
+  summary,
+  outcome,
+  error
+from
+  audit_events
+where
+  true
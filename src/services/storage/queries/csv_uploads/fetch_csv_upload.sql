@@ -0,0 +1,8 @@
+select
+  volunteers,
+  mentors,
+  nonprofits
+from
+  csv_uploads
+where
+  id = $1;
@@ -0,0 +1,15 @@
+update
+  project_cycles
+set
+  name = coalesce($2, name),
+  description = coalesce($3, description),
+  archived = coalesce($4, archived)
+where
+  id = $1
+returning
+  id,
+  created_at,
+  updated_at,
+  name,
+  description,
+  archived;
@@ -0,0 +1,30 @@
+update
+  jobs
+set
+  status = 'running',
+  attempts = attempts + 1,
+  locked_by = $1,
+  locked_until = now() + make_interval(secs => $2),
+  heartbeat_at = now()
+where
+  id = (
+    select
+      id
+    from
+      jobs
+    where
+      status = 'pending'
+    order by
+      created_at
+    limit 1
+    for update
+      skip locked)
+returning
+  id,
+  created_at,
+  updated_at,
+  project_cycle_id,
+  status,
+  label,
+  description,
+  details;
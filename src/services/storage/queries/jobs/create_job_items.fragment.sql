@@ -0,0 +1 @@
+insert into job_items(job_id, kind, item_key, entity_id, label, status, error)
@@ -8,5 +8,6 @@ select
   description,
   details
 from
-  jobs;
-
+  jobs
+where
+  true
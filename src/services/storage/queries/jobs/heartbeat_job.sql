@@ -0,0 +1,11 @@
+update
+  jobs
+set
+  locked_until = now() + make_interval(secs => $3),
+  heartbeat_at = now()
+where
+  id = $1
+  and locked_by = $2
+  and status = 'running'
+returning
+  id;
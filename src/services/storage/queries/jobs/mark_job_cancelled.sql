@@ -0,0 +1,7 @@
+update
+  jobs
+set
+  status = 'cancelled',
+  details = jsonb_set(details, '{summary}', to_jsonb($2::text), true)
+where
+  id = $1;
@@ -0,0 +1,7 @@
+update
+  jobs
+set
+  cancel_requested_at = coalesce(cancel_requested_at, now())
+where
+  id = $1
+  and status = 'running';
@@ -0,0 +1,20 @@
+update
+  jobs
+set
+  status = case when attempts < $1 then
+    'pending'::job_status
+  else
+    'error'::job_status
+  end,
+  details = case when attempts < $1 then
+    details
+  else
+    jsonb_set(details, '{error}', to_jsonb('Job was abandoned by its worker too many times'::text), true)
+  end,
+  locked_by = null,
+  locked_until = null
+where
+  status = 'running'
+  and locked_until < now()
+returning
+  id;
@@ -0,0 +1,2 @@
+delete from airtable_mapping_profiles
+where id = $1;
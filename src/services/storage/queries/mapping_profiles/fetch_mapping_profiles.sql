@@ -0,0 +1,11 @@
+select
+  id,
+  created_at,
+  updated_at,
+  name,
+  description,
+  mapping
+from
+  airtable_mapping_profiles
+order by
+  name;
@@ -1,10 +1,10 @@
 update
   mentors
 set
-  first_name = $2,
-  last_name = $3,
-  email = $4,
-  phone = $5
+  first_name = coalesce($2, first_name),
+  last_name = coalesce($3, last_name),
+  email = coalesce($4, email),
+  phone = coalesce($5, phone)
 where
   id = $1;
 
@@ -19,6 +19,7 @@ select
   prior_student,
   university,
   hear_about,
+  active,
   volunteers,
   clients
 from
@@ -19,8 +19,11 @@ select
   prior_student,
   university,
   hear_about,
+  active,
   volunteers,
   clients
 from
-  mentor_details;
+  mentor_details
+where
+  active;
 
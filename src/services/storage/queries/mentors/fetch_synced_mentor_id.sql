@@ -0,0 +1,13 @@
+-- Mentors imported before external IDs were recorded are matched by email.
+select
+  id
+from
+  mentors
+where
+  project_cycle_id = $1
+  and (external_id = $2
+    or (external_id is null
+      and email = $3))
+order by
+  external_id nulls last
+limit 1;
@@ -0,0 +1,25 @@
+-- Only mentors which differ from the synced data are updated, so that the number of rows affected
+-- tells whether the mentor changed.
+update
+  mentors
+set
+  external_id = $2,
+  active = true,
+  first_name = $3,
+  last_name = $4,
+  email = $5,
+  phone = $6,
+  company = $7,
+  job_title = $8,
+  country = $9,
+  us_state = $10,
+  years_experience = $11::mentor_years_experience,
+  experience_level = $12::mentor_experience_level,
+  prior_mentor = $13,
+  prior_mentee = $14,
+  prior_student = $15,
+  university = $16,
+  hear_about = $17
+where
+  id = $1
+  and (external_id, active, first_name, last_name, email, phone, company, job_title, country, us_state, years_experience, experience_level, prior_mentor, prior_mentee, prior_student, university, hear_about) is distinct from ($2, true, $3, $4, $5, $6, $7, $8, $9, $10, $11::mentor_years_experience, $12::mentor_experience_level, $13, $14, $15, $16, $17);
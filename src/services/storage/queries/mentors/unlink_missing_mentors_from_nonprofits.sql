@@ -0,0 +1,7 @@
+delete from client_mentors
+where project_cycle_id = $1
+  and (mentor_id, client_id) not in (
+    select
+      *
+    from
+      unnest($2::uuid[], $3::uuid[]));
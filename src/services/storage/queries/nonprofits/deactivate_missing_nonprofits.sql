@@ -0,0 +1,8 @@
+update
+  nonprofit_clients
+set
+  active = false
+where
+  project_cycle_id = $1
+  and active
+  and id <> all ($2);
@@ -17,6 +17,7 @@ select
   us_state_hq,
   address,
   size,
+  active,
   impact_causes,
   volunteers,
   mentors
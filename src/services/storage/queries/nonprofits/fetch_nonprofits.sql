@@ -17,9 +17,12 @@ select
   us_state_hq,
   address,
   size,
+  active,
   impact_causes,
   volunteers,
   mentors
 from
-  nonprofit_client_details;
+  nonprofit_client_details
+where
+  active;
 
@@ -0,0 +1,15 @@
+-- Nonprofits imported before external IDs were recorded are matched by their organization and
+-- project names.
+select
+  id
+from
+  nonprofit_clients
+where
+  project_cycle_id = $1
+  and (external_id = $2
+    or (external_id is null
+      and org_name = $3
+      and project_name = $4))
+order by
+  external_id nulls last
+limit 1;
@@ -0,0 +1,4 @@
+insert into volunteer_team_roles(project_cycle_id, volunteer_id, role_id)
+  values ($1, $2, $3)
+on conflict
+  do nothing;
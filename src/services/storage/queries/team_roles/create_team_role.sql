@@ -0,0 +1,4 @@
+insert into team_roles(name, description)
+  values ($1, $2)
+returning
+  id;
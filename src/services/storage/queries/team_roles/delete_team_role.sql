@@ -0,0 +1,2 @@
+delete from team_roles
+where id = $1;
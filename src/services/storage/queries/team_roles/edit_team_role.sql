@@ -0,0 +1,13 @@
+update
+  team_roles
+set
+  name = coalesce($2, name),
+  description = coalesce($3, description)
+where
+  id = $1
+returning
+  id,
+  created_at,
+  updated_at,
+  name,
+  description;
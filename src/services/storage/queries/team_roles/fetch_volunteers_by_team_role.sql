@@ -0,0 +1,43 @@
+select
+  volunteer_id,
+  created_at,
+  updated_at,
+  project_cycle_id,
+  project_cycle_name,
+  first_name,
+  last_name,
+  email,
+  phone,
+  volunteer_gender,
+  volunteer_ethnicity,
+  volunteer_age_range,
+  university,
+  lgbt,
+  country,
+  us_state,
+  fli,
+  student_stage,
+  majors,
+  minors,
+  hear_about,
+  active,
+  clients,
+  mentors,
+  workspace_email,
+  roles
+from
+  volunteer_details vd
+where
+  vd.active
+  and exists (
+    select
+    from
+      volunteer_team_roles vtr
+    where
+      vtr.volunteer_id = vd.volunteer_id
+      and vtr.role_id = $1
+      and ($2::uuid is null
+        or vtr.project_cycle_id = $2))
+order by
+  last_name,
+  first_name;
@@ -0,0 +1,4 @@
+insert into volunteers(project_cycle_id, external_id, first_name, last_name, email, phone, volunteer_gender, volunteer_ethnicity, volunteer_age_range, university, lgbt, country, us_state, fli, student_stage, majors, minors, hear_about, external_source)
+  values ($1, $2, $3, $4, $5, $6, $7::gender, $8::ethnicity[], $9::age_range, $10, $11::lgbt_status, $12, $13, $14::fli_status[], $15::student_stage, $16, $17, $18, $19::external_source)
+returning
+  id;
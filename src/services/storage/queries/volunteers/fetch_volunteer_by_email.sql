@@ -20,6 +20,7 @@ select
   majors,
   minors,
   hear_about,
+  active,
   clients,
   mentors,
   workspace_email,
@@ -0,0 +1,9 @@
+select
+  id,
+  external_id
+from
+  volunteers
+where
+  id = any($1)
+  and external_id is not null
+  and external_source = $2::external_source;
@@ -0,0 +1,6 @@
+select
+  id
+from
+  volunteers
+where
+  email = $1;
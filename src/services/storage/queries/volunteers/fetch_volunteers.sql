@@ -20,10 +20,13 @@ select
   majors,
   minors,
   hear_about,
+  active,
   clients,
   mentors,
   workspace_email,
   roles
 from
-  volunteer_details;
+  volunteer_details
+where
+  active;
 
@@ -20,6 +20,7 @@ select
   majors,
   minors,
   hear_about,
+  active,
   clients,
   mentors,
   workspace_email,
@@ -27,5 +28,6 @@ select
 from
   volunteer_details
 where
-  project_cycle_id = $1;
+  project_cycle_id = $1
+  and active;
 
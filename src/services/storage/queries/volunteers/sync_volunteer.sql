@@ -0,0 +1,27 @@
+-- Only volunteers which differ from the synced data are updated, so that the number of rows
+-- affected tells whether the volunteer changed.
+update
+  volunteers
+set
+  external_id = $2,
+  external_source = $19::external_source,
+  active = true,
+  first_name = $3,
+  last_name = $4,
+  email = $5,
+  phone = $6,
+  volunteer_gender = $7::gender,
+  volunteer_ethnicity = $8::ethnicity[],
+  volunteer_age_range = $9::age_range,
+  university = $10,
+  lgbt = $11::lgbt_status,
+  country = $12,
+  us_state = $13,
+  fli = $14::fli_status[],
+  student_stage = $15::student_stage,
+  majors = $16,
+  minors = $17,
+  hear_about = $18
+where
+  id = $1
+  and (external_id, external_source, active, first_name, last_name, email, phone, volunteer_gender, volunteer_ethnicity, volunteer_age_range, university, lgbt, country, us_state, fli, student_stage, majors, minors, hear_about) is distinct from ($2, $19::external_source, true, $3, $4, $5, $6, $7::gender, $8::ethnicity[], $9::age_range, $10, $11::lgbt_status, $12, $13, $14::fli_status[], $15::student_stage, $16, $17, $18);
@@ -0,0 +1,7 @@
+delete from volunteer_mentors
+where project_cycle_id = $1
+  and (volunteer_id, mentor_id) not in (
+    select
+      *
+    from
+      unnest($2::uuid[], $3::uuid[]));
@@ -0,0 +1,2 @@
+delete from airtable_webhooks
+where id = $1;
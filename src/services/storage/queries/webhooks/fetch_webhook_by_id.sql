@@ -0,0 +1,15 @@
+select
+  id,
+  created_at,
+  updated_at,
+  base_id,
+  webhook_id,
+  mac_secret_base64,
+  project_cycle_id,
+  mapping_profile_id,
+  cursor,
+  expiration_time
+from
+  airtable_webhooks
+where
+  id = $1;
@@ -0,0 +1,6 @@
+update
+  airtable_webhooks
+set
+  cursor = $2
+where
+  id = $1;
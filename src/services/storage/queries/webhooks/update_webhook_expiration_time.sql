@@ -0,0 +1,6 @@
+update
+  airtable_webhooks
+set
+  expiration_time = $2
+where
+  id = $1;
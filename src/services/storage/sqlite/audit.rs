@@ -0,0 +1,112 @@
+//! This module contains the implementation of the `QueryAudit` trait for the `SqliteBackend`
+//! struct.
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use sqlx::types::Json;
+use sqlx::{QueryBuilder, Sqlite, Transaction};
+use uuid::Uuid;
+
+use super::entities::AuditEventRow;
+use super::SqliteBackend;
+use crate::services::storage::audit::{AuditFilter, CreateAuditEvent, QueryAudit};
+use crate::services::storage::entities::AuditEvent;
+use crate::services::storage::pagination::{Page, PageOptions};
+use crate::services::storage::{exec_with_tx, Acquire, ExecOpts};
+
+#[async_trait]
+impl QueryAudit<Sqlite> for SqliteBackend {
+    async fn create_audit_event(
+        &self,
+        data: CreateAuditEvent,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<Uuid> {
+        async fn exec(data: CreateAuditEvent, tx: &mut Transaction<'_, Sqlite>) -> Result<Uuid> {
+            let query = include_str!("queries/audit/create_audit_event.sql");
+
+            let id = sqlx::query_scalar::<_, Uuid>(query)
+                .bind(data.principal)
+                .bind(data.action.to_string())
+                .bind(Json(data.target_ids))
+                .bind(data.summary)
+                .bind(data.outcome)
+                .bind(data.error)
+                .fetch_one(&mut **tx)
+                .await
+                .context("error recording audit event")?;
+            Ok(id)
+        }
+
+        exec_with_tx!(self, exec_opts, exec, data)
+    }
+
+    async fn fetch_audit_events(
+        &self,
+        filter: AuditFilter,
+        page: PageOptions,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<Page<AuditEvent>> {
+        async fn exec(
+            filter: AuditFilter,
+            page: PageOptions,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<Page<AuditEvent>> {
+            let fragment = include_str!("queries/audit/fetch_audit_events.fragment.sql");
+            let mut query = QueryBuilder::<Sqlite>::new(fragment);
+
+            if let Some(principal) = filter.principal {
+                query.push(" and principal = ").push_bind(principal);
+            }
+
+            if let Some(action) = filter.action {
+                query.push(" and action = ").push_bind(action.to_string());
+            }
+
+            // `target_ids` is a JSON array of hyphenated UUIDs
+            if let Some(target_id) = filter.target_id {
+                query
+                    .push(" and exists (select 1 from json_each(target_ids) where value = ")
+                    .push_bind(target_id.to_string())
+                    .push(")");
+            }
+
+            if let Some(outcome) = filter.outcome {
+                query.push(" and outcome = ").push_bind(outcome);
+            }
+
+            if let Some(created_after) = filter.created_after {
+                query
+                    .push(" and julianday(created_at) >= julianday(")
+                    .push_bind(created_after)
+                    .push(")");
+            }
+
+            if let Some(created_before) = filter.created_before {
+                query
+                    .push(" and julianday(created_at) < julianday(")
+                    .push_bind(created_before)
+                    .push(")");
+            }
+
+            if let Some(cursor) = page.cursor {
+                query
+                    .push(" and (created_at, id) < (select created_at, id from audit_events where id = ")
+                    .push_bind(cursor)
+                    .push(")");
+            }
+
+            query.push(" order by created_at desc, id desc limit ").push_bind(page.limit + 1);
+
+            let events = query
+                .build_query_as::<AuditEventRow>()
+                .fetch_all(&mut **tx)
+                .await
+                .context("error fetching audit events")?;
+            let events = events.into_iter().map(AuditEvent::from).collect();
+
+            Ok(Page::from_rows(events, page.limit, |event| event.id))
+        }
+
+        exec_with_tx!(self, exec_opts, exec, filter, page)
+    }
+}
@@ -0,0 +1,72 @@
+//! This module contains the implementation of the `QueryCsvUploads` trait for the `SqliteBackend`
+//! struct.
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use sqlx::{Sqlite, Transaction};
+use uuid::Uuid;
+
+use super::SqliteBackend;
+use crate::services::storage::csv_uploads::QueryCsvUploads;
+use crate::services::storage::types::CsvImportFiles;
+use crate::services::storage::{exec_with_tx, Acquire, ExecOpts};
+
+#[async_trait]
+impl QueryCsvUploads<Sqlite> for SqliteBackend {
+    async fn create_csv_upload(
+        &self,
+        files: CsvImportFiles,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<Uuid> {
+        async fn exec(files: CsvImportFiles, tx: &mut Transaction<'_, Sqlite>) -> Result<Uuid> {
+            let query = include_str!("queries/csv_uploads/create_csv_upload.sql");
+
+            let id = sqlx::query_scalar::<_, Uuid>(query)
+                .bind(files.volunteers)
+                .bind(files.mentors)
+                .bind(files.nonprofits)
+                .fetch_one(&mut **tx)
+                .await
+                .context("error creating csv upload")?;
+            Ok(id)
+        }
+
+        exec_with_tx!(self, exec_opts, exec, files)
+    }
+
+    async fn fetch_csv_upload(
+        &self,
+        id: Uuid,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<Option<CsvImportFiles>> {
+        async fn exec(
+            id: Uuid,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<Option<CsvImportFiles>> {
+            let query = include_str!("queries/csv_uploads/fetch_csv_upload.sql");
+
+            let files = sqlx::query_as::<_, CsvImportFiles>(query)
+                .bind(id)
+                .fetch_optional(&mut **tx)
+                .await
+                .context("error fetching csv upload")?;
+            Ok(files)
+        }
+
+        exec_with_tx!(self, exec_opts, exec, id)
+    }
+
+    async fn delete_csv_upload(&self, id: Uuid, exec_opts: &mut ExecOpts<Sqlite>) -> Result<()> {
+        async fn exec(id: Uuid, tx: &mut Transaction<'_, Sqlite>) -> Result<()> {
+            let query = include_str!("queries/csv_uploads/delete_csv_upload.sql");
+            sqlx::query(query)
+                .bind(id)
+                .execute(&mut **tx)
+                .await
+                .context("error deleting csv upload")?;
+            Ok(())
+        }
+
+        exec_with_tx!(self, exec_opts, exec, id)
+    }
+}
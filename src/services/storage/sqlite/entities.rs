@@ -0,0 +1,224 @@
+//! This module defines how entities containing arrays are read from SQLite.
+//!
+//! SQLite stores arrays as JSON, and a row can't be decoded straight into a `Vec<T>`. Each row
+//! type here decodes the arrays as JSON and converts into the corresponding entity.
+
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use sqlx::types::Json;
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::services::storage::entities::{
+    AuditEvent, MentorDetails, NonprofitClientDetails, VolunteerDetails,
+};
+use crate::services::storage::types::{
+    AgeRange, AuditOutcome, ClientSize, Ethnicity, Fli, Gender, ImpactCause, Lgbt,
+    MentorExperienceLevel, MentorYearsExperience, StudentStage, VolunteerHearAbout,
+};
+
+/// A row of the `volunteer_details` view.
+#[derive(FromRow)]
+pub(super) struct VolunteerDetailsRow {
+    volunteer_id: Uuid,
+    created_at: DateTime<Utc>,
+    updated_at: Option<DateTime<Utc>>,
+    project_cycle_id: Uuid,
+    project_cycle_name: String,
+    first_name: String,
+    last_name: String,
+    email: String,
+    phone: Option<String>,
+    volunteer_gender: Gender,
+    volunteer_ethnicity: Json<Vec<Ethnicity>>,
+    volunteer_age_range: AgeRange,
+    workspace_email: Option<String>,
+    university: Json<Vec<String>>,
+    lgbt: Lgbt,
+    country: String,
+    us_state: Option<String>,
+    fli: Json<Vec<Fli>>,
+    student_stage: StudentStage,
+    majors: Json<Vec<String>>,
+    minors: Json<Vec<String>>,
+    hear_about: Json<Vec<VolunteerHearAbout>>,
+    active: bool,
+    clients: Value,
+    mentors: Value,
+    roles: Value,
+}
+
+impl From<VolunteerDetailsRow> for VolunteerDetails {
+    fn from(row: VolunteerDetailsRow) -> Self {
+        Self {
+            volunteer_id: row.volunteer_id,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+            project_cycle_id: row.project_cycle_id,
+            project_cycle_name: row.project_cycle_name,
+            first_name: row.first_name,
+            last_name: row.last_name,
+            email: row.email,
+            phone: row.phone,
+            volunteer_gender: row.volunteer_gender,
+            volunteer_ethnicity: row.volunteer_ethnicity.0,
+            volunteer_age_range: row.volunteer_age_range,
+            workspace_email: row.workspace_email,
+            university: row.university.0,
+            lgbt: row.lgbt,
+            country: row.country,
+            us_state: row.us_state,
+            fli: row.fli.0,
+            student_stage: row.student_stage,
+            majors: row.majors.0,
+            minors: row.minors.0,
+            hear_about: row.hear_about.0,
+            active: row.active,
+            clients: row.clients,
+            mentors: row.mentors,
+            roles: row.roles,
+        }
+    }
+}
+
+/// A row of the `nonprofit_client_details` view.
+#[derive(FromRow)]
+pub(super) struct NonprofitClientDetailsRow {
+    client_id: Uuid,
+    created_at: DateTime<Utc>,
+    updated_at: Option<DateTime<Utc>>,
+    project_cycle_id: Uuid,
+    project_cycle_name: String,
+    representative_first_name: String,
+    representative_last_name: String,
+    representative_job_title: Option<String>,
+    email: String,
+    email_cc: Option<String>,
+    phone: String,
+    org_name: String,
+    project_name: String,
+    org_website: Option<String>,
+    country_hq: Option<String>,
+    us_state_hq: Option<String>,
+    address: String,
+    size: ClientSize,
+    impact_causes: Json<Vec<ImpactCause>>,
+    active: bool,
+    volunteers: Value,
+    mentors: Value,
+}
+
+impl From<NonprofitClientDetailsRow> for NonprofitClientDetails {
+    fn from(row: NonprofitClientDetailsRow) -> Self {
+        Self {
+            client_id: row.client_id,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+            project_cycle_id: row.project_cycle_id,
+            project_cycle_name: row.project_cycle_name,
+            representative_first_name: row.representative_first_name,
+            representative_last_name: row.representative_last_name,
+            representative_job_title: row.representative_job_title,
+            email: row.email,
+            email_cc: row.email_cc,
+            phone: row.phone,
+            org_name: row.org_name,
+            project_name: row.project_name,
+            org_website: row.org_website,
+            country_hq: row.country_hq,
+            us_state_hq: row.us_state_hq,
+            address: row.address,
+            size: row.size,
+            impact_causes: row.impact_causes.0,
+            active: row.active,
+            volunteers: row.volunteers,
+            mentors: row.mentors,
+        }
+    }
+}
+
+/// A row of the `mentor_details` view.
+#[derive(FromRow)]
+pub(super) struct MentorDetailsRow {
+    mentor_id: Uuid,
+    created_at: DateTime<Utc>,
+    updated_at: Option<DateTime<Utc>>,
+    project_cycle_id: Uuid,
+    project_cycle_name: String,
+    first_name: String,
+    last_name: String,
+    email: String,
+    phone: Option<String>,
+    company: String,
+    job_title: String,
+    country: String,
+    us_state: Option<String>,
+    years_experience: MentorYearsExperience,
+    experience_level: MentorExperienceLevel,
+    prior_mentor: bool,
+    prior_mentee: bool,
+    prior_student: bool,
+    university: Json<Vec<String>>,
+    hear_about: Json<Vec<VolunteerHearAbout>>,
+    active: bool,
+    volunteers: Value,
+    clients: Value,
+}
+
+impl From<MentorDetailsRow> for MentorDetails {
+    fn from(row: MentorDetailsRow) -> Self {
+        Self {
+            mentor_id: row.mentor_id,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+            project_cycle_id: row.project_cycle_id,
+            project_cycle_name: row.project_cycle_name,
+            first_name: row.first_name,
+            last_name: row.last_name,
+            email: row.email,
+            phone: row.phone,
+            company: row.company,
+            job_title: row.job_title,
+            country: row.country,
+            us_state: row.us_state,
+            years_experience: row.years_experience,
+            experience_level: row.experience_level,
+            prior_mentor: row.prior_mentor,
+            prior_mentee: row.prior_mentee,
+            prior_student: row.prior_student,
+            university: row.university.0,
+            hear_about: row.hear_about.0,
+            active: row.active,
+            volunteers: row.volunteers,
+            clients: row.clients,
+        }
+    }
+}
+
+/// A row of the `audit_events` table.
+#[derive(FromRow)]
+pub(super) struct AuditEventRow {
+    id: Uuid,
+    created_at: DateTime<Utc>,
+    principal: String,
+    action: String,
+    target_ids: Json<Vec<Uuid>>,
+    summary: Value,
+    outcome: AuditOutcome,
+    error: Option<String>,
+}
+
+impl From<AuditEventRow> for AuditEvent {
+    fn from(row: AuditEventRow) -> Self {
+        Self {
+            id: row.id,
+            created_at: row.created_at,
+            principal: row.principal,
+            action: row.action,
+            target_ids: row.target_ids.0,
+            summary: row.summary,
+            outcome: row.outcome,
+            error: row.error,
+        }
+    }
+}
@@ -0,0 +1,446 @@
+//! This module contains the implementation of the `QueryJobs` trait for the `SqliteBackend`
+//! struct.
+
+use anyhow::Result;
+use async_trait::async_trait;
+use sqlx::{QueryBuilder, Sqlite, Transaction};
+use uuid::Uuid;
+
+use super::SqliteBackend;
+use crate::services::storage::entities::{Job, JobItem, JobItemCount};
+use crate::services::storage::jobs::{
+    CreateJob, CreateJobItem, EditJob, JobFilter, QueryJobs, UpdateJobItem, UpdateJobStatus,
+};
+use crate::services::storage::pagination::{Page, PageOptions};
+use crate::services::storage::types::{JobItemKind, JobStatus, SyncDiff, WriteBackReport};
+use crate::services::storage::{exec_with_tx, Acquire, ExecOpts};
+
+#[async_trait]
+impl QueryJobs<Sqlite> for SqliteBackend {
+    async fn create_job(
+        &self,
+        project_cycle_id: Option<Uuid>,
+        data: CreateJob,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<Uuid> {
+        async fn exec(
+            project_cycle_id: Option<Uuid>,
+            data: CreateJob,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<Uuid> {
+            let query = include_str!("queries/jobs/create_job.sql");
+            let id = sqlx::query_scalar::<_, Uuid>(query)
+                .bind(project_cycle_id)
+                .bind(data.label)
+                .bind(data.description)
+                .bind(serde_json::to_value(data.data)?)
+                .fetch_one(&mut **tx)
+                .await?;
+            Ok(id)
+        }
+        exec_with_tx!(self, exec_opts, exec, project_cycle_id, data)
+    }
+
+    async fn fetch_jobs(
+        &self,
+        filter: JobFilter,
+        page: PageOptions,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<Page<Job>> {
+        async fn exec(
+            filter: JobFilter,
+            page: PageOptions,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<Page<Job>> {
+            let fragment = include_str!("queries/jobs/fetch_jobs.fragment.sql");
+            let mut query = QueryBuilder::<Sqlite>::new(fragment);
+
+            if let Some(status) = filter.status {
+                query.push(" and status = ").push_bind(status);
+            }
+
+            if let Some(job_type) = filter.job_type {
+                // The job type is stored in the job details the same way it is serialized
+                let job_type = serde_json::to_value(job_type)?;
+                query
+                    .push(" and json_extract(details, '$.jobType') = ")
+                    .push_bind(job_type.as_str().map(str::to_owned));
+            }
+
+            if let Some(project_cycle_id) = filter.project_cycle_id {
+                query.push(" and project_cycle_id = ").push_bind(project_cycle_id);
+            }
+
+            if let Some(created_after) = filter.created_after {
+                query
+                    .push(" and julianday(created_at) >= julianday(")
+                    .push_bind(created_after)
+                    .push(")");
+            }
+
+            if let Some(created_before) = filter.created_before {
+                query
+                    .push(" and julianday(created_at) < julianday(")
+                    .push_bind(created_before)
+                    .push(")");
+            }
+
+            if let Some(cursor) = page.cursor {
+                query
+                    .push(" and (created_at, id) < (select created_at, id from jobs where id = ")
+                    .push_bind(cursor)
+                    .push(")");
+            }
+
+            query.push(" order by created_at desc, id desc limit ").push_bind(page.limit + 1);
+
+            let jobs = query.build_query_as::<Job>().fetch_all(&mut **tx).await?;
+
+            Ok(Page::from_rows(jobs, page.limit, |job| job.id))
+        }
+        exec_with_tx!(self, exec_opts, exec, filter, page)
+    }
+
+    async fn fetch_job(&self, id: Uuid, exec_opts: &mut ExecOpts<Sqlite>) -> Result<Option<Job>> {
+        async fn exec(id: Uuid, tx: &mut Transaction<'_, Sqlite>) -> Result<Option<Job>> {
+            let query = include_str!("queries/jobs/fetch_job.sql");
+            let job = sqlx::query_as::<_, Job>(query).bind(id).fetch_optional(&mut **tx).await?;
+            Ok(job)
+        }
+        exec_with_tx!(self, exec_opts, exec, id)
+    }
+
+    async fn update_job_status(
+        &self,
+        id: Uuid,
+        data: UpdateJobStatus,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<()> {
+        async fn exec(
+            id: Uuid,
+            data: UpdateJobStatus,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<()> {
+            let query = include_str!("queries/jobs/update_job_status.sql");
+            sqlx::query(query)
+                .bind(id)
+                .bind(data.status)
+                .bind(data.error)
+                .execute(&mut **tx)
+                .await?;
+            Ok(())
+        }
+        exec_with_tx!(self, exec_opts, exec, id, data)
+    }
+
+    async fn set_job_project_cycle(
+        &self,
+        id: Uuid,
+        project_cycle_id: Uuid,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<()> {
+        async fn exec(
+            id: Uuid,
+            project_cycle_id: Uuid,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<()> {
+            let query = include_str!("queries/jobs/set_job_project_cycle.sql");
+            sqlx::query(query).bind(id).bind(project_cycle_id).execute(&mut **tx).await?;
+            Ok(())
+        }
+        exec_with_tx!(self, exec_opts, exec, id, project_cycle_id)
+    }
+
+    async fn edit_job(&self, id: Uuid, data: EditJob, opts: &mut ExecOpts<Sqlite>) -> Result<()> {
+        async fn exec(id: Uuid, data: EditJob, tx: &mut Transaction<'_, Sqlite>) -> Result<()> {
+            let query = include_str!("queries/jobs/edit_job.sql");
+            sqlx::query(query)
+                .bind(id)
+                .bind(data.label)
+                .bind(data.description)
+                .execute(&mut **tx)
+                .await?;
+            Ok(())
+        }
+        exec_with_tx!(self, opts, exec, id, data)
+    }
+
+    async fn cancel_job(&self, id: Uuid, exec_opts: &mut ExecOpts<Sqlite>) -> Result<bool> {
+        async fn exec(id: Uuid, tx: &mut Transaction<'_, Sqlite>) -> Result<bool> {
+            let query = include_str!("queries/jobs/cancel_job.sql");
+            let res = sqlx::query(query).bind(id).execute(&mut **tx).await?;
+            Ok(res.rows_affected() > 0)
+        }
+        exec_with_tx!(self, exec_opts, exec, id)
+    }
+
+    async fn mark_job_cancelled(
+        &self,
+        id: Uuid,
+        summary: String,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<()> {
+        async fn exec(id: Uuid, summary: String, tx: &mut Transaction<'_, Sqlite>) -> Result<()> {
+            let query = include_str!("queries/jobs/mark_job_cancelled.sql");
+            sqlx::query(query).bind(id).bind(summary).execute(&mut **tx).await?;
+            Ok(())
+        }
+        exec_with_tx!(self, exec_opts, exec, id, summary)
+    }
+
+    async fn request_job_cancellation(
+        &self,
+        id: Uuid,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<bool> {
+        async fn exec(id: Uuid, tx: &mut Transaction<'_, Sqlite>) -> Result<bool> {
+            let query = include_str!("queries/jobs/request_job_cancellation.sql");
+            let res = sqlx::query(query).bind(id).execute(&mut **tx).await?;
+            Ok(res.rows_affected() > 0)
+        }
+        exec_with_tx!(self, exec_opts, exec, id)
+    }
+
+    async fn is_job_cancellation_requested(
+        &self,
+        id: Uuid,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<bool> {
+        async fn exec(id: Uuid, tx: &mut Transaction<'_, Sqlite>) -> Result<bool> {
+            let query = include_str!("queries/jobs/is_job_cancellation_requested.sql");
+            let requested =
+                sqlx::query_scalar::<_, bool>(query).bind(id).fetch_optional(&mut **tx).await?;
+            Ok(requested.unwrap_or(false))
+        }
+        exec_with_tx!(self, exec_opts, exec, id)
+    }
+
+    async fn set_job_diff(
+        &self,
+        id: Uuid,
+        diff: SyncDiff,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<()> {
+        async fn exec(id: Uuid, diff: SyncDiff, tx: &mut Transaction<'_, Sqlite>) -> Result<()> {
+            let query = include_str!("queries/jobs/set_job_diff.sql");
+            sqlx::query(query)
+                .bind(id)
+                .bind(serde_json::to_value(diff)?)
+                .execute(&mut **tx)
+                .await?;
+            Ok(())
+        }
+        exec_with_tx!(self, exec_opts, exec, id, diff)
+    }
+
+    async fn set_job_write_back(
+        &self,
+        id: Uuid,
+        report: WriteBackReport,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<()> {
+        async fn exec(
+            id: Uuid,
+            report: WriteBackReport,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<()> {
+            let query = include_str!("queries/jobs/set_job_write_back.sql");
+            sqlx::query(query)
+                .bind(id)
+                .bind(serde_json::to_value(report)?)
+                .execute(&mut **tx)
+                .await?;
+            Ok(())
+        }
+        exec_with_tx!(self, exec_opts, exec, id, report)
+    }
+
+    async fn mark_job_complete(&self, id: Uuid, exec_opts: &mut ExecOpts<Sqlite>) -> Result<()> {
+        async fn exec(id: Uuid, tx: &mut Transaction<'_, Sqlite>) -> Result<()> {
+            let query = include_str!("queries/jobs/update_job_status.sql");
+            sqlx::query(query)
+                .bind(id)
+                .bind(JobStatus::Complete)
+                .bind(Option::<String>::None)
+                .execute(&mut **tx)
+                .await?;
+            Ok(())
+        }
+        exec_with_tx!(self, exec_opts, exec, id)
+    }
+
+    async fn mark_job_errored(
+        &self,
+        id: Uuid,
+        error: String,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<()> {
+        async fn exec(id: Uuid, error: String, tx: &mut Transaction<'_, Sqlite>) -> Result<()> {
+            let query = include_str!("queries/jobs/update_job_status.sql");
+            sqlx::query(query)
+                .bind(id)
+                .bind(JobStatus::Error)
+                .bind(Some(error))
+                .execute(&mut **tx)
+                .await?;
+            Ok(())
+        }
+        exec_with_tx!(self, exec_opts, exec, id, error)
+    }
+
+    async fn claim_job(
+        &self,
+        worker_id: &str,
+        lease_seconds: i64,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<Option<Job>> {
+        async fn exec(
+            worker_id: &str,
+            lease_seconds: i64,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<Option<Job>> {
+            let query = include_str!("queries/jobs/claim_job.sql");
+            let job = sqlx::query_as::<_, Job>(query)
+                .bind(worker_id)
+                .bind(lease_seconds)
+                .fetch_optional(&mut **tx)
+                .await?;
+            Ok(job)
+        }
+        exec_with_tx!(self, exec_opts, exec, worker_id, lease_seconds)
+    }
+
+    async fn heartbeat_job(
+        &self,
+        id: Uuid,
+        worker_id: &str,
+        lease_seconds: i64,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<bool> {
+        async fn exec(
+            id: Uuid,
+            worker_id: &str,
+            lease_seconds: i64,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<bool> {
+            let query = include_str!("queries/jobs/heartbeat_job.sql");
+            let held = sqlx::query_scalar::<_, Uuid>(query)
+                .bind(id)
+                .bind(worker_id)
+                .bind(lease_seconds)
+                .fetch_optional(&mut **tx)
+                .await?;
+            Ok(held.is_some())
+        }
+        exec_with_tx!(self, exec_opts, exec, id, worker_id, lease_seconds)
+    }
+
+    async fn requeue_expired_jobs(
+        &self,
+        max_attempts: i32,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<Vec<Uuid>> {
+        async fn exec(max_attempts: i32, tx: &mut Transaction<'_, Sqlite>) -> Result<Vec<Uuid>> {
+            let query = include_str!("queries/jobs/requeue_expired_jobs.sql");
+            let ids = sqlx::query_scalar::<_, Uuid>(query)
+                .bind(max_attempts)
+                .fetch_all(&mut **tx)
+                .await?;
+            Ok(ids)
+        }
+        exec_with_tx!(self, exec_opts, exec, max_attempts)
+    }
+
+    async fn batch_create_job_items(
+        &self,
+        job_id: Uuid,
+        data: Vec<CreateJobItem>,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<()> {
+        async fn exec(
+            job_id: Uuid,
+            data: Vec<CreateJobItem>,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<()> {
+            if data.is_empty() {
+                return Ok(());
+            }
+
+            let fragment = include_str!("queries/jobs/create_job_items.fragment.sql");
+            QueryBuilder::<Sqlite>::new(fragment)
+                .push_values(data, |mut b, item| {
+                    b.push_bind(job_id)
+                        .push_bind(item.kind)
+                        .push_bind(item.item_key)
+                        .push_bind(item.entity_id)
+                        .push_bind(item.label)
+                        .push_bind(item.status)
+                        .push_bind(item.error);
+                })
+                .push(" on conflict (job_id, kind, item_key) do nothing")
+                .build()
+                .execute(&mut **tx)
+                .await?;
+            Ok(())
+        }
+        exec_with_tx!(self, exec_opts, exec, job_id, data)
+    }
+
+    async fn update_job_item(
+        &self,
+        job_id: Uuid,
+        kind: JobItemKind,
+        item_key: &str,
+        data: UpdateJobItem,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<()> {
+        async fn exec(
+            job_id: Uuid,
+            kind: JobItemKind,
+            item_key: &str,
+            data: UpdateJobItem,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<()> {
+            let query = include_str!("queries/jobs/update_job_item.sql");
+            sqlx::query(query)
+                .bind(job_id)
+                .bind(kind)
+                .bind(item_key)
+                .bind(data.status)
+                .bind(data.entity_id)
+                .bind(data.error)
+                .execute(&mut **tx)
+                .await?;
+            Ok(())
+        }
+        exec_with_tx!(self, exec_opts, exec, job_id, kind, item_key, data)
+    }
+
+    async fn fetch_job_items(
+        &self,
+        job_id: Uuid,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<Vec<JobItem>> {
+        async fn exec(job_id: Uuid, tx: &mut Transaction<'_, Sqlite>) -> Result<Vec<JobItem>> {
+            let query = include_str!("queries/jobs/fetch_job_items.sql");
+            let items =
+                sqlx::query_as::<_, JobItem>(query).bind(job_id).fetch_all(&mut **tx).await?;
+            Ok(items)
+        }
+        exec_with_tx!(self, exec_opts, exec, job_id)
+    }
+
+    async fn fetch_job_item_counts(
+        &self,
+        job_id: Uuid,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<Vec<JobItemCount>> {
+        async fn exec(job_id: Uuid, tx: &mut Transaction<'_, Sqlite>) -> Result<Vec<JobItemCount>> {
+            let query = include_str!("queries/jobs/fetch_job_item_counts.sql");
+            let counts =
+                sqlx::query_as::<_, JobItemCount>(query).bind(job_id).fetch_all(&mut **tx).await?;
+            Ok(counts)
+        }
+        exec_with_tx!(self, exec_opts, exec, job_id)
+    }
+}
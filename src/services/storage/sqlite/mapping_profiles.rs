@@ -0,0 +1,95 @@
+//! This module contains the implementation of the `QueryMappingProfiles` trait for the
+//! `SqliteBackend` struct.
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use sqlx::{Sqlite, Transaction};
+use uuid::Uuid;
+
+use super::SqliteBackend;
+use crate::services::storage::entities::MappingProfile;
+use crate::services::storage::mapping_profiles::{CreateMappingProfile, QueryMappingProfiles};
+use crate::services::storage::{exec_with_tx, Acquire, ExecOpts};
+
+#[async_trait]
+impl QueryMappingProfiles<Sqlite> for SqliteBackend {
+    async fn create_mapping_profile(
+        &self,
+        data: CreateMappingProfile,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<Uuid> {
+        async fn exec(
+            data: CreateMappingProfile,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<Uuid> {
+            let query = include_str!("queries/mapping_profiles/create_mapping_profile.sql");
+
+            let id = sqlx::query_scalar::<_, Uuid>(query)
+                .bind(data.name)
+                .bind(data.description)
+                .bind(data.mapping)
+                .fetch_one(&mut **tx)
+                .await?;
+            Ok(id)
+        }
+
+        exec_with_tx!(self, exec_opts, exec, data)
+    }
+
+    async fn fetch_mapping_profiles(
+        &self,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<Vec<MappingProfile>> {
+        async fn exec(tx: &mut Transaction<'_, Sqlite>) -> Result<Vec<MappingProfile>> {
+            let query = include_str!("queries/mapping_profiles/fetch_mapping_profiles.sql");
+
+            let profiles = sqlx::query_as::<_, MappingProfile>(query)
+                .fetch_all(&mut **tx)
+                .await
+                .context("error fetching mapping profiles")?;
+            Ok(profiles)
+        }
+
+        exec_with_tx!(self, exec_opts, exec)
+    }
+
+    async fn fetch_mapping_profile_by_id(
+        &self,
+        id: Uuid,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<Option<MappingProfile>> {
+        async fn exec(
+            id: Uuid,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<Option<MappingProfile>> {
+            let query = include_str!("queries/mapping_profiles/fetch_mapping_profile_by_id.sql");
+
+            let profile = sqlx::query_as::<_, MappingProfile>(query)
+                .bind(id)
+                .fetch_optional(&mut **tx)
+                .await
+                .context("error fetching mapping profile by id")?;
+            Ok(profile)
+        }
+
+        exec_with_tx!(self, exec_opts, exec, id)
+    }
+
+    async fn delete_mapping_profile(
+        &self,
+        id: Uuid,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<()> {
+        async fn exec(id: Uuid, tx: &mut Transaction<'_, Sqlite>) -> Result<()> {
+            let query = include_str!("queries/mapping_profiles/delete_mapping_profile.sql");
+            sqlx::query(query)
+                .bind(id)
+                .execute(&mut **tx)
+                .await
+                .context("error deleting mapping profile")?;
+            Ok(())
+        }
+
+        exec_with_tx!(self, exec_opts, exec, id)
+    }
+}
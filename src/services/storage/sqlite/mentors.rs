@@ -0,0 +1,386 @@
+//! This module contains the implementation of the `QueryMentors` trait for the `SqliteBackend`
+//! struct.
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use sqlx::types::Json;
+use sqlx::{QueryBuilder, Row, Sqlite, Transaction};
+use uuid::Uuid;
+
+use super::entities::MentorDetailsRow;
+use super::SqliteBackend;
+use crate::services::storage::entities::MentorDetails;
+use crate::services::storage::mentors::{CreateMentor, EditMentor, QueryMentors};
+use crate::services::storage::types::SyncOutcome;
+use crate::services::storage::{exec_with_tx, Acquire, ExecOpts};
+
+#[async_trait]
+impl QueryMentors<Sqlite> for SqliteBackend {
+    async fn create_mentor(
+        &self,
+        project_cycle_id: Uuid,
+        data: CreateMentor,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<Uuid> {
+        async fn exec(
+            project_cycle_id: Uuid,
+            data: CreateMentor,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<Uuid> {
+            let query = include_str!("queries/mentors/create_mentor.sql");
+
+            let id = sqlx::query_scalar::<_, Uuid>(query)
+                .bind(project_cycle_id)
+                .bind(data.first_name)
+                .bind(data.last_name)
+                .bind(data.email)
+                .bind(data.phone)
+                .bind(data.company)
+                .bind(data.job_title)
+                .bind(data.country)
+                .bind(data.us_state)
+                .bind(data.years_experience)
+                .bind(data.experience_level)
+                .bind(data.prior_mentor)
+                .bind(data.prior_mentee)
+                .bind(data.prior_student)
+                .bind(Json(data.university))
+                .bind(Json(data.hear_about))
+                .fetch_one(&mut **tx)
+                .await
+                .context("error creating mentor")?;
+
+            Ok(id)
+        }
+
+        exec_with_tx!(self, exec_opts, exec, project_cycle_id, data)
+    }
+
+    async fn batch_create_mentors(
+        &self,
+        project_cycle_id: Uuid,
+        data: Vec<CreateMentor>,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<Vec<(String, Uuid)>> {
+        async fn exec(
+            project_cycle_id: Uuid,
+            data: Vec<CreateMentor>,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<Vec<(String, Uuid)>> {
+            let fragment = include_str!("queries/mentors/create_mentors.fragment.sql");
+
+            let data = QueryBuilder::<Sqlite>::new(fragment)
+                .push_values(data, |mut b, mentor| {
+                    b.push_bind(project_cycle_id)
+                        .push_bind(mentor.first_name)
+                        .push_bind(mentor.last_name)
+                        .push_bind(mentor.email)
+                        .push_bind(mentor.phone)
+                        .push_bind(mentor.company)
+                        .push_bind(mentor.job_title)
+                        .push_bind(mentor.country)
+                        .push_bind(mentor.us_state)
+                        .push_bind(mentor.years_experience)
+                        .push_bind(mentor.experience_level)
+                        .push_bind(mentor.prior_mentor)
+                        .push_bind(mentor.prior_mentee)
+                        .push_bind(mentor.prior_student)
+                        .push_bind(Json(mentor.university))
+                        .push_bind(Json(mentor.hear_about));
+                })
+                .push("returning email, id")
+                .build_query_as::<(String, Uuid)>()
+                .fetch_all(&mut **tx)
+                .await?;
+
+            Ok(data)
+        }
+
+        exec_with_tx!(self, exec_opts, exec, project_cycle_id, data)
+    }
+
+    async fn fetch_mentors(&self, exec_opts: &mut ExecOpts<Sqlite>) -> Result<Vec<MentorDetails>> {
+        async fn exec(tx: &mut Transaction<'_, Sqlite>) -> Result<Vec<MentorDetails>> {
+            let query = include_str!("queries/mentors/fetch_mentors.sql");
+
+            let mentors = sqlx::query_as::<_, MentorDetailsRow>(query)
+                .fetch_all(&mut **tx)
+                .await
+                .context("error fetching mentors")?;
+            Ok(mentors.into_iter().map(Into::into).collect())
+        }
+
+        exec_with_tx!(self, exec_opts, exec)
+    }
+
+    async fn fetch_mentors_by_cycle(
+        &self,
+        project_cycle_id: Uuid,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<Vec<MentorDetails>> {
+        async fn exec(
+            project_cycle_id: Uuid,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<Vec<MentorDetails>> {
+            let query = include_str!("queries/mentors/fetch_mentors_by_cycle.sql");
+
+            let mentors = sqlx::query_as::<_, MentorDetailsRow>(query)
+                .bind(project_cycle_id)
+                .fetch_all(&mut **tx)
+                .await
+                .context("error fetching mentors by cycle")?;
+            Ok(mentors.into_iter().map(Into::into).collect())
+        }
+
+        exec_with_tx!(self, exec_opts, exec, project_cycle_id)
+    }
+
+    async fn fetch_mentor_by_id(
+        &self,
+        id: Uuid,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<Option<MentorDetails>> {
+        async fn exec(id: Uuid, tx: &mut Transaction<'_, Sqlite>) -> Result<Option<MentorDetails>> {
+            let query = include_str!("queries/mentors/fetch_mentor_by_id.sql");
+
+            let mentor = sqlx::query_as::<_, MentorDetailsRow>(query)
+                .bind(id)
+                .fetch_optional(&mut **tx)
+                .await
+                .context("error fetching mentor by id")?;
+            Ok(mentor.map(Into::into))
+        }
+
+        exec_with_tx!(self, exec_opts, exec, id)
+    }
+
+    async fn edit_mentor(
+        &self,
+        id: Uuid,
+        data: EditMentor,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<()> {
+        async fn exec(id: Uuid, data: EditMentor, tx: &mut Transaction<'_, Sqlite>) -> Result<()> {
+            let query = include_str!("queries/mentors/edit_mentor.sql");
+
+            sqlx::query(query)
+                .bind(id)
+                .bind(data.first_name)
+                .bind(data.last_name)
+                .bind(data.email)
+                .bind(data.phone)
+                .execute(&mut **tx)
+                .await
+                .context("error editing mentor")?;
+            Ok(())
+        }
+
+        exec_with_tx!(self, exec_opts, exec, id, data)
+    }
+
+    async fn delete_mentor(&self, id: Uuid, exec_opts: &mut ExecOpts<Sqlite>) -> Result<()> {
+        async fn exec(id: Uuid, tx: &mut Transaction<'_, Sqlite>) -> Result<()> {
+            let query = include_str!("queries/mentors/delete_mentor.sql");
+            sqlx::query(query)
+                .bind(id)
+                .execute(&mut **tx)
+                .await
+                .context("error deleting mentor")?;
+            Ok(())
+        }
+
+        exec_with_tx!(self, exec_opts, exec, id)
+    }
+
+    async fn batch_link_mentors_to_nonprofits(
+        &self,
+        project_cycle_id: Uuid,
+        data: Vec<(Uuid, Uuid)>,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<()> {
+        async fn exec(
+            project_cycle_id: Uuid,
+            data: Vec<(Uuid, Uuid)>,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<()> {
+            let fragment = include_str!("queries/mentors/link_mentors_to_nonprofits.fragment.sql");
+
+            QueryBuilder::<Sqlite>::new(fragment)
+                .push_values(data, |mut b, (mentor_id, nonprofit_id)| {
+                    b.push_bind(project_cycle_id).push_bind(mentor_id).push_bind(nonprofit_id);
+                })
+                .build()
+                .execute(&mut **tx)
+                .await?;
+            Ok(())
+        }
+
+        exec_with_tx!(self, exec_opts, exec, project_cycle_id, data)
+    }
+
+    async fn batch_sync_mentors(
+        &self,
+        project_cycle_id: Uuid,
+        data: Vec<(String, CreateMentor)>,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<Vec<(String, Uuid, SyncOutcome)>> {
+        async fn exec(
+            project_cycle_id: Uuid,
+            data: Vec<(String, CreateMentor)>,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<Vec<(String, Uuid, SyncOutcome)>> {
+            let fetch_id = include_str!("queries/mentors/fetch_synced_mentor_id.sql");
+            let create = include_str!("queries/mentors/create_synced_mentor.sql");
+            let sync = include_str!("queries/mentors/sync_mentor.sql");
+
+            let mut synced = Vec::with_capacity(data.len());
+
+            for (external_id, m) in data {
+                let id = sqlx::query_scalar::<_, Uuid>(fetch_id)
+                    .bind(project_cycle_id)
+                    .bind(&external_id)
+                    .bind(&m.email)
+                    .fetch_optional(&mut **tx)
+                    .await?;
+
+                // Both queries take the same parameters, except that the first is the ID of the
+                // mentor to update or the project cycle to create the mentor in
+                let (query, id_or_cycle) = match id {
+                    Some(id) => (sqlx::query(sync), id),
+                    None => (sqlx::query(create), project_cycle_id),
+                };
+
+                let query = query
+                    .bind(id_or_cycle)
+                    .bind(&external_id)
+                    .bind(m.first_name)
+                    .bind(m.last_name)
+                    .bind(&m.email)
+                    .bind(m.phone)
+                    .bind(m.company)
+                    .bind(m.job_title)
+                    .bind(m.country)
+                    .bind(m.us_state)
+                    .bind(m.years_experience)
+                    .bind(m.experience_level)
+                    .bind(m.prior_mentor)
+                    .bind(m.prior_mentee)
+                    .bind(m.prior_student)
+                    .bind(Json(m.university))
+                    .bind(Json(m.hear_about));
+
+                let (id, outcome) = match id {
+                    Some(id) => {
+                        let res = query
+                            .execute(&mut **tx)
+                            .await
+                            .with_context(|| format!("error syncing mentor {}", m.email))?;
+                        match res.rows_affected() {
+                            0 => (id, SyncOutcome::Unchanged),
+                            _ => (id, SyncOutcome::Updated),
+                        }
+                    }
+                    None => {
+                        let id = query
+                            .fetch_one(&mut **tx)
+                            .await
+                            .with_context(|| format!("error creating mentor {}", m.email))?
+                            .try_get("id")?;
+                        (id, SyncOutcome::Created)
+                    }
+                };
+
+                synced.push((external_id, id, outcome));
+            }
+
+            Ok(synced)
+        }
+
+        exec_with_tx!(self, exec_opts, exec, project_cycle_id, data)
+    }
+
+    async fn deactivate_missing_mentors(
+        &self,
+        project_cycle_id: Uuid,
+        keep: Vec<Uuid>,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<u64> {
+        async fn exec(
+            project_cycle_id: Uuid,
+            keep: Vec<Uuid>,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<u64> {
+            let fragment = include_str!("queries/mentors/deactivate_missing_mentors.fragment.sql");
+            let mut query = QueryBuilder::<Sqlite>::new(fragment);
+            query.push_bind(project_cycle_id);
+
+            if !keep.is_empty() {
+                query.push(" and id not in (");
+                let mut separated = query.separated(", ");
+                for id in keep {
+                    separated.push_bind(id);
+                }
+                separated.push_unseparated(")");
+            }
+
+            let res = query.build().execute(&mut **tx).await?;
+            Ok(res.rows_affected())
+        }
+
+        exec_with_tx!(self, exec_opts, exec, project_cycle_id, keep)
+    }
+
+    async fn sync_mentor_nonprofit_links(
+        &self,
+        project_cycle_id: Uuid,
+        linkage: Vec<(Uuid, Uuid)>,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<(u64, u64)> {
+        async fn exec(
+            project_cycle_id: Uuid,
+            linkage: Vec<(Uuid, Uuid)>,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<(u64, u64)> {
+            let fragment =
+                include_str!("queries/mentors/unlink_missing_mentors_from_nonprofits.fragment.sql");
+            let mut query = QueryBuilder::<Sqlite>::new(fragment);
+            query.push_bind(project_cycle_id);
+
+            if !linkage.is_empty() {
+                query.push(" and (mentor_id, client_id) not in (values ");
+                let mut separated = query.separated(", ");
+                for (left, right) in &linkage {
+                    separated
+                        .push("(")
+                        .push_bind_unseparated(*left)
+                        .push_unseparated(", ")
+                        .push_bind_unseparated(*right)
+                        .push_unseparated(")");
+                }
+                separated.push_unseparated(")");
+            }
+
+            let removed = query.build().execute(&mut **tx).await?.rows_affected();
+
+            if linkage.is_empty() {
+                return Ok((0, removed));
+            }
+
+            let fragment = include_str!("queries/mentors/link_mentors_to_nonprofits.fragment.sql");
+            let added = QueryBuilder::<Sqlite>::new(fragment)
+                .push_values(linkage, |mut b, (left, right)| {
+                    b.push_bind(project_cycle_id).push_bind(left).push_bind(right);
+                })
+                .push(" on conflict do nothing")
+                .build()
+                .execute(&mut **tx)
+                .await
+                .context("error linking mentors to nonprofits")?
+                .rows_affected();
+
+            Ok((added, removed))
+        }
+
+        exec_with_tx!(self, exec_opts, exec, project_cycle_id, linkage)
+    }
+}
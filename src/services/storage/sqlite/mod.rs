@@ -0,0 +1,107 @@
+//! This module contains a SQLite implementation of the storage layer.
+//!
+//! `SqliteBackend` implements every query trait, so it can stand in for `PgBackend` in local
+//! development and tests without a database service. Its schema lives in `migrations/sqlite` and
+//! mirrors the Postgres schema. SQLite has no uuid, enum, or array types, so IDs are stored as
+//! blobs, enums as text, and arrays as JSON. Entities containing arrays are decoded through the
+//! row types in `entities`.
+//!
+//! IDs are generated by the database with `unhex`, which was added in SQLite 3.41. The SQLite
+//! bundled with sqlx is newer than that, but a build which links the system's SQLite must make
+//! sure it is at least 3.41. `SqliteBackend::new` refuses to connect to an older one.
+
+mod audit;
+mod csv_uploads;
+mod cycles;
+mod entities;
+mod jobs;
+mod mapping_profiles;
+mod mentors;
+mod nonprofits;
+mod stats;
+mod team_roles;
+mod volunteers;
+mod webhooks;
+
+use std::str::FromStr;
+use std::time::Duration;
+
+use anyhow::{bail, Context, Result};
+use async_trait::async_trait;
+use sqlx::sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePoolOptions};
+use sqlx::{Sqlite, SqlitePool, Transaction};
+
+use crate::services::storage::{Acquire, Migrator};
+use crate::services::Service;
+
+/// The oldest version of SQLite which has `unhex`.
+const MIN_SQLITE_VERSION: &str = "3.41";
+
+/// Whether a version of SQLite has `unhex`.
+///
+/// * `version`: The version, e.g. `3.45.0`
+fn supports_unhex(version: &str) -> bool {
+    let mut parts = version.split('.').map(|part| part.parse::<u32>().unwrap_or(0));
+    let major = parts.next().unwrap_or(0);
+    let minor = parts.next().unwrap_or(0);
+    (major, minor) >= (3, 41)
+}
+
+/// Defines a backend for a SQLite database. It contains a connection pool.
+///
+/// * `pool`: A SQLite connection pool
+pub struct SqliteBackend {
+    pub pool: SqlitePool,
+}
+
+impl SqliteBackend {
+    /// Construct a new `SqliteBackend` from a URL.
+    ///
+    /// The database file is created if it does not exist.
+    ///
+    /// * `url`: A string slice that holds a valid SQLite URL, e.g. `sqlite://scipio.db`
+    pub async fn new(url: &str) -> Result<Self> {
+        let options = SqliteConnectOptions::from_str(url)
+            .context("parse sqlite url")?
+            .create_if_missing(true)
+            .journal_mode(SqliteJournalMode::Wal)
+            .busy_timeout(Duration::from_secs(5));
+
+        let pool = SqlitePoolOptions::new()
+            .max_connections(10)
+            .connect_with(options)
+            .await
+            .context("create sqlite pool")?;
+
+        let version = sqlx::query_scalar::<_, String>("select sqlite_version()")
+            .fetch_one(&pool)
+            .await
+            .context("fetch sqlite version")?;
+        if !supports_unhex(&version) {
+            bail!("SQLite {version} is too old, at least {MIN_SQLITE_VERSION} is required");
+        }
+
+        Ok(Self { pool })
+    }
+}
+
+#[async_trait]
+impl Acquire<Sqlite> for SqliteBackend {
+    async fn acquire<'a>(&self) -> Result<Transaction<'a, Sqlite>> {
+        Ok(self.pool.begin().await.context("acquire transaction")?)
+    }
+}
+
+#[async_trait]
+impl Migrator for SqliteBackend {
+    async fn migrate(&self) -> Result<()> {
+        sqlx::migrate!("./migrations/sqlite").run(&self.pool).await?;
+        Ok(())
+    }
+}
+
+impl Service for SqliteBackend {
+    fn get_id(&self) -> &'static str {
+        "sqlite-backend"
+    }
+}
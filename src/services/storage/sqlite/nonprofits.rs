@@ -0,0 +1,340 @@
+//! This module contains the implementation of the `QueryNonprofits` trait for the
+//! `SqliteBackend` struct.
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use sqlx::types::Json;
+use sqlx::{QueryBuilder, Row, Sqlite, Transaction};
+use uuid::Uuid;
+
+use super::entities::NonprofitClientDetailsRow;
+use super::SqliteBackend;
+use crate::services::storage::entities::NonprofitClientDetails;
+use crate::services::storage::nonprofits::{CreateNonprofit, EditNonprofit, QueryNonprofits};
+use crate::services::storage::types::SyncOutcome;
+use crate::services::storage::{exec_with_tx, Acquire, ExecOpts};
+
+#[async_trait]
+impl QueryNonprofits<Sqlite> for SqliteBackend {
+    async fn create_nonprofit(
+        &self,
+        project_cycle_id: Uuid,
+        data: CreateNonprofit,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<Uuid> {
+        async fn exec(
+            project_cycle_id: Uuid,
+            data: CreateNonprofit,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<Uuid> {
+            let query = include_str!("queries/nonprofits/create_nonprofit.sql");
+
+            let id = sqlx::query_scalar::<_, Uuid>(query)
+                .bind(project_cycle_id)
+                .bind(data.representative_first_name)
+                .bind(data.representative_last_name)
+                .bind(data.representative_job_title)
+                .bind(data.email)
+                .bind(data.email_cc)
+                .bind(data.phone)
+                .bind(data.org_name)
+                .bind(data.project_name)
+                // .bind(data.agreement_and_invoice_sent)
+                // .bind(data.services_agreement_signature)
+                // .bind(data.availability_confirmed)
+                // .bind(data.invoice_paid)
+                .bind(data.org_website)
+                .bind(data.country_hq)
+                .bind(data.us_state_hq)
+                .bind(data.address)
+                .bind(data.size)
+                .bind(Json(data.impact_causes))
+                .fetch_one(&mut **tx)
+                .await
+                .context("error creating nonprofit")?;
+            Ok(id)
+        }
+
+        exec_with_tx!(self, exec_opts, exec, project_cycle_id, data)
+    }
+
+    async fn batch_create_nonprofits(
+        &self,
+        project_cycle_id: Uuid,
+        data: Vec<CreateNonprofit>,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<Vec<(String, Uuid)>> {
+        async fn exec(
+            project_cycle_id: Uuid,
+            data: Vec<CreateNonprofit>,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<Vec<(String, Uuid)>> {
+            let fragment = include_str!("queries/nonprofits/create_nonprofits.fragment.sql");
+
+            let data = QueryBuilder::<Sqlite>::new(fragment)
+                .push_values(data, |mut b, n| {
+                    b.push_bind(project_cycle_id)
+                        .push_bind(n.representative_first_name)
+                        .push_bind(n.representative_last_name)
+                        .push_bind(n.representative_job_title)
+                        .push_bind(n.email)
+                        .push_bind(n.email_cc)
+                        .push_bind(n.phone)
+                        .push_bind(n.org_name)
+                        .push_bind(n.project_name)
+                        // .push_bind(n.agreement_and_invoice_sent)
+                        // .push_bind(n.services_agreement_signature)
+                        // .push_bind(n.availability_confirmed)
+                        // .push_bind(n.invoice_paid)
+                        .push_bind(n.org_website)
+                        .push_bind(n.country_hq)
+                        .push_bind(n.us_state_hq)
+                        .push_bind(n.address)
+                        .push_bind(n.size)
+                        .push_bind(Json(n.impact_causes));
+                })
+                .push("returning org_name, id")
+                .build_query_as::<(String, Uuid)>()
+                .fetch_all(&mut **tx)
+                .await?;
+            // .map_err(|e| e.into())
+            // .context("error creating nonprofits: {:#}")?;
+            Ok(data)
+        }
+
+        exec_with_tx!(self, exec_opts, exec, project_cycle_id, data)
+    }
+
+    async fn fetch_nonprofits(
+        &self,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<Vec<NonprofitClientDetails>> {
+        async fn exec(tx: &mut Transaction<'_, Sqlite>) -> Result<Vec<NonprofitClientDetails>> {
+            let query = include_str!("queries/nonprofits/fetch_nonprofits.sql");
+            let nonprofits = sqlx::query_as::<_, NonprofitClientDetailsRow>(query)
+                .fetch_all(&mut **tx)
+                .await
+                .context("error fetching nonprofits")?;
+
+            Ok(nonprofits.into_iter().map(Into::into).collect())
+        }
+
+        exec_with_tx!(self, exec_opts, exec)
+    }
+
+    async fn fetch_nonprofits_by_cycle(
+        &self,
+        project_cycle_id: Uuid,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<Vec<NonprofitClientDetails>> {
+        async fn exec(
+            project_cycle_id: Uuid,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<Vec<NonprofitClientDetails>> {
+            let query = include_str!("queries/nonprofits/fetch_nonprofits_by_cycle.sql");
+            let nonprofits = sqlx::query_as::<_, NonprofitClientDetailsRow>(query)
+                .bind(project_cycle_id)
+                .fetch_all(&mut **tx)
+                .await
+                .context("error fetching nonprofits by cycle")?;
+
+            Ok(nonprofits.into_iter().map(Into::into).collect())
+        }
+
+        exec_with_tx!(self, exec_opts, exec, project_cycle_id)
+    }
+
+    async fn fetch_nonprofit_by_id(
+        &self,
+        id: Uuid,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<Option<NonprofitClientDetails>> {
+        async fn exec(
+            id: Uuid,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<Option<NonprofitClientDetails>> {
+            let query = include_str!("queries/nonprofits/fetch_nonprofit_by_id.sql");
+
+            let nonprofit = sqlx::query_as::<_, NonprofitClientDetailsRow>(query)
+                .bind(id)
+                .fetch_optional(&mut **tx)
+                .await
+                .context("error fetching nonprofit by id")?;
+            Ok(nonprofit.map(Into::into))
+        }
+
+        exec_with_tx!(self, exec_opts, exec, id)
+    }
+
+    async fn fetch_nonprofit_by_org_name(
+        &self,
+        org_name: &str,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<Option<NonprofitClientDetails>> {
+        async fn exec(
+            org_name: &str,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<Option<NonprofitClientDetails>> {
+            let query = include_str!("queries/nonprofits/fetch_nonprofit_by_org_name.sql");
+
+            let nonprofit = sqlx::query_as::<_, NonprofitClientDetailsRow>(query)
+                .bind(org_name)
+                .fetch_optional(&mut **tx)
+                .await
+                .context("error fetching nonprofit by org name")?;
+            Ok(nonprofit.map(Into::into))
+        }
+
+        exec_with_tx!(self, exec_opts, exec, org_name)
+    }
+
+    async fn edit_nonprofit(
+        &self,
+        id: Uuid,
+        data: EditNonprofit,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<()> {
+        async fn exec(
+            id: Uuid,
+            data: EditNonprofit,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<()> {
+            let query = include_str!("queries/nonprofits/edit_nonprofit.sql");
+            sqlx::query(query)
+                .bind(id)
+                .bind(data.email)
+                .bind(data.email_cc)
+                .bind(data.phone)
+                .bind(data.org_website)
+                .execute(&mut **tx)
+                .await
+                .context("error editing nonprofit")?;
+            Ok(())
+        }
+
+        exec_with_tx!(self, exec_opts, exec, id, data)
+    }
+
+    async fn delete_nonprofit(&self, id: Uuid, exec_opts: &mut ExecOpts<Sqlite>) -> Result<()> {
+        async fn exec(id: Uuid, tx: &mut Transaction<'_, Sqlite>) -> Result<()> {
+            let query = include_str!("queries/nonprofits/delete_nonprofit.sql");
+            sqlx::query(query).bind(id).execute(&mut **tx).await?;
+            Ok(())
+        }
+
+        exec_with_tx!(self, exec_opts, exec, id)
+    }
+
+    async fn batch_sync_nonprofits(
+        &self,
+        project_cycle_id: Uuid,
+        data: Vec<(String, CreateNonprofit)>,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<Vec<(String, Uuid, SyncOutcome)>> {
+        async fn exec(
+            project_cycle_id: Uuid,
+            data: Vec<(String, CreateNonprofit)>,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<Vec<(String, Uuid, SyncOutcome)>> {
+            let fetch_id = include_str!("queries/nonprofits/fetch_synced_nonprofit_id.sql");
+            let create = include_str!("queries/nonprofits/create_synced_nonprofit.sql");
+            let sync = include_str!("queries/nonprofits/sync_nonprofit.sql");
+
+            let mut synced = Vec::with_capacity(data.len());
+
+            for (external_id, n) in data {
+                let id = sqlx::query_scalar::<_, Uuid>(fetch_id)
+                    .bind(project_cycle_id)
+                    .bind(&external_id)
+                    .bind(&n.org_name)
+                    .bind(&n.project_name)
+                    .fetch_optional(&mut **tx)
+                    .await?;
+
+                // Both queries take the same parameters, except that the first is the ID of the
+                // nonprofit to update or the project cycle to create the nonprofit in
+                let (query, id_or_cycle) = match id {
+                    Some(id) => (sqlx::query(sync), id),
+                    None => (sqlx::query(create), project_cycle_id),
+                };
+
+                let query = query
+                    .bind(id_or_cycle)
+                    .bind(&external_id)
+                    .bind(n.representative_first_name)
+                    .bind(n.representative_last_name)
+                    .bind(n.representative_job_title)
+                    .bind(n.email)
+                    .bind(n.email_cc)
+                    .bind(n.phone)
+                    .bind(&n.org_name)
+                    .bind(n.project_name)
+                    .bind(n.org_website)
+                    .bind(n.country_hq)
+                    .bind(n.us_state_hq)
+                    .bind(n.address)
+                    .bind(n.size)
+                    .bind(Json(n.impact_causes));
+
+                let (id, outcome) = match id {
+                    Some(id) => {
+                        let res = query
+                            .execute(&mut **tx)
+                            .await
+                            .with_context(|| format!("error syncing nonprofit {}", n.org_name))?;
+                        match res.rows_affected() {
+                            0 => (id, SyncOutcome::Unchanged),
+                            _ => (id, SyncOutcome::Updated),
+                        }
+                    }
+                    None => {
+                        let id = query
+                            .fetch_one(&mut **tx)
+                            .await
+                            .with_context(|| format!("error creating nonprofit {}", n.org_name))?
+                            .try_get("id")?;
+                        (id, SyncOutcome::Created)
+                    }
+                };
+
+                synced.push((external_id, id, outcome));
+            }
+
+            Ok(synced)
+        }
+
+        exec_with_tx!(self, exec_opts, exec, project_cycle_id, data)
+    }
+
+    async fn deactivate_missing_nonprofits(
+        &self,
+        project_cycle_id: Uuid,
+        keep: Vec<Uuid>,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<u64> {
+        async fn exec(
+            project_cycle_id: Uuid,
+            keep: Vec<Uuid>,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<u64> {
+            let fragment =
+                include_str!("queries/nonprofits/deactivate_missing_nonprofits.fragment.sql");
+            let mut query = QueryBuilder::<Sqlite>::new(fragment);
+            query.push_bind(project_cycle_id);
+
+            if !keep.is_empty() {
+                query.push(" and id not in (");
+                let mut separated = query.separated(", ");
+                for id in keep {
+                    separated.push_bind(id);
+                }
+                separated.push_unseparated(")");
+            }
+
+            let res = query.build().execute(&mut **tx).await?;
+            Ok(res.rows_affected())
+        }
+
+        exec_with_tx!(self, exec_opts, exec, project_cycle_id, keep)
+    }
+}
@@ -0,0 +1,4 @@
+insert into csv_uploads(volunteers, mentors, nonprofits)
+  values (?1, ?2, ?3)
+returning
+  id;
@@ -0,0 +1,2 @@
+delete from csv_uploads
+where id = ?1;
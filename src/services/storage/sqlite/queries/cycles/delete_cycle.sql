@@ -0,0 +1,3 @@
+delete from project_cycles
+where id = ?1;
+
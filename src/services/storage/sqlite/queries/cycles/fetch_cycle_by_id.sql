@@ -0,0 +1,12 @@
+select
+  id,
+  created_at,
+  updated_at,
+  name,
+  description,
+  archived
+from
+  project_cycles
+where
+  id = ?1;
+
@@ -0,0 +1,10 @@
+select
+  id,
+  created_at,
+  updated_at,
+  name,
+  description,
+  archived
+from
+  project_cycles;
+
@@ -0,0 +1,28 @@
+update
+  jobs
+set
+  status = 'running',
+  attempts = attempts + 1,
+  locked_by = ?1,
+  locked_until = strftime('%Y-%m-%dT%H:%M:%fZ', 'now', printf('%+d seconds', ?2)),
+  heartbeat_at = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+where
+  id = (
+    select
+      id
+    from
+      jobs
+    where
+      status = 'pending'
+    order by
+      created_at
+    limit 1)
+returning
+  id,
+  created_at,
+  updated_at,
+  project_cycle_id,
+  status,
+  label,
+  description,
+  details;
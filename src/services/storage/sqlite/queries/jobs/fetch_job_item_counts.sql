@@ -0,0 +1,11 @@
+select
+  kind,
+  status,
+  count(*) as count
+from
+  job_items
+where
+  job_id = ?1
+group by
+  kind,
+  status;
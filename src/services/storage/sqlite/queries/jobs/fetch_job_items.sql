@@ -0,0 +1,18 @@
+select
+  id,
+  created_at,
+  updated_at,
+  job_id,
+  kind,
+  item_key,
+  entity_id,
+  label,
+  status,
+  error
+from
+  job_items
+where
+  job_id = ?1
+order by
+  created_at,
+  item_key;
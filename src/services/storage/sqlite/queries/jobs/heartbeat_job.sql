@@ -0,0 +1,11 @@
+update
+  jobs
+set
+  locked_until = strftime('%Y-%m-%dT%H:%M:%fZ', 'now', printf('%+d seconds', ?3)),
+  heartbeat_at = strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+where
+  id = ?1
+  and locked_by = ?2
+  and status = 'running'
+returning
+  id;
@@ -0,0 +1,6 @@
+select
+  cancel_requested_at is not null
+from
+  jobs
+where
+  id = ?1;
@@ -0,0 +1,7 @@
+update
+  jobs
+set
+  status = 'cancelled',
+  details = json_set(details, '$.summary', ?2)
+where
+  id = ?1;
@@ -0,0 +1,20 @@
+update
+  jobs
+set
+  status = case when attempts < ?1 then
+    'pending'
+  else
+    'error'
+  end,
+  details = case when attempts < ?1 then
+    details
+  else
+    json_set(details, '$.error', 'Job was abandoned by its worker too many times')
+  end,
+  locked_by = null,
+  locked_until = null
+where
+  status = 'running'
+  and locked_until < strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+returning
+  id;
@@ -0,0 +1,6 @@
+update
+  jobs
+set
+  details = json_set(details, '$.diff', json(?2))
+where
+  id = ?1;
@@ -0,0 +1,10 @@
+update
+  job_items
+set
+  status = ?4,
+  entity_id = coalesce(?5, entity_id),
+  error = ?6
+where
+  job_id = ?1
+  and kind = ?2
+  and item_key = ?3;
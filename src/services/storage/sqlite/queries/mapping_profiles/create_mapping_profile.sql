@@ -0,0 +1,4 @@
+insert into airtable_mapping_profiles(name, description, mapping)
+  values (?1, ?2, ?3)
+returning
+  id;
@@ -0,0 +1 @@
+insert into mentors(project_cycle_id, first_name, last_name, email, phone, company, job_title, country, us_state, years_experience, experience_level, prior_mentor, prior_mentee, prior_student, university, hear_about)
@@ -0,0 +1,7 @@
+update
+  mentors
+set
+  active = false
+where
+  active
+  and project_cycle_id =
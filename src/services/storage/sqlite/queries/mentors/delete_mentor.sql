@@ -0,0 +1,3 @@
+delete from mentors
+where id = ?1;
+
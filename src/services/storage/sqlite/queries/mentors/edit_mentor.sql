@@ -0,0 +1,10 @@
+update
+  mentors
+set
+  first_name = coalesce(?2, first_name),
+  last_name = coalesce(?3, last_name),
+  email = coalesce(?4, email),
+  phone = coalesce(?5, phone)
+where
+  id = ?1;
+
@@ -0,0 +1,29 @@
+select
+  mentor_id,
+  created_at,
+  updated_at,
+  project_cycle_id,
+  project_cycle_name,
+  first_name,
+  last_name,
+  email,
+  phone,
+  company,
+  job_title,
+  country,
+  us_state,
+  years_experience,
+  experience_level,
+  prior_mentor,
+  prior_mentee,
+  prior_student,
+  university,
+  hear_about,
+  active,
+  volunteers,
+  clients
+from
+  mentor_details
+where
+  active;
+
@@ -0,0 +1 @@
+insert into client_mentors(project_cycle_id, mentor_id, client_id)
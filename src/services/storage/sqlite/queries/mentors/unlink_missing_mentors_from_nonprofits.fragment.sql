@@ -0,0 +1,2 @@
+delete from client_mentors
+where project_cycle_id =
@@ -0,0 +1 @@
+insert into nonprofit_clients(project_cycle_id, representative_first_name, representative_last_name, representative_job_title, email, email_cc, phone, org_name, project_name, org_website, country_hq, us_state_hq, address, size, impact_causes)
@@ -0,0 +1,7 @@
+update
+  nonprofit_clients
+set
+  active = false
+where
+  active
+  and project_cycle_id =
@@ -0,0 +1,3 @@
+delete from nonprofit_clients
+where id = ?1;
+
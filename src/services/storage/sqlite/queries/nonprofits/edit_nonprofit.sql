@@ -0,0 +1,10 @@
+update
+  nonprofit_clients
+set
+  email = coalesce(?2, email),
+  email_cc = coalesce(?3, email_cc),
+  phone = coalesce(?4, phone),
+  org_website = coalesce(?5, org_website)
+where
+  id = ?1;
+
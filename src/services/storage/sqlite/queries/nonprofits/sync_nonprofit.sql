@@ -0,0 +1,24 @@
+-- Only nonprofits which differ from the synced data are updated, so that the number of rows
+-- affected tells whether the nonprofit changed.
+update
+  nonprofit_clients
+set
+  external_id = ?2,
+  active = true,
+  representative_first_name = ?3,
+  representative_last_name = ?4,
+  representative_job_title = ?5,
+  email = ?6,
+  email_cc = ?7,
+  phone = ?8,
+  org_name = ?9,
+  project_name = ?10,
+  org_website = ?11,
+  country_hq = ?12,
+  us_state_hq = ?13,
+  address = ?14,
+  size = ?15,
+  impact_causes = ?16
+where
+  id = ?1
+  and (external_id, active, representative_first_name, representative_last_name, representative_job_title, email, email_cc, phone, org_name, project_name, org_website, country_hq, us_state_hq, address, size, impact_causes) is not (?2, true, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16);
@@ -0,0 +1,10 @@
+select
+  id,
+  created_at,
+  updated_at,
+  name,
+  description
+from
+  team_roles
+where
+  id = ?1;
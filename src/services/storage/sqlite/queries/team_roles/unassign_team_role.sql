@@ -0,0 +1,4 @@
+delete from volunteer_team_roles
+where project_cycle_id = ?1
+  and volunteer_id = ?2
+  and role_id = ?3;
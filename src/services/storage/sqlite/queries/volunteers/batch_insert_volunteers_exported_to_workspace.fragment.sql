@@ -0,0 +1 @@
+insert into volunteers_exported_to_workspace(volunteer_id, job_id, workspace_email, org_unit)
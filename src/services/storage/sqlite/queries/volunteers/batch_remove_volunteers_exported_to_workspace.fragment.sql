@@ -0,0 +1,2 @@
+delete from volunteers_exported_to_workspace
+where volunteer_id in
@@ -0,0 +1,7 @@
+update
+  volunteers
+set
+  active = false
+where
+  active
+  and project_cycle_id =
@@ -0,0 +1,3 @@
+delete from volunteers
+where id = ?1;
+
@@ -0,0 +1,30 @@
+update
+  volunteers
+set
+  first_name = coalesce(?2, first_name),
+  last_name = coalesce(?3, last_name),
+  email = coalesce(?4, email),
+  phone = case when ?5 then
+    ?6
+  else
+    phone
+  end,
+  volunteer_gender = coalesce(?7, volunteer_gender),
+  volunteer_ethnicity = coalesce(?8, volunteer_ethnicity),
+  volunteer_age_range = coalesce(?9, volunteer_age_range),
+  university = coalesce(?10, university),
+  lgbt = coalesce(?11, lgbt),
+  country = coalesce(?12, country),
+  us_state = case when ?13 then
+    ?14
+  else
+    us_state
+  end,
+  fli = coalesce(?15, fli),
+  student_stage = coalesce(?16, student_stage),
+  majors = coalesce(?17, majors),
+  minors = coalesce(?18, minors),
+  hear_about = coalesce(?19, hear_about)
+where
+  id = ?1;
+
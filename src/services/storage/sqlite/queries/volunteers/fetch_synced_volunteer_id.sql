@@ -0,0 +1,14 @@
+-- Volunteers are matched by the ID of their record in the source, or else by email, so that
+-- volunteers imported before external IDs were recorded, or imported from another source, are
+-- updated rather than created again.
+select
+  id
+from
+  volunteers
+where
+  project_cycle_id = ?1
+  and (external_id = ?2
+    or email = ?3)
+order by
+  external_id is ?2 desc
+limit 1;
@@ -0,0 +1,8 @@
+select
+  id,
+  external_id
+from
+  volunteers
+where
+  external_id is not null
+  and external_source =
@@ -0,0 +1,31 @@
+select
+  v.volunteer_id,
+  v.created_at,
+  v.updated_at,
+  v.project_cycle_id,
+  v.project_cycle_name,
+  v.first_name,
+  v.last_name,
+  v.email,
+  v.phone,
+  v.volunteer_gender,
+  v.volunteer_ethnicity,
+  v.volunteer_age_range,
+  v.university,
+  v.lgbt,
+  v.country,
+  v.us_state,
+  v.fli,
+  v.student_stage,
+  v.majors,
+  v.minors,
+  v.hear_about,
+  v.active,
+  v.clients,
+  v.mentors,
+  v.workspace_email,
+  v.roles
+from
+  volunteer_details v
+where
+  true
@@ -0,0 +1,3 @@
+insert into volunteers_exported_to_workspace(volunteer_id, job_id, workspace_email, org_unit)
+  values (?1, ?2, ?3, ?4);
+
@@ -0,0 +1 @@
+insert into volunteer_mentors(project_cycle_id, volunteer_id, mentor_id)
@@ -0,0 +1 @@
+insert into client_volunteers(project_cycle_id, volunteer_id, client_id, currently_active)
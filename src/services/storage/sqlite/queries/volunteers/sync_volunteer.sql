@@ -0,0 +1,27 @@
+-- Only volunteers which differ from the synced data are updated, so that the number of rows
+-- affected tells whether the volunteer changed.
+update
+  volunteers
+set
+  external_id = ?2,
+  external_source = ?19,
+  active = true,
+  first_name = ?3,
+  last_name = ?4,
+  email = ?5,
+  phone = ?6,
+  volunteer_gender = ?7,
+  volunteer_ethnicity = ?8,
+  volunteer_age_range = ?9,
+  university = ?10,
+  lgbt = ?11,
+  country = ?12,
+  us_state = ?13,
+  fli = ?14,
+  student_stage = ?15,
+  majors = ?16,
+  minors = ?17,
+  hear_about = ?18
+where
+  id = ?1
+  and (external_id, external_source, active, first_name, last_name, email, phone, volunteer_gender, volunteer_ethnicity, volunteer_age_range, university, lgbt, country, us_state, fli, student_stage, majors, minors, hear_about) is not (?2, ?19, true, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18);
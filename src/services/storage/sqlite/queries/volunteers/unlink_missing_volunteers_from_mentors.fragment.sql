@@ -0,0 +1,2 @@
+delete from volunteer_mentors
+where project_cycle_id =
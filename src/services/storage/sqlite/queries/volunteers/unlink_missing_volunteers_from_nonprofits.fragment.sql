@@ -0,0 +1,2 @@
+delete from client_volunteers
+where project_cycle_id =
@@ -0,0 +1,4 @@
+insert into airtable_webhooks(base_id, webhook_id, mac_secret_base64, project_cycle_id, mapping_profile_id, cursor, expiration_time)
+  values (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+returning
+  id;
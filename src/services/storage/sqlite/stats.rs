@@ -0,0 +1,35 @@
+//! This module contains the implementation of the `QueryStats` trait for the `SqliteBackend`
+//! struct.
+
+use anyhow::Result;
+use async_trait::async_trait;
+use sqlx::{Sqlite, Transaction};
+use uuid::Uuid;
+
+use super::SqliteBackend;
+use crate::services::storage::entities::BasicStats;
+use crate::services::storage::stats::QueryStats;
+use crate::services::storage::{exec_with_tx, Acquire, ExecOpts};
+
+#[async_trait]
+impl QueryStats<Sqlite> for SqliteBackend {
+    async fn get_basic_stats(
+        &self,
+        project_cycle_id: Uuid,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<BasicStats> {
+        async fn exec(
+            project_cycle_id: Uuid,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<BasicStats> {
+            let query = include_str!("queries/stats/fetch_basic_stats.sql");
+            let stats = sqlx::query_as::<_, BasicStats>(query)
+                .bind(project_cycle_id)
+                .fetch_one(&mut **tx)
+                .await?;
+            Ok(stats)
+        }
+
+        exec_with_tx!(self, exec_opts, exec, project_cycle_id)
+    }
+}
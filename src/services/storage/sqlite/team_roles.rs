@@ -0,0 +1,188 @@
+//! This module contains the implementation of the `QueryTeamRoles` trait for the
+//! `SqliteBackend` struct.
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use sqlx::{Sqlite, Transaction};
+use uuid::Uuid;
+
+use super::entities::VolunteerDetailsRow;
+use super::SqliteBackend;
+use crate::services::storage::entities::{TeamRole, VolunteerDetails};
+use crate::services::storage::team_roles::{CreateTeamRole, EditTeamRole, QueryTeamRoles};
+use crate::services::storage::{exec_with_tx, Acquire, ExecOpts};
+
+#[async_trait]
+impl QueryTeamRoles<Sqlite> for SqliteBackend {
+    async fn create_team_role(
+        &self,
+        data: CreateTeamRole,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<Uuid> {
+        async fn exec(data: CreateTeamRole, tx: &mut Transaction<'_, Sqlite>) -> Result<Uuid> {
+            let query = include_str!("queries/team_roles/create_team_role.sql");
+
+            let id = sqlx::query_scalar::<_, Uuid>(query)
+                .bind(data.name)
+                .bind(data.description)
+                .fetch_one(&mut **tx)
+                .await?;
+            Ok(id)
+        }
+
+        exec_with_tx!(self, exec_opts, exec, data)
+    }
+
+    async fn fetch_team_roles(&self, exec_opts: &mut ExecOpts<Sqlite>) -> Result<Vec<TeamRole>> {
+        async fn exec(tx: &mut Transaction<'_, Sqlite>) -> Result<Vec<TeamRole>> {
+            let query = include_str!("queries/team_roles/fetch_team_roles.sql");
+
+            let roles = sqlx::query_as::<_, TeamRole>(query)
+                .fetch_all(&mut **tx)
+                .await
+                .context("error fetching team roles")?;
+            Ok(roles)
+        }
+
+        exec_with_tx!(self, exec_opts, exec)
+    }
+
+    async fn fetch_team_role_by_id(
+        &self,
+        id: Uuid,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<Option<TeamRole>> {
+        async fn exec(id: Uuid, tx: &mut Transaction<'_, Sqlite>) -> Result<Option<TeamRole>> {
+            let query = include_str!("queries/team_roles/fetch_team_role_by_id.sql");
+
+            let role = sqlx::query_as::<_, TeamRole>(query)
+                .bind(id)
+                .fetch_optional(&mut **tx)
+                .await
+                .context("error fetching team role by id")?;
+            Ok(role)
+        }
+
+        exec_with_tx!(self, exec_opts, exec, id)
+    }
+
+    async fn edit_team_role(
+        &self,
+        id: Uuid,
+        data: EditTeamRole,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<Option<TeamRole>> {
+        async fn exec(
+            id: Uuid,
+            data: EditTeamRole,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<Option<TeamRole>> {
+            let query = include_str!("queries/team_roles/edit_team_role.sql");
+
+            let role = sqlx::query_as::<_, TeamRole>(query)
+                .bind(id)
+                .bind(data.name)
+                .bind(data.description)
+                .fetch_optional(&mut **tx)
+                .await?;
+            Ok(role)
+        }
+
+        exec_with_tx!(self, exec_opts, exec, id, data)
+    }
+
+    async fn delete_team_role(&self, id: Uuid, exec_opts: &mut ExecOpts<Sqlite>) -> Result<()> {
+        async fn exec(id: Uuid, tx: &mut Transaction<'_, Sqlite>) -> Result<()> {
+            let query = include_str!("queries/team_roles/delete_team_role.sql");
+            sqlx::query(query)
+                .bind(id)
+                .execute(&mut **tx)
+                .await
+                .context("error deleting team role")?;
+            Ok(())
+        }
+
+        exec_with_tx!(self, exec_opts, exec, id)
+    }
+
+    async fn assign_team_role(
+        &self,
+        project_cycle_id: Uuid,
+        volunteer_id: Uuid,
+        role_id: Uuid,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<bool> {
+        async fn exec(
+            project_cycle_id: Uuid,
+            volunteer_id: Uuid,
+            role_id: Uuid,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<bool> {
+            let query = include_str!("queries/team_roles/assign_team_role.sql");
+
+            let res = sqlx::query(query)
+                .bind(project_cycle_id)
+                .bind(volunteer_id)
+                .bind(role_id)
+                .execute(&mut **tx)
+                .await
+                .context("error assigning team role")?;
+            Ok(res.rows_affected() > 0)
+        }
+
+        exec_with_tx!(self, exec_opts, exec, project_cycle_id, volunteer_id, role_id)
+    }
+
+    async fn unassign_team_role(
+        &self,
+        project_cycle_id: Uuid,
+        volunteer_id: Uuid,
+        role_id: Uuid,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<bool> {
+        async fn exec(
+            project_cycle_id: Uuid,
+            volunteer_id: Uuid,
+            role_id: Uuid,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<bool> {
+            let query = include_str!("queries/team_roles/unassign_team_role.sql");
+
+            let res = sqlx::query(query)
+                .bind(project_cycle_id)
+                .bind(volunteer_id)
+                .bind(role_id)
+                .execute(&mut **tx)
+                .await
+                .context("error unassigning team role")?;
+            Ok(res.rows_affected() > 0)
+        }
+
+        exec_with_tx!(self, exec_opts, exec, project_cycle_id, volunteer_id, role_id)
+    }
+
+    async fn fetch_volunteers_by_team_role(
+        &self,
+        role_id: Uuid,
+        project_cycle_id: Option<Uuid>,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<Vec<VolunteerDetails>> {
+        async fn exec(
+            role_id: Uuid,
+            project_cycle_id: Option<Uuid>,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<Vec<VolunteerDetails>> {
+            let query = include_str!("queries/team_roles/fetch_volunteers_by_team_role.sql");
+
+            let volunteers = sqlx::query_as::<_, VolunteerDetailsRow>(query)
+                .bind(role_id)
+                .bind(project_cycle_id)
+                .fetch_all(&mut **tx)
+                .await
+                .context("error fetching volunteers by team role")?;
+            Ok(volunteers.into_iter().map(Into::into).collect())
+        }
+
+        exec_with_tx!(self, exec_opts, exec, role_id, project_cycle_id)
+    }
+}
@@ -0,0 +1,829 @@
+//! This module contains the implementation of the `QueryVolunteers` trait for the
+//! `SqliteBackend` struct.
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use sqlx::types::Json;
+use sqlx::{QueryBuilder, Row, Sqlite, Transaction};
+use uuid::Uuid;
+
+use super::entities::VolunteerDetailsRow;
+use super::SqliteBackend;
+use crate::services::storage::entities::{ExportedVolunteerDetails, VolunteerDetails};
+use crate::services::storage::pagination::{escape_like, Page, PageOptions};
+use crate::services::storage::types::{ExternalSource, SyncOutcome};
+use crate::services::storage::volunteers::{
+    CreateVolunteer, EditVolunteer, InsertVolunteerExportedToWorkspace, QueryVolunteers,
+    VolunteerFilter,
+};
+use crate::services::storage::{exec_with_tx, Acquire, ExecOpts};
+
+#[async_trait]
+impl QueryVolunteers<Sqlite> for SqliteBackend {
+    async fn create_volunteer(
+        &self,
+        project_cycle_id: Uuid,
+        data: CreateVolunteer,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<Uuid> {
+        async fn exec(
+            project_cycle_id: Uuid,
+            data: CreateVolunteer,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<Uuid> {
+            let query = include_str!("queries/volunteers/create_volunteer.sql");
+
+            let id = sqlx::query_scalar::<_, Uuid>(query)
+                .bind(project_cycle_id)
+                .bind(data.first_name)
+                .bind(data.last_name)
+                .bind(data.email)
+                .bind(data.phone)
+                .bind(data.volunteer_gender)
+                .bind(Json(data.volunteer_ethnicity))
+                .bind(data.volunteer_age_range)
+                .bind(Json(data.university))
+                .bind(data.lgbt)
+                .bind(data.country)
+                .bind(data.us_state)
+                .bind(Json(data.fli))
+                .bind(data.student_stage)
+                .bind(Json(data.majors))
+                .bind(Json(data.minors))
+                .bind(Json(data.hear_about))
+                .fetch_one(&mut **tx)
+                .await?;
+
+            Ok(id)
+        }
+
+        exec_with_tx!(self, exec_opts, exec, project_cycle_id, data)
+    }
+
+    async fn batch_create_volunteers(
+        &self,
+        project_cycle_id: Uuid,
+        data: Vec<CreateVolunteer>,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<Vec<(String, Uuid)>> {
+        async fn exec(
+            project_cycle_id: Uuid,
+            data: Vec<CreateVolunteer>,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<Vec<(String, Uuid)>> {
+            let fragment = include_str!("queries/volunteers/create_volunteers.fragment.sql");
+            let data = QueryBuilder::<Sqlite>::new(fragment)
+                .push_values(data, |mut b, v| {
+                    b.push_bind(project_cycle_id)
+                        .push_bind(v.first_name)
+                        .push_bind(v.last_name)
+                        .push_bind(v.email)
+                        .push_bind(v.phone)
+                        .push_bind(v.volunteer_gender)
+                        .push_bind(Json(v.volunteer_ethnicity))
+                        .push_bind(v.volunteer_age_range)
+                        .push_bind(Json(v.university))
+                        .push_bind(v.lgbt)
+                        .push_bind(v.country)
+                        .push_bind(v.us_state)
+                        .push_bind(Json(v.fli))
+                        .push_bind(v.student_stage)
+                        .push_bind(Json(v.majors))
+                        .push_bind(Json(v.minors))
+                        .push_bind(Json(v.hear_about));
+                })
+                .push(" returning email, id")
+                .build_query_as::<(String, Uuid)>()
+                .fetch_all(&mut **tx)
+                .await?;
+            Ok(data)
+        }
+
+        exec_with_tx!(self, exec_opts, exec, project_cycle_id, data)
+    }
+
+    async fn fetch_volunteer_by_id(
+        &self,
+        id: Uuid,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<Option<VolunteerDetails>> {
+        async fn exec(
+            id: Uuid,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<Option<VolunteerDetails>> {
+            let query = include_str!("queries/volunteers/fetch_volunteer_by_id.sql");
+            let volunteer = sqlx::query_as::<_, VolunteerDetailsRow>(query)
+                .bind(id)
+                .fetch_optional(&mut **tx)
+                .await
+                .context("error fetching volunteer by id")?;
+            Ok(volunteer.map(Into::into))
+        }
+
+        exec_with_tx!(self, exec_opts, exec, id)
+    }
+
+    async fn fetch_volunteer_by_email(
+        &self,
+        email: &str,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<Option<VolunteerDetails>> {
+        async fn exec(
+            email: &str,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<Option<VolunteerDetails>> {
+            let query = include_str!("queries/volunteers/fetch_volunteer_by_email.sql");
+            let volunteer = sqlx::query_as::<_, VolunteerDetailsRow>(query)
+                .bind(email)
+                .fetch_optional(&mut **tx)
+                .await
+                .context("error fetching volunteer by id")?;
+            Ok(volunteer.map(Into::into))
+        }
+
+        exec_with_tx!(self, exec_opts, exec, email)
+    }
+
+    async fn fetch_volunteers(
+        &self,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<Vec<VolunteerDetails>> {
+        async fn exec(tx: &mut Transaction<'_, Sqlite>) -> Result<Vec<VolunteerDetails>> {
+            let query = include_str!("queries/volunteers/fetch_volunteers.sql");
+            let volunteers = sqlx::query_as::<_, VolunteerDetailsRow>(query)
+                .fetch_all(&mut **tx)
+                .await
+                .context("error fetching volunteers")?;
+            Ok(volunteers.into_iter().map(Into::into).collect())
+        }
+
+        exec_with_tx!(self, exec_opts, exec)
+    }
+
+    async fn fetch_volunteers_by_cycle(
+        &self,
+        project_cycle_id: Uuid,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<Vec<VolunteerDetails>> {
+        async fn exec(
+            project_cycle_id: Uuid,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<Vec<VolunteerDetails>> {
+            let query = include_str!("queries/volunteers/fetch_volunteers_by_cycle.sql");
+            let volunteers = sqlx::query_as::<_, VolunteerDetailsRow>(query)
+                .bind(project_cycle_id)
+                .fetch_all(&mut **tx)
+                .await
+                .context("error fetching volunteers by cycle")?;
+            Ok(volunteers.into_iter().map(Into::into).collect())
+        }
+
+        exec_with_tx!(self, exec_opts, exec, project_cycle_id)
+    }
+
+    async fn fetch_volunteers_filtered(
+        &self,
+        filter: VolunteerFilter,
+        page: PageOptions,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<Page<VolunteerDetails>> {
+        async fn exec(
+            filter: VolunteerFilter,
+            page: PageOptions,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<Page<VolunteerDetails>> {
+            let fragment =
+                include_str!("queries/volunteers/fetch_volunteers_filtered.fragment.sql");
+            let mut query = QueryBuilder::<Sqlite>::new(fragment);
+
+            if !filter.include_inactive {
+                query.push(" and v.active");
+            }
+
+            if let Some(project_cycle_id) = filter.project_cycle_id {
+                query.push(" and v.project_cycle_id = ").push_bind(project_cycle_id);
+            }
+
+            if let Some(nonprofit_id) = filter.nonprofit_id {
+                query
+                    .push(" and exists (select 1 from client_volunteers cv where cv.volunteer_id = v.volunteer_id and cv.client_id = ")
+                    .push_bind(nonprofit_id)
+                    .push(")");
+            }
+
+            if let Some(mentor_id) = filter.mentor_id {
+                query
+                    .push(" and exists (select 1 from volunteer_mentors vm where vm.volunteer_id = v.volunteer_id and vm.mentor_id = ")
+                    .push_bind(mentor_id)
+                    .push(")");
+            }
+
+            if let Some(role) = filter.role {
+                query
+                    .push(" and exists (select 1 from volunteer_team_roles vtr join team_roles tr on vtr.role_id = tr.id where vtr.volunteer_id = v.volunteer_id and tr.name = ")
+                    .push_bind(role.to_string())
+                    .push(")");
+            }
+
+            match filter.exported {
+                Some(true) => {
+                    query.push(" and v.workspace_email is not null");
+                }
+                Some(false) => {
+                    query.push(" and v.workspace_email is null");
+                }
+                None => {}
+            }
+
+            if let Some(university) = filter.university {
+                query
+                    .push(" and exists (select 1 from json_each(v.university) u where lower(u.value) = lower(")
+                    .push_bind(university)
+                    .push("))");
+            }
+
+            if let Some(student_stage) = filter.student_stage {
+                query.push(" and v.student_stage = ").push_bind(student_stage);
+            }
+
+            if let Some(search) = filter.search {
+                let pattern = format!("%{}%", escape_like(&search));
+                query
+                    .push(" and ((v.first_name || ' ' || v.last_name) like ")
+                    .push_bind(pattern.clone())
+                    .push(" escape '\\' or v.email like ")
+                    .push_bind(pattern.clone())
+                    .push(" escape '\\' or v.workspace_email like ")
+                    .push_bind(pattern)
+                    .push(" escape '\\')");
+            }
+
+            let column = filter.sort_by.column();
+            let direction = filter.sort_direction;
+
+            if let Some(cursor) = page.cursor {
+                query
+                    .push(format!(
+                        " and (v.{column}, v.volunteer_id) {} (select {column}, id from volunteers where id = ",
+                        direction.comparison(),
+                    ))
+                    .push_bind(cursor)
+                    .push(")");
+            }
+
+            query
+                .push(format!(
+                    " order by v.{column} {0}, v.volunteer_id {0} limit ",
+                    direction.keyword()
+                ))
+                .push_bind(page.limit + 1);
+
+            let volunteers = query
+                .build_query_as::<VolunteerDetailsRow>()
+                .fetch_all(&mut **tx)
+                .await
+                .context("error fetching filtered volunteers")?;
+            let volunteers = volunteers.into_iter().map(VolunteerDetails::from).collect();
+
+            Ok(Page::from_rows(volunteers, page.limit, |v| v.volunteer_id))
+        }
+
+        exec_with_tx!(self, exec_opts, exec, filter, page)
+    }
+
+    async fn fetch_volunteers_by_ids(
+        &self,
+        ids: Vec<Uuid>,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<Vec<VolunteerDetails>> {
+        async fn exec(
+            ids: Vec<Uuid>,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<Vec<VolunteerDetails>> {
+            if ids.is_empty() {
+                return Ok(vec![]);
+            }
+
+            let fragment = include_str!("queries/volunteers/fetch_volunteers_by_ids.fragment.sql");
+            let mut query = QueryBuilder::<Sqlite>::new(fragment);
+            query.push(" (");
+            let mut separated = query.separated(", ");
+            for id in ids {
+                separated.push_bind(id);
+            }
+            separated.push_unseparated(")");
+
+            let volunteers = query
+                .build_query_as::<VolunteerDetailsRow>()
+                .fetch_all(&mut **tx)
+                .await
+                .context("error fetching volunteers by ids")?;
+            Ok(volunteers.into_iter().map(Into::into).collect())
+        }
+
+        exec_with_tx!(self, exec_opts, exec, ids)
+    }
+
+    async fn fetch_volunteer_external_ids(
+        &self,
+        ids: Vec<Uuid>,
+        source: ExternalSource,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<Vec<(Uuid, String)>> {
+        async fn exec(
+            ids: Vec<Uuid>,
+            source: ExternalSource,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<Vec<(Uuid, String)>> {
+            if ids.is_empty() {
+                return Ok(vec![]);
+            }
+
+            let fragment =
+                include_str!("queries/volunteers/fetch_volunteer_external_ids.fragment.sql");
+            let mut query = QueryBuilder::<Sqlite>::new(fragment);
+            query.push(" ").push_bind(source).push(" and id in (");
+            let mut separated = query.separated(", ");
+            for id in ids {
+                separated.push_bind(id);
+            }
+            separated.push_unseparated(")");
+
+            let external_ids = query
+                .build_query_as::<(Uuid, String)>()
+                .fetch_all(&mut **tx)
+                .await
+                .context("error fetching volunteer external ids")?;
+            Ok(external_ids)
+        }
+
+        exec_with_tx!(self, exec_opts, exec, ids, source)
+    }
+
+    async fn edit_volunteer(
+        &self,
+        id: Uuid,
+        data: EditVolunteer,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<()> {
+        async fn exec(
+            id: Uuid,
+            data: EditVolunteer,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<()> {
+            let query = include_str!("queries/volunteers/edit_volunteer.sql");
+            sqlx::query(query)
+                .bind(id)
+                .bind(data.first_name)
+                .bind(data.last_name)
+                .bind(data.email)
+                .bind(data.phone.is_some())
+                .bind(data.phone.flatten())
+                .bind(data.volunteer_gender)
+                .bind(data.volunteer_ethnicity.map(Json))
+                .bind(data.volunteer_age_range)
+                .bind(data.university.map(Json))
+                .bind(data.lgbt)
+                .bind(data.country)
+                .bind(data.us_state.is_some())
+                .bind(data.us_state.flatten())
+                .bind(data.fli.map(Json))
+                .bind(data.student_stage)
+                .bind(data.majors.map(Json))
+                .bind(data.minors.map(Json))
+                .bind(data.hear_about.map(Json))
+                .execute(&mut **tx)
+                .await
+                .context("error editing volunteer")?;
+            Ok(())
+        }
+
+        exec_with_tx!(self, exec_opts, exec, id, data)
+    }
+
+    async fn delete_volunteer(&self, id: Uuid, exec_opts: &mut ExecOpts<Sqlite>) -> Result<()> {
+        async fn exec(id: Uuid, tx: &mut Transaction<'_, Sqlite>) -> Result<()> {
+            let query = include_str!("queries/volunteers/delete_volunteer.sql");
+            sqlx::query(query).bind(id).execute(&mut **tx).await.context("error deleting user")?;
+            Ok(())
+        }
+
+        exec_with_tx!(self, exec_opts, exec, id)
+    }
+
+    async fn batch_link_volunteers_to_nonprofits(
+        &self,
+        project_cycle_id: Uuid,
+        linkage: Vec<(Uuid, Uuid)>,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<()> {
+        async fn exec(
+            project_cycle_id: Uuid,
+            linkage: Vec<(Uuid, Uuid)>,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<()> {
+            let fragment =
+                include_str!("queries/volunteers/link_volunteers_to_nonprofits.fragment.sql");
+
+            // v.0 needs to be turned into an id by calling the get_volunteer_id function
+
+            QueryBuilder::<Sqlite>::new(fragment)
+                .push_values(linkage, |mut b, (volunteer_id, nonprofit_id)| {
+                    b.push_bind(project_cycle_id)
+                        .push_bind(volunteer_id)
+                        .push_bind(nonprofit_id)
+                        .push_bind(true);
+                })
+                .build()
+                .execute(&mut **tx)
+                .await
+                .context("error linking volunteers to nonprofits")?;
+            Ok(())
+        }
+
+        exec_with_tx!(self, exec_opts, exec, project_cycle_id, linkage)
+    }
+
+    async fn batch_link_volunteers_to_mentors(
+        &self,
+        project_cycle_id: Uuid,
+        linkage: Vec<(Uuid, Uuid)>,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<()> {
+        async fn exec(
+            project_cycle_id: Uuid,
+            linkage: Vec<(Uuid, Uuid)>,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<()> {
+            let fragment =
+                include_str!("queries/volunteers/link_volunteers_to_mentors.fragment.sql");
+
+            // insert into volunteer_mentors(project_cycle_id, volunteer_id, mentor_id)
+
+            QueryBuilder::<Sqlite>::new(fragment)
+                .push_values(linkage, |mut b, (volunteer_id, mentor_id)| {
+                    b.push_bind(project_cycle_id).push_bind(volunteer_id).push_bind(mentor_id);
+                })
+                .build()
+                .execute(&mut **tx)
+                .await
+                .map_err(|e| {
+                    log::error!("error linking volunteers to mentors: {:?}", e);
+                    e
+                })?;
+            Ok(())
+        }
+
+        exec_with_tx!(self, exec_opts, exec, project_cycle_id, linkage)
+    }
+
+    async fn batch_sync_volunteers(
+        &self,
+        project_cycle_id: Uuid,
+        source: ExternalSource,
+        data: Vec<(String, CreateVolunteer)>,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<Vec<(String, Uuid, SyncOutcome)>> {
+        async fn exec(
+            project_cycle_id: Uuid,
+            source: ExternalSource,
+            data: Vec<(String, CreateVolunteer)>,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<Vec<(String, Uuid, SyncOutcome)>> {
+            let fetch_id = include_str!("queries/volunteers/fetch_synced_volunteer_id.sql");
+            let fetch_id_by_email =
+                include_str!("queries/volunteers/fetch_volunteer_id_by_email.sql");
+            let create = include_str!("queries/volunteers/create_synced_volunteer.sql");
+            let sync = include_str!("queries/volunteers/sync_volunteer.sql");
+
+            let mut synced = Vec::with_capacity(data.len());
+
+            for (external_id, v) in data {
+                let id = sqlx::query_scalar::<_, Uuid>(fetch_id)
+                    .bind(project_cycle_id)
+                    .bind(&external_id)
+                    .bind(&v.email)
+                    .fetch_optional(&mut **tx)
+                    .await?;
+
+                // Emails are unique across project cycles, so a volunteer whose email belongs to
+                // another volunteer, e.g. one in a different project cycle, can't be synced
+                let holder = sqlx::query_scalar::<_, Uuid>(fetch_id_by_email)
+                    .bind(&v.email)
+                    .fetch_optional(&mut **tx)
+                    .await?;
+                if let Some(holder) = holder.filter(|holder| Some(*holder) != id) {
+                    synced.push((external_id, holder, SyncOutcome::Conflict));
+                    continue;
+                }
+
+                // Both queries take the same parameters, except that the first is the ID of the
+                // volunteer to update or the project cycle to create the volunteer in
+                let (query, id_or_cycle) = match id {
+                    Some(id) => (sqlx::query(sync), id),
+                    None => (sqlx::query(create), project_cycle_id),
+                };
+
+                let query = query
+                    .bind(id_or_cycle)
+                    .bind(&external_id)
+                    .bind(v.first_name)
+                    .bind(v.last_name)
+                    .bind(&v.email)
+                    .bind(v.phone)
+                    .bind(v.volunteer_gender)
+                    .bind(Json(v.volunteer_ethnicity))
+                    .bind(v.volunteer_age_range)
+                    .bind(Json(v.university))
+                    .bind(v.lgbt)
+                    .bind(v.country)
+                    .bind(v.us_state)
+                    .bind(Json(v.fli))
+                    .bind(v.student_stage)
+                    .bind(Json(v.majors))
+                    .bind(Json(v.minors))
+                    .bind(Json(v.hear_about))
+                    .bind(source);
+
+                let (id, outcome) = match id {
+                    Some(id) => {
+                        let res = query
+                            .execute(&mut **tx)
+                            .await
+                            .with_context(|| format!("error syncing volunteer {}", v.email))?;
+                        match res.rows_affected() {
+                            0 => (id, SyncOutcome::Unchanged),
+                            _ => (id, SyncOutcome::Updated),
+                        }
+                    }
+                    None => {
+                        let id = query
+                            .fetch_one(&mut **tx)
+                            .await
+                            .with_context(|| format!("error creating volunteer {}", v.email))?
+                            .try_get("id")?;
+                        (id, SyncOutcome::Created)
+                    }
+                };
+
+                synced.push((external_id, id, outcome));
+            }
+
+            Ok(synced)
+        }
+
+        exec_with_tx!(self, exec_opts, exec, project_cycle_id, source, data)
+    }
+
+    async fn deactivate_missing_volunteers(
+        &self,
+        project_cycle_id: Uuid,
+        keep: Vec<Uuid>,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<u64> {
+        async fn exec(
+            project_cycle_id: Uuid,
+            keep: Vec<Uuid>,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<u64> {
+            let fragment =
+                include_str!("queries/volunteers/deactivate_missing_volunteers.fragment.sql");
+            let mut query = QueryBuilder::<Sqlite>::new(fragment);
+            query.push_bind(project_cycle_id);
+
+            if !keep.is_empty() {
+                query.push(" and id not in (");
+                let mut separated = query.separated(", ");
+                for id in keep {
+                    separated.push_bind(id);
+                }
+                separated.push_unseparated(")");
+            }
+
+            let res = query.build().execute(&mut **tx).await?;
+            Ok(res.rows_affected())
+        }
+
+        exec_with_tx!(self, exec_opts, exec, project_cycle_id, keep)
+    }
+
+    async fn sync_volunteer_nonprofit_links(
+        &self,
+        project_cycle_id: Uuid,
+        linkage: Vec<(Uuid, Uuid)>,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<(u64, u64)> {
+        async fn exec(
+            project_cycle_id: Uuid,
+            linkage: Vec<(Uuid, Uuid)>,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<(u64, u64)> {
+            let fragment = include_str!(
+                "queries/volunteers/unlink_missing_volunteers_from_nonprofits.fragment.sql"
+            );
+            let mut query = QueryBuilder::<Sqlite>::new(fragment);
+            query.push_bind(project_cycle_id);
+
+            if !linkage.is_empty() {
+                query.push(" and (volunteer_id, client_id) not in (values ");
+                let mut separated = query.separated(", ");
+                for (left, right) in &linkage {
+                    separated
+                        .push("(")
+                        .push_bind_unseparated(*left)
+                        .push_unseparated(", ")
+                        .push_bind_unseparated(*right)
+                        .push_unseparated(")");
+                }
+                separated.push_unseparated(")");
+            }
+
+            let removed = query.build().execute(&mut **tx).await?.rows_affected();
+
+            if linkage.is_empty() {
+                return Ok((0, removed));
+            }
+
+            let fragment =
+                include_str!("queries/volunteers/link_volunteers_to_nonprofits.fragment.sql");
+            let added = QueryBuilder::<Sqlite>::new(fragment)
+                .push_values(linkage, |mut b, (left, right)| {
+                    b.push_bind(project_cycle_id).push_bind(left).push_bind(right).push_bind(true);
+                })
+                .push(
+                    " on conflict (volunteer_id, client_id, project_cycle_id) do update set \
+                     currently_active = true where not client_volunteers.currently_active",
+                )
+                .build()
+                .execute(&mut **tx)
+                .await
+                .context("error linking volunteers to nonprofits")?
+                .rows_affected();
+
+            Ok((added, removed))
+        }
+
+        exec_with_tx!(self, exec_opts, exec, project_cycle_id, linkage)
+    }
+
+    async fn sync_volunteer_mentor_links(
+        &self,
+        project_cycle_id: Uuid,
+        linkage: Vec<(Uuid, Uuid)>,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<(u64, u64)> {
+        async fn exec(
+            project_cycle_id: Uuid,
+            linkage: Vec<(Uuid, Uuid)>,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<(u64, u64)> {
+            let fragment = include_str!(
+                "queries/volunteers/unlink_missing_volunteers_from_mentors.fragment.sql"
+            );
+            let mut query = QueryBuilder::<Sqlite>::new(fragment);
+            query.push_bind(project_cycle_id);
+
+            if !linkage.is_empty() {
+                query.push(" and (volunteer_id, mentor_id) not in (values ");
+                let mut separated = query.separated(", ");
+                for (left, right) in &linkage {
+                    separated
+                        .push("(")
+                        .push_bind_unseparated(*left)
+                        .push_unseparated(", ")
+                        .push_bind_unseparated(*right)
+                        .push_unseparated(")");
+                }
+                separated.push_unseparated(")");
+            }
+
+            let removed = query.build().execute(&mut **tx).await?.rows_affected();
+
+            if linkage.is_empty() {
+                return Ok((0, removed));
+            }
+
+            let fragment =
+                include_str!("queries/volunteers/link_volunteers_to_mentors.fragment.sql");
+            let added = QueryBuilder::<Sqlite>::new(fragment)
+                .push_values(linkage, |mut b, (left, right)| {
+                    b.push_bind(project_cycle_id).push_bind(left).push_bind(right);
+                })
+                .push(" on conflict do nothing")
+                .build()
+                .execute(&mut **tx)
+                .await
+                .context("error linking volunteers to mentors")?
+                .rows_affected();
+
+            Ok((added, removed))
+        }
+
+        exec_with_tx!(self, exec_opts, exec, project_cycle_id, linkage)
+    }
+
+    async fn insert_volunteer_exported_to_workspace(
+        &self,
+        data: InsertVolunteerExportedToWorkspace,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<()> {
+        async fn exec(
+            data: InsertVolunteerExportedToWorkspace,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<()> {
+            let query =
+                include_str!("queries/volunteers/insert_volunteer_exported_to_workspace.sql");
+            sqlx::query(query)
+                .bind(data.volunteer_id)
+                .bind(data.job_id)
+                .bind(data.workspace_email)
+                .bind(data.org_unit)
+                .execute(&mut **tx)
+                .await
+                .context("error recording volunteer as exported to workspace")?;
+            Ok(())
+        }
+
+        exec_with_tx!(self, exec_opts, exec, data)
+    }
+
+    async fn batch_insert_volunteers_exported_to_workspace(
+        &self,
+        data: Vec<InsertVolunteerExportedToWorkspace>,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<()> {
+        async fn exec(
+            data: Vec<InsertVolunteerExportedToWorkspace>,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<()> {
+            let fragment = include_str!(
+                "queries/volunteers/batch_insert_volunteers_exported_to_workspace.fragment.sql"
+            );
+
+            QueryBuilder::<Sqlite>::new(fragment)
+                .push_values(data, |mut b, v| {
+                    b.push_bind(v.volunteer_id)
+                        .push_bind(v.job_id)
+                        .push_bind(v.workspace_email)
+                        .push_bind(v.org_unit);
+                })
+                .build()
+                .execute(&mut **tx)
+                .await?;
+
+            Ok(())
+        }
+
+        exec_with_tx!(self, exec_opts, exec, data)
+    }
+
+    async fn batch_remove_volunteers_exported_to_workspace(
+        &self,
+        data: Vec<Uuid>,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<()> {
+        async fn exec(data: Vec<Uuid>, tx: &mut Transaction<'_, Sqlite>) -> Result<()> {
+            if data.is_empty() {
+                return Ok(());
+            }
+
+            let fragment = include_str!(
+                "queries/volunteers/batch_remove_volunteers_exported_to_workspace.fragment.sql"
+            );
+            let mut query = QueryBuilder::<Sqlite>::new(fragment);
+            query.push(" (");
+            let mut separated = query.separated(", ");
+            for id in data {
+                separated.push_bind(id);
+            }
+            separated.push_unseparated(")");
+
+            query.build().execute(&mut **tx).await?;
+            Ok(())
+        }
+
+        exec_with_tx!(self, exec_opts, exec, data)
+    }
+
+    async fn fetch_exported_volunteer_details_by_project_cycle(
+        &self,
+        project_cycle_id: Uuid,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<Vec<ExportedVolunteerDetails>> {
+        async fn exec(
+            project_cycle_id: Uuid,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<Vec<ExportedVolunteerDetails>> {
+            let query = include_str!(
+                "queries/volunteers/fetch_exported_volunteer_details_by_project_cycle.sql"
+            );
+            let volunteers = sqlx::query_as::<_, ExportedVolunteerDetails>(query)
+                .bind(project_cycle_id)
+                .fetch_all(&mut **tx)
+                .await?;
+            Ok(volunteers)
+        }
+
+        exec_with_tx!(self, exec_opts, exec, project_cycle_id)
+    }
+}
@@ -0,0 +1,159 @@
+//! This module contains the implementation of the `QueryWebhooks` trait for the `SqliteBackend`
+//! struct.
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Sqlite, Transaction};
+use uuid::Uuid;
+
+use super::SqliteBackend;
+use crate::services::storage::entities::AirtableWebhook;
+use crate::services::storage::webhooks::{CreateWebhook, QueryWebhooks};
+use crate::services::storage::{exec_with_tx, Acquire, ExecOpts};
+
+#[async_trait]
+impl QueryWebhooks<Sqlite> for SqliteBackend {
+    async fn create_webhook(
+        &self,
+        data: CreateWebhook,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<Uuid> {
+        async fn exec(data: CreateWebhook, tx: &mut Transaction<'_, Sqlite>) -> Result<Uuid> {
+            let query = include_str!("queries/webhooks/create_webhook.sql");
+
+            let id = sqlx::query_scalar::<_, Uuid>(query)
+                .bind(data.base_id)
+                .bind(data.webhook_id)
+                .bind(data.mac_secret_base64)
+                .bind(data.project_cycle_id)
+                .bind(data.mapping_profile_id)
+                .bind(data.cursor)
+                .bind(data.expiration_time)
+                .fetch_one(&mut **tx)
+                .await?;
+            Ok(id)
+        }
+
+        exec_with_tx!(self, exec_opts, exec, data)
+    }
+
+    async fn fetch_webhooks(
+        &self,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<Vec<AirtableWebhook>> {
+        async fn exec(tx: &mut Transaction<'_, Sqlite>) -> Result<Vec<AirtableWebhook>> {
+            let query = include_str!("queries/webhooks/fetch_webhooks.sql");
+
+            let webhooks = sqlx::query_as::<_, AirtableWebhook>(query)
+                .fetch_all(&mut **tx)
+                .await
+                .context("error fetching webhooks")?;
+            Ok(webhooks)
+        }
+
+        exec_with_tx!(self, exec_opts, exec)
+    }
+
+    async fn fetch_webhook_by_id(
+        &self,
+        id: Uuid,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<Option<AirtableWebhook>> {
+        async fn exec(
+            id: Uuid,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<Option<AirtableWebhook>> {
+            let query = include_str!("queries/webhooks/fetch_webhook_by_id.sql");
+
+            let webhook = sqlx::query_as::<_, AirtableWebhook>(query)
+                .bind(id)
+                .fetch_optional(&mut **tx)
+                .await
+                .context("error fetching webhook by id")?;
+            Ok(webhook)
+        }
+
+        exec_with_tx!(self, exec_opts, exec, id)
+    }
+
+    async fn fetch_webhook_by_airtable_id(
+        &self,
+        webhook_id: String,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<Option<AirtableWebhook>> {
+        async fn exec(
+            webhook_id: String,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<Option<AirtableWebhook>> {
+            let query = include_str!("queries/webhooks/fetch_webhook_by_airtable_id.sql");
+
+            let webhook = sqlx::query_as::<_, AirtableWebhook>(query)
+                .bind(webhook_id)
+                .fetch_optional(&mut **tx)
+                .await
+                .context("error fetching webhook by airtable id")?;
+            Ok(webhook)
+        }
+
+        exec_with_tx!(self, exec_opts, exec, webhook_id)
+    }
+
+    async fn update_webhook_cursor(
+        &self,
+        id: Uuid,
+        cursor: i64,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<()> {
+        async fn exec(id: Uuid, cursor: i64, tx: &mut Transaction<'_, Sqlite>) -> Result<()> {
+            let query = include_str!("queries/webhooks/update_webhook_cursor.sql");
+            sqlx::query(query)
+                .bind(id)
+                .bind(cursor)
+                .execute(&mut **tx)
+                .await
+                .context("error updating webhook cursor")?;
+            Ok(())
+        }
+
+        exec_with_tx!(self, exec_opts, exec, id, cursor)
+    }
+
+    async fn update_webhook_expiration_time(
+        &self,
+        id: Uuid,
+        expiration_time: Option<DateTime<Utc>>,
+        exec_opts: &mut ExecOpts<Sqlite>,
+    ) -> Result<()> {
+        async fn exec(
+            id: Uuid,
+            expiration_time: Option<DateTime<Utc>>,
+            tx: &mut Transaction<'_, Sqlite>,
+        ) -> Result<()> {
+            let query = include_str!("queries/webhooks/update_webhook_expiration_time.sql");
+            sqlx::query(query)
+                .bind(id)
+                .bind(expiration_time)
+                .execute(&mut **tx)
+                .await
+                .context("error updating webhook expiration time")?;
+            Ok(())
+        }
+
+        exec_with_tx!(self, exec_opts, exec, id, expiration_time)
+    }
+
+    async fn delete_webhook(&self, id: Uuid, exec_opts: &mut ExecOpts<Sqlite>) -> Result<()> {
+        async fn exec(id: Uuid, tx: &mut Transaction<'_, Sqlite>) -> Result<()> {
+            let query = include_str!("queries/webhooks/delete_webhook.sql");
+            sqlx::query(query)
+                .bind(id)
+                .execute(&mut **tx)
+                .await
+                .context("error deleting webhook")?;
+            Ok(())
+        }
+
+        exec_with_tx!(self, exec_opts, exec, id)
+    }
+}
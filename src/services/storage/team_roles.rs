@@ -0,0 +1,331 @@
+//! This module contains the definition of the `QueryTeamRoles` trait as well as the default
+//! implementation of the trait for the `PgBackend` struct.
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use derive_builder::Builder;
+use sqlx::{Database, Postgres, Transaction};
+use uuid::Uuid;
+
+use super::exec_with_tx;
+use crate::services::storage::entities::{TeamRole, VolunteerDetails};
+use crate::services::storage::{Acquire, ExecOpts, PgBackend};
+
+/// Data needed to create a new team role.
+///
+/// * `name`: The name of the role, e.g. `product_manager`. It must be unique.
+/// * `description`: A description of the role
+#[derive(Debug, Builder, Clone)]
+pub struct CreateTeamRole {
+    #[builder(setter(into))]
+    pub name: String,
+    #[builder(setter(into))]
+    pub description: String,
+}
+
+/// Data needed to edit a team role.
+///
+/// Fields which are `None` are left unchanged.
+///
+/// * `name`: The new name of the role. It must be unique.
+/// * `description`: The new description of the role
+#[derive(Debug, Builder, Default)]
+#[builder(default)]
+pub struct EditTeamRole {
+    #[builder(setter(into, strip_option))]
+    pub name: Option<String>,
+    #[builder(setter(into, strip_option))]
+    pub description: Option<String>,
+}
+
+/// A trait for querying team roles and the roles assigned to volunteers.
+///
+/// Roles are assigned to volunteers per project cycle. If you implement a new storage backend, this
+/// trait is required for it to implement `StorageLayer`. The default implementation is for
+/// `Postgres`.
+#[async_trait]
+#[allow(unused)]
+pub trait QueryTeamRoles<DB: Database> {
+    /// Create a new team role.
+    ///
+    /// * `data`: Data required to create the role
+    /// * `exec_opts`: Execution options for the query
+    async fn create_team_role(
+        &self,
+        data: CreateTeamRole,
+        exec_opts: &mut ExecOpts<DB>,
+    ) -> Result<Uuid> {
+        unimplemented!()
+    }
+
+    /// Fetch all team roles, ordered by name.
+    ///
+    /// * `exec_opts`: Execution options for the query
+    async fn fetch_team_roles(&self, exec_opts: &mut ExecOpts<DB>) -> Result<Vec<TeamRole>> {
+        unimplemented!()
+    }
+
+    /// Fetch a team role by ID.
+    ///
+    /// * `id`: The ID of the role to fetch
+    /// * `exec_opts`: Execution options for the query
+    async fn fetch_team_role_by_id(
+        &self,
+        id: Uuid,
+        exec_opts: &mut ExecOpts<DB>,
+    ) -> Result<Option<TeamRole>> {
+        unimplemented!()
+    }
+
+    /// Edit a team role.
+    ///
+    /// Returns the edited role, or `None` if there is no role with the given ID.
+    ///
+    /// * `id`: The ID of the role to edit
+    /// * `data`: The changes to make to the role
+    /// * `exec_opts`: Execution options for the query
+    async fn edit_team_role(
+        &self,
+        id: Uuid,
+        data: EditTeamRole,
+        exec_opts: &mut ExecOpts<DB>,
+    ) -> Result<Option<TeamRole>> {
+        unimplemented!()
+    }
+
+    /// Delete a team role by ID.
+    ///
+    /// Deleting a role unassigns it from every volunteer who has it.
+    ///
+    /// * `id`: The ID of the role to delete
+    /// * `exec_opts`: Execution options for the query
+    async fn delete_team_role(&self, id: Uuid, exec_opts: &mut ExecOpts<DB>) -> Result<()> {
+        unimplemented!()
+    }
+
+    /// Assign a role to a volunteer for a project cycle.
+    ///
+    /// Returns `false` if the volunteer already had the role for the cycle.
+    ///
+    /// * `project_cycle_id`: The ID of the project cycle the role is held in
+    /// * `volunteer_id`: The ID of the volunteer
+    /// * `role_id`: The ID of the role
+    /// * `exec_opts`: Execution options for the query
+    async fn assign_team_role(
+        &self,
+        project_cycle_id: Uuid,
+        volunteer_id: Uuid,
+        role_id: Uuid,
+        exec_opts: &mut ExecOpts<DB>,
+    ) -> Result<bool> {
+        unimplemented!()
+    }
+
+    /// Unassign a role from a volunteer for a project cycle.
+    ///
+    /// Returns `false` if the volunteer did not have the role for the cycle.
+    ///
+    /// * `project_cycle_id`: The ID of the project cycle the role is held in
+    /// * `volunteer_id`: The ID of the volunteer
+    /// * `role_id`: The ID of the role
+    /// * `exec_opts`: Execution options for the query
+    async fn unassign_team_role(
+        &self,
+        project_cycle_id: Uuid,
+        volunteer_id: Uuid,
+        role_id: Uuid,
+        exec_opts: &mut ExecOpts<DB>,
+    ) -> Result<bool> {
+        unimplemented!()
+    }
+
+    /// Fetch the volunteers who have been assigned a role, ordered by name.
+    ///
+    /// * `role_id`: The ID of the role
+    /// * `project_cycle_id`: If present, only volunteers holding the role in this project cycle
+    ///   are fetched
+    /// * `exec_opts`: Execution options for the query
+    async fn fetch_volunteers_by_team_role(
+        &self,
+        role_id: Uuid,
+        project_cycle_id: Option<Uuid>,
+        exec_opts: &mut ExecOpts<DB>,
+    ) -> Result<Vec<VolunteerDetails>> {
+        unimplemented!()
+    }
+}
+
+#[async_trait]
+impl QueryTeamRoles<Postgres> for PgBackend {
+    async fn create_team_role(
+        &self,
+        data: CreateTeamRole,
+        exec_opts: &mut ExecOpts<Postgres>,
+    ) -> Result<Uuid> {
+        async fn exec(data: CreateTeamRole, tx: &mut Transaction<'_, Postgres>) -> Result<Uuid> {
+            let query = include_str!("queries/team_roles/create_team_role.sql");
+
+            let id = sqlx::query_scalar::<_, Uuid>(query)
+                .bind(data.name)
+                .bind(data.description)
+                .fetch_one(&mut **tx)
+                .await?;
+            Ok(id)
+        }
+
+        exec_with_tx!(self, exec_opts, exec, data)
+    }
+
+    async fn fetch_team_roles(&self, exec_opts: &mut ExecOpts<Postgres>) -> Result<Vec<TeamRole>> {
+        async fn exec(tx: &mut Transaction<'_, Postgres>) -> Result<Vec<TeamRole>> {
+            let query = include_str!("queries/team_roles/fetch_team_roles.sql");
+
+            let roles = sqlx::query_as::<_, TeamRole>(query)
+                .fetch_all(&mut **tx)
+                .await
+                .context("error fetching team roles")?;
+            Ok(roles)
+        }
+
+        exec_with_tx!(self, exec_opts, exec)
+    }
+
+    async fn fetch_team_role_by_id(
+        &self,
+        id: Uuid,
+        exec_opts: &mut ExecOpts<Postgres>,
+    ) -> Result<Option<TeamRole>> {
+        async fn exec(id: Uuid, tx: &mut Transaction<'_, Postgres>) -> Result<Option<TeamRole>> {
+            let query = include_str!("queries/team_roles/fetch_team_role_by_id.sql");
+
+            let role = sqlx::query_as::<_, TeamRole>(query)
+                .bind(id)
+                .fetch_optional(&mut **tx)
+                .await
+                .context("error fetching team role by id")?;
+            Ok(role)
+        }
+
+        exec_with_tx!(self, exec_opts, exec, id)
+    }
+
+    async fn edit_team_role(
+        &self,
+        id: Uuid,
+        data: EditTeamRole,
+        exec_opts: &mut ExecOpts<Postgres>,
+    ) -> Result<Option<TeamRole>> {
+        async fn exec(
+            id: Uuid,
+            data: EditTeamRole,
+            tx: &mut Transaction<'_, Postgres>,
+        ) -> Result<Option<TeamRole>> {
+            let query = include_str!("queries/team_roles/edit_team_role.sql");
+
+            let role = sqlx::query_as::<_, TeamRole>(query)
+                .bind(id)
+                .bind(data.name)
+                .bind(data.description)
+                .fetch_optional(&mut **tx)
+                .await?;
+            Ok(role)
+        }
+
+        exec_with_tx!(self, exec_opts, exec, id, data)
+    }
+
+    async fn delete_team_role(&self, id: Uuid, exec_opts: &mut ExecOpts<Postgres>) -> Result<()> {
+        async fn exec(id: Uuid, tx: &mut Transaction<'_, Postgres>) -> Result<()> {
+            let query = include_str!("queries/team_roles/delete_team_role.sql");
+            sqlx::query(query)
+                .bind(id)
+                .execute(&mut **tx)
+                .await
+                .context("error deleting team role")?;
+            Ok(())
+        }
+
+        exec_with_tx!(self, exec_opts, exec, id)
+    }
+
+    async fn assign_team_role(
+        &self,
+        project_cycle_id: Uuid,
+        volunteer_id: Uuid,
+        role_id: Uuid,
+        exec_opts: &mut ExecOpts<Postgres>,
+    ) -> Result<bool> {
+        async fn exec(
+            project_cycle_id: Uuid,
+            volunteer_id: Uuid,
+            role_id: Uuid,
+            tx: &mut Transaction<'_, Postgres>,
+        ) -> Result<bool> {
+            let query = include_str!("queries/team_roles/assign_team_role.sql");
+
+            let res = sqlx::query(query)
+                .bind(project_cycle_id)
+                .bind(volunteer_id)
+                .bind(role_id)
+                .execute(&mut **tx)
+                .await
+                .context("error assigning team role")?;
+            Ok(res.rows_affected() > 0)
+        }
+
+        exec_with_tx!(self, exec_opts, exec, project_cycle_id, volunteer_id, role_id)
+    }
+
+    async fn unassign_team_role(
+        &self,
+        project_cycle_id: Uuid,
+        volunteer_id: Uuid,
+        role_id: Uuid,
+        exec_opts: &mut ExecOpts<Postgres>,
+    ) -> Result<bool> {
+        async fn exec(
+            project_cycle_id: Uuid,
+            volunteer_id: Uuid,
+            role_id: Uuid,
+            tx: &mut Transaction<'_, Postgres>,
+        ) -> Result<bool> {
+            let query = include_str!("queries/team_roles/unassign_team_role.sql");
+
+            let res = sqlx::query(query)
+                .bind(project_cycle_id)
+                .bind(volunteer_id)
+                .bind(role_id)
+                .execute(&mut **tx)
+                .await
+                .context("error unassigning team role")?;
+            Ok(res.rows_affected() > 0)
+        }
+
+        exec_with_tx!(self, exec_opts, exec, project_cycle_id, volunteer_id, role_id)
+    }
+
+    async fn fetch_volunteers_by_team_role(
+        &self,
+        role_id: Uuid,
+        project_cycle_id: Option<Uuid>,
+        exec_opts: &mut ExecOpts<Postgres>,
+    ) -> Result<Vec<VolunteerDetails>> {
+        async fn exec(
+            role_id: Uuid,
+            project_cycle_id: Option<Uuid>,
+            tx: &mut Transaction<'_, Postgres>,
+        ) -> Result<Vec<VolunteerDetails>> {
+            let query = include_str!("queries/team_roles/fetch_volunteers_by_team_role.sql");
+
+            let volunteers = sqlx::query_as::<_, VolunteerDetails>(query)
+                .bind(role_id)
+                .bind(project_cycle_id)
+                .fetch_all(&mut **tx)
+                .await
+                .context("error fetching volunteers by team role")?;
+            Ok(volunteers)
+        }
+
+        exec_with_tx!(self, exec_opts, exec, role_id, project_cycle_id)
+    }
+}
@@ -0,0 +1,84 @@
+use anyhow::Result;
+use serde_json::json;
+use sqlx::PgPool;
+use uuid::uuid;
+
+use crate::services::storage::audit::{AuditFilterBuilder, CreateAuditEventBuilder, QueryAudit};
+use crate::services::storage::pagination::PageOptions;
+use crate::services::storage::types::{AuditAction, AuditOutcome};
+use crate::services::storage::{ExecOptsBuilder, PgBackend};
+
+#[sqlx::test(fixtures("setup"))]
+pub async fn test_audit_events(pool: PgPool) -> Result<()> {
+    let storage = PgBackend { pool };
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+    let spring_2024 = uuid!("0e12b846-4de5-432e-8137-1bc2c92827b3");
+    let federer = uuid!("9edc52d8-8cc7-4d44-80c1-7efcce246e90");
+
+    let data = CreateAuditEventBuilder::default()
+        .principal("admin@example.com")
+        .action(AuditAction::EditCycle)
+        .target_ids(vec![spring_2024])
+        .summary(json!({ "fields": ["name"] }))
+        .build()?;
+    storage.create_audit_event(data, &mut exec_opts).await?;
+
+    let data = CreateAuditEventBuilder::default()
+        .principal("admin@example.com")
+        .action(AuditAction::DeleteVolunteer)
+        .target_ids(vec![federer])
+        .outcome(AuditOutcome::Failed)
+        .error("volunteer is still assigned to a project")
+        .build()?;
+    storage.create_audit_event(data, &mut exec_opts).await?;
+
+    let data = CreateAuditEventBuilder::default()
+        .principal("staff@example.com")
+        .action(AuditAction::EditVolunteer)
+        .target_ids(vec![federer, spring_2024])
+        .build()?;
+    let newest = storage.create_audit_event(data, &mut exec_opts).await?;
+
+    let page = storage
+        .fetch_audit_events(Default::default(), PageOptions::new(None, None), &mut exec_opts)
+        .await?;
+    assert_eq!(page.items.len(), 3);
+    assert_eq!(page.items[0].id, newest);
+    assert_eq!(page.items[0].action, "volunteer.edit");
+
+    let filter = AuditFilterBuilder::default().target_id(federer).build()?;
+    let page =
+        storage.fetch_audit_events(filter, PageOptions::new(None, None), &mut exec_opts).await?;
+    assert_eq!(page.items.len(), 2);
+
+    let filter = AuditFilterBuilder::default()
+        .principal("admin@example.com")
+        .outcome(AuditOutcome::Failed)
+        .build()?;
+    let page =
+        storage.fetch_audit_events(filter, PageOptions::new(None, None), &mut exec_opts).await?;
+    assert_eq!(page.items.len(), 1);
+    assert_eq!(page.items[0].error.as_deref(), Some("volunteer is still assigned to a project"));
+
+    let filter = AuditFilterBuilder::default().action(AuditAction::EditCycle).build()?;
+    let page =
+        storage.fetch_audit_events(filter, PageOptions::new(None, None), &mut exec_opts).await?;
+    assert_eq!(page.items.len(), 1);
+    assert_eq!(page.items[0].summary, json!({ "fields": ["name"] }));
+
+    let first = storage
+        .fetch_audit_events(Default::default(), PageOptions::new(None, Some(2)), &mut exec_opts)
+        .await?;
+    assert_eq!(first.items.len(), 2);
+    let second = storage
+        .fetch_audit_events(
+            Default::default(),
+            PageOptions::new(first.next_cursor, Some(2)),
+            &mut exec_opts,
+        )
+        .await?;
+    assert_eq!(second.items.len(), 1);
+    assert!(second.next_cursor.is_none());
+
+    Ok(())
+}
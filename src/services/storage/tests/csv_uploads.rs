@@ -0,0 +1,27 @@
+use anyhow::Result;
+use sqlx::PgPool;
+
+use crate::services::storage::csv_uploads::QueryCsvUploads;
+use crate::services::storage::types::CsvImportFiles;
+use crate::services::storage::{ExecOptsBuilder, PgBackend};
+
+#[sqlx::test(fixtures("setup"))]
+pub async fn test_manage_csv_uploads(pool: PgPool) -> Result<()> {
+    let storage = PgBackend { pool };
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+
+    let files = CsvImportFiles {
+        volunteers: "FirstName,LastName,Email\nAda,Lovelace,ada@example.com\n".to_owned(),
+        mentors: "FirstName,LastName,Email\n".to_owned(),
+        nonprofits: String::new(),
+    };
+    let id = storage.create_csv_upload(files.clone(), &mut exec_opts).await?;
+
+    let upload = storage.fetch_csv_upload(id, &mut exec_opts).await?;
+    assert_eq!(upload, Some(files));
+
+    storage.delete_csv_upload(id, &mut exec_opts).await?;
+    assert!(storage.fetch_csv_upload(id, &mut exec_opts).await?.is_none());
+
+    Ok(())
+}
@@ -3,7 +3,7 @@ use sqlx::PgPool;
 use uuid::uuid;
 
 use crate::services::storage::{
-    cycles::{CreateCycleBuilder, EditCycleBuilder, QueryCycles},
+    cycles::{CreateCycleBuilder, EditCycle, EditCycleBuilder, QueryCycles},
     ExecOptsBuilder, PgBackend,
 };
 
@@ -38,8 +38,9 @@ pub async fn test_fetch_cycle_by_id(pool: PgPool) -> Result<()> {
     let cycle_id = uuid!("0e12b846-4de5-432e-8137-1bc2c92827b3");
     let cycle = storage
         .fetch_cycle_by_id(cycle_id, &mut ExecOptsBuilder::default().build()?)
-        .await?;
-    dbg!(cycle);
+        .await?
+        .expect("cycle not found");
+    assert_eq!(cycle.name, "Spring 2024");
     Ok(())
 }
 
@@ -53,9 +54,31 @@ pub async fn test_edit_cycle(pool: PgPool) -> Result<()> {
         .archived(true)
         .build()?;
 
-    storage
+    let cycle = storage
         .edit_cycle(cycle_id, data, &mut ExecOptsBuilder::default().build()?)
+        .await?
+        .expect("cycle not found");
+    assert_eq!(cycle.name, "Changed");
+    assert_eq!(cycle.description.as_deref(), Some("changed"));
+    assert!(cycle.archived);
+
+    // Fields which are not set are left unchanged
+    let data = EditCycleBuilder::default().archived(false).build()?;
+    let cycle = storage
+        .edit_cycle(cycle_id, data, &mut ExecOptsBuilder::default().build()?)
+        .await?
+        .expect("cycle not found");
+    assert_eq!(cycle.name, "Changed");
+    assert!(!cycle.archived);
+
+    let missing = storage
+        .edit_cycle(
+            uuid!("00000000-0000-0000-0000-000000000000"),
+            EditCycle::default(),
+            &mut ExecOptsBuilder::default().build()?,
+        )
         .await?;
+    assert!(missing.is_none());
 
     Ok(())
 }
@@ -0,0 +1,66 @@
+-- Mirrors ../setup.sql for the SQLite schema: IDs are blobs and arrays are JSON.
+
+-- Insert into project_cycles
+insert into project_cycles(id, created_at, name, description, archived)
+  values (X'0e12b8464de5432e81371bc2c92827b3', strftime('%Y-%m-%dT%H:%M:%fZ', 'now'), 'Spring 2024', 'Spring 2024 project cycle', false),
+(X'76ed64a0d88f41489b02331ea888d5d1', strftime('%Y-%m-%dT%H:%M:%fZ', 'now'), 'Fall 2024', 'Fall 2024 project cycle', false);
+
+-- Insert into volunteers
+insert into volunteers(id, created_at, project_cycle_id, first_name, last_name, email, phone, volunteer_gender, volunteer_ethnicity, volunteer_age_range, university, lgbt, country, us_state, fli, student_stage, majors, minors, hear_about)
+  values (X'1b1b5e16d0d64ad18fdc80df15b18b67', strftime('%Y-%m-%dT%H:%M:%fZ', 'now'), X'0e12b8464de5432e81371bc2c92827b3', 'Rafael', 'Nadal', 'rafael.nadal@gmail.com', '123-456-7890', 'man', '["White or Caucasian"]', '35-39', '["Stanford University"]', 'no', 'Spain', null, '["Prefer not to say"]', 'recent_graduate', '["Computer Science"]', '["Mathematics"]', '["Linkedin"]'),
+(X'9edc52d88cc74d4480c17efcce246e90', strftime('%Y-%m-%dT%H:%M:%fZ', 'now'), X'0e12b8464de5432e81371bc2c92827b3', 'Roger', 'Federer', 'roger.federer@gmail.com', '123-456-7891', 'man', '["White or Caucasian"]', '40-44', '["Stanford University"]', 'no', 'Switzerland', null, '["Prefer not to say"]', 'recent_graduate', '["Computer Science"]', '[]', '["Linkedin"]'),
+(X'5e7b3f352b8446e78b7b73e2716d42c9', strftime('%Y-%m-%dT%H:%M:%fZ', 'now'), X'76ed64a0d88f41489b02331ea888d5d1', 'Novak', 'Djokovic', 'novak.djokovic@gmail.com', '123-456-7892', 'man', '["White or Caucasian"]', '40-44', '["Harvard University"]', 'no', 'Serbia', null, '["Prefer not to say"]', 'recent_graduate', '["Computer Science"]', '[]', '["Linkedin"]'),
+(X'0ef67e25543c4f0d9a968cb71b3c0f60', strftime('%Y-%m-%dT%H:%M:%fZ', 'now'), X'0e12b8464de5432e81371bc2c92827b3', 'Andy', 'Murray', 'andy.murray@gmail.com', '123-456-7893', 'man', '["White or Caucasian"]', '35-39', '["Caltech", "University of California, Berkeley"]', 'ally', 'UK', null, '["Prefer not to say"]', 'recent_graduate', '["Computer Science"]', '[]', '["Linkedin"]'),
+(X'fc32f48544974ee9a162a5d12d6e737f', strftime('%Y-%m-%dT%H:%M:%fZ', 'now'), X'0e12b8464de5432e81371bc2c92827b3', 'Stan', 'Wawrinka', 'stan.wawrinka@gmail.com', '123-456-7894', 'man', '["White or Caucasian"]', '35-39', '["ETH Zurich"]', 'no', 'Switzerland', null, '["Prefer not to say"]', 'recent_graduate', '["Electrical Engineering"]', '["Mathematics"]', '["Linkedin"]'),
+(X'61596073faf94cb5b613aa6b0ab86bb2', strftime('%Y-%m-%dT%H:%M:%fZ', 'now'), X'0e12b8464de5432e81371bc2c92827b3', 'Dominic', 'Thiem', 'dominic.thiem@gmail.com', '123-456-7895', 'man', '["White or Caucasian"]', '25-29', '["Massachusetts Institute of Technology"]', 'no', 'Austria', null, '["Prefer not to say"]', 'recent_graduate', '["Mathematics"]', '["Data Science"]', '["Linkedin"]');
+
+-- Insert into volunteer_team_roles
+insert into volunteer_team_roles(project_cycle_id, volunteer_id, role_id)
+  values (X'0e12b8464de5432e81371bc2c92827b3', X'1b1b5e16d0d64ad18fdc80df15b18b67',(
+      select
+        id
+      from
+        team_roles
+      where
+        name = 'product_manager')),
+(X'0e12b8464de5432e81371bc2c92827b3',
+    X'9edc52d88cc74d4480c17efcce246e90',
+(
+      select
+        id
+      from
+        team_roles
+      where
+        name = 'engineer'));
+
+-- Insert into mentors
+insert into mentors(id, created_at, project_cycle_id, first_name, last_name, email, phone, company, job_title, country, us_state, years_experience, experience_level, prior_mentor, prior_mentee, prior_student, university, hear_about)
+  values (X'fa8377c81c0d4f4e9a2b2c29f2737e0d', strftime('%Y-%m-%dT%H:%M:%fZ', 'now'), X'0e12b8464de5432e81371bc2c92827b3', 'John', 'McEnroe', 'john.mcenroe@gmail.com', '123-456-7893', 'Team World', 'Coach', 'United States', 'New York', '21+', 'senior_or_executive', false, false, false, '["Stanford University"]', '["Linkedin"]'),
+(X'ab839d8880c547f8835a1abbe269c7f8', strftime('%Y-%m-%dT%H:%M:%fZ', 'now'), X'76ed64a0d88f41489b02331ea888d5d1', 'Bjorn', 'Borg', 'bjorn.borg@gmail.com', '123-456-7894', 'Team Europe', 'Coach', 'Sweden', null, '21+', 'senior_or_executive', false, false, false, '["Harvard University"]', '["Linkedin"]');
+
+-- Insert into nonprofit_clients
+insert into nonprofit_clients(id, created_at, project_cycle_id, representative_first_name, representative_last_name, representative_job_title, email, email_cc, phone, org_name, project_name, org_website, country_hq, us_state_hq, address, size, impact_causes)
+  values (X'bb9b7fa572834b7382e1c7244e47421d', strftime('%Y-%m-%dT%H:%M:%fZ', 'now'), X'0e12b8464de5432e81371bc2c92827b3', 'Petros', 'Sampras', 'CEO', 'pete.sampras@gmail.com', null, '123-456-7895', 'PeteOrg', 'PeteProject', 'www.peteorg.org', 'USA', null, '99999 Random Way', '21-50', '["Global relations", "Other"]'),
+(X'dce8d3c38f2d4f3e80d3c1633f5282e2', strftime('%Y-%m-%dT%H:%M:%fZ', 'now'), X'76ed64a0d88f41489b02331ea888d5d1', 'Andre', 'Agassi', 'President', 'andre.agassi@gmail.com', null, '123-456-7896', 'AgassiOrg', 'AgassiProject', 'www.agassiorg.org', 'USA', null, '10000 Random Street', '101-500', '["Career & professional development"]');
+
+-- Insert into client_volunteers
+insert into client_volunteers(created_at, volunteer_id, client_id, project_cycle_id, currently_active)
+  values (strftime('%Y-%m-%dT%H:%M:%fZ', 'now'), X'1b1b5e16d0d64ad18fdc80df15b18b67', X'bb9b7fa572834b7382e1c7244e47421d', X'0e12b8464de5432e81371bc2c92827b3', true),
+(strftime('%Y-%m-%dT%H:%M:%fZ', 'now'), X'9edc52d88cc74d4480c17efcce246e90', X'bb9b7fa572834b7382e1c7244e47421d', X'0e12b8464de5432e81371bc2c92827b3', true),
+(strftime('%Y-%m-%dT%H:%M:%fZ', 'now'), X'5e7b3f352b8446e78b7b73e2716d42c9', X'dce8d3c38f2d4f3e80d3c1633f5282e2', X'76ed64a0d88f41489b02331ea888d5d1', true);
+
+-- Insert into client_mentors
+insert into client_mentors(created_at, mentor_id, client_id, project_cycle_id)
+  values (strftime('%Y-%m-%dT%H:%M:%fZ', 'now'), X'fa8377c81c0d4f4e9a2b2c29f2737e0d', X'bb9b7fa572834b7382e1c7244e47421d', X'0e12b8464de5432e81371bc2c92827b3'),
+(strftime('%Y-%m-%dT%H:%M:%fZ', 'now'), X'ab839d8880c547f8835a1abbe269c7f8', X'dce8d3c38f2d4f3e80d3c1633f5282e2', X'76ed64a0d88f41489b02331ea888d5d1');
+
+-- Insert into volunteer_mentors
+insert into volunteer_mentors(mentor_id, volunteer_id, project_cycle_id)
+  values (X'fa8377c81c0d4f4e9a2b2c29f2737e0d', X'1b1b5e16d0d64ad18fdc80df15b18b67', X'0e12b8464de5432e81371bc2c92827b3'),
+(X'fa8377c81c0d4f4e9a2b2c29f2737e0d', X'9edc52d88cc74d4480c17efcce246e90', X'0e12b8464de5432e81371bc2c92827b3'),
+(X'ab839d8880c547f8835a1abbe269c7f8', X'5e7b3f352b8446e78b7b73e2716d42c9', X'76ed64a0d88f41489b02331ea888d5d1');
+
+insert into jobs(id, project_cycle_id, label, details, status)
+  values (X'bc080e0d8b1446e092684bbb370035ec', X'0e12b8464de5432e81371bc2c92827b3', 'Import base', '{"jobType": "airtable_import_base", "baseId": "appAasdawef"}', 'pending'),
+(X'413eed733c6f456ab9f0ae72d136c742', X'76ed64a0d88f41489b02331ea888d5d1', 'Export Users', '{"jobType": "export_users", "error": "Error serializing X at line Y", "export_destination": "google_workspace"}', 'error');
+
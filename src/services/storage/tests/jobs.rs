@@ -1,10 +1,18 @@
 use anyhow::Result;
+use chrono::{Duration, Utc};
 use sqlx::PgPool;
 use uuid::uuid;
 
 use crate::services::storage::{
-    jobs::{CreateJob, EditJobBuilder, QueryJobs, UpdateJobStatus},
-    types::{JobData, JobDetails, JobStatus, JobType},
+    jobs::{
+        CreateJob, CreateJobItemBuilder, EditJobBuilder, JobFilter, JobFilterBuilder, QueryJobs,
+        UpdateJobItemBuilder, UpdateJobStatus,
+    },
+    pagination::PageOptionsBuilder,
+    types::{
+        JobData, JobDetails, JobItemKind, JobItemStatus, JobStatus, JobType, SyncCounts, SyncDiff,
+        WriteBackFailure, WriteBackReport,
+    },
     ExecOptsBuilder, PgBackend,
 };
 
@@ -22,8 +30,16 @@ pub async fn test_create_job(pool: PgPool) -> Result<()> {
                 data: JobDetails {
                     job_type: JobType::AirtableImportBase,
                     error: None,
+                    summary: None,
+                    diff: None,
+                    write_back: None,
                     data: JobData::AirtableImportBase {
                         base_id: "appS5z0uqz4l0IJvP".to_owned(),
+                        name: Some("Test".to_owned()),
+                        description: Some("Test".to_owned()),
+                        principal: None,
+                        project_cycle_id: None,
+                        mapping_profile_id: None,
                     },
                 },
             },
@@ -43,7 +59,7 @@ pub async fn test_fetch_job(pool: PgPool) -> Result<()> {
 
     let mut exec_opts = ExecOptsBuilder::default().build()?;
 
-    let job1 = storage.fetch_job(job_id1, &mut exec_opts).await?;
+    let job1 = storage.fetch_job(job_id1, &mut exec_opts).await?.expect("job not found");
     dbg!(&job1);
 
     let details = serde_json::from_value::<JobDetails>(job1.details)?;
@@ -60,18 +76,13 @@ pub async fn test_update_job_status(pool: PgPool) -> Result<()> {
     let storage = PgBackend { pool };
     let job_id1 = uuid!("bc080e0d-8b14-46e0-9268-4bbb370035ec");
 
-    let data = UpdateJobStatus {
-        status: JobStatus::Error,
-        error: Some("asdf".to_string()),
-    };
+    let data = UpdateJobStatus { status: JobStatus::Error, error: Some("asdf".to_string()) };
 
     let mut exec_opts = ExecOptsBuilder::default().build()?;
     // let job = storage.fetch_job(job_id1, &mut exec_opts).await?;
     // dbg!(&job);
 
-    storage
-        .update_job_status(job_id1, data, &mut exec_opts)
-        .await?;
+    storage.update_job_status(job_id1, data, &mut exec_opts).await?;
     let job = storage.fetch_job(job_id1, &mut exec_opts).await?;
     dbg!(&job);
 
@@ -95,3 +106,277 @@ pub async fn test_edit_job(pool: PgPool) -> Result<()> {
 
     Ok(())
 }
+
+#[sqlx::test(fixtures("setup"))]
+pub async fn test_claim_job(pool: PgPool) -> Result<()> {
+    let storage = PgBackend { pool };
+    let pending_job_id = uuid!("bc080e0d-8b14-46e0-9268-4bbb370035ec");
+
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+
+    let job = storage.claim_job("worker-1", 60, &mut exec_opts).await?;
+    dbg!(&job);
+
+    let job = job.expect("expected to claim the pending job");
+    assert_eq!(job.id, pending_job_id);
+    assert_eq!(job.status, JobStatus::Running);
+
+    // The only pending job has been claimed
+    let job = storage.claim_job("worker-2", 60, &mut exec_opts).await?;
+    assert!(job.is_none());
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("setup"))]
+pub async fn test_heartbeat_job(pool: PgPool) -> Result<()> {
+    let storage = PgBackend { pool };
+
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+
+    let job = storage.claim_job("worker-1", 60, &mut exec_opts).await?.expect("no pending job");
+
+    assert!(storage.heartbeat_job(job.id, "worker-1", 60, &mut exec_opts).await?);
+    assert!(!storage.heartbeat_job(job.id, "worker-2", 60, &mut exec_opts).await?);
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("setup"))]
+pub async fn test_requeue_expired_jobs(pool: PgPool) -> Result<()> {
+    let storage = PgBackend { pool };
+
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+
+    // A lease of 0 seconds expires immediately, as if the worker had died
+    let job = storage.claim_job("worker-1", 0, &mut exec_opts).await?.expect("no pending job");
+
+    let requeued = storage.requeue_expired_jobs(3, &mut exec_opts).await?;
+    assert_eq!(requeued, vec![job.id]);
+
+    let job = storage.fetch_job(job.id, &mut exec_opts).await?.expect("job not found");
+    assert_eq!(job.status, JobStatus::Pending);
+
+    // The worker which lost the lease can no longer extend it
+    assert!(!storage.heartbeat_job(job.id, "worker-1", 60, &mut exec_opts).await?);
+
+    // Once a job has used up its attempts, it is marked as errored instead
+    storage.claim_job("worker-2", 0, &mut exec_opts).await?.expect("no pending job");
+    storage.requeue_expired_jobs(2, &mut exec_opts).await?;
+
+    let job = storage.fetch_job(job.id, &mut exec_opts).await?.expect("job not found");
+    dbg!(&job);
+    assert_eq!(job.status, JobStatus::Error);
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("setup"))]
+pub async fn test_job_items(pool: PgPool) -> Result<()> {
+    let storage = PgBackend { pool };
+    let job_id = uuid!("bc080e0d-8b14-46e0-9268-4bbb370035ec");
+    let volunteer_id = uuid!("1b1b5e16-d0d6-4ad1-8fdc-80df15b18b67");
+
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+
+    let items = vec![
+        CreateJobItemBuilder::default()
+            .kind(JobItemKind::Volunteer)
+            .item_key(volunteer_id.to_string())
+            .entity_id(volunteer_id)
+            .label("Rafael Nadal")
+            .build()?,
+        CreateJobItemBuilder::default()
+            .kind(JobItemKind::Nonprofit)
+            .item_key("Develop for Good")
+            .status(JobItemStatus::Skipped)
+            .build()?,
+    ];
+    storage.batch_create_job_items(job_id, items, &mut exec_opts).await?;
+
+    // Recording an item twice leaves the first record untouched
+    let items = vec![CreateJobItemBuilder::default()
+        .kind(JobItemKind::Nonprofit)
+        .item_key("Develop for Good")
+        .status(JobItemStatus::Failed)
+        .build()?];
+    storage.batch_create_job_items(job_id, items, &mut exec_opts).await?;
+
+    storage
+        .update_job_item(
+            job_id,
+            JobItemKind::Volunteer,
+            &volunteer_id.to_string(),
+            UpdateJobItemBuilder::default()
+                .status(JobItemStatus::Failed)
+                .error("asdf".to_owned())
+                .build()?,
+            &mut exec_opts,
+        )
+        .await?;
+
+    let items = storage.fetch_job_items(job_id, &mut exec_opts).await?;
+    dbg!(&items);
+    assert_eq!(items.len(), 2);
+
+    let volunteer = items.iter().find(|item| item.kind == JobItemKind::Volunteer).unwrap();
+    assert_eq!(volunteer.status, JobItemStatus::Failed);
+    assert_eq!(volunteer.entity_id, Some(volunteer_id));
+    assert_eq!(volunteer.error.as_deref(), Some("asdf"));
+
+    let nonprofit = items.iter().find(|item| item.kind == JobItemKind::Nonprofit).unwrap();
+    assert_eq!(nonprofit.status, JobItemStatus::Skipped);
+
+    let counts = storage.fetch_job_item_counts(job_id, &mut exec_opts).await?;
+    dbg!(&counts);
+    assert_eq!(counts.len(), 2);
+    assert!(counts.iter().all(|c| c.count == 1));
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("setup"))]
+pub async fn test_cancel_job(pool: PgPool) -> Result<()> {
+    let storage = PgBackend { pool };
+    let pending_job_id = uuid!("bc080e0d-8b14-46e0-9268-4bbb370035ec");
+    let errored_job_id = uuid!("413eed73-3c6f-456a-b9f0-ae72d136c742");
+
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+
+    assert!(storage.cancel_job(pending_job_id, &mut exec_opts).await?);
+    assert!(!storage.cancel_job(errored_job_id, &mut exec_opts).await?);
+
+    let job = storage.fetch_job(pending_job_id, &mut exec_opts).await?.expect("job not found");
+    assert_eq!(job.status, JobStatus::Cancelled);
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("setup"))]
+pub async fn test_request_job_cancellation(pool: PgPool) -> Result<()> {
+    let storage = PgBackend { pool };
+    let pending_job_id = uuid!("bc080e0d-8b14-46e0-9268-4bbb370035ec");
+
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+
+    // Only a running job can be asked to stop
+    assert!(!storage.request_job_cancellation(pending_job_id, &mut exec_opts).await?);
+
+    let job = storage.claim_job("worker-1", 60, &mut exec_opts).await?.expect("no pending job");
+    assert_eq!(job.id, pending_job_id);
+    assert!(!storage.is_job_cancellation_requested(job.id, &mut exec_opts).await?);
+
+    assert!(storage.request_job_cancellation(job.id, &mut exec_opts).await?);
+    assert!(storage.is_job_cancellation_requested(job.id, &mut exec_opts).await?);
+
+    // The request doesn't stop the worker from holding the job until it stops
+    assert!(storage.heartbeat_job(job.id, "worker-1", 60, &mut exec_opts).await?);
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("setup"))]
+pub async fn test_mark_job_cancelled(pool: PgPool) -> Result<()> {
+    let storage = PgBackend { pool };
+
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+
+    let job = storage.claim_job("worker-1", 60, &mut exec_opts).await?.expect("no pending job");
+    storage.mark_job_cancelled(job.id, "Cancelled early".to_owned(), &mut exec_opts).await?;
+
+    let job = storage.fetch_job(job.id, &mut exec_opts).await?.expect("job not found");
+    dbg!(&job);
+    assert_eq!(job.status, JobStatus::Cancelled);
+
+    let details = serde_json::from_value::<JobDetails>(job.details)?;
+    assert_eq!(details.summary.as_deref(), Some("Cancelled early"));
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("setup"))]
+pub async fn test_set_job_diff(pool: PgPool) -> Result<()> {
+    let storage = PgBackend { pool };
+    let job_id = uuid!("bc080e0d-8b14-46e0-9268-4bbb370035ec");
+
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+
+    let diff = SyncDiff {
+        volunteers: SyncCounts { created: 2, updated: 1, unchanged: 3, deactivated: 1, failed: 0 },
+        links_added: 2,
+        ..Default::default()
+    };
+    storage.set_job_diff(job_id, diff.clone(), &mut exec_opts).await?;
+
+    let job = storage.fetch_job(job_id, &mut exec_opts).await?.expect("job not found");
+    let details = serde_json::from_value::<JobDetails>(job.details)?;
+    assert_eq!(details.diff, Some(diff));
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("setup"))]
+pub async fn test_set_job_write_back(pool: PgPool) -> Result<()> {
+    let storage = PgBackend { pool };
+    let job_id = uuid!("bc080e0d-8b14-46e0-9268-4bbb370035ec");
+
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+
+    let report = WriteBackReport {
+        written: 1,
+        failures: vec![WriteBackFailure {
+            volunteer_id: uuid!("9edc52d8-8cc7-4d44-80c1-7efcce246e90"),
+            workspace_email: "rogerfederer@developforgood.org".to_owned(),
+            error: "The volunteer was not imported from Airtable".to_owned(),
+        }],
+    };
+    storage.set_job_write_back(job_id, report.clone(), &mut exec_opts).await?;
+
+    let job = storage.fetch_job(job_id, &mut exec_opts).await?.expect("job not found");
+    let details = serde_json::from_value::<JobDetails>(job.details)?;
+    assert_eq!(details.write_back, Some(report));
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("setup"))]
+pub async fn test_fetch_jobs(pool: PgPool) -> Result<()> {
+    let storage = PgBackend { pool };
+    let pending_job_id = uuid!("bc080e0d-8b14-46e0-9268-4bbb370035ec");
+    let errored_job_id = uuid!("413eed73-3c6f-456a-b9f0-ae72d136c742");
+
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+
+    let page = PageOptionsBuilder::default().limit(1).build()?;
+    let first = storage.fetch_jobs(JobFilter::default(), page, &mut exec_opts).await?;
+    assert_eq!(first.items.len(), 1);
+    let cursor = first.next_cursor.expect("first page should have a next page");
+
+    let page = PageOptionsBuilder::default().cursor(cursor).limit(1).build()?;
+    let second = storage.fetch_jobs(JobFilter::default(), page, &mut exec_opts).await?;
+    assert_eq!(second.items.len(), 1);
+    assert_ne!(first.items[0].id, second.items[0].id);
+    assert!(second.next_cursor.is_none());
+
+    let filter = JobFilterBuilder::default().status(JobStatus::Error).build()?;
+    let page = PageOptionsBuilder::default().build()?;
+    let jobs = storage.fetch_jobs(filter, page, &mut exec_opts).await?;
+    assert_eq!(jobs.items.iter().map(|job| job.id).collect::<Vec<_>>(), vec![errored_job_id]);
+
+    let filter = JobFilterBuilder::default()
+        .job_type(JobType::AirtableImportBase)
+        .project_cycle_id(uuid!("0e12b846-4de5-432e-8137-1bc2c92827b3"))
+        .created_after(Utc::now() - Duration::days(1))
+        .build()?;
+    let page = PageOptionsBuilder::default().build()?;
+    let jobs = storage.fetch_jobs(filter, page, &mut exec_opts).await?;
+    assert_eq!(jobs.items.iter().map(|job| job.id).collect::<Vec<_>>(), vec![pending_job_id]);
+
+    let filter =
+        JobFilterBuilder::default().created_before(Utc::now() - Duration::days(1)).build()?;
+    let page = PageOptionsBuilder::default().build()?;
+    let jobs = storage.fetch_jobs(filter, page, &mut exec_opts).await?;
+    assert!(jobs.items.is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use anyhow::Result;
+use serde_json::json;
+use sqlx::PgPool;
+
+use crate::services::storage::mapping_profiles::{
+    CreateMappingProfileBuilder, QueryMappingProfiles,
+};
+use crate::services::storage::{ExecOptsBuilder, PgBackend};
+
+#[sqlx::test(fixtures("setup"))]
+pub async fn test_manage_mapping_profiles(pool: PgPool) -> Result<()> {
+    let storage = PgBackend { pool };
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+
+    assert!(storage.fetch_mapping_profiles(&mut exec_opts).await?.is_empty());
+
+    let mapping = json!({ "volunteers": { "table": "Students", "view": { "name": "Active" } } });
+    let data = CreateMappingProfileBuilder::default()
+        .name("Summer 2024")
+        .description(Some("The summer base renamed the volunteers table".to_owned()))
+        .mapping(mapping.clone())
+        .build()?;
+    let id = storage.create_mapping_profile(data, &mut exec_opts).await?;
+
+    let data =
+        CreateMappingProfileBuilder::default().name("Summer 2024").mapping(json!({})).build()?;
+    assert!(storage.create_mapping_profile(data, &mut exec_opts).await.is_err());
+
+    let profile =
+        storage.fetch_mapping_profile_by_id(id, &mut exec_opts).await?.expect("profile not found");
+    assert_eq!(profile.name, "Summer 2024");
+    assert_eq!(profile.mapping, mapping);
+
+    let profiles = storage.fetch_mapping_profiles(&mut exec_opts).await?;
+    assert_eq!(profiles, vec![profile]);
+
+    storage.delete_mapping_profile(id, &mut exec_opts).await?;
+    assert!(storage.fetch_mapping_profile_by_id(id, &mut exec_opts).await?.is_none());
+
+    Ok(())
+}
@@ -4,7 +4,7 @@ use uuid::uuid;
 
 use crate::services::storage::mentors::{CreateMentorBuilder, EditMentorBuilder, QueryMentors};
 use crate::services::storage::types::{
-    MentorExperienceLevel, MentorYearsExperience, VolunteerHearAbout,
+    MentorExperienceLevel, MentorYearsExperience, SyncOutcome, VolunteerHearAbout,
 };
 use crate::services::storage::{ExecOptsBuilder, PgBackend};
 
@@ -118,6 +118,38 @@ pub async fn test_edit_mentor(pool: PgPool) -> Result<()> {
         .build()?;
     storage.edit_mentor(mentor_id, data, &mut ExecOptsBuilder::default().build()?).await?;
 
+    // Fields which are not set are left unchanged
+    let data = EditMentorBuilder::default().phone("555-555-5555").build()?;
+    storage.edit_mentor(mentor_id, data, &mut ExecOptsBuilder::default().build()?).await?;
+
+    let mentor = storage
+        .fetch_mentor_by_id(mentor_id, &mut ExecOptsBuilder::default().build()?)
+        .await?
+        .expect("mentor not found");
+    assert_eq!(mentor.email, "johnny.mac@gmail.com");
+    assert_eq!(mentor.phone.as_deref(), Some("555-555-5555"));
+
+    // Taking another mentor's email is reported as a conflict
+    let data = EditMentorBuilder::default().email("bjorn.borg@gmail.com").build()?;
+    let err = storage
+        .edit_mentor(mentor_id, data, &mut ExecOptsBuilder::default().build()?)
+        .await
+        .unwrap_err();
+    let err = err.downcast_ref::<sqlx::Error>().and_then(|e| e.as_database_error());
+    assert!(err.is_some_and(|e| e.is_unique_violation()));
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("setup"))]
+pub async fn test_fetch_mentors_by_cycle(pool: PgPool) -> Result<()> {
+    let storage = PgBackend { pool };
+    let project_cycle_id = uuid!("76ed64a0-d88f-4148-9b02-331ea888d5d1");
+    let mentors = storage
+        .fetch_mentors_by_cycle(project_cycle_id, &mut ExecOptsBuilder::default().build()?)
+        .await?;
+    assert!(!mentors.is_empty());
+    assert!(mentors.iter().all(|m| m.project_cycle_id == project_cycle_id));
     Ok(())
 }
 
@@ -129,3 +161,51 @@ pub async fn test_delete_mentor(pool: PgPool) -> Result<()> {
     storage.delete_mentor(mentor_id, &mut ExecOptsBuilder::default().build()?).await?;
     Ok(())
 }
+
+#[sqlx::test(fixtures("setup"))]
+pub async fn test_sync_mentors(pool: PgPool) -> Result<()> {
+    let storage = PgBackend { pool };
+    let project_cycle_id = uuid!("0e12b846-4de5-432e-8137-1bc2c92827b3");
+    let client_id = uuid!("bb9b7fa5-7283-4b73-82e1-c7244e47421d");
+
+    let data = CreateMentorBuilder::default()
+        .first_name("Martina")
+        .last_name("Navratilova")
+        .email("martina.navratilova@gmail.com")
+        .phone("777-888-9999")
+        .company("Tennis Channel")
+        .job_title("Commentator")
+        .country("United States".to_string())
+        .years_experience(MentorYearsExperience::R21Plus)
+        .experience_level(MentorExperienceLevel::SeniorOrExecutive)
+        .prior_mentor(true)
+        .prior_mentee(false)
+        .prior_student(false)
+        .university(vec![])
+        .hear_about(vec![VolunteerHearAbout::OnlineAd])
+        .build()?;
+
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+    let synced = storage
+        .batch_sync_mentors(project_cycle_id, vec![("recMartina".to_owned(), data)], &mut exec_opts)
+        .await?;
+    assert_eq!(synced[0].2, SyncOutcome::Created);
+    let mentor_id = synced[0].1;
+
+    // McEnroe is no longer in the source, so he is deactivated and unlinked from PeteOrg
+    let deactivated = storage
+        .deactivate_missing_mentors(project_cycle_id, vec![mentor_id], &mut exec_opts)
+        .await?;
+    assert_eq!(deactivated, 1);
+
+    let changes = storage
+        .sync_mentor_nonprofit_links(project_cycle_id, vec![(mentor_id, client_id)], &mut exec_opts)
+        .await?;
+    assert_eq!(changes, (1, 1));
+
+    let mentor =
+        storage.fetch_mentor_by_id(mentor_id, &mut exec_opts).await?.expect("mentor not found");
+    assert_eq!(mentor.clients[0]["org_name"], "PeteOrg");
+
+    Ok(())
+}
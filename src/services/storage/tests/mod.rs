@@ -1,5 +1,11 @@
+mod audit;
+mod csv_uploads;
 mod cycles;
 mod jobs;
+mod mapping_profiles;
 mod mentors;
 mod nonprofits;
+mod sqlite;
+mod team_roles;
 mod volunteers;
+mod webhooks;
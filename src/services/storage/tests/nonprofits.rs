@@ -5,7 +5,7 @@ use uuid::{uuid, Uuid};
 use crate::services::storage::nonprofits::{
     CreateNonprofitBuilder, EditNonprofitBuilder, QueryNonprofits,
 };
-use crate::services::storage::types::{ClientSize, ImpactCause};
+use crate::services::storage::types::{ClientSize, ImpactCause, SyncOutcome};
 use crate::services::storage::{Acquire, ExecOptsBuilder, PgBackend};
 
 #[sqlx::test(fixtures("setup"))]
@@ -146,6 +146,29 @@ pub async fn test_edit_nonprofit(pool: PgPool) -> Result<()> {
 
     storage.edit_nonprofit(nonprofit_id, data, &mut exec_opts).await?;
 
+    // Fields which are not set are left unchanged
+    let data = EditNonprofitBuilder::default().org_website("www.sampras.org").build()?;
+    storage.edit_nonprofit(nonprofit_id, data, &mut exec_opts).await?;
+
+    let nonprofit =
+        storage.fetch_nonprofit_by_id(nonprofit_id, &mut exec_opts).await?.expect("not found");
+    assert_eq!(nonprofit.email_cc.as_deref(), Some("jimmy.connors@gmail.com"));
+    assert_eq!(nonprofit.org_website.as_deref(), Some("www.sampras.org"));
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("setup"))]
+pub async fn test_fetch_nonprofits_by_cycle(pool: PgPool) -> Result<()> {
+    let storage = PgBackend { pool };
+    let project_cycle_id = uuid!("76ed64a0-d88f-4148-9b02-331ea888d5d1");
+
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+    let nonprofits = storage.fetch_nonprofits_by_cycle(project_cycle_id, &mut exec_opts).await?;
+
+    assert!(!nonprofits.is_empty());
+    assert!(nonprofits.iter().all(|n| n.project_cycle_id == project_cycle_id));
+
     Ok(())
 }
 
@@ -158,3 +181,55 @@ pub async fn test_delete_nonprofit(pool: PgPool) -> Result<()> {
     storage.delete_nonprofit(nonprofit_id, &mut exec_opts).await?;
     Ok(())
 }
+
+#[sqlx::test(fixtures("setup"))]
+pub async fn test_sync_nonprofits(pool: PgPool) -> Result<()> {
+    let storage = PgBackend { pool };
+    let project_cycle_id = uuid!("0e12b846-4de5-432e-8137-1bc2c92827b3");
+    let pete_org_id = uuid!("bb9b7fa5-7283-4b73-82e1-c7244e47421d");
+
+    // PeteOrg was imported before external IDs were recorded, so it is matched by its names
+    let pete_org = CreateNonprofitBuilder::default()
+        .representative_first_name("Pete")
+        .representative_last_name("Sampras")
+        .representative_job_title("CEO")
+        .email("pete.sampras@gmail.com")
+        .phone("123-456-7895")
+        .org_name("PeteOrg")
+        .project_name("PeteProject")
+        .address("99999 Random Way")
+        .size(ClientSize::S21_50)
+        .impact_causes(vec![ImpactCause::GlobalRelations])
+        .build()?;
+
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+    let synced = storage
+        .batch_sync_nonprofits(
+            project_cycle_id,
+            vec![("recPeteOrg".to_owned(), pete_org.clone())],
+            &mut exec_opts,
+        )
+        .await?;
+    assert_eq!(synced, vec![("recPeteOrg".to_owned(), pete_org_id, SyncOutcome::Updated)]);
+
+    let nonprofit = storage
+        .fetch_nonprofit_by_id(pete_org_id, &mut exec_opts)
+        .await?
+        .expect("nonprofit not found");
+    assert_eq!(nonprofit.representative_first_name, "Pete");
+
+    let synced = storage
+        .batch_sync_nonprofits(
+            project_cycle_id,
+            vec![("recPeteOrg".to_owned(), pete_org)],
+            &mut exec_opts,
+        )
+        .await?;
+    assert_eq!(synced[0].2, SyncOutcome::Unchanged);
+
+    let deactivated =
+        storage.deactivate_missing_nonprofits(project_cycle_id, vec![], &mut exec_opts).await?;
+    assert_eq!(deactivated, 1);
+
+    Ok(())
+}
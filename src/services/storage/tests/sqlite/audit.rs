@@ -0,0 +1,56 @@
+use anyhow::Result;
+use serde_json::json;
+use sqlx::SqlitePool;
+use uuid::uuid;
+
+use crate::services::storage::audit::{AuditFilterBuilder, CreateAuditEventBuilder, QueryAudit};
+use crate::services::storage::pagination::PageOptions;
+use crate::services::storage::sqlite::SqliteBackend;
+use crate::services::storage::types::AuditAction;
+use crate::services::storage::ExecOptsBuilder;
+
+#[sqlx::test(
+    migrations = "migrations/sqlite",
+    fixtures(path = "../fixtures/sqlite", scripts("setup"))
+)]
+pub async fn test_audit_events(pool: SqlitePool) -> Result<()> {
+    let storage = SqliteBackend { pool };
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+    let spring_2024 = uuid!("0e12b846-4de5-432e-8137-1bc2c92827b3");
+    let federer = uuid!("9edc52d8-8cc7-4d44-80c1-7efcce246e90");
+
+    let data = CreateAuditEventBuilder::default()
+        .principal("admin@example.com")
+        .action(AuditAction::EditCycle)
+        .target_ids(vec![spring_2024])
+        .summary(json!({ "fields": ["name"] }))
+        .build()?;
+    storage.create_audit_event(data, &mut exec_opts).await?;
+
+    let data = CreateAuditEventBuilder::default()
+        .principal("staff@example.com")
+        .action(AuditAction::EditVolunteer)
+        .target_ids(vec![federer, spring_2024])
+        .build()?;
+    let newest = storage.create_audit_event(data, &mut exec_opts).await?;
+
+    let page = storage
+        .fetch_audit_events(Default::default(), PageOptions::new(None, Some(1)), &mut exec_opts)
+        .await?;
+    assert_eq!(page.items.len(), 1);
+    assert_eq!(page.items[0].id, newest);
+    assert_eq!(page.items[0].target_ids, vec![federer, spring_2024]);
+
+    let filter = AuditFilterBuilder::default().target_id(federer).build()?;
+    let page =
+        storage.fetch_audit_events(filter, PageOptions::new(None, None), &mut exec_opts).await?;
+    assert_eq!(page.items.len(), 1);
+
+    let filter = AuditFilterBuilder::default().action(AuditAction::EditCycle).build()?;
+    let page =
+        storage.fetch_audit_events(filter, PageOptions::new(None, None), &mut exec_opts).await?;
+    assert_eq!(page.items.len(), 1);
+    assert_eq!(page.items[0].summary, json!({ "fields": ["name"] }));
+
+    Ok(())
+}
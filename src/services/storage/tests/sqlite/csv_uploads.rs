@@ -0,0 +1,30 @@
+use anyhow::Result;
+use sqlx::SqlitePool;
+
+use crate::services::storage::csv_uploads::QueryCsvUploads;
+use crate::services::storage::sqlite::SqliteBackend;
+use crate::services::storage::types::CsvImportFiles;
+use crate::services::storage::ExecOptsBuilder;
+
+#[sqlx::test(
+    migrations = "migrations/sqlite",
+    fixtures(path = "../fixtures/sqlite", scripts("setup"))
+)]
+pub async fn test_csv_uploads(pool: SqlitePool) -> Result<()> {
+    let storage = SqliteBackend { pool };
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+
+    let files = CsvImportFiles {
+        volunteers: "FirstName,LastName,Email\nAda,Lovelace,ada@example.com\n".to_owned(),
+        ..Default::default()
+    };
+    let id = storage.create_csv_upload(files.clone(), &mut exec_opts).await?;
+
+    let upload = storage.fetch_csv_upload(id, &mut exec_opts).await?;
+    assert_eq!(upload, Some(files));
+
+    storage.delete_csv_upload(id, &mut exec_opts).await?;
+    assert!(storage.fetch_csv_upload(id, &mut exec_opts).await?.is_none());
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use anyhow::Result;
+use sqlx::SqlitePool;
+use uuid::uuid;
+
+use crate::services::storage::cycles::{CreateCycleBuilder, QueryCycles};
+use crate::services::storage::sqlite::SqliteBackend;
+use crate::services::storage::ExecOptsBuilder;
+
+#[sqlx::test(
+    migrations = "migrations/sqlite",
+    fixtures(path = "../fixtures/sqlite", scripts("setup"))
+)]
+pub async fn test_cycles(pool: SqlitePool) -> Result<()> {
+    let storage = SqliteBackend { pool };
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+
+    let cycle = storage
+        .fetch_cycle_by_id(uuid!("0e12b846-4de5-432e-8137-1bc2c92827b3"), &mut exec_opts)
+        .await?
+        .expect("cycle not found");
+    assert_eq!(cycle.name, "Spring 2024");
+
+    let data = CreateCycleBuilder::default()
+        .name("TestCycle")
+        .description("Description of test cycle")
+        .build()?;
+    let id = storage.create_cycle(data, &mut exec_opts).await?;
+
+    let cycles = storage.fetch_cycles(&mut exec_opts).await?;
+    assert_eq!(cycles.len(), 3);
+    assert!(cycles.iter().any(|cycle| cycle.id == id));
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use anyhow::Result;
+use chrono::{Duration, Utc};
+use sqlx::SqlitePool;
+use uuid::uuid;
+
+use crate::services::storage::jobs::{JobFilterBuilder, QueryJobs};
+use crate::services::storage::pagination::PageOptionsBuilder;
+use crate::services::storage::sqlite::SqliteBackend;
+use crate::services::storage::types::{JobStatus, JobType};
+use crate::services::storage::ExecOptsBuilder;
+
+#[sqlx::test(
+    migrations = "migrations/sqlite",
+    fixtures(path = "../fixtures/sqlite", scripts("setup"))
+)]
+pub async fn test_jobs(pool: SqlitePool) -> Result<()> {
+    let storage = SqliteBackend { pool };
+    let pending_job_id = uuid!("bc080e0d-8b14-46e0-9268-4bbb370035ec");
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+
+    let filter = JobFilterBuilder::default()
+        .job_type(JobType::AirtableImportBase)
+        .created_after(Utc::now() - Duration::days(1))
+        .build()?;
+    let page = PageOptionsBuilder::default().build()?;
+    let jobs = storage.fetch_jobs(filter, page, &mut exec_opts).await?;
+    assert_eq!(jobs.items.iter().map(|job| job.id).collect::<Vec<_>>(), vec![pending_job_id]);
+
+    let job = storage.claim_job("worker-1", 60, &mut exec_opts).await?.expect("no pending job");
+    assert_eq!(job.id, pending_job_id);
+    assert_eq!(job.status, JobStatus::Running);
+    assert!(storage.claim_job("worker-2", 60, &mut exec_opts).await?.is_none());
+
+    assert!(storage.heartbeat_job(job.id, "worker-1", 60, &mut exec_opts).await?);
+    assert!(!storage.heartbeat_job(job.id, "worker-2", 60, &mut exec_opts).await?);
+
+    assert!(!storage.is_job_cancellation_requested(job.id, &mut exec_opts).await?);
+    assert!(storage.request_job_cancellation(job.id, &mut exec_opts).await?);
+    assert!(storage.is_job_cancellation_requested(job.id, &mut exec_opts).await?);
+
+    storage.mark_job_errored(job.id, "something broke".to_owned(), &mut exec_opts).await?;
+    let job = storage.fetch_job(job.id, &mut exec_opts).await?.expect("job not found");
+    assert_eq!(job.status, JobStatus::Error);
+    assert_eq!(job.details["error"], "something broke");
+
+    Ok(())
+}
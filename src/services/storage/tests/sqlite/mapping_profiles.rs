@@ -0,0 +1,35 @@
+use anyhow::Result;
+use serde_json::json;
+use sqlx::SqlitePool;
+
+use crate::services::storage::mapping_profiles::{
+    CreateMappingProfileBuilder, QueryMappingProfiles,
+};
+use crate::services::storage::sqlite::SqliteBackend;
+use crate::services::storage::ExecOptsBuilder;
+
+#[sqlx::test(
+    migrations = "migrations/sqlite",
+    fixtures(path = "../fixtures/sqlite", scripts("setup"))
+)]
+pub async fn test_mapping_profiles(pool: SqlitePool) -> Result<()> {
+    let storage = SqliteBackend { pool };
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+
+    let mapping = json!({ "volunteers": { "table": "Students", "view": { "name": "Active" } } });
+    let data = CreateMappingProfileBuilder::default()
+        .name("Summer 2024")
+        .mapping(mapping.clone())
+        .build()?;
+    let id = storage.create_mapping_profile(data, &mut exec_opts).await?;
+
+    let profiles = storage.fetch_mapping_profiles(&mut exec_opts).await?;
+    assert_eq!(profiles.len(), 1);
+    assert_eq!(profiles[0].id, id);
+    assert_eq!(profiles[0].mapping, mapping);
+
+    storage.delete_mapping_profile(id, &mut exec_opts).await?;
+    assert!(storage.fetch_mapping_profile_by_id(id, &mut exec_opts).await?.is_none());
+
+    Ok(())
+}
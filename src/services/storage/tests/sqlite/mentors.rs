@@ -0,0 +1,72 @@
+use anyhow::Result;
+use sqlx::SqlitePool;
+use uuid::uuid;
+
+use crate::services::storage::mentors::{CreateMentorBuilder, QueryMentors};
+use crate::services::storage::nonprofits::{CreateNonprofitBuilder, QueryNonprofits};
+use crate::services::storage::sqlite::SqliteBackend;
+use crate::services::storage::stats::QueryStats;
+use crate::services::storage::types::{
+    ClientSize, ImpactCause, MentorExperienceLevel, MentorYearsExperience, VolunteerHearAbout,
+};
+use crate::services::storage::ExecOptsBuilder;
+
+#[sqlx::test(
+    migrations = "migrations/sqlite",
+    fixtures(path = "../fixtures/sqlite", scripts("setup"))
+)]
+pub async fn test_mentors_and_nonprofits(pool: SqlitePool) -> Result<()> {
+    let storage = SqliteBackend { pool };
+    let project_cycle_id = uuid!("0e12b846-4de5-432e-8137-1bc2c92827b3");
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+
+    let data = CreateMentorBuilder::default()
+        .first_name("Martina")
+        .last_name("Navratilova")
+        .email("martina.navratilova@gmail.com")
+        .phone("777-888-9999")
+        .company("Tennis Channel")
+        .job_title("Commentator")
+        .country("United States".to_string())
+        .us_state("California".to_string())
+        .years_experience(MentorYearsExperience::R21Plus)
+        .experience_level(MentorExperienceLevel::SeniorOrExecutive)
+        .prior_mentor(true)
+        .prior_mentee(true)
+        .prior_student(true)
+        .university(vec!["University of Prague".to_string()])
+        .hear_about(vec![VolunteerHearAbout::OnlineAd])
+        .build()?;
+    let id = storage.create_mentor(project_cycle_id, data, &mut exec_opts).await?;
+
+    let mentor = storage.fetch_mentor_by_id(id, &mut exec_opts).await?.expect("mentor not found");
+    assert_eq!(mentor.university, vec!["University of Prague".to_string()]);
+    assert_eq!(mentor.hear_about, vec![VolunteerHearAbout::OnlineAd]);
+    assert!(mentor.prior_mentor);
+
+    let data = CreateNonprofitBuilder::default()
+        .representative_first_name("Venus")
+        .representative_last_name("Williams")
+        .representative_job_title("Tennis Champion")
+        .email("venus.williams@gmail.com")
+        .phone("989-989-9881")
+        .org_name("Williams Tennis Nonprofit")
+        .project_name("Williams Tennis Website Refresh")
+        .address("Somewhere in LA")
+        .size(ClientSize::S101_500)
+        .impact_causes(vec![ImpactCause::CareerAndProfessionalDevelopment])
+        .build()?;
+    storage.create_nonprofit(project_cycle_id, data, &mut exec_opts).await?;
+
+    let nonprofit = storage
+        .fetch_nonprofit_by_org_name("Williams Tennis Nonprofit", &mut exec_opts)
+        .await?
+        .expect("nonprofit not found");
+    assert_eq!(nonprofit.impact_causes, vec![ImpactCause::CareerAndProfessionalDevelopment]);
+
+    let stats = storage.get_basic_stats(project_cycle_id, &mut exec_opts).await?;
+    assert_eq!(stats.num_mentors, 2);
+    assert_eq!(stats.num_nonprofits, 2);
+
+    Ok(())
+}
@@ -0,0 +1,10 @@
+mod audit;
+mod csv_uploads;
+mod cycles;
+mod jobs;
+mod mapping_profiles;
+mod mentors;
+mod sync;
+mod team_roles;
+mod volunteers;
+mod webhooks;
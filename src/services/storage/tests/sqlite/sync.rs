@@ -0,0 +1,186 @@
+use anyhow::Result;
+use sqlx::SqlitePool;
+use uuid::uuid;
+
+use crate::services::storage::jobs::QueryJobs;
+use crate::services::storage::mentors::{CreateMentorBuilder, QueryMentors};
+use crate::services::storage::nonprofits::{CreateNonprofitBuilder, QueryNonprofits};
+use crate::services::storage::sqlite::SqliteBackend;
+use crate::services::storage::types::{
+    ClientSize, ExternalSource, ImpactCause, Lgbt, MentorExperienceLevel, MentorYearsExperience,
+    StudentStage, SyncDiff, SyncOutcome, VolunteerHearAbout, WriteBackReport,
+};
+use crate::services::storage::volunteers::{CreateVolunteerBuilder, QueryVolunteers};
+use crate::services::storage::{Acquire, ExecOptsBuilder};
+
+#[sqlx::test(
+    migrations = "migrations/sqlite",
+    fixtures(path = "../fixtures/sqlite", scripts("setup"))
+)]
+pub async fn test_sync(pool: SqlitePool) -> Result<()> {
+    let storage = SqliteBackend { pool };
+    let project_cycle_id = uuid!("0e12b846-4de5-432e-8137-1bc2c92827b3");
+    let federer_id = uuid!("9edc52d8-8cc7-4d44-80c1-7efcce246e90");
+    let djokovic_id = uuid!("5e7b3f35-2b84-46e7-8b7b-73e2716d42c9");
+    let pete_org_id = uuid!("bb9b7fa5-7283-4b73-82e1-c7244e47421d");
+    let job_id = uuid!("bc080e0d-8b14-46e0-9268-4bbb370035ec");
+
+    let federer = CreateVolunteerBuilder::default()
+        .first_name("Roger")
+        .last_name("Federer")
+        .email("roger.federer@gmail.com")
+        .lgbt(Lgbt::No)
+        .country("Switzerland".to_owned())
+        .student_stage(StudentStage::RecentGraduate)
+        .majors(vec!["Computer Science".to_owned()])
+        .minors(vec![])
+        .hear_about(vec![VolunteerHearAbout::Linkedin])
+        .build()?;
+    let volunteers = vec![("recFederer".to_owned(), federer)];
+
+    let pete_org = CreateNonprofitBuilder::default()
+        .representative_first_name("Pete")
+        .representative_last_name("Sampras")
+        .representative_job_title("CEO")
+        .email("pete.sampras@gmail.com")
+        .phone("123-456-7895")
+        .org_name("PeteOrg")
+        .project_name("PeteProject")
+        .address("99999 Random Way")
+        .size(ClientSize::S21_50)
+        .impact_causes(vec![ImpactCause::GlobalRelations])
+        .build()?;
+    let nonprofits = vec![("recPeteOrg".to_owned(), pete_org)];
+
+    let mentor = CreateMentorBuilder::default()
+        .first_name("Martina")
+        .last_name("Navratilova")
+        .email("martina.navratilova@gmail.com")
+        .phone("777-888-9999")
+        .company("Tennis Channel")
+        .job_title("Commentator")
+        .country("United States".to_owned())
+        .years_experience(MentorYearsExperience::R21Plus)
+        .experience_level(MentorExperienceLevel::SeniorOrExecutive)
+        .prior_mentor(true)
+        .prior_mentee(false)
+        .prior_student(false)
+        .university(vec![])
+        .hear_about(vec![VolunteerHearAbout::OnlineAd])
+        .build()?;
+    let mentors = vec![("recMartina".to_owned(), mentor)];
+
+    let mut tx = storage.acquire().await?;
+    let mut exec_opts = ExecOptsBuilder::default().tx(&mut tx).build()?;
+
+    let airtable = ExternalSource::Airtable;
+    let synced = storage
+        .batch_sync_volunteers(project_cycle_id, airtable, volunteers.clone(), &mut exec_opts)
+        .await?;
+    assert_eq!(synced, vec![("recFederer".to_owned(), federer_id, SyncOutcome::Updated)]);
+    let synced = storage
+        .batch_sync_volunteers(project_cycle_id, airtable, volunteers, &mut exec_opts)
+        .await?;
+    assert_eq!(synced[0].2, SyncOutcome::Unchanged);
+
+    // Djokovic is in another project cycle, and emails are unique across project cycles
+    let djokovic = CreateVolunteerBuilder::default()
+        .first_name("Novak")
+        .last_name("Djokovic")
+        .email("novak.djokovic@gmail.com")
+        .lgbt(Lgbt::No)
+        .country("Serbia".to_owned())
+        .student_stage(StudentStage::RecentGraduate)
+        .majors(vec![])
+        .minors(vec![])
+        .hear_about(vec![])
+        .build()?;
+    let synced = storage
+        .batch_sync_volunteers(
+            project_cycle_id,
+            airtable,
+            vec![("recDjokovic".to_owned(), djokovic)],
+            &mut exec_opts,
+        )
+        .await?;
+    assert_eq!(synced, vec![("recDjokovic".to_owned(), djokovic_id, SyncOutcome::Conflict)]);
+
+    let synced =
+        storage.batch_sync_nonprofits(project_cycle_id, nonprofits, &mut exec_opts).await?;
+    assert_eq!(synced, vec![("recPeteOrg".to_owned(), pete_org_id, SyncOutcome::Updated)]);
+
+    let synced = storage.batch_sync_mentors(project_cycle_id, mentors, &mut exec_opts).await?;
+    assert_eq!(synced[0].2, SyncOutcome::Created);
+    let mentor_id = synced[0].1;
+
+    let deactivated = storage
+        .deactivate_missing_volunteers(project_cycle_id, vec![federer_id], &mut exec_opts)
+        .await?;
+    assert_eq!(deactivated, 4);
+    let deactivated = storage
+        .deactivate_missing_mentors(project_cycle_id, vec![mentor_id], &mut exec_opts)
+        .await?;
+    assert_eq!(deactivated, 1);
+    let deactivated = storage
+        .deactivate_missing_nonprofits(project_cycle_id, vec![pete_org_id], &mut exec_opts)
+        .await?;
+    assert_eq!(deactivated, 0);
+
+    let changes = storage
+        .sync_volunteer_nonprofit_links(
+            project_cycle_id,
+            vec![(federer_id, pete_org_id)],
+            &mut exec_opts,
+        )
+        .await?;
+    assert_eq!(changes, (0, 1));
+    let changes = storage
+        .sync_volunteer_mentor_links(
+            project_cycle_id,
+            vec![(federer_id, mentor_id)],
+            &mut exec_opts,
+        )
+        .await?;
+    assert_eq!(changes, (1, 2));
+    let changes = storage
+        .sync_mentor_nonprofit_links(
+            project_cycle_id,
+            vec![(mentor_id, pete_org_id)],
+            &mut exec_opts,
+        )
+        .await?;
+    assert_eq!(changes, (1, 1));
+
+    let diff = SyncDiff { links_added: 2, links_removed: 4, ..Default::default() };
+    storage.set_job_diff(job_id, diff, &mut exec_opts).await?;
+
+    let external_ids =
+        storage.fetch_volunteer_external_ids(vec![federer_id], airtable, &mut exec_opts).await?;
+    assert_eq!(external_ids, vec![(federer_id, "recFederer".to_owned())]);
+    let external_ids = storage
+        .fetch_volunteer_external_ids(vec![federer_id], ExternalSource::Csv, &mut exec_opts)
+        .await?;
+    assert!(external_ids.is_empty());
+
+    let report = WriteBackReport { written: 1, failures: vec![] };
+    storage.set_job_write_back(job_id, report, &mut exec_opts).await?;
+
+    tx.commit().await?;
+
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+    let federer = storage
+        .fetch_volunteer_by_id(federer_id, &mut exec_opts)
+        .await?
+        .expect("volunteer not found");
+    assert_eq!(federer.phone, None);
+    assert_eq!(federer.mentors[0]["email"], "martina.navratilova@gmail.com");
+    assert!(federer.active);
+    let volunteers = storage.fetch_volunteers_by_cycle(project_cycle_id, &mut exec_opts).await?;
+    assert_eq!(volunteers.len(), 1);
+
+    let job = storage.fetch_job(job_id, &mut exec_opts).await?.expect("job not found");
+    assert_eq!(job.details["diff"]["linksRemoved"], 4);
+    assert_eq!(job.details["writeBack"]["written"], 1);
+
+    Ok(())
+}
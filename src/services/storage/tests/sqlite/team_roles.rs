@@ -0,0 +1,35 @@
+use anyhow::Result;
+use sqlx::SqlitePool;
+use uuid::uuid;
+
+use crate::services::storage::sqlite::SqliteBackend;
+use crate::services::storage::team_roles::QueryTeamRoles;
+use crate::services::storage::ExecOptsBuilder;
+
+#[sqlx::test(
+    migrations = "migrations/sqlite",
+    fixtures(path = "../fixtures/sqlite", scripts("setup"))
+)]
+pub async fn test_team_roles(pool: SqlitePool) -> Result<()> {
+    let storage = SqliteBackend { pool };
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+    let spring_2024 = uuid!("0e12b846-4de5-432e-8137-1bc2c92827b3");
+    let federer = uuid!("9edc52d8-8cc7-4d44-80c1-7efcce246e90");
+    let murray = uuid!("0ef67e25-543c-4f0d-9a96-8cb71b3c0f60");
+
+    let roles = storage.fetch_team_roles(&mut exec_opts).await?;
+    assert_eq!(roles.len(), 6);
+    let engineer =
+        roles.into_iter().find(|role| role.name == "engineer").expect("engineer role is seeded");
+
+    assert!(storage.assign_team_role(spring_2024, murray, engineer.id, &mut exec_opts).await?);
+    assert!(!storage.assign_team_role(spring_2024, murray, engineer.id, &mut exec_opts).await?);
+
+    let volunteers = storage
+        .fetch_volunteers_by_team_role(engineer.id, Some(spring_2024), &mut exec_opts)
+        .await?;
+    let ids = volunteers.iter().map(|v| v.volunteer_id).collect::<Vec<_>>();
+    assert_eq!(ids, vec![federer, murray]);
+
+    Ok(())
+}
@@ -0,0 +1,116 @@
+use anyhow::Result;
+use sqlx::SqlitePool;
+use uuid::uuid;
+
+use crate::services::storage::pagination::{PageOptionsBuilder, SortDirection};
+use crate::services::storage::sqlite::SqliteBackend;
+use crate::services::storage::types::{
+    AgeRange, Ethnicity, Fli, Gender, Lgbt, StudentStage, VolunteerHearAbout, VolunteerRole,
+};
+use crate::services::storage::volunteers::{
+    CreateVolunteerBuilder, PartialCreateVolunteerBuilder, QueryVolunteers, VolunteerFilterBuilder,
+    VolunteerSortKey,
+};
+use crate::services::storage::{Acquire, ExecOptsBuilder};
+
+#[sqlx::test(
+    migrations = "migrations/sqlite",
+    fixtures(path = "../fixtures/sqlite", scripts("setup"))
+)]
+pub async fn test_volunteers(pool: SqlitePool) -> Result<()> {
+    let storage = SqliteBackend { pool };
+    let project_cycle_id = uuid!("0e12b846-4de5-432e-8137-1bc2c92827b3");
+
+    let data = CreateVolunteerBuilder::default()
+        .first_name("Carlos")
+        .last_name("Alcaraz")
+        .email("carlos.alcaraz@gmail.com")
+        .volunteer_gender(Gender::Man)
+        .volunteer_ethnicity(vec![Ethnicity::LatinoOrHispanic])
+        .volunteer_age_range(AgeRange::R18_24)
+        .university(vec!["University of Murcia".to_owned()])
+        .lgbt(Lgbt::No)
+        .country("Spain".to_owned())
+        .fli(vec![Fli::FirstGeneration])
+        .student_stage(StudentStage::Freshman)
+        .majors(vec!["Computer Science".to_owned()])
+        .minors(vec![])
+        .hear_about(vec![VolunteerHearAbout::Linkedin])
+        .build()?;
+
+    let mut tx = storage.acquire().await?;
+    let mut exec_opts = ExecOptsBuilder::default().tx(&mut tx).build()?;
+    let id = storage.create_volunteer(project_cycle_id, data, &mut exec_opts).await?;
+    tx.commit().await?;
+
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+    let volunteer =
+        storage.fetch_volunteer_by_id(id, &mut exec_opts).await?.expect("volunteer not found");
+    assert_eq!(volunteer.project_cycle_name, "Spring 2024");
+    assert_eq!(volunteer.volunteer_ethnicity, vec![Ethnicity::LatinoOrHispanic]);
+    assert_eq!(volunteer.hear_about, vec![VolunteerHearAbout::Linkedin]);
+
+    let data = PartialCreateVolunteerBuilder::default()
+        .university(vec!["University of Madrid".to_owned()])
+        .phone(None::<String>)
+        .build()?;
+    storage.edit_volunteer(id, data, &mut exec_opts).await?;
+
+    let volunteers = storage.fetch_volunteers_by_ids(vec![id], &mut exec_opts).await?;
+    assert_eq!(volunteers.len(), 1);
+    assert_eq!(volunteers[0].university, vec!["University of Madrid".to_owned()]);
+    assert_eq!(volunteers[0].first_name, "Carlos");
+
+    Ok(())
+}
+
+#[sqlx::test(
+    migrations = "migrations/sqlite",
+    fixtures(path = "../fixtures/sqlite", scripts("setup"))
+)]
+pub async fn test_fetch_volunteers_filtered(pool: SqlitePool) -> Result<()> {
+    let storage = SqliteBackend { pool };
+    let project_cycle_id = uuid!("0e12b846-4de5-432e-8137-1bc2c92827b3");
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+
+    let filter = VolunteerFilterBuilder::default()
+        .project_cycle_id(project_cycle_id)
+        .sort_by(VolunteerSortKey::LastName)
+        .sort_direction(SortDirection::Desc)
+        .build()?;
+    let page = PageOptionsBuilder::default().limit(2).build()?;
+
+    let first = storage.fetch_volunteers_filtered(filter.clone(), page, &mut exec_opts).await?;
+    let last_names = first.items.iter().map(|v| v.last_name.as_str()).collect::<Vec<_>>();
+    assert_eq!(last_names, vec!["Wawrinka", "Thiem"]);
+    let cursor = first.next_cursor.expect("first page should have a next page");
+
+    let page = PageOptionsBuilder::default().cursor(cursor).limit(3).build()?;
+    let second = storage.fetch_volunteers_filtered(filter, page, &mut exec_opts).await?;
+    let last_names = second.items.iter().map(|v| v.last_name.as_str()).collect::<Vec<_>>();
+    assert_eq!(last_names, vec!["Nadal", "Murray", "Federer"]);
+    assert!(second.next_cursor.is_none());
+
+    let filter = VolunteerFilterBuilder::default()
+        .role(VolunteerRole::ProductManager)
+        .search("NADAL@")
+        .build()?;
+    let page = PageOptionsBuilder::default().build()?;
+    let volunteers = storage.fetch_volunteers_filtered(filter, page, &mut exec_opts).await?;
+    assert_eq!(volunteers.items.len(), 1);
+    assert_eq!(volunteers.items[0].first_name, "Rafael");
+
+    let filter = VolunteerFilterBuilder::default()
+        .university("harvard university")
+        .nonprofit_id(uuid!("dce8d3c3-8f2d-4f3e-80d3-c1633f5282e2"))
+        .mentor_id(uuid!("ab839d88-80c5-47f8-835a-1abbe269c7f8"))
+        .student_stage(StudentStage::RecentGraduate)
+        .exported(false)
+        .build()?;
+    let page = PageOptionsBuilder::default().build()?;
+    let volunteers = storage.fetch_volunteers_filtered(filter, page, &mut exec_opts).await?;
+    assert_eq!(volunteers.items.len(), 1);
+    assert_eq!(volunteers.items[0].first_name, "Novak");
+
+    Ok(())
+}
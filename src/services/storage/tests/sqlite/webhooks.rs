@@ -0,0 +1,47 @@
+use anyhow::Result;
+use chrono::{Duration, Utc};
+use sqlx::SqlitePool;
+use uuid::uuid;
+
+use crate::services::storage::sqlite::SqliteBackend;
+use crate::services::storage::webhooks::{CreateWebhookBuilder, QueryWebhooks};
+use crate::services::storage::ExecOptsBuilder;
+
+#[sqlx::test(
+    migrations = "migrations/sqlite",
+    fixtures(path = "../fixtures/sqlite", scripts("setup"))
+)]
+pub async fn test_webhooks(pool: SqlitePool) -> Result<()> {
+    let storage = SqliteBackend { pool };
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+    let cycle_id = uuid!("0e12b846-4de5-432e-8137-1bc2c92827b3");
+
+    let data = CreateWebhookBuilder::default()
+        .base_id("appSpring2024")
+        .webhook_id("achSpring2024")
+        .mac_secret_base64("c2NpcGlvLXRlc3Qtc2VjcmV0")
+        .project_cycle_id(cycle_id)
+        .expiration_time(Some(Utc::now() + Duration::days(7)))
+        .build()?;
+    let id = storage.create_webhook(data, &mut exec_opts).await?;
+
+    storage.update_webhook_cursor(id, 4, &mut exec_opts).await?;
+    let webhook = storage
+        .fetch_webhook_by_airtable_id("achSpring2024".to_owned(), &mut exec_opts)
+        .await?
+        .expect("webhook not found");
+    assert_eq!(webhook.id, id);
+    assert_eq!(webhook.project_cycle_id, cycle_id);
+    assert_eq!(webhook.cursor, 4);
+
+    let refreshed_until = Utc::now() + Duration::days(14);
+    storage.update_webhook_expiration_time(id, Some(refreshed_until), &mut exec_opts).await?;
+    let webhook =
+        storage.fetch_webhook_by_id(id, &mut exec_opts).await?.expect("webhook not found");
+    assert_eq!(webhook.expiration_time.map(|t| t.timestamp()), Some(refreshed_until.timestamp()));
+
+    storage.delete_webhook(id, &mut exec_opts).await?;
+    assert!(storage.fetch_webhooks(&mut exec_opts).await?.is_empty());
+
+    Ok(())
+}
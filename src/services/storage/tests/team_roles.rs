@@ -0,0 +1,86 @@
+use anyhow::Result;
+use sqlx::PgPool;
+use uuid::{uuid, Uuid};
+
+use crate::services::storage::team_roles::{
+    CreateTeamRoleBuilder, EditTeamRoleBuilder, QueryTeamRoles,
+};
+use crate::services::storage::{ExecOptsBuilder, PgBackend};
+
+#[sqlx::test(fixtures("setup"))]
+pub async fn test_manage_team_roles(pool: PgPool) -> Result<()> {
+    let storage = PgBackend { pool };
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+
+    let roles = storage.fetch_team_roles(&mut exec_opts).await?;
+    assert_eq!(roles.len(), 6);
+    assert_eq!(roles[0].name, "design_manager");
+
+    let data = CreateTeamRoleBuilder::default()
+        .name("data_scientist")
+        .description("A data scientist volunteer is assigned to data projects")
+        .build()?;
+    let id = storage.create_team_role(data, &mut exec_opts).await?;
+
+    let data = EditTeamRoleBuilder::default().description("Assigned to data projects").build()?;
+    let role = storage.edit_team_role(id, data, &mut exec_opts).await?.expect("role should exist");
+    assert_eq!(role.name, "data_scientist");
+    assert_eq!(role.description.as_deref(), Some("Assigned to data projects"));
+
+    storage.delete_team_role(id, &mut exec_opts).await?;
+    assert!(storage.fetch_team_role_by_id(id, &mut exec_opts).await?.is_none());
+
+    let data = EditTeamRoleBuilder::default().name("missing").build()?;
+    assert!(storage.edit_team_role(id, data, &mut exec_opts).await?.is_none());
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("setup"))]
+pub async fn test_assign_team_roles(pool: PgPool) -> Result<()> {
+    let storage = PgBackend { pool };
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+    let spring_2024 = uuid!("0e12b846-4de5-432e-8137-1bc2c92827b3");
+    let fall_2024 = uuid!("76ed64a0-d88f-4148-9b02-331ea888d5d1");
+    let federer = uuid!("9edc52d8-8cc7-4d44-80c1-7efcce246e90");
+    let murray = uuid!("0ef67e25-543c-4f0d-9a96-8cb71b3c0f60");
+
+    let engineer = storage
+        .fetch_team_roles(&mut exec_opts)
+        .await?
+        .into_iter()
+        .find(|role| role.name == "engineer")
+        .expect("engineer role should be seeded");
+
+    let volunteers =
+        storage.fetch_volunteers_by_team_role(engineer.id, None, &mut exec_opts).await?;
+    assert_eq!(volunteers.len(), 1);
+    assert_eq!(volunteers[0].volunteer_id, federer);
+
+    assert!(storage.assign_team_role(spring_2024, murray, engineer.id, &mut exec_opts).await?);
+    assert!(!storage.assign_team_role(spring_2024, murray, engineer.id, &mut exec_opts).await?);
+
+    let volunteers = storage
+        .fetch_volunteers_by_team_role(engineer.id, Some(spring_2024), &mut exec_opts)
+        .await?;
+    let names = volunteers.iter().map(|v| v.last_name.as_str()).collect::<Vec<_>>();
+    assert_eq!(names, vec!["Federer", "Murray"]);
+
+    let volunteers =
+        storage.fetch_volunteers_by_team_role(engineer.id, Some(fall_2024), &mut exec_opts).await?;
+    assert!(volunteers.is_empty());
+
+    assert!(storage.unassign_team_role(spring_2024, federer, engineer.id, &mut exec_opts).await?);
+    assert!(!storage.unassign_team_role(spring_2024, federer, engineer.id, &mut exec_opts).await?);
+    // A role which doesn't exist can't be unassigned either
+    assert!(
+        !storage.unassign_team_role(spring_2024, murray, Uuid::new_v4(), &mut exec_opts).await?
+    );
+
+    let volunteers =
+        storage.fetch_volunteers_by_team_role(engineer.id, None, &mut exec_opts).await?;
+    assert_eq!(volunteers.len(), 1);
+    assert_eq!(volunteers[0].volunteer_id, murray);
+
+    Ok(())
+}
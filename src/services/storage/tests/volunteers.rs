@@ -1,13 +1,17 @@
 use anyhow::Result;
+use serde_json::json;
 use sqlx::PgPool;
 use uuid::uuid;
 
+use crate::services::storage::entities::VolunteerDetails;
+use crate::services::storage::pagination::{PageOptionsBuilder, SortDirection};
 use crate::services::storage::types::{
-    AgeRange, Ethnicity, Fli, Gender, Lgbt, StudentStage, VolunteerHearAbout,
+    AgeRange, Ethnicity, ExternalSource, Fli, Gender, Lgbt, StudentStage, SyncOutcome,
+    VolunteerHearAbout, VolunteerRole,
 };
 use crate::services::storage::volunteers::{
-    CreateVolunteerBuilder, EditVolunteerBuilder, InsertVolunteerExportedToWorkspaceBuilder,
-    QueryVolunteers,
+    CreateVolunteer, CreateVolunteerBuilder, InsertVolunteerExportedToWorkspaceBuilder,
+    PartialCreateVolunteerBuilder, QueryVolunteers, VolunteerFilterBuilder, VolunteerSortKey,
 };
 use crate::services::storage::{Acquire, ExecOptsBuilder, PgBackend};
 
@@ -134,7 +138,8 @@ pub async fn test_fetch_volunteer_by_email(pool: PgPool) -> Result<()> {
 pub async fn edit_volunteer(pool: PgPool) -> Result<()> {
     let storage = PgBackend { pool };
     let test_volunteer_id = uuid!("5e7b3f35-2b84-46e7-8b7b-73e2716d42c9");
-    let data = EditVolunteerBuilder::default().email("novak.djokovic@gmail.com").build()?;
+    let data =
+        PartialCreateVolunteerBuilder::default().email("novak.djokovic@gmail.com").build()?;
 
     let mut tx = storage.acquire().await?;
     let mut exec_opts = ExecOptsBuilder::default().tx(&mut tx).build()?;
@@ -143,6 +148,51 @@ pub async fn edit_volunteer(pool: PgPool) -> Result<()> {
     Ok(())
 }
 
+#[sqlx::test(fixtures("setup"))]
+pub async fn test_edit_volunteer_email_conflict(pool: PgPool) -> Result<()> {
+    let storage = PgBackend { pool };
+    let test_volunteer_id = uuid!("5e7b3f35-2b84-46e7-8b7b-73e2716d42c9");
+
+    // Emails are unique across project cycles, so taking Nadal's email is reported as a conflict
+    let data = PartialCreateVolunteerBuilder::default().email("rafael.nadal@gmail.com").build()?;
+    let err = storage
+        .edit_volunteer(test_volunteer_id, data, &mut ExecOptsBuilder::default().build()?)
+        .await
+        .unwrap_err();
+    let err = err.downcast_ref::<sqlx::Error>().and_then(|e| e.as_database_error());
+    assert!(err.is_some_and(|e| e.is_unique_violation()));
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("setup"))]
+pub async fn test_edit_volunteer_partially(pool: PgPool) -> Result<()> {
+    let storage = PgBackend { pool };
+    let test_volunteer_id = uuid!("5e7b3f35-2b84-46e7-8b7b-73e2716d42c9");
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+
+    let data = PartialCreateVolunteerBuilder::default()
+        .student_stage(StudentStage::PhdStudent)
+        .university(vec!["University of Belgrade".to_owned()])
+        .phone(None::<String>)
+        .us_state("California".to_owned())
+        .build()?;
+    storage.edit_volunteer(test_volunteer_id, data, &mut exec_opts).await?;
+
+    let volunteer = storage
+        .fetch_volunteer_by_id(test_volunteer_id, &mut exec_opts)
+        .await?
+        .expect("volunteer not found");
+    assert_eq!(volunteer.student_stage, StudentStage::PhdStudent);
+    assert_eq!(volunteer.university, vec!["University of Belgrade".to_owned()]);
+    assert_eq!(volunteer.phone, None);
+    assert_eq!(volunteer.us_state.as_deref(), Some("California"));
+    assert_eq!(volunteer.first_name, "Novak");
+    assert_eq!(volunteer.email, "novak.djokovic@gmail.com");
+
+    Ok(())
+}
+
 #[sqlx::test(fixtures("setup"))]
 pub async fn test_link_volunteers_to_nonprofits(pool: PgPool) -> Result<()> {
     let storage = PgBackend { pool };
@@ -190,6 +240,156 @@ pub async fn test_link_volunteers_to_mentors(pool: PgPool) -> Result<()> {
     Ok(())
 }
 
+#[sqlx::test(fixtures("setup"))]
+pub async fn test_sync_volunteers(pool: PgPool) -> Result<()> {
+    let storage = PgBackend { pool };
+
+    let project_cycle_id = uuid!("0e12b846-4de5-432e-8137-1bc2c92827b3");
+    let nadal_id = uuid!("1b1b5e16-d0d6-4ad1-8fdc-80df15b18b67");
+    let federer_id = uuid!("9edc52d8-8cc7-4d44-80c1-7efcce246e90");
+    let djokovic_id = uuid!("5e7b3f35-2b84-46e7-8b7b-73e2716d42c9");
+    let client_id = uuid!("bb9b7fa5-7283-4b73-82e1-c7244e47421d");
+    let mentor_id = uuid!("fa8377c8-1c0d-4f4e-9a2b-2c29f2737e0d");
+
+    let federer = CreateVolunteerBuilder::default()
+        .first_name("Roger")
+        .last_name("Federer")
+        .email("roger.federer@gmail.com")
+        .phone(Some("000-000-0000".to_owned()))
+        .volunteer_gender(Gender::Man)
+        .volunteer_ethnicity(vec![Ethnicity::WhiteOrCaucasian])
+        .volunteer_age_range(AgeRange::R40_44)
+        .university(vec!["Stanford University".to_owned()])
+        .lgbt(Lgbt::No)
+        .country("Switzerland".to_owned())
+        .student_stage(StudentStage::RecentGraduate)
+        .majors(vec!["Computer Science".to_owned()])
+        .minors(vec![])
+        .hear_about(vec![VolunteerHearAbout::Linkedin])
+        .build()?;
+
+    let sinner = CreateVolunteerBuilder::default()
+        .first_name("Jannik")
+        .last_name("Sinner")
+        .email("jannik.sinner@gmail.com")
+        .lgbt(Lgbt::No)
+        .country("Italy".to_owned())
+        .student_stage(StudentStage::Freshman)
+        .majors(vec!["Business Administration".to_owned()])
+        .minors(vec![])
+        .hear_about(vec![VolunteerHearAbout::University])
+        .build()?;
+
+    let data =
+        vec![("recFederer".to_owned(), federer.clone()), ("recSinner".to_owned(), sinner.clone())];
+
+    let mut tx = storage.acquire().await?;
+    let mut exec_opts = ExecOptsBuilder::default().tx(&mut tx).build()?;
+
+    let airtable = ExternalSource::Airtable;
+
+    // Federer was imported before external IDs were recorded, so he is matched by email
+    let synced = storage
+        .batch_sync_volunteers(project_cycle_id, airtable, data.clone(), &mut exec_opts)
+        .await?;
+    assert_eq!(synced[0], ("recFederer".to_owned(), federer_id, SyncOutcome::Updated));
+    assert_eq!(synced[1].2, SyncOutcome::Created);
+    let sinner_id = synced[1].1;
+
+    let deactivated = storage
+        .deactivate_missing_volunteers(
+            project_cycle_id,
+            vec![federer_id, sinner_id],
+            &mut exec_opts,
+        )
+        .await?;
+    assert_eq!(deactivated, 4);
+
+    let synced =
+        storage.batch_sync_volunteers(project_cycle_id, airtable, data, &mut exec_opts).await?;
+    assert!(synced.iter().all(|(_, _, outcome)| *outcome == SyncOutcome::Unchanged));
+
+    let mut external_ids = storage
+        .fetch_volunteer_external_ids(
+            vec![federer_id, sinner_id, nadal_id],
+            airtable,
+            &mut exec_opts,
+        )
+        .await?;
+    external_ids.sort();
+    let mut expected =
+        vec![(federer_id, "recFederer".to_owned()), (sinner_id, "recSinner".to_owned())];
+    expected.sort();
+    assert_eq!(external_ids, expected);
+
+    // Volunteers synced from CSV files are keyed by email, so Sinner is matched by email and his
+    // external ID no longer names an Airtable record
+    let csv_key = "jannik.sinner@gmail.com".to_owned();
+    let data = vec![(csv_key.clone(), sinner)];
+    let synced = storage
+        .batch_sync_volunteers(project_cycle_id, ExternalSource::Csv, data, &mut exec_opts)
+        .await?;
+    assert_eq!(synced[0], (csv_key, sinner_id, SyncOutcome::Updated));
+
+    // Djokovic is in another project cycle, and emails are unique across project cycles, so he
+    // can't be synced into this one
+    let djokovic = CreateVolunteer { email: "novak.djokovic@gmail.com".to_owned(), ..federer };
+    let data = vec![("recDjokovic".to_owned(), djokovic)];
+    let synced =
+        storage.batch_sync_volunteers(project_cycle_id, airtable, data, &mut exec_opts).await?;
+    assert_eq!(synced[0], ("recDjokovic".to_owned(), djokovic_id, SyncOutcome::Conflict));
+
+    let external_ids = storage
+        .fetch_volunteer_external_ids(vec![federer_id, sinner_id], airtable, &mut exec_opts)
+        .await?;
+    assert_eq!(external_ids, vec![(federer_id, "recFederer".to_owned())]);
+
+    let linkage = vec![(federer_id, client_id), (sinner_id, client_id)];
+    let changes =
+        storage.sync_volunteer_nonprofit_links(project_cycle_id, linkage, &mut exec_opts).await?;
+    assert_eq!(changes, (1, 1));
+
+    let linkage = vec![(federer_id, mentor_id)];
+    let changes =
+        storage.sync_volunteer_mentor_links(project_cycle_id, linkage, &mut exec_opts).await?;
+    assert_eq!(changes, (0, 1));
+
+    tx.commit().await?;
+
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+    let nadal = storage
+        .fetch_volunteer_by_id(nadal_id, &mut exec_opts)
+        .await?
+        .expect("volunteer not found");
+    assert_eq!(nadal.clients, json!([]));
+    assert_eq!(nadal.mentors, json!([]));
+    assert!(!nadal.active);
+
+    // Inactive volunteers are left out of listings
+    let volunteers = storage.fetch_volunteers_by_cycle(project_cycle_id, &mut exec_opts).await?;
+    assert!(volunteers.iter().all(|v| v.volunteer_id != nadal_id));
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("setup"))]
+pub async fn test_deserialize_volunteer_without_active(pool: PgPool) -> Result<()> {
+    let storage = PgBackend { pool };
+    let volunteer_id = uuid!("9edc52d8-8cc7-4d44-80c1-7efcce246e90");
+    let volunteer = storage
+        .fetch_volunteer_by_id(volunteer_id, &mut ExecOptsBuilder::default().build()?)
+        .await?
+        .expect("volunteer not found");
+
+    // Clients which send volunteers to be exported may leave out whether they are active
+    let mut body = serde_json::to_value(&volunteer)?;
+    body.as_object_mut().expect("volunteer is not an object").remove("active");
+    let volunteer = serde_json::from_value::<VolunteerDetails>(body)?;
+    assert!(volunteer.active);
+
+    Ok(())
+}
+
 #[sqlx::test(fixtures("setup"))]
 pub async fn test_batch_insert_and_remove_volunteers_exported_to_workspace(
     pool: PgPool,
@@ -231,3 +431,56 @@ pub async fn test_batch_insert_and_remove_volunteers_exported_to_workspace(
 
     Ok(())
 }
+
+#[sqlx::test(fixtures("setup"))]
+pub async fn test_fetch_volunteers_filtered(pool: PgPool) -> Result<()> {
+    let storage = PgBackend { pool };
+    let project_cycle_id = uuid!("0e12b846-4de5-432e-8137-1bc2c92827b3");
+    let mut exec_opts = ExecOptsBuilder::default().build()?;
+
+    let filter = VolunteerFilterBuilder::default()
+        .project_cycle_id(project_cycle_id)
+        .sort_by(VolunteerSortKey::LastName)
+        .sort_direction(SortDirection::Desc)
+        .build()?;
+    let page = PageOptionsBuilder::default().limit(2).build()?;
+
+    let first = storage.fetch_volunteers_filtered(filter.clone(), page, &mut exec_opts).await?;
+    let last_names = first.items.iter().map(|v| v.last_name.as_str()).collect::<Vec<_>>();
+    assert_eq!(last_names, vec!["Wawrinka", "Thiem"]);
+    let cursor = first.next_cursor.expect("first page should have a next page");
+
+    let page = PageOptionsBuilder::default().cursor(cursor).limit(3).build()?;
+    let second = storage.fetch_volunteers_filtered(filter, page, &mut exec_opts).await?;
+    let last_names = second.items.iter().map(|v| v.last_name.as_str()).collect::<Vec<_>>();
+    assert_eq!(last_names, vec!["Nadal", "Murray", "Federer"]);
+    assert!(second.next_cursor.is_none());
+
+    let filter = VolunteerFilterBuilder::default()
+        .role(VolunteerRole::ProductManager)
+        .search("NADAL@")
+        .build()?;
+    let page = PageOptionsBuilder::default().build()?;
+    let volunteers = storage.fetch_volunteers_filtered(filter, page, &mut exec_opts).await?;
+    assert_eq!(volunteers.items.len(), 1);
+    assert_eq!(volunteers.items[0].first_name, "Rafael");
+
+    let filter = VolunteerFilterBuilder::default()
+        .university("harvard university")
+        .nonprofit_id(uuid!("dce8d3c3-8f2d-4f3e-80d3-c1633f5282e2"))
+        .mentor_id(uuid!("ab839d88-80c5-47f8-835a-1abbe269c7f8"))
+        .student_stage(StudentStage::RecentGraduate)
+        .exported(false)
+        .build()?;
+    let page = PageOptionsBuilder::default().build()?;
+    let volunteers = storage.fetch_volunteers_filtered(filter, page, &mut exec_opts).await?;
+    assert_eq!(volunteers.items.len(), 1);
+    assert_eq!(volunteers.items[0].first_name, "Novak");
+
+    let filter = VolunteerFilterBuilder::default().exported(true).build()?;
+    let page = PageOptionsBuilder::default().build()?;
+    let volunteers = storage.fetch_volunteers_filtered(filter, page, &mut exec_opts).await?;
+    assert!(volunteers.items.is_empty());
+
+    Ok(())
+}
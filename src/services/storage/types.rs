@@ -2,7 +2,7 @@
 
 use derive_more::Display;
 use serde::{Deserialize, Serialize};
-use sqlx::Type;
+use sqlx::{FromRow, Type};
 use uuid::Uuid;
 
 /// Possible project roles for a volunteer.
@@ -37,6 +37,8 @@ pub enum JobStatus {
     ///
     /// This is the default state for a job at creation and indicates that there is work to be done.
     Pending,
+    /// The job has been claimed by a worker and is running
+    Running,
     /// The job has terminated with an erro
     Error,
     /// The job has completed successfully
@@ -45,6 +47,183 @@ pub enum JobStatus {
     Cancelled,
 }
 
+/// Kinds of records that a job can touch
+#[derive(Debug, Serialize, Deserialize, Type, Copy, Clone, PartialEq, Eq, Hash)]
+#[sqlx(type_name = "job_item_kind", rename_all = "snake_case")]
+#[serde(rename_all = "camelCase")]
+pub enum JobItemKind {
+    Volunteer,
+    Mentor,
+    Nonprofit,
+}
+
+/// Possible outcomes of a job for a single record
+#[derive(Debug, Serialize, Deserialize, Type, Copy, Clone, PartialEq, Eq, Hash)]
+#[sqlx(type_name = "job_item_status", rename_all = "snake_case")]
+#[serde(rename_all = "camelCase")]
+pub enum JobItemStatus {
+    /// The job has not processed the record yet
+    Pending,
+    /// The job processed the record successfully
+    Succeeded,
+    /// The job deliberately did not process the record
+    Skipped,
+    /// The job failed to process the record
+    Failed,
+}
+
+/// How a record synced from an external source compares to the record already stored
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub enum SyncOutcome {
+    /// The record was not stored yet, so it was created
+    Created,
+    /// The stored record was different, so it was updated
+    Updated,
+    /// The stored record was already up to date
+    Unchanged,
+    /// Another record, e.g. one in a different project cycle, already has a value which must be
+    /// unique, so the record was not synced
+    Conflict,
+}
+
+/// Privileged actions which are recorded in the audit log
+///
+/// Actions are stored as text (e.g. `cycle.delete`) so that new actions can be added without a
+/// migration.
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq, Eq, Display)]
+pub enum AuditAction {
+    /// Create a project cycle
+    #[serde(rename = "cycle.create")]
+    #[display("cycle.create")]
+    CreateCycle,
+    /// Edit a project cycle
+    #[serde(rename = "cycle.edit")]
+    #[display("cycle.edit")]
+    EditCycle,
+    /// Delete a project cycle
+    #[serde(rename = "cycle.delete")]
+    #[display("cycle.delete")]
+    DeleteCycle,
+    /// Edit a volunteer
+    #[serde(rename = "volunteer.edit")]
+    #[display("volunteer.edit")]
+    EditVolunteer,
+    /// Delete a volunteer
+    #[serde(rename = "volunteer.delete")]
+    #[display("volunteer.delete")]
+    DeleteVolunteer,
+    /// Edit a mentor
+    #[serde(rename = "mentor.edit")]
+    #[display("mentor.edit")]
+    EditMentor,
+    /// Delete a mentor
+    #[serde(rename = "mentor.delete")]
+    #[display("mentor.delete")]
+    DeleteMentor,
+    /// Edit a nonprofit
+    #[serde(rename = "nonprofit.edit")]
+    #[display("nonprofit.edit")]
+    EditNonprofit,
+    /// Delete a nonprofit
+    #[serde(rename = "nonprofit.delete")]
+    #[display("nonprofit.delete")]
+    DeleteNonprofit,
+    /// Create a team role
+    #[serde(rename = "teamRole.create")]
+    #[display("teamRole.create")]
+    CreateTeamRole,
+    /// Edit a team role
+    #[serde(rename = "teamRole.edit")]
+    #[display("teamRole.edit")]
+    EditTeamRole,
+    /// Delete a team role, unassigning it from every volunteer who holds it
+    #[serde(rename = "teamRole.delete")]
+    #[display("teamRole.delete")]
+    DeleteTeamRole,
+    /// Assign a team role to a volunteer
+    #[serde(rename = "teamRole.assign")]
+    #[display("teamRole.assign")]
+    AssignTeamRole,
+    /// Unassign a team role from a volunteer
+    #[serde(rename = "teamRole.unassign")]
+    #[display("teamRole.unassign")]
+    UnassignTeamRole,
+    /// Queue a job to import a base from Airtable
+    #[serde(rename = "airtable.import")]
+    #[display("airtable.import")]
+    ImportAirtableBase,
+    /// Queue a job to sync a base from Airtable into an existing project cycle
+    #[serde(rename = "airtable.sync")]
+    #[display("airtable.sync")]
+    SyncAirtableBase,
+    /// Save a profile which describes how an Airtable base is laid out
+    #[serde(rename = "airtableMappingProfile.create")]
+    #[display("airtableMappingProfile.create")]
+    CreateMappingProfile,
+    /// Delete an Airtable mapping profile
+    #[serde(rename = "airtableMappingProfile.delete")]
+    #[display("airtableMappingProfile.delete")]
+    DeleteMappingProfile,
+    /// Register a webhook which syncs a base into a project cycle when the base changes
+    #[serde(rename = "airtableWebhook.create")]
+    #[display("airtableWebhook.create")]
+    CreateAirtableWebhook,
+    /// Delete a webhook registered for a base
+    #[serde(rename = "airtableWebhook.delete")]
+    #[display("airtableWebhook.delete")]
+    DeleteAirtableWebhook,
+    /// Queue a job to import CSV files
+    #[serde(rename = "csv.import")]
+    #[display("csv.import")]
+    ImportCsv,
+    /// Queue a job to export volunteers to Workspace
+    #[serde(rename = "workspace.export")]
+    #[display("workspace.export")]
+    ExportToWorkspace,
+    /// Queue a job to undo an export of volunteers to Workspace
+    #[serde(rename = "workspace.undoExport")]
+    #[display("workspace.undoExport")]
+    UndoWorkspaceExport,
+    /// Cancel a pending or running job
+    #[serde(rename = "job.cancel")]
+    #[display("job.cancel")]
+    CancelJob,
+    /// Queue a job to export the volunteers an export job missed
+    #[serde(rename = "job.retry")]
+    #[display("job.retry")]
+    RetryJob,
+    /// Run a queued job to completion. This is recorded by the worker which ran the job, on behalf
+    /// of the user who queued it.
+    #[serde(rename = "job.run")]
+    #[display("job.run")]
+    RunJob,
+}
+
+/// Possible outcomes of an audited action
+#[derive(Debug, Serialize, Deserialize, Type, Copy, Clone, PartialEq, Eq)]
+#[sqlx(type_name = "audit_outcome", rename_all = "snake_case")]
+#[serde(rename_all = "camelCase")]
+pub enum AuditOutcome {
+    /// The action succeeded
+    Succeeded,
+    /// The action failed
+    Failed,
+    /// The action was cancelled before it finished
+    Cancelled,
+}
+
+/// Kinds of external sources records can be imported from
+#[derive(Debug, Serialize, Deserialize, Type, Copy, Clone, PartialEq, Eq)]
+#[sqlx(type_name = "external_source", rename_all = "snake_case")]
+#[serde(rename_all = "camelCase")]
+pub enum ExternalSource {
+    /// An Airtable base. External IDs are the IDs of Airtable records.
+    Airtable,
+    /// Uploaded CSV files. External IDs are emails, or organization names for nonprofits.
+    Csv,
+}
+
 /// Possible destinations for exporting users
 #[derive(Debug, Serialize, Deserialize, Type, Copy, Clone, PartialEq, Eq, Display)]
 #[serde(rename_all = "camelCase")]
@@ -65,6 +244,8 @@ pub enum JobType {
     AirtableExportUsers,
     /// Undo an export of users to Workspace
     UndoWorkspaceExport,
+    /// Import CSV files uploaded by a user
+    CsvImport,
 }
 
 /// Data needed to run a job
@@ -75,33 +256,240 @@ pub enum JobType {
 #[serde(rename_all = "camelCase", untagged)]
 pub enum JobData {
     /// Data we track when we start a job to import a base from Airtable.
+    ///
+    /// `name` and `description` are used for the project cycle created by the import, and
+    /// `principal` is the user who started the job. They are optional so that jobs recorded before
+    /// they were tracked can still be read. If `project_cycle_id` is set, the base is synced into
+    /// that project cycle instead of a new one. If `mapping_profile_id` is set, the base is read
+    /// with that Airtable mapping profile instead of the default mapping.
     AirtableImportBase {
         #[serde(rename = "baseId")]
         base_id: String,
+        #[serde(default)]
+        name: Option<String>,
+        #[serde(default)]
+        description: Option<String>,
+        #[serde(default)]
+        principal: Option<String>,
+        #[serde(rename = "projectCycleId", default)]
+        project_cycle_id: Option<Uuid>,
+        #[serde(rename = "mappingProfileId", default)]
+        mapping_profile_id: Option<Uuid>,
     },
     /// Data we track when we start a job to export users from Airtable to a destination.
+    ///
+    /// `principal`, `volunteer_ids` and `policy` are everything a worker needs to run the export.
+    /// They are optional so that jobs recorded before they were tracked can still be read.
+    /// `retry_of` is the ID of the export job this job retries, if it is a retry. If
+    /// `airtable_write_back` is set, the Workspace email of each exported volunteer is written
+    /// back to their Airtable record.
     AirtableExportUsers {
         #[serde(rename = "exportDestination")]
         export_destination: ExportDesination,
+        #[serde(default)]
+        principal: Option<String>,
+        #[serde(rename = "volunteerIds", default)]
+        volunteer_ids: Vec<Uuid>,
+        #[serde(default)]
+        policy: Option<WorkspaceExportPolicy>,
+        #[serde(rename = "retryOf", default)]
+        retry_of: Option<Uuid>,
+        #[serde(rename = "airtableWriteBack", default)]
+        airtable_write_back: Option<AirtableWriteBack>,
     },
     /// Data we track when we start a job to undo an export of users to Workspace.
-    UndoWorkspaceExport { volunteers: Vec<(Uuid, String)> },
+    ///
+    /// `volunteers` pairs the ID of each volunteer with their Workspace email. `principal` is the
+    /// user who started the job, and `export_job_id` is the export being undone, if a whole export
+    /// is being undone.
+    UndoWorkspaceExport {
+        volunteers: Vec<(Uuid, String)>,
+        #[serde(default)]
+        principal: Option<String>,
+        #[serde(rename = "exportJobId", default)]
+        export_job_id: Option<Uuid>,
+    },
+    /// Data we track when we start a job to import CSV files.
+    ///
+    /// `upload_id` refers to the uploaded files, which are stored apart from the job since they
+    /// hold personal information, and deleted once the job has run. `name` and `description` are
+    /// used for the project cycle created by the import, unless `project_cycle_id` is set, in which
+    /// case the files are synced into that project cycle instead.
+    CsvImport {
+        #[serde(rename = "csvUploadId")]
+        upload_id: Uuid,
+        #[serde(default)]
+        name: Option<String>,
+        #[serde(default)]
+        description: Option<String>,
+        #[serde(default)]
+        principal: Option<String>,
+        #[serde(rename = "projectCycleId", default)]
+        project_cycle_id: Option<Uuid>,
+    },
+}
+
+impl JobData {
+    /// The user who started the job, if it is known.
+    pub fn principal(&self) -> Option<&str> {
+        match self {
+            JobData::AirtableImportBase { principal, .. }
+            | JobData::AirtableExportUsers { principal, .. }
+            | JobData::UndoWorkspaceExport { principal, .. }
+            | JobData::CsvImport { principal, .. } => principal.as_deref(),
+        }
+    }
+}
+
+/// The contents of the CSV files uploaded for an import, one for each kind of record. A file which
+/// wasn't uploaded is empty.
+#[derive(Debug, Default, Serialize, Deserialize, FromRow, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CsvImportFiles {
+    #[serde(default)]
+    pub volunteers: String,
+    #[serde(default)]
+    pub mentors: String,
+    #[serde(default)]
+    pub nonprofits: String,
+}
+
+/// Options for generating Workspace accounts when exporting volunteers.
+///
+/// * `add_unique_numeric_suffix`: Whether to add a unique 2-digit numeric suffix to the email
+///   handle
+/// * `change_password_at_next_login`: Whether to force users to change their password at their
+///   next login
+/// * `generated_password_length`: The length of the generated password
+/// * `separator`: The separator to use between the first and last names in the email handle
+/// * `use_first_and_last_name`: Whether to use the first and last names for the email handle
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceExportPolicy {
+    pub add_unique_numeric_suffix: bool,
+    pub change_password_at_next_login: bool,
+    pub generated_password_length: u8,
+    pub separator: Option<String>,
+    pub use_first_and_last_name: bool,
+}
+
+/// Where to write the Workspace emails of exported volunteers back to in Airtable.
+///
+/// Volunteers are matched to their Airtable record by the ID of the record they were imported
+/// from.
+///
+/// * `base_id`: The ID of the base the volunteers were imported from
+/// * `table`: The name of the table the volunteers are in
+/// * `workspace_email_field`: The field to write the Workspace email to
+/// * `exported_at_field`: The field to write the time of the export to, if any
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AirtableWriteBack {
+    pub base_id: String,
+    #[serde(default = "AirtableWriteBack::default_table")]
+    pub table: String,
+    #[serde(default = "AirtableWriteBack::default_workspace_email_field")]
+    pub workspace_email_field: String,
+    #[serde(default = "AirtableWriteBack::default_exported_at_field")]
+    pub exported_at_field: Option<String>,
+}
+
+impl AirtableWriteBack {
+    fn default_table() -> String {
+        "Volunteers".to_owned()
+    }
+
+    fn default_workspace_email_field() -> String {
+        "WorkspaceEmail".to_owned()
+    }
+
+    fn default_exported_at_field() -> Option<String> {
+        Some("WorkspaceExportedAt".to_owned())
+    }
+}
+
+/// A volunteer whose Workspace email could not be written back to Airtable.
+///
+/// * `volunteer_id`: The ID of the volunteer
+/// * `workspace_email`: The Workspace email which was not written
+/// * `error`: Why it was not written
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WriteBackFailure {
+    pub volunteer_id: Uuid,
+    pub workspace_email: String,
+    pub error: String,
+}
+
+/// The outcome of writing Workspace emails back to Airtable after an export.
+///
+/// * `written`: The number of volunteers whose Airtable record was updated
+/// * `failures`: The volunteers whose Airtable record was not updated
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WriteBackReport {
+    pub written: u64,
+    pub failures: Vec<WriteBackFailure>,
 }
 
 /// Details about a job
 ///
 /// * `job_type`: The type of the job
 /// * `error`: An error message if the job failed (otherwise this is `None`)
+/// * `summary`: A summary of what the job did, if it was cut short (e.g. by being cancelled)
+/// * `diff`: What an import changed, once it has finished
+/// * `write_back`: What an export wrote back to Airtable, once it has finished
 /// * `data`: Job metadata
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct JobDetails {
     pub job_type: JobType,
     pub error: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub diff: Option<SyncDiff>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub write_back: Option<WriteBackReport>,
     #[serde(flatten)]
     pub data: JobData,
 }
 
+/// How many records of one kind an import changed.
+///
+/// * `created`: Records which were created
+/// * `updated`: Records which were updated
+/// * `unchanged`: Records which were already up to date
+/// * `deactivated`: Records which were marked inactive because they were removed from the source
+/// * `failed`: Records which could not be synced because they conflict with another record
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncCounts {
+    pub created: u64,
+    pub updated: u64,
+    pub unchanged: u64,
+    pub deactivated: u64,
+    #[serde(default)]
+    pub failed: u64,
+}
+
+/// What an import changed in a project cycle.
+///
+/// * `volunteers`: Changes to volunteers
+/// * `mentors`: Changes to mentors
+/// * `nonprofits`: Changes to nonprofits
+/// * `links_added`: Links between volunteers, mentors, and nonprofits which were added
+/// * `links_removed`: Links between volunteers, mentors, and nonprofits which were removed
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncDiff {
+    pub volunteers: SyncCounts,
+    pub mentors: SyncCounts,
+    pub nonprofits: SyncCounts,
+    pub links_added: u64,
+    pub links_removed: u64,
+}
+
 /// Possible age ranges for volunteers
 ///
 /// These are the age ranges that volunteers can select when signing up through Airtable.
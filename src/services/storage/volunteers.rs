@@ -4,13 +4,18 @@
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use derive_builder::Builder;
+use scipio_macros::Partial;
 use serde::{Deserialize, Serialize};
-use sqlx::{Database, Postgres, QueryBuilder, Transaction};
+use sqlx::{Database, Postgres, QueryBuilder, Row, Transaction};
 use uuid::Uuid;
 
 use super::entities::{ExportedVolunteerDetails, VolunteerDetails};
 use super::exec_with_tx;
-use super::types::{AgeRange, Ethnicity, Fli, Gender, Lgbt, StudentStage, VolunteerHearAbout};
+use super::pagination::{escape_like, Page, PageOptions, SortDirection};
+use super::types::{
+    AgeRange, Ethnicity, ExternalSource, Fli, Gender, Lgbt, StudentStage, SyncOutcome,
+    VolunteerHearAbout, VolunteerRole,
+};
 use crate::services::storage::{Acquire, ExecOpts, PgBackend};
 
 /// Create a new volunteer.
@@ -23,8 +28,9 @@ use crate::services::storage::{Acquire, ExecOpts, PgBackend};
 /// * `volunteer_gender`: The gneder of the volunteer
 /// * `volunteer_ethnicity`: The ethnicity of the volunteer
 /// * `volunteer_age_range`: The age range of the volunteer
-#[derive(Debug, Builder, Serialize, Deserialize, Clone)]
+#[derive(Debug, Builder, Serialize, Deserialize, Clone, Partial)]
 #[serde(rename_all = "PascalCase")]
+#[partial(derive(Debug, Clone, Default, Builder), builder(default, setter(into, strip_option)))]
 pub struct CreateVolunteer {
     #[builder(setter(into))]
     pub first_name: String,
@@ -59,16 +65,9 @@ pub struct CreateVolunteer {
 
 /// Edit a volunteer.
 ///
-/// * `email`: The new email of the volunteer (if they change their personal email)
-/// * `phone`: The new phone number of the volunteer
-/// * `offer_letter_signature`: The new status of the volunteer's offer letter signature
-#[derive(Builder)]
-pub struct EditVolunteer {
-    #[builder(setter(into))]
-    pub email: String,
-    #[builder(setter(into), default = "None")]
-    pub phone: Option<String>,
-}
+/// Any field of `CreateVolunteer` can be edited. Fields which are `None` are left unchanged. The
+/// nullable fields (`phone` and `us_state`) are cleared when they are `Some(None)`.
+pub type EditVolunteer = PartialCreateVolunteer;
 
 /// Record a volunteer as exported to a workspace.
 ///
@@ -87,6 +86,70 @@ pub struct InsertVolunteerExportedToWorkspace {
     pub org_unit: String,
 }
 
+/// Fields volunteers can be sorted by.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum VolunteerSortKey {
+    #[default]
+    CreatedAt,
+    FirstName,
+    LastName,
+    Email,
+}
+
+impl VolunteerSortKey {
+    /// The column this key sorts by. It is a column of both `volunteers` and `volunteer_details`.
+    pub(super) fn column(&self) -> &'static str {
+        match self {
+            VolunteerSortKey::CreatedAt => "created_at",
+            VolunteerSortKey::FirstName => "first_name",
+            VolunteerSortKey::LastName => "last_name",
+            VolunteerSortKey::Email => "email",
+        }
+    }
+}
+
+/// Filter and sort volunteers.
+///
+/// Every filter is optional, and only volunteers matching all of the filters which are set are
+/// returned.
+///
+/// * `project_cycle_id`: Only volunteers in this project cycle
+/// * `nonprofit_id`: Only volunteers working with this nonprofit
+/// * `mentor_id`: Only volunteers mentored by this mentor
+/// * `role`: Only volunteers with this team role
+/// * `exported`: Only volunteers who have (or have not) been exported to a workspace
+/// * `university`: Only volunteers who attend this university (case-insensitive)
+/// * `student_stage`: Only volunteers at this student stage
+/// * `search`: Only volunteers whose name, email, or workspace email contains this text
+///   (case-insensitive)
+/// * `sort_by`: The field to sort by
+/// * `sort_direction`: The direction to sort in
+/// * `include_inactive`: Also return volunteers which a sync marked inactive
+#[derive(Debug, Clone, Default, Builder)]
+#[builder(default)]
+pub struct VolunteerFilter {
+    #[builder(setter(into, strip_option))]
+    pub project_cycle_id: Option<Uuid>,
+    #[builder(setter(into, strip_option))]
+    pub nonprofit_id: Option<Uuid>,
+    #[builder(setter(into, strip_option))]
+    pub mentor_id: Option<Uuid>,
+    #[builder(setter(into, strip_option))]
+    pub role: Option<VolunteerRole>,
+    #[builder(setter(into, strip_option))]
+    pub exported: Option<bool>,
+    #[builder(setter(into, strip_option))]
+    pub university: Option<String>,
+    #[builder(setter(into, strip_option))]
+    pub student_stage: Option<StudentStage>,
+    #[builder(setter(into, strip_option))]
+    pub search: Option<String>,
+    pub sort_by: VolunteerSortKey,
+    pub sort_direction: SortDirection,
+    pub include_inactive: bool,
+}
+
 /// A trait for querying data about volunteers.
 ///
 /// If you implement a new storage backend, this trait is required for it to implement
@@ -126,8 +189,7 @@ pub trait QueryVolunteers<DB: Database> {
     ///
     /// * `exec_opts`: Execution options for the query
     ///
-    /// This function should be deprecated in favor of a version that takes filtering and pagination options.
-    // TODO: Add a new function that fetches volunteers with filtering and pagination options
+    /// Prefer `fetch_volunteers_filtered`, which fetches volunteers a page at a time.
     async fn fetch_volunteers(
         &self,
         exec_opts: &mut ExecOpts<DB>,
@@ -140,10 +202,7 @@ pub trait QueryVolunteers<DB: Database> {
     /// * `project_cycle_id`: The ID of the project cycle to fetch volunteers for
     /// * `exec_opts`: Execution options for the query
     ///
-    /// This function should be deprecated in favor of a version of `fetch_volunteers` that takes filtering and
-    /// pagination options.
-    // TODO: Deprecate this function after a generic version of `fetch_volunteers` with filtering
-    // and pagination options is implemented
+    /// Prefer `fetch_volunteers_filtered`, which fetches volunteers a page at a time.
     async fn fetch_volunteers_by_cycle(
         &self,
         project_cycle_id: Uuid,
@@ -152,6 +211,20 @@ pub trait QueryVolunteers<DB: Database> {
         unimplemented!()
     }
 
+    /// Fetch a page of volunteers matching a filter.
+    ///
+    /// * `filter`: Which volunteers to fetch, and how to sort them
+    /// * `page`: Which page of volunteers to fetch
+    /// * `exec_opts`: Execution options for the query
+    async fn fetch_volunteers_filtered(
+        &self,
+        filter: VolunteerFilter,
+        page: PageOptions,
+        exec_opts: &mut ExecOpts<DB>,
+    ) -> Result<Page<VolunteerDetails>> {
+        unimplemented!()
+    }
+
     /// Fetch a volunteer by ID.
     ///
     /// * `id`: The ID of the volunteer to fetch
@@ -176,6 +249,37 @@ pub trait QueryVolunteers<DB: Database> {
         unimplemented!()
     }
 
+    /// Fetch volunteers by ID.
+    ///
+    /// IDs which do not match a volunteer are ignored.
+    ///
+    /// * `ids`: The IDs of the volunteers to fetch
+    /// * `exec_opts`: Execution options for the query
+    async fn fetch_volunteers_by_ids(
+        &self,
+        ids: Vec<Uuid>,
+        exec_opts: &mut ExecOpts<DB>,
+    ) -> Result<Vec<VolunteerDetails>> {
+        unimplemented!()
+    }
+
+    /// Fetch the external IDs of volunteers which were imported from a kind of external source.
+    ///
+    /// Returns the ID of each volunteer paired with its external ID. Volunteers which weren't
+    /// imported from that kind of source are left out.
+    ///
+    /// * `ids`: The IDs of the volunteers
+    /// * `source`: The kind of source the volunteers were imported from
+    /// * `exec_opts`: Execution options for the query
+    async fn fetch_volunteer_external_ids(
+        &self,
+        ids: Vec<Uuid>,
+        source: ExternalSource,
+        exec_opts: &mut ExecOpts<DB>,
+    ) -> Result<Vec<(Uuid, String)>> {
+        unimplemented!()
+    }
+
     /// Edit a volunteer.
     ///
     /// * `id`: The ID of the volunteer to edit
@@ -230,6 +334,94 @@ pub trait QueryVolunteers<DB: Database> {
         unimplemented!()
     }
 
+    /// Create or update volunteers synced from an external source (e.g. Airtable).
+    ///
+    /// Volunteers in the project cycle are matched by their external ID, or else by their email
+    /// (e.g. if they were imported before external IDs were recorded, or from another source).
+    /// Matched volunteers are updated and marked active. Returns the ID of each volunteer and how
+    /// it was synced, keyed by external ID.
+    ///
+    /// A volunteer whose email belongs to another volunteer, e.g. one in a different project
+    /// cycle, is not synced. Its outcome is `Conflict`, along with the ID of the other volunteer.
+    ///
+    /// * `project_cycle_id`: The ID of the project cycle to sync the volunteers into
+    /// * `source`: The kind of source the volunteers are synced from
+    /// * `data`: The volunteers to sync, keyed by external ID
+    /// * `exec_opts`: Execution options for the query
+    async fn batch_sync_volunteers(
+        &self,
+        project_cycle_id: Uuid,
+        source: ExternalSource,
+        data: Vec<(String, CreateVolunteer)>,
+        exec_opts: &mut ExecOpts<DB>,
+    ) -> Result<Vec<(String, Uuid, SyncOutcome)>> {
+        unimplemented!()
+    }
+
+    /// Mark the active volunteers in a project cycle which are not in `keep` as inactive.
+    ///
+    /// Returns the number of volunteers marked inactive.
+    ///
+    /// * `project_cycle_id`: The ID of the project cycle
+    /// * `keep`: The IDs of the volunteers to leave active
+    /// * `exec_opts`: Execution options for the query
+    async fn deactivate_missing_volunteers(
+        &self,
+        project_cycle_id: Uuid,
+        keep: Vec<Uuid>,
+        exec_opts: &mut ExecOpts<DB>,
+    ) -> Result<u64> {
+        unimplemented!()
+    }
+
+    /// Make the links between volunteers and nonprofits in a project cycle match `linkage`.
+    ///
+    /// Links which are missing are added, and links which are not in `linkage` are removed.
+    /// Returns the number of links added and removed.
+    ///
+    /// * `project_cycle_id`: The ID of the project cycle
+    /// * `linkage`: The linkage, represented as a vector of 2-tuples mapping volunteer IDs to
+    ///   nonprofit IDs
+    /// * `exec_opts`: Execution options for the query
+    async fn sync_volunteer_nonprofit_links(
+        &self,
+        project_cycle_id: Uuid,
+        linkage: Vec<(Uuid, Uuid)>,
+        exec_opts: &mut ExecOpts<DB>,
+    ) -> Result<(u64, u64)> {
+        unimplemented!()
+    }
+
+    /// Make the links between volunteers and mentors in a project cycle match `linkage`.
+    ///
+    /// Links which are missing are added, and links which are not in `linkage` are removed.
+    /// Returns the number of links added and removed.
+    ///
+    /// * `project_cycle_id`: The ID of the project cycle
+    /// * `linkage`: The linkage, represented as a vector of 2-tuples mapping volunteer IDs to
+    ///   mentor IDs
+    /// * `exec_opts`: Execution options for the query
+    async fn sync_volunteer_mentor_links(
+        &self,
+        project_cycle_id: Uuid,
+        linkage: Vec<(Uuid, Uuid)>,
+        exec_opts: &mut ExecOpts<DB>,
+    ) -> Result<(u64, u64)> {
+        unimplemented!()
+    }
+
+    /// Record a volunteer as exported to a workspace.
+    ///
+    /// * `data`: Data required to record the volunteer as exported to a workspace
+    /// * `exec_opts`: Execution options for the query
+    async fn insert_volunteer_exported_to_workspace(
+        &self,
+        data: InsertVolunteerExportedToWorkspace,
+        exec_opts: &mut ExecOpts<DB>,
+    ) -> Result<()> {
+        unimplemented!()
+    }
+
     /// Batch record volunteers as exported to a workspace.
     ///
     /// * `data`: Data required to record the volunteers as exported to a workspace
@@ -426,6 +618,162 @@ impl QueryVolunteers<Postgres> for PgBackend {
         exec_with_tx!(self, exec_opts, exec, project_cycle_id)
     }
 
+    async fn fetch_volunteers_filtered(
+        &self,
+        filter: VolunteerFilter,
+        page: PageOptions,
+        exec_opts: &mut ExecOpts<Postgres>,
+    ) -> Result<Page<VolunteerDetails>> {
+        async fn exec(
+            filter: VolunteerFilter,
+            page: PageOptions,
+            tx: &mut Transaction<'_, Postgres>,
+        ) -> Result<Page<VolunteerDetails>> {
+            let fragment =
+                include_str!("queries/volunteers/fetch_volunteers_filtered.fragment.sql");
+            let mut query = QueryBuilder::<Postgres>::new(fragment);
+
+            if !filter.include_inactive {
+                query.push(" and v.active");
+            }
+
+            if let Some(project_cycle_id) = filter.project_cycle_id {
+                query.push(" and v.project_cycle_id = ").push_bind(project_cycle_id);
+            }
+
+            if let Some(nonprofit_id) = filter.nonprofit_id {
+                query
+                    .push(" and exists (select 1 from client_volunteers cv where cv.volunteer_id = v.volunteer_id and cv.client_id = ")
+                    .push_bind(nonprofit_id)
+                    .push(")");
+            }
+
+            if let Some(mentor_id) = filter.mentor_id {
+                query
+                    .push(" and exists (select 1 from volunteer_mentors vm where vm.volunteer_id = v.volunteer_id and vm.mentor_id = ")
+                    .push_bind(mentor_id)
+                    .push(")");
+            }
+
+            if let Some(role) = filter.role {
+                query
+                    .push(" and exists (select 1 from volunteer_team_roles vtr join team_roles tr on vtr.role_id = tr.id where vtr.volunteer_id = v.volunteer_id and tr.name = ")
+                    .push_bind(role.to_string())
+                    .push(")");
+            }
+
+            match filter.exported {
+                Some(true) => {
+                    query.push(" and v.workspace_email is not null");
+                }
+                Some(false) => {
+                    query.push(" and v.workspace_email is null");
+                }
+                None => {}
+            }
+
+            if let Some(university) = filter.university {
+                query
+                    .push(
+                        " and exists (select 1 from unnest(v.university) u where lower(u) = lower(",
+                    )
+                    .push_bind(university)
+                    .push("))");
+            }
+
+            if let Some(student_stage) = filter.student_stage {
+                query.push(" and v.student_stage = ").push_bind(student_stage);
+            }
+
+            if let Some(search) = filter.search {
+                let pattern = format!("%{}%", escape_like(&search));
+                query
+                    .push(" and ((v.first_name || ' ' || v.last_name) ilike ")
+                    .push_bind(pattern.clone())
+                    .push(" or v.email ilike ")
+                    .push_bind(pattern.clone())
+                    .push(" or v.workspace_email ilike ")
+                    .push_bind(pattern)
+                    .push(")");
+            }
+
+            let column = filter.sort_by.column();
+            let direction = filter.sort_direction;
+
+            if let Some(cursor) = page.cursor {
+                query
+                    .push(format!(
+                        " and (v.{column}, v.volunteer_id) {} (select {column}, id from volunteers where id = ",
+                        direction.comparison(),
+                    ))
+                    .push_bind(cursor)
+                    .push(")");
+            }
+
+            query
+                .push(format!(
+                    " order by v.{column} {0}, v.volunteer_id {0} limit ",
+                    direction.keyword()
+                ))
+                .push_bind(page.limit + 1);
+
+            let volunteers = query
+                .build_query_as::<VolunteerDetails>()
+                .fetch_all(&mut **tx)
+                .await
+                .context("error fetching filtered volunteers")?;
+
+            Ok(Page::from_rows(volunteers, page.limit, |v| v.volunteer_id))
+        }
+
+        exec_with_tx!(self, exec_opts, exec, filter, page)
+    }
+
+    async fn fetch_volunteers_by_ids(
+        &self,
+        ids: Vec<Uuid>,
+        exec_opts: &mut ExecOpts<Postgres>,
+    ) -> Result<Vec<VolunteerDetails>> {
+        async fn exec(
+            ids: Vec<Uuid>,
+            tx: &mut Transaction<'_, Postgres>,
+        ) -> Result<Vec<VolunteerDetails>> {
+            let query = include_str!("queries/volunteers/fetch_volunteers_by_ids.sql");
+            let volunteers = sqlx::query_as::<_, VolunteerDetails>(query)
+                .bind(ids)
+                .fetch_all(&mut **tx)
+                .await
+                .context("error fetching volunteers by ids")?;
+            Ok(volunteers)
+        }
+
+        exec_with_tx!(self, exec_opts, exec, ids)
+    }
+
+    async fn fetch_volunteer_external_ids(
+        &self,
+        ids: Vec<Uuid>,
+        source: ExternalSource,
+        exec_opts: &mut ExecOpts<Postgres>,
+    ) -> Result<Vec<(Uuid, String)>> {
+        async fn exec(
+            ids: Vec<Uuid>,
+            source: ExternalSource,
+            tx: &mut Transaction<'_, Postgres>,
+        ) -> Result<Vec<(Uuid, String)>> {
+            let query = include_str!("queries/volunteers/fetch_volunteer_external_ids.sql");
+            let external_ids = sqlx::query_as::<_, (Uuid, String)>(query)
+                .bind(ids)
+                .bind(source)
+                .fetch_all(&mut **tx)
+                .await
+                .context("error fetching volunteer external ids")?;
+            Ok(external_ids)
+        }
+
+        exec_with_tx!(self, exec_opts, exec, ids, source)
+    }
+
     async fn edit_volunteer(
         &self,
         id: Uuid,
@@ -440,8 +788,24 @@ impl QueryVolunteers<Postgres> for PgBackend {
             let query = include_str!("queries/volunteers/edit_volunteer.sql");
             sqlx::query(query)
                 .bind(id)
+                .bind(data.first_name)
+                .bind(data.last_name)
                 .bind(data.email)
-                .bind(data.phone)
+                .bind(data.phone.is_some())
+                .bind(data.phone.flatten())
+                .bind(data.volunteer_gender)
+                .bind(data.volunteer_ethnicity)
+                .bind(data.volunteer_age_range)
+                .bind(data.university)
+                .bind(data.lgbt)
+                .bind(data.country)
+                .bind(data.us_state.is_some())
+                .bind(data.us_state.flatten())
+                .bind(data.fli)
+                .bind(data.student_stage)
+                .bind(data.majors)
+                .bind(data.minors)
+                .bind(data.hear_about)
                 .execute(&mut **tx)
                 .await
                 .context("error editing volunteer")?;
@@ -527,6 +891,249 @@ impl QueryVolunteers<Postgres> for PgBackend {
         exec_with_tx!(self, exec_opts, exec, project_cycle_id, linkage)
     }
 
+    async fn batch_sync_volunteers(
+        &self,
+        project_cycle_id: Uuid,
+        source: ExternalSource,
+        data: Vec<(String, CreateVolunteer)>,
+        exec_opts: &mut ExecOpts<Postgres>,
+    ) -> Result<Vec<(String, Uuid, SyncOutcome)>> {
+        async fn exec(
+            project_cycle_id: Uuid,
+            source: ExternalSource,
+            data: Vec<(String, CreateVolunteer)>,
+            tx: &mut Transaction<'_, Postgres>,
+        ) -> Result<Vec<(String, Uuid, SyncOutcome)>> {
+            let fetch_id = include_str!("queries/volunteers/fetch_synced_volunteer_id.sql");
+            let fetch_id_by_email =
+                include_str!("queries/volunteers/fetch_volunteer_id_by_email.sql");
+            let create = include_str!("queries/volunteers/create_synced_volunteer.sql");
+            let sync = include_str!("queries/volunteers/sync_volunteer.sql");
+
+            let mut synced = Vec::with_capacity(data.len());
+
+            for (external_id, v) in data {
+                let id = sqlx::query_scalar::<_, Uuid>(fetch_id)
+                    .bind(project_cycle_id)
+                    .bind(&external_id)
+                    .bind(&v.email)
+                    .fetch_optional(&mut **tx)
+                    .await?;
+
+                // Emails are unique across project cycles, so a volunteer whose email belongs to
+                // another volunteer, e.g. one in a different project cycle, can't be synced
+                let holder = sqlx::query_scalar::<_, Uuid>(fetch_id_by_email)
+                    .bind(&v.email)
+                    .fetch_optional(&mut **tx)
+                    .await?;
+                if let Some(holder) = holder.filter(|holder| Some(*holder) != id) {
+                    synced.push((external_id, holder, SyncOutcome::Conflict));
+                    continue;
+                }
+
+                // Both queries take the same parameters, except that the first is the ID of the
+                // volunteer to update or the project cycle to create the volunteer in
+                let (query, id_or_cycle) = match id {
+                    Some(id) => (sqlx::query(sync), id),
+                    None => (sqlx::query(create), project_cycle_id),
+                };
+
+                let query = query
+                    .bind(id_or_cycle)
+                    .bind(&external_id)
+                    .bind(v.first_name)
+                    .bind(v.last_name)
+                    .bind(&v.email)
+                    .bind(v.phone)
+                    .bind(v.volunteer_gender)
+                    .bind(v.volunteer_ethnicity)
+                    .bind(v.volunteer_age_range)
+                    .bind(v.university)
+                    .bind(v.lgbt)
+                    .bind(v.country)
+                    .bind(v.us_state)
+                    .bind(v.fli)
+                    .bind(v.student_stage)
+                    .bind(v.majors)
+                    .bind(v.minors)
+                    .bind(v.hear_about)
+                    .bind(source);
+
+                let (id, outcome) = match id {
+                    Some(id) => {
+                        let res = query
+                            .execute(&mut **tx)
+                            .await
+                            .with_context(|| format!("error syncing volunteer {}", v.email))?;
+                        match res.rows_affected() {
+                            0 => (id, SyncOutcome::Unchanged),
+                            _ => (id, SyncOutcome::Updated),
+                        }
+                    }
+                    None => {
+                        let id = query
+                            .fetch_one(&mut **tx)
+                            .await
+                            .with_context(|| format!("error creating volunteer {}", v.email))?
+                            .try_get("id")?;
+                        (id, SyncOutcome::Created)
+                    }
+                };
+
+                synced.push((external_id, id, outcome));
+            }
+
+            Ok(synced)
+        }
+
+        exec_with_tx!(self, exec_opts, exec, project_cycle_id, source, data)
+    }
+
+    async fn deactivate_missing_volunteers(
+        &self,
+        project_cycle_id: Uuid,
+        keep: Vec<Uuid>,
+        exec_opts: &mut ExecOpts<Postgres>,
+    ) -> Result<u64> {
+        async fn exec(
+            project_cycle_id: Uuid,
+            keep: Vec<Uuid>,
+            tx: &mut Transaction<'_, Postgres>,
+        ) -> Result<u64> {
+            let query = include_str!("queries/volunteers/deactivate_missing_volunteers.sql");
+            let res =
+                sqlx::query(query).bind(project_cycle_id).bind(keep).execute(&mut **tx).await?;
+            Ok(res.rows_affected())
+        }
+
+        exec_with_tx!(self, exec_opts, exec, project_cycle_id, keep)
+    }
+
+    async fn sync_volunteer_nonprofit_links(
+        &self,
+        project_cycle_id: Uuid,
+        linkage: Vec<(Uuid, Uuid)>,
+        exec_opts: &mut ExecOpts<Postgres>,
+    ) -> Result<(u64, u64)> {
+        async fn exec(
+            project_cycle_id: Uuid,
+            linkage: Vec<(Uuid, Uuid)>,
+            tx: &mut Transaction<'_, Postgres>,
+        ) -> Result<(u64, u64)> {
+            let (volunteer_ids, nonprofit_ids): (Vec<Uuid>, Vec<Uuid>) =
+                linkage.iter().copied().unzip();
+
+            let query =
+                include_str!("queries/volunteers/unlink_missing_volunteers_from_nonprofits.sql");
+            let removed = sqlx::query(query)
+                .bind(project_cycle_id)
+                .bind(volunteer_ids)
+                .bind(nonprofit_ids)
+                .execute(&mut **tx)
+                .await?
+                .rows_affected();
+
+            if linkage.is_empty() {
+                return Ok((0, removed));
+            }
+
+            let fragment =
+                include_str!("queries/volunteers/link_volunteers_to_nonprofits.fragment.sql");
+            let added = QueryBuilder::<Postgres>::new(fragment)
+                .push_values(linkage, |mut b, (volunteer_id, nonprofit_id)| {
+                    b.push_bind(project_cycle_id)
+                        .push_bind(volunteer_id)
+                        .push_bind(nonprofit_id)
+                        .push_bind(true);
+                })
+                .push(
+                    " on conflict (volunteer_id, client_id, project_cycle_id) do update set \
+                     currently_active = true where not client_volunteers.currently_active",
+                )
+                .build()
+                .execute(&mut **tx)
+                .await
+                .context("error linking volunteers to nonprofits")?
+                .rows_affected();
+
+            Ok((added, removed))
+        }
+
+        exec_with_tx!(self, exec_opts, exec, project_cycle_id, linkage)
+    }
+
+    async fn sync_volunteer_mentor_links(
+        &self,
+        project_cycle_id: Uuid,
+        linkage: Vec<(Uuid, Uuid)>,
+        exec_opts: &mut ExecOpts<Postgres>,
+    ) -> Result<(u64, u64)> {
+        async fn exec(
+            project_cycle_id: Uuid,
+            linkage: Vec<(Uuid, Uuid)>,
+            tx: &mut Transaction<'_, Postgres>,
+        ) -> Result<(u64, u64)> {
+            let (volunteer_ids, mentor_ids): (Vec<Uuid>, Vec<Uuid>) =
+                linkage.iter().copied().unzip();
+
+            let query =
+                include_str!("queries/volunteers/unlink_missing_volunteers_from_mentors.sql");
+            let removed = sqlx::query(query)
+                .bind(project_cycle_id)
+                .bind(volunteer_ids)
+                .bind(mentor_ids)
+                .execute(&mut **tx)
+                .await?
+                .rows_affected();
+
+            if linkage.is_empty() {
+                return Ok((0, removed));
+            }
+
+            let fragment =
+                include_str!("queries/volunteers/link_volunteers_to_mentors.fragment.sql");
+            let added = QueryBuilder::<Postgres>::new(fragment)
+                .push_values(linkage, |mut b, (volunteer_id, mentor_id)| {
+                    b.push_bind(project_cycle_id).push_bind(volunteer_id).push_bind(mentor_id);
+                })
+                .push(" on conflict do nothing")
+                .build()
+                .execute(&mut **tx)
+                .await
+                .context("error linking volunteers to mentors")?
+                .rows_affected();
+
+            Ok((added, removed))
+        }
+
+        exec_with_tx!(self, exec_opts, exec, project_cycle_id, linkage)
+    }
+
+    async fn insert_volunteer_exported_to_workspace(
+        &self,
+        data: InsertVolunteerExportedToWorkspace,
+        exec_opts: &mut ExecOpts<Postgres>,
+    ) -> Result<()> {
+        async fn exec(
+            data: InsertVolunteerExportedToWorkspace,
+            tx: &mut Transaction<'_, Postgres>,
+        ) -> Result<()> {
+            let query =
+                include_str!("queries/volunteers/insert_volunteer_exported_to_workspace.sql");
+            sqlx::query(query)
+                .bind(data.volunteer_id)
+                .bind(data.job_id)
+                .bind(data.workspace_email)
+                .bind(data.org_unit)
+                .execute(&mut **tx)
+                .await
+                .context("error recording volunteer as exported to workspace")?;
+            Ok(())
+        }
+
+        exec_with_tx!(self, exec_opts, exec, data)
+    }
+
     async fn batch_insert_volunteers_exported_to_workspace(
         &self,
         data: Vec<InsertVolunteerExportedToWorkspace>,
@@ -0,0 +1,272 @@
+//! This module contains the definition of the `QueryWebhooks` trait as well as the default
+//! implementation of the trait for the `PgBackend` struct.
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+use sqlx::{Database, Postgres, Transaction};
+use uuid::Uuid;
+
+use super::exec_with_tx;
+use crate::services::storage::entities::AirtableWebhook;
+use crate::services::storage::{Acquire, ExecOpts, PgBackend};
+
+/// Data needed to store a webhook which was registered on an Airtable base.
+///
+/// * `base_id`: The ID of the base the webhook is registered on
+/// * `webhook_id`: The ID Airtable gave the webhook. It must be unique.
+/// * `mac_secret_base64`: The secret Airtable signs notifications with
+/// * `project_cycle_id`: The project cycle to sync when the base changes
+/// * `mapping_profile_id`: The profile to read the base with, if it isn't laid out the default way
+/// * `cursor`: The number of the first payload to read
+/// * `expiration_time`: When the webhook expires, unless it is refreshed
+#[derive(Debug, Builder, Clone)]
+pub struct CreateWebhook {
+    #[builder(setter(into))]
+    pub base_id: String,
+    #[builder(setter(into))]
+    pub webhook_id: String,
+    #[builder(setter(into))]
+    pub mac_secret_base64: String,
+    pub project_cycle_id: Uuid,
+    #[builder(default)]
+    pub mapping_profile_id: Option<Uuid>,
+    #[builder(default = "1")]
+    pub cursor: i64,
+    #[builder(default)]
+    pub expiration_time: Option<DateTime<Utc>>,
+}
+
+/// A trait for querying the webhooks registered on Airtable bases.
+///
+/// If you implement a new storage backend, this trait is required for it to implement
+/// `StorageLayer`. The default implementation is for `Postgres`.
+#[async_trait]
+#[allow(unused)]
+pub trait QueryWebhooks<DB: Database> {
+    /// Store a new webhook.
+    ///
+    /// * `data`: Data required to store the webhook
+    /// * `exec_opts`: Execution options for the query
+    async fn create_webhook(
+        &self,
+        data: CreateWebhook,
+        exec_opts: &mut ExecOpts<DB>,
+    ) -> Result<Uuid> {
+        unimplemented!()
+    }
+
+    /// Fetch all webhooks, oldest first.
+    ///
+    /// * `exec_opts`: Execution options for the query
+    async fn fetch_webhooks(&self, exec_opts: &mut ExecOpts<DB>) -> Result<Vec<AirtableWebhook>> {
+        unimplemented!()
+    }
+
+    /// Fetch a webhook by ID.
+    ///
+    /// * `id`: The ID of the webhook to fetch
+    /// * `exec_opts`: Execution options for the query
+    async fn fetch_webhook_by_id(
+        &self,
+        id: Uuid,
+        exec_opts: &mut ExecOpts<DB>,
+    ) -> Result<Option<AirtableWebhook>> {
+        unimplemented!()
+    }
+
+    /// Fetch a webhook by the ID Airtable gave it.
+    ///
+    /// * `webhook_id`: The Airtable ID of the webhook to fetch
+    /// * `exec_opts`: Execution options for the query
+    async fn fetch_webhook_by_airtable_id(
+        &self,
+        webhook_id: String,
+        exec_opts: &mut ExecOpts<DB>,
+    ) -> Result<Option<AirtableWebhook>> {
+        unimplemented!()
+    }
+
+    /// Move a webhook's cursor past the payloads which have been read.
+    ///
+    /// * `id`: The ID of the webhook
+    /// * `cursor`: The number of the first payload which hasn't been read
+    /// * `exec_opts`: Execution options for the query
+    async fn update_webhook_cursor(
+        &self,
+        id: Uuid,
+        cursor: i64,
+        exec_opts: &mut ExecOpts<DB>,
+    ) -> Result<()> {
+        unimplemented!()
+    }
+
+    /// Record when a webhook expires, after it was refreshed.
+    ///
+    /// * `id`: The ID of the webhook
+    /// * `expiration_time`: When the webhook now expires
+    /// * `exec_opts`: Execution options for the query
+    async fn update_webhook_expiration_time(
+        &self,
+        id: Uuid,
+        expiration_time: Option<DateTime<Utc>>,
+        exec_opts: &mut ExecOpts<DB>,
+    ) -> Result<()> {
+        unimplemented!()
+    }
+
+    /// Delete a webhook by ID.
+    ///
+    /// * `id`: The ID of the webhook to delete
+    /// * `exec_opts`: Execution options for the query
+    async fn delete_webhook(&self, id: Uuid, exec_opts: &mut ExecOpts<DB>) -> Result<()> {
+        unimplemented!()
+    }
+}
+
+#[async_trait]
+impl QueryWebhooks<Postgres> for PgBackend {
+    async fn create_webhook(
+        &self,
+        data: CreateWebhook,
+        exec_opts: &mut ExecOpts<Postgres>,
+    ) -> Result<Uuid> {
+        async fn exec(data: CreateWebhook, tx: &mut Transaction<'_, Postgres>) -> Result<Uuid> {
+            let query = include_str!("queries/webhooks/create_webhook.sql");
+
+            let id = sqlx::query_scalar::<_, Uuid>(query)
+                .bind(data.base_id)
+                .bind(data.webhook_id)
+                .bind(data.mac_secret_base64)
+                .bind(data.project_cycle_id)
+                .bind(data.mapping_profile_id)
+                .bind(data.cursor)
+                .bind(data.expiration_time)
+                .fetch_one(&mut **tx)
+                .await?;
+            Ok(id)
+        }
+
+        exec_with_tx!(self, exec_opts, exec, data)
+    }
+
+    async fn fetch_webhooks(
+        &self,
+        exec_opts: &mut ExecOpts<Postgres>,
+    ) -> Result<Vec<AirtableWebhook>> {
+        async fn exec(tx: &mut Transaction<'_, Postgres>) -> Result<Vec<AirtableWebhook>> {
+            let query = include_str!("queries/webhooks/fetch_webhooks.sql");
+
+            let webhooks = sqlx::query_as::<_, AirtableWebhook>(query)
+                .fetch_all(&mut **tx)
+                .await
+                .context("error fetching webhooks")?;
+            Ok(webhooks)
+        }
+
+        exec_with_tx!(self, exec_opts, exec)
+    }
+
+    async fn fetch_webhook_by_id(
+        &self,
+        id: Uuid,
+        exec_opts: &mut ExecOpts<Postgres>,
+    ) -> Result<Option<AirtableWebhook>> {
+        async fn exec(
+            id: Uuid,
+            tx: &mut Transaction<'_, Postgres>,
+        ) -> Result<Option<AirtableWebhook>> {
+            let query = include_str!("queries/webhooks/fetch_webhook_by_id.sql");
+
+            let webhook = sqlx::query_as::<_, AirtableWebhook>(query)
+                .bind(id)
+                .fetch_optional(&mut **tx)
+                .await
+                .context("error fetching webhook by id")?;
+            Ok(webhook)
+        }
+
+        exec_with_tx!(self, exec_opts, exec, id)
+    }
+
+    async fn fetch_webhook_by_airtable_id(
+        &self,
+        webhook_id: String,
+        exec_opts: &mut ExecOpts<Postgres>,
+    ) -> Result<Option<AirtableWebhook>> {
+        async fn exec(
+            webhook_id: String,
+            tx: &mut Transaction<'_, Postgres>,
+        ) -> Result<Option<AirtableWebhook>> {
+            let query = include_str!("queries/webhooks/fetch_webhook_by_airtable_id.sql");
+
+            let webhook = sqlx::query_as::<_, AirtableWebhook>(query)
+                .bind(webhook_id)
+                .fetch_optional(&mut **tx)
+                .await
+                .context("error fetching webhook by airtable id")?;
+            Ok(webhook)
+        }
+
+        exec_with_tx!(self, exec_opts, exec, webhook_id)
+    }
+
+    async fn update_webhook_cursor(
+        &self,
+        id: Uuid,
+        cursor: i64,
+        exec_opts: &mut ExecOpts<Postgres>,
+    ) -> Result<()> {
+        async fn exec(id: Uuid, cursor: i64, tx: &mut Transaction<'_, Postgres>) -> Result<()> {
+            let query = include_str!("queries/webhooks/update_webhook_cursor.sql");
+            sqlx::query(query)
+                .bind(id)
+                .bind(cursor)
+                .execute(&mut **tx)
+                .await
+                .context("error updating webhook cursor")?;
+            Ok(())
+        }
+
+        exec_with_tx!(self, exec_opts, exec, id, cursor)
+    }
+
+    async fn update_webhook_expiration_time(
+        &self,
+        id: Uuid,
+        expiration_time: Option<DateTime<Utc>>,
+        exec_opts: &mut ExecOpts<Postgres>,
+    ) -> Result<()> {
+        async fn exec(
+            id: Uuid,
+            expiration_time: Option<DateTime<Utc>>,
+            tx: &mut Transaction<'_, Postgres>,
+        ) -> Result<()> {
+            let query = include_str!("queries/webhooks/update_webhook_expiration_time.sql");
+            sqlx::query(query)
+                .bind(id)
+                .bind(expiration_time)
+                .execute(&mut **tx)
+                .await
+                .context("error updating webhook expiration time")?;
+            Ok(())
+        }
+
+        exec_with_tx!(self, exec_opts, exec, id, expiration_time)
+    }
+
+    async fn delete_webhook(&self, id: Uuid, exec_opts: &mut ExecOpts<Postgres>) -> Result<()> {
+        async fn exec(id: Uuid, tx: &mut Transaction<'_, Postgres>) -> Result<()> {
+            let query = include_str!("queries/webhooks/delete_webhook.sql");
+            sqlx::query(query)
+                .bind(id)
+                .execute(&mut **tx)
+                .await
+                .context("error deleting webhook")?;
+            Ok(())
+        }
+
+        exec_with_tx!(self, exec_opts, exec, id)
+    }
+}